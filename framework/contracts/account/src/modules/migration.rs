@@ -1,7 +1,8 @@
 use abstract_std::{
     account::state::{CALLING_TO_AS_ADMIN, CALLING_TO_AS_ADMIN_WILD_CARD},
     adapter::{
-        AdapterBaseMsg, AuthorizedAddressesResponse, BaseQueryMsg, QueryMsg as AdapterQuery,
+        AdapterBaseMsg, AuthorizedAddressesResponse, AuthorizedScopesResponse, BaseQueryMsg,
+        QueryMsg as AdapterQuery,
     },
     native_addrs,
     objects::{
@@ -238,7 +239,7 @@ pub(crate) fn build_module_migrate_msg(
 }
 
 /// Replaces the current adapter with a different version
-/// Also moves all the authorized address permissions to the new contract and removes them from the old
+/// Also moves all the authorized address permissions, including their scopes, to the new contract and removes them from the old
 pub fn replace_adapter(
     deps: DepsMut,
     env: &Env,
@@ -271,10 +272,31 @@ pub fn replace_adapter(
     msgs.push(configure_adapter(
         &new_adapter_addr,
         AdapterBaseMsg::UpdateAuthorizedAddresses {
-            to_add: authorized_to_migrate,
+            to_add: authorized_to_migrate.clone(),
             to_remove: vec![],
         },
     )?);
+    // Keep scoped authorized addresses restricted on the new adapter.
+    // Adapters that predate authorized scopes fail this query, their addresses are unscoped.
+    for address in authorized_to_migrate {
+        let scopes = deps
+            .querier
+            .query_wasm_smart::<AuthorizedScopesResponse>(
+                old_adapter_addr.to_string(),
+                &<AdapterQuery<Empty>>::Base(BaseQueryMsg::AuthorizedScopes {
+                    account_address: env.contract.address.to_string(),
+                    address: address.clone(),
+                }),
+            )
+            .ok()
+            .and_then(|response| response.scopes);
+        if scopes.is_some() {
+            msgs.push(configure_adapter(
+                &new_adapter_addr,
+                AdapterBaseMsg::UpdateAuthorizedScopes { address, scopes },
+            )?);
+        }
+    }
     // Replace adapter permissions from old to new address to account
     _update_whitelisted_modules(deps.storage, vec![new_adapter_addr], vec![old_adapter_addr])?;
 
@@ -2,33 +2,42 @@ use abstract_sdk::{
     feature_objects::{AnsHost, RegistryContract},
     features::AccountIdentification,
     namespaces::BASE_STATE,
-    HookMemoBuilder, ModuleRegistryInterface, Resolve,
+    HookMemoBuilder, ModuleRegistryInterface, PfmMemoBuilder, Resolve,
 };
 use abstract_std::{
     account::{self, ModuleInstallConfig},
     app::AppState,
-    ibc::{polytone_callbacks::CallbackRequest, Callback, ModuleQuery},
+    ibc::{
+        polytone_callbacks::{Callback as PolytoneCallback, CallbackRequest},
+        Callback, IbcResponseMsg, IbcResult, ModuleQuery,
+    },
     ibc_client::{
         state::{
-            AccountCallbackPayload, IbcInfrastructure, ACCOUNTS, IBC_INFRA,
-            ICS20_ACCOUNT_CALLBACK_PAYLOAD, REVERSE_POLYTONE_NOTE,
+            AccountCallbackPayload, AccountsMigration, IbcInfrastructure, MultihopOrigin,
+            MultihopRoute, ACCOUNTS, ACCOUNTS_MIGRATIONS, IBC_INFRA,
+            ICS20_ACCOUNT_CALLBACK_PAYLOAD, MODULE_IBC_SEQUENCES, MULTIHOP_ROUTES,
+            MULTIHOP_ROUTE_SEQUENCE, REVERSE_POLYTONE_NOTE,
         },
-        IbcClientCallback, InstalledModuleIdentification, PolytoneNoteExecuteMsg,
+        IbcClientCallback, InstalledModuleIdentification, MultihopAction, MultihopPacket,
+        MultihopRegisteredAccount, PolytoneNoteExecuteMsg,
     },
-    ibc_host::{self, HostAction, InternalAction},
+    ibc_host::{self, module_ibc_source_key, HostAction, InternalAction},
     native_addrs,
     objects::{
-        module::ModuleInfo, module_reference::ModuleReference, AccountId, ChannelEntry,
-        TruncatedChainId,
+        account::{AccountTrace, MAX_TRACE_LENGTH},
+        module::ModuleInfo,
+        module_reference::ModuleReference,
+        AccountId, ChannelEntry, TruncatedChainId,
     },
     registry::Account,
-    IBC_CLIENT, ICS20,
+    ABSTRACT_VERSION, IBC_CLIENT, IBC_HOST, ICS20,
 };
 use cosmwasm_std::{
-    ensure, to_json_binary, wasm_execute, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
-    IbcMsg, MessageInfo, QueryRequest, SubMsg, WasmQuery,
+    ensure, to_json_binary, wasm_execute, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
+    IbcMsg, MessageInfo, Order, QueryRequest, StdResult, SubMsg, WasmMsg, WasmQuery,
 };
-use cw_storage_plus::Item;
+use cw_storage_plus::{Bound, Item};
+use std::collections::BTreeSet;
 
 use crate::{
     contract::{IbcClientResponse, IbcClientResult, SEND_FUNDS_WITH_ACTIONS_REPLY_ID},
@@ -38,6 +47,9 @@ use crate::{
 /// Packet lifetime in seconds
 pub const PACKET_LIFETIME: u64 = 60 * 60;
 
+const DEFAULT_MIGRATION_LIMIT: u32 = 10;
+const MAX_MIGRATION_LIMIT: u32 = 50;
+
 /// Registers a chain to the client.
 /// This registration includes the counterparty information (note and proxy address)
 pub fn execute_register_infrastructure(
@@ -70,9 +82,14 @@ pub fn execute_register_infrastructure(
     )?;
     REVERSE_POLYTONE_NOTE.save(deps.storage, &note, &host_chain)?;
 
-    // When registering a new chain host, we need to get the remote proxy address of the local note.
-    // We do so by calling an empty message on the polytone note. This will come back in form of a execute by callback
+    let note_proxy_msg = remote_proxy_request(&env, note)?;
+
+    Ok(IbcClientResponse::action("allow_chain_port").add_message(note_proxy_msg))
+}
 
+/// When registering a new chain host, we need to get the remote proxy address of the local note.
+/// We do so by calling an empty message on the polytone note. This will come back in form of a execute by callback
+fn remote_proxy_request(env: &Env, note: Addr) -> IbcClientResult<WasmMsg> {
     let note_proxy_msg = wasm_execute(
         note,
         &PolytoneNoteExecuteMsg::Execute {
@@ -85,8 +102,158 @@ pub fn execute_register_infrastructure(
         },
         vec![],
     )?;
+    Ok(note_proxy_msg)
+}
 
-    Ok(IbcClientResponse::action("allow_chain_port").add_message(note_proxy_msg))
+/// Re-points the infrastructure of a registered chain to a new note and/or host.
+/// Renaming the chain starts a migration of its remote-account records.
+pub fn execute_update_infrastructure(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    chain: TruncatedChainId,
+    new_chain: Option<TruncatedChainId>,
+    note: Option<String>,
+    host: Option<String>,
+) -> IbcClientResult {
+    chain.verify()?;
+
+    // auth check
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut ibc_infra = IBC_INFRA
+        .may_load(deps.storage, &chain)?
+        .ok_or_else(|| IbcClientError::UnregisteredChain(chain.to_string()))?;
+
+    if let Some(host) = host {
+        ibc_infra.remote_abstract_host = host;
+    }
+
+    let new_note = note.map(|note| deps.api.addr_validate(&note)).transpose()?;
+    if let Some(note) = &new_note {
+        // Can't allow if it's used by another chain
+        if REVERSE_POLYTONE_NOTE.has(deps.storage, note) {
+            return Err(IbcClientError::HostAddressExists {});
+        }
+        REVERSE_POLYTONE_NOTE.remove(deps.storage, &ibc_infra.polytone_note);
+        ibc_infra.polytone_note = note.clone();
+        // The new note has a different remote proxy
+        ibc_infra.remote_proxy = None;
+    }
+
+    let registered_chain = match new_chain {
+        Some(new_chain) => {
+            new_chain.verify()?;
+            if IBC_INFRA.has(deps.storage, &new_chain) {
+                return Err(IbcClientError::HostAddressExists {});
+            }
+            // Records of an earlier rename have to be moved first,
+            // including records that are still moving to this chain
+            if ACCOUNTS_MIGRATIONS.has(deps.storage, &chain) {
+                return Err(IbcClientError::PendingAccountsMigration(chain.to_string()));
+            }
+            let pending_to_chain = ACCOUNTS_MIGRATIONS
+                .range(deps.storage, None, None, Order::Ascending)
+                .find(|migration| {
+                    migration
+                        .as_ref()
+                        .map_or(true, |(_, migration)| migration.to_chain == chain)
+                })
+                .transpose()?;
+            if let Some((from_chain, _)) = pending_to_chain {
+                return Err(IbcClientError::PendingAccountsMigration(
+                    from_chain.to_string(),
+                ));
+            }
+            IBC_INFRA.remove(deps.storage, &chain);
+            ACCOUNTS_MIGRATIONS.save(
+                deps.storage,
+                &chain,
+                &AccountsMigration {
+                    to_chain: new_chain.clone(),
+                    cursor: None,
+                },
+            )?;
+            new_chain
+        }
+        None => chain.clone(),
+    };
+
+    IBC_INFRA.save(deps.storage, &registered_chain, &ibc_infra)?;
+    REVERSE_POLYTONE_NOTE.save(deps.storage, &ibc_infra.polytone_note, &registered_chain)?;
+
+    let mut response = IbcClientResponse::new(
+        "update_infrastructure",
+        vec![
+            ("chain", chain.to_string()),
+            ("registered_chain", registered_chain.to_string()),
+        ],
+    );
+    if let Some(note) = new_note {
+        response = response.add_message(remote_proxy_request(&env, note)?);
+    }
+    Ok(response)
+}
+
+/// Moves the remote-account records of a renamed chain to its new name.
+/// At most `limit` records are scanned per call to bound the gas usage.
+pub fn execute_migrate_remote_accounts(
+    deps: DepsMut,
+    info: MessageInfo,
+    chain: TruncatedChainId,
+    limit: Option<u32>,
+) -> IbcClientResult {
+    // auth check
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut migration = ACCOUNTS_MIGRATIONS
+        .may_load(deps.storage, &chain)?
+        .ok_or_else(|| IbcClientError::NoAccountsMigration(chain.to_string()))?;
+    let limit = limit
+        .unwrap_or(DEFAULT_MIGRATION_LIMIT)
+        .min(MAX_MIGRATION_LIMIT) as usize;
+
+    let start = migration
+        .cursor
+        .as_ref()
+        .map(|(trace, seq, chain)| Bound::exclusive((trace, *seq, chain)));
+    let keys = ACCOUNTS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut moved = 0;
+    for (trace, seq, record_chain) in keys.iter() {
+        if record_chain != &chain {
+            continue;
+        }
+        let remote_account = ACCOUNTS.load(deps.storage, (trace, *seq, record_chain))?;
+        ACCOUNTS.remove(deps.storage, (trace, *seq, record_chain));
+        ACCOUNTS.save(
+            deps.storage,
+            (trace, *seq, &migration.to_chain),
+            &remote_account,
+        )?;
+        moved += 1;
+    }
+
+    let finished = keys.len() < limit;
+    if finished {
+        ACCOUNTS_MIGRATIONS.remove(deps.storage, &chain);
+    } else {
+        migration.cursor = keys.last().cloned();
+        ACCOUNTS_MIGRATIONS.save(deps.storage, &chain, &migration)?;
+    }
+
+    Ok(IbcClientResponse::new(
+        "migrate_remote_accounts",
+        vec![
+            ("chain", chain.to_string()),
+            ("to_chain", migration.to_chain.to_string()),
+            ("moved", moved.to_string()),
+            ("finished", finished.to_string()),
+        ],
+    ))
 }
 
 // allows admins to clear host if needed
@@ -192,6 +359,7 @@ pub fn execute_send_module_to_module_packet(
     target_module: ModuleInfo,
     msg: Binary,
     callback: Option<Callback>,
+    sequence: Option<u64>,
 ) -> IbcClientResult {
     host_chain.verify()?;
 
@@ -200,10 +368,90 @@ pub fn execute_send_module_to_module_packet(
 
     let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
 
+    let source_module = source_module(deps.as_ref(), &registry, &info.sender)?;
+
+    send_module_to_module_packet(
+        deps,
+        env,
+        info,
+        host_chain,
+        source_module,
+        target_module,
+        msg,
+        callback,
+        sequence,
+    )
+}
+
+/// Sends the packet of an already identified source module
+#[allow(clippy::too_many_arguments)]
+fn send_module_to_module_packet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    host_chain: TruncatedChainId,
+    source_module: InstalledModuleIdentification,
+    target_module: ModuleInfo,
+    msg: Binary,
+    callback: Option<Callback>,
+    sequence: Option<u64>,
+) -> IbcClientResult {
+    // We send a message to the target module on the remote chain
+    // Send this message via the Polytone implementation
+
+    let callback_request = callback.map(|c| CallbackRequest {
+        receiver: env.contract.address.to_string(),
+        msg: to_json_binary(&IbcClientCallback::ModuleRemoteAction {
+            sender_address: info.sender.to_string(),
+            callback: c,
+            initiator_msg: msg.clone(),
+        })
+        .unwrap(),
+    });
+    let ibc_infra = IBC_INFRA.load(deps.storage, &host_chain)?;
+    let note_contract = ibc_infra.polytone_note;
+    let remote_ibc_host = ibc_infra.remote_abstract_host;
+
+    // message that will be called on the local note contract
+    let note_message = wasm_execute(
+        note_contract.to_string(),
+        &PolytoneNoteExecuteMsg::Execute {
+            msgs: vec![wasm_execute(
+                // The note's remote proxy will call the ibc host
+                remote_ibc_host,
+                &ibc_host::ExecuteMsg::ModuleExecute {
+                    msg,
+                    source_module,
+                    target_module,
+                    sequence,
+                },
+                vec![],
+            )?
+            .into()],
+            callback: callback_request,
+            timeout_seconds: PACKET_LIFETIME.into(),
+        },
+        vec![],
+    )?;
+    let mut response =
+        IbcClientResponse::action("handle_send_module_to_module_packet").add_message(note_message);
+    if let Some(sequence) = sequence {
+        response = response.add_attribute("sequence", sequence.to_string());
+    }
+    Ok(response)
+}
+
+/// Identifies the module that sends a module-to-module packet.
+/// Apps are identified with their account, which needs the ibc-client installed.
+fn source_module(
+    deps: Deps,
+    registry: &RegistryContract,
+    sender: &Addr,
+) -> IbcClientResult<InstalledModuleIdentification> {
     // Query the sender module information
     let module_info = registry
-        .module_registry(deps.as_ref())?
-        .module_info(info.sender.clone())?;
+        .module_registry(deps)?
+        .module_info(sender.clone())?;
 
     // We need additional information depending on the module type
     let source_module = match module_info.reference {
@@ -218,7 +466,7 @@ pub fn execute_send_module_to_module_packet(
         ModuleReference::App(_) => {
             // We verify the associated account id
             let account = Item::<AppState>::new(BASE_STATE)
-                .query(&deps.querier, info.sender.clone())?
+                .query(&deps.querier, sender.clone())?
                 .account;
             let account_id = registry.account_id(account.addr(), &deps.querier)?;
             let account = registry.account(&account_id, &deps.querier)?;
@@ -244,44 +492,68 @@ pub fn execute_send_module_to_module_packet(
             "This module type didn't exist when implementing module-to-module interactions"
         ),
     };
+    Ok(source_module)
+}
 
-    // We send a message to the target module on the remote chain
-    // Send this message via the Polytone implementation
+/// Sends a module-to-module packet over the ordered channel of the (sender, target module) pair.
+/// A new sequence is assigned unless an already sent sequence is re-sent.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_send_ordered_module_to_module_packet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    host_chain: TruncatedChainId,
+    target_module: ModuleInfo,
+    msg: Binary,
+    resend_sequence: Option<u64>,
+    callback: Option<Callback>,
+) -> IbcClientResult {
+    host_chain.verify()?;
 
-    let callback_request = callback.map(|c| CallbackRequest {
-        receiver: env.contract.address.to_string(),
-        msg: to_json_binary(&IbcClientCallback::ModuleRemoteAction {
-            sender_address: info.sender.to_string(),
-            callback: c,
-            initiator_msg: msg.clone(),
-        })
-        .unwrap(),
-    });
-    let ibc_infra = IBC_INFRA.load(deps.storage, &host_chain)?;
-    let note_contract = ibc_infra.polytone_note;
-    let remote_ibc_host = ibc_infra.remote_abstract_host;
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
 
-    // message that will be called on the local note contract
-    let note_message = wasm_execute(
-        note_contract.to_string(),
-        &PolytoneNoteExecuteMsg::Execute {
-            msgs: vec![wasm_execute(
-                // The note's remote proxy will call the ibc host
-                remote_ibc_host,
-                &ibc_host::ExecuteMsg::ModuleExecute {
-                    msg,
-                    source_module,
-                    target_module,
-                },
-                vec![],
-            )?
-            .into()],
-            callback: callback_request,
-            timeout_seconds: PACKET_LIFETIME.into(),
-        },
-        vec![],
-    )?;
-    Ok(IbcClientResponse::action("handle_send_module_to_module_packet").add_message(note_message))
+    let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
+
+    let source_module = source_module(deps.as_ref(), &registry, &info.sender)?;
+
+    // Keyed like the ibc-host does, the sequence follows the module and not its address
+    let source_key = module_ibc_source_key(&source_module);
+    let target_module_id = target_module.id();
+    let sequence_key = (source_key.as_str(), &host_chain, target_module_id.as_str());
+    let last_sent = MODULE_IBC_SEQUENCES
+        .may_load(deps.storage, sequence_key)?
+        .unwrap_or_default();
+
+    let sequence = match resend_sequence {
+        Some(sequence) => {
+            ensure!(
+                sequence > 0 && sequence <= last_sent,
+                IbcClientError::UnknownModuleIbcSequence {
+                    sequence,
+                    last_sent
+                }
+            );
+            sequence
+        }
+        None => {
+            let sequence = last_sent + 1;
+            MODULE_IBC_SEQUENCES.save(deps.storage, sequence_key, &sequence)?;
+            sequence
+        }
+    };
+
+    send_module_to_module_packet(
+        deps,
+        env,
+        info,
+        host_chain,
+        source_module,
+        target_module,
+        msg,
+        callback,
+        Some(sequence),
+    )
 }
 
 /// Sends a packet with an optional callback.
@@ -378,6 +650,419 @@ pub fn execute_register_account(
     Ok(IbcClientResponse::action("handle_register").add_message(note_message))
 }
 
+/// Registers an Abstract Account on a chain that is multiple hops away.
+/// The registration is routed through the ibc-clients of the intermediate chains.
+pub fn execute_register_multihop_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    path: Vec<TruncatedChainId>,
+    namespace: Option<String>,
+    install_modules: Vec<ModuleInstallConfig>,
+    callback: Option<Callback>,
+) -> IbcClientResult {
+    verify_multihop_path(&env, &path)?;
+    let account_id = multihop_account_id(deps.as_ref(), &env, &info.sender, &path)?;
+
+    let action = MultihopAction::Register {
+        namespace,
+        install_modules,
+    };
+    let origin = MultihopOrigin::Local {
+        sender: info.sender,
+        account_id: Some(account_id.clone()),
+        callback,
+        initiator_msg: to_json_binary(&action)?,
+    };
+
+    let note_message = route_multihop(deps, &env, path, Some(account_id), action, origin)?;
+    Ok(IbcClientResponse::action("handle_register_multihop").add_message(note_message))
+}
+
+/// Sends a [`HostAction`] to the host of a chain that is multiple hops away.
+/// The action is routed through the ibc-clients of the intermediate chains.
+pub fn execute_send_multihop_packet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    path: Vec<TruncatedChainId>,
+    action: HostAction,
+    callback: Option<Callback>,
+) -> IbcClientResult {
+    verify_multihop_path(&env, &path)?;
+
+    if let HostAction::Internal(_) = action {
+        // Can only call non-internal actions
+        return Err(IbcClientError::ForbiddenInternalCall {});
+    }
+    let account_id = multihop_account_id(deps.as_ref(), &env, &info.sender, &path)?;
+
+    let action = MultihopAction::HostAction(action);
+    let origin = MultihopOrigin::Local {
+        sender: info.sender,
+        account_id: Some(account_id.clone()),
+        callback,
+        initiator_msg: to_json_binary(&action)?,
+    };
+
+    let note_message = route_multihop(deps, &env, path, Some(account_id), action, origin)?;
+    Ok(IbcClientResponse::action("handle_send_multihop_msgs").add_message(note_message))
+}
+
+/// Sends a module-to-module message to a module on a chain that is multiple hops away.
+/// The callback is sent to the module once the final hop got acknowledged.
+pub fn execute_send_multihop_module_to_module_packet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    path: Vec<TruncatedChainId>,
+    target_module: ModuleInfo,
+    msg: Binary,
+    callback: Option<Callback>,
+) -> IbcClientResult {
+    verify_multihop_path(&env, &path)?;
+
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
+    let source_module = source_module(deps.as_ref(), &registry, &info.sender)?;
+    if let Some(account_id) = &source_module.account_id {
+        verify_trace_length(account_id, &path)?;
+    }
+
+    let action = MultihopAction::ModuleIbc {
+        source_module: source_module.module_info,
+        target_module,
+        msg: msg.clone(),
+    };
+    let origin = MultihopOrigin::Local {
+        sender: info.sender,
+        account_id: source_module.account_id.clone(),
+        callback,
+        initiator_msg: msg,
+    };
+
+    let note_message = route_multihop(deps, &env, path, source_module.account_id, action, origin)?;
+    Ok(
+        IbcClientResponse::action("handle_send_multihop_module_to_module_packet")
+            .add_message(note_message),
+    )
+}
+
+/// Handles a packet of a multi-hop route that got relayed by the local ibc-host.
+pub fn execute_receive_multihop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    src_chain: TruncatedChainId,
+    packet: MultihopPacket,
+) -> IbcClientResult {
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
+    let ibc_host = registry
+        .query_module_reference_raw(
+            &ModuleInfo::from_id(IBC_HOST, ABSTRACT_VERSION.into())?,
+            &deps.querier,
+        )?
+        .unwrap_native()?;
+    ensure!(info.sender == ibc_host, IbcClientError::Unauthorized {});
+
+    match packet {
+        MultihopPacket::Route {
+            route_id,
+            path,
+            account_id,
+            action,
+        } => {
+            ensure!(
+                !path.is_empty(),
+                IbcClientError::InvalidMultihopPath("path can't be empty".to_owned())
+            );
+            let origin = MultihopOrigin::Remote {
+                chain: src_chain.clone(),
+                route_id,
+            };
+            let note_message = route_multihop(deps, &env, path, account_id, action, origin)?;
+            Ok(IbcClientResponse::action("route_multihop")
+                .add_message(note_message)
+                .add_attribute("chain", src_chain.to_string()))
+        }
+        MultihopPacket::Result {
+            route_id,
+            result,
+            registered_account,
+        } => {
+            let route = MULTIHOP_ROUTES
+                .may_load(deps.storage, route_id)?
+                .ok_or(IbcClientError::UnknownMultihopRoute(route_id))?;
+            // Only the chain the route got forwarded to knows its result
+            ensure!(
+                route.next_chain == src_chain,
+                IbcClientError::Unauthorized {}
+            );
+            MULTIHOP_ROUTES.remove(deps.storage, route_id);
+            multihop_result(deps, route_id, route, result, registered_account)
+        }
+    }
+}
+
+/// Forwards `action` to the next chain of `path`.
+/// The route is stored until the result of its final hop is back and delivered to `origin`.
+fn route_multihop(
+    deps: DepsMut,
+    env: &Env,
+    path: Vec<TruncatedChainId>,
+    account_id: Option<AccountId>,
+    action: MultihopAction,
+    origin: MultihopOrigin,
+) -> IbcClientResult<CosmosMsg> {
+    let next_chain = path[0].clone();
+    let route_id = MULTIHOP_ROUTE_SEQUENCE
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    MULTIHOP_ROUTE_SEQUENCE.save(deps.storage, &route_id)?;
+    MULTIHOP_ROUTES.save(
+        deps.storage,
+        route_id,
+        &MultihopRoute {
+            next_chain: next_chain.clone(),
+            origin,
+        },
+    )?;
+
+    let remaining_path = path[1..].to_vec();
+    let (host_msg, callback) = if remaining_path.is_empty() {
+        final_hop_msg(deps.as_ref(), env, route_id, account_id, action)?
+    } else {
+        let host_msg = ibc_host::ExecuteMsg::Multihop {
+            packet: MultihopPacket::Route {
+                route_id,
+                path: remaining_path,
+                account_id,
+                action,
+            },
+        };
+        (host_msg, IbcClientCallback::MultihopRoute { route_id })
+    };
+
+    let callback = CallbackRequest {
+        receiver: env.contract.address.to_string(),
+        msg: to_json_binary(&callback)?,
+    };
+    let ibc_infra = IBC_INFRA.load(deps.storage, &next_chain)?;
+    remote_host_message(ibc_infra, &host_msg, Some(callback))
+}
+
+/// Host message of the final hop of a multi-hop route, executed on behalf of the routed account.
+fn final_hop_msg(
+    deps: Deps,
+    env: &Env,
+    route_id: u64,
+    account_id: Option<AccountId>,
+    action: MultihopAction,
+) -> IbcClientResult<(ibc_host::ExecuteMsg, IbcClientCallback)> {
+    let (host_msg, registered_account) = match action {
+        MultihopAction::ModuleIbc {
+            source_module,
+            target_module,
+            msg,
+        } => {
+            let host_msg = ibc_host::ExecuteMsg::ModuleExecute {
+                source_module: InstalledModuleIdentification {
+                    module_info: source_module,
+                    account_id,
+                },
+                target_module,
+                msg,
+                sequence: None,
+            };
+            (host_msg, None)
+        }
+        MultihopAction::Register {
+            namespace,
+            install_modules,
+        } => {
+            let account_id = account_id.ok_or(IbcClientError::Unauthorized {})?;
+            let host_msg = ibc_host::ExecuteMsg::Execute {
+                account_address: routed_account_address(deps, env, &account_id)?,
+                account_id: account_id.clone(),
+                action: HostAction::Internal(InternalAction::Register {
+                    name: None,
+                    description: None,
+                    link: None,
+                    namespace,
+                    install_modules,
+                }),
+            };
+            (host_msg, Some(account_id))
+        }
+        MultihopAction::HostAction(action) => {
+            let account_id = account_id.ok_or(IbcClientError::Unauthorized {})?;
+            let host_msg = ibc_host::ExecuteMsg::Execute {
+                account_address: routed_account_address(deps, env, &account_id)?,
+                account_id,
+                action,
+            };
+            (host_msg, None)
+        }
+    };
+
+    Ok((
+        host_msg,
+        IbcClientCallback::MultihopFinalHop {
+            route_id,
+            registered_account,
+        },
+    ))
+}
+
+/// Delivers the result of the final hop of a multi-hop route to its origin.
+/// Routes that started on another chain send the result back to the ibc-client of that chain.
+/// A remote account registered by the final hop is stored for the account that started the route.
+pub(crate) fn multihop_result(
+    deps: DepsMut,
+    route_id: u64,
+    route: MultihopRoute,
+    result: PolytoneCallback,
+    registered_account: Option<MultihopRegisteredAccount>,
+) -> IbcClientResult {
+    let response = IbcClientResponse::action("multihop_result")
+        .add_attribute("route_id", route_id.to_string());
+
+    match route.origin {
+        MultihopOrigin::Local {
+            sender,
+            account_id,
+            callback,
+            initiator_msg,
+        } => {
+            if let (Some(account_id), Some(registered_account)) = (account_id, registered_account) {
+                ACCOUNTS.save(
+                    deps.storage,
+                    (
+                        account_id.trace(),
+                        account_id.seq(),
+                        &registered_account.chain,
+                    ),
+                    &registered_account.address,
+                )?;
+            }
+            let Some(callback) = callback else {
+                return Ok(response);
+            };
+            let resp_msg = IbcResponseMsg {
+                callback,
+                result: IbcResult::from_execute(result, initiator_msg)?,
+            };
+            Ok(response.add_message(resp_msg.into_cosmos_msg(sender)?))
+        }
+        MultihopOrigin::Remote { chain, route_id } => {
+            let ibc_infra = IBC_INFRA.load(deps.storage, &chain)?;
+            let host_msg = ibc_host::ExecuteMsg::Multihop {
+                packet: MultihopPacket::Result {
+                    route_id,
+                    result,
+                    registered_account,
+                },
+            };
+            Ok(response
+                .add_message(remote_host_message(ibc_infra, &host_msg, None)?)
+                .add_attribute("chain", chain.to_string()))
+        }
+    }
+}
+
+/// Message that calls the remote ibc host with `host_msg` through polytone
+fn remote_host_message(
+    ibc_infra: IbcInfrastructure,
+    host_msg: &ibc_host::ExecuteMsg,
+    callback: Option<CallbackRequest>,
+) -> IbcClientResult<CosmosMsg> {
+    let note_message = wasm_execute(
+        ibc_infra.polytone_note,
+        &PolytoneNoteExecuteMsg::Execute {
+            msgs: vec![wasm_execute(ibc_infra.remote_abstract_host, host_msg, vec![])?.into()],
+            callback,
+            timeout_seconds: PACKET_LIFETIME.into(),
+        },
+        vec![],
+    )?;
+    Ok(note_message.into())
+}
+
+/// Address of the local account that a multi-hop route acts for
+fn routed_account_address(
+    deps: Deps,
+    env: &Env,
+    account_id: &AccountId,
+) -> IbcClientResult<String> {
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps, abstract_code_id)?;
+    let account = registry.account(account_id, &deps.querier)?;
+    Ok(account.addr().to_string())
+}
+
+/// Verifies that the sender is an account and that its account id on the last chain of `path`
+/// doesn't exceed the maximum trace length.
+fn multihop_account_id(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    path: &[TruncatedChainId],
+) -> IbcClientResult<AccountId> {
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps, abstract_code_id)?;
+
+    let account = registry.assert_account(sender, &deps.querier)?;
+    let account_id = account.account_id(deps)?;
+    verify_trace_length(&account_id, path)?;
+    Ok(account_id)
+}
+
+/// The account id on the last chain can't exceed the maximum trace length
+fn verify_trace_length(account_id: &AccountId, path: &[TruncatedChainId]) -> IbcClientResult<()> {
+    let trace_len = match account_id.trace() {
+        AccountTrace::Local => 0,
+        AccountTrace::Remote(trace) => trace.len(),
+    };
+    ensure!(
+        trace_len + path.len() <= MAX_TRACE_LENGTH as usize,
+        IbcClientError::InvalidMultihopPath(format!(
+            "resulting account trace exceeds {MAX_TRACE_LENGTH} chains"
+        ))
+    );
+    Ok(())
+}
+
+/// Verifies that the path contains at least two valid chains and doesn't visit a chain twice or
+/// loop back to this chain
+fn verify_multihop_path(env: &Env, path: &[TruncatedChainId]) -> IbcClientResult<()> {
+    ensure!(
+        path.len() >= 2,
+        IbcClientError::InvalidMultihopPath(
+            "path must contain at least two chains, use single-hop messages otherwise".to_owned()
+        )
+    );
+    let local_chain = TruncatedChainId::new(env);
+    let mut visited = BTreeSet::new();
+    for chain in path {
+        chain.verify()?;
+        ensure!(
+            chain != &local_chain,
+            IbcClientError::InvalidMultihopPath(format!("path can't contain {local_chain}"))
+        );
+        ensure!(
+            visited.insert(chain),
+            IbcClientError::InvalidMultihopPath(format!("{chain} is repeated"))
+        );
+    }
+    Ok(())
+}
+
 pub fn execute_send_funds(
     deps: DepsMut,
     env: Env,
@@ -432,6 +1117,78 @@ pub fn execute_send_funds(
     Ok(IbcClientResponse::action("handle_send_funds").add_messages(transfers))
 }
 
+/// Forwards the funds to a receiver that is multiple hops away with a packet-forward memo.
+pub fn execute_send_funds_multihop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    path: Vec<TruncatedChainId>,
+    forward_channels: Vec<String>,
+    receiver: String,
+) -> IbcClientResult {
+    verify_multihop_path(&env, &path)?;
+    ensure!(
+        forward_channels.len() == path.len() - 1,
+        IbcClientError::InvalidMultihopPath(format!(
+            "expected {} forward channels, got {}",
+            path.len() - 1,
+            forward_channels.len()
+        ))
+    );
+
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+
+    let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
+    let ans = AnsHost::new(deps.as_ref(), abstract_code_id)?;
+    // Verify that the sender is a account contract
+    let account = registry.assert_account(&info.sender, &deps.querier)?;
+    let account_id = account.account_id(deps.as_ref())?;
+
+    let first_hop = path[0].clone();
+    // Packet-forward-middleware doesn't deliver the funds to the receiver of the first hop,
+    // but it has to be a valid address on that chain.
+    // If a forward fails, the funds are refunded back along the path to this contract, the sender of the transfer.
+    let first_hop_receiver = ACCOUNTS
+        .may_load(
+            deps.storage,
+            (account_id.trace(), account_id.seq(), &first_hop),
+        )?
+        .ok_or_else(|| IbcClientError::NoRemoteAccount {
+            account_id: account_id.clone(),
+            chain: first_hop.to_string(),
+        })?;
+
+    let mut forward_channels = forward_channels.into_iter();
+    let mut memo = PfmMemoBuilder::new(forward_channels.next().unwrap());
+    for channel in forward_channels {
+        memo = memo.hop(channel);
+    }
+    let memo = memo.build(receiver)?;
+
+    let ics20_channel_entry = ChannelEntry {
+        connected_chain: first_hop,
+        protocol: ICS20.to_string(),
+    };
+    let ics20_channel_id = ics20_channel_entry.resolve(&deps.querier, &ans)?;
+
+    let transfers: Vec<CosmosMsg> = info
+        .funds
+        .into_iter()
+        .map(|coin| {
+            _ics_20_send_msg(
+                &env,
+                ics20_channel_id.clone(),
+                coin,
+                first_hop_receiver.clone(),
+                Some(memo.clone()),
+            )
+        })
+        .collect();
+
+    Ok(IbcClientResponse::action("handle_send_funds_multihop").add_messages(transfers))
+}
+
 pub(crate) fn execute_send_funds_with_actions(
     deps: DepsMut,
     env: Env,
@@ -74,6 +74,58 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> I
             namespace,
             install_modules,
         ),
+        ExecuteMsg::RegisterMultihop {
+            path,
+            namespace,
+            install_modules,
+            callback,
+        } => commands::execute_register_multihop_account(
+            deps,
+            info,
+            env,
+            path,
+            namespace,
+            install_modules,
+            callback,
+        ),
+        ExecuteMsg::MultihopRemoteAction {
+            path,
+            action,
+            callback,
+        } => commands::execute_send_multihop_packet(deps, env, info, path, action, callback),
+        ExecuteMsg::MultihopModuleIbcAction {
+            path,
+            target_module,
+            msg,
+            callback,
+        } => commands::execute_send_multihop_module_to_module_packet(
+            deps,
+            env,
+            info,
+            path,
+            target_module,
+            msg,
+            callback,
+        ),
+        ExecuteMsg::ReceiveMultihop { src_chain, packet } => {
+            commands::execute_receive_multihop(deps, env, info, src_chain, packet)
+        }
+        ExecuteMsg::SendFundsMultihop {
+            path,
+            forward_channels,
+            receiver,
+        } => {
+            commands::execute_send_funds_multihop(deps, env, info, path, forward_channels, receiver)
+        }
+        ExecuteMsg::UpdateInfrastructure {
+            chain,
+            new_chain,
+            note,
+            host,
+        } => commands::execute_update_infrastructure(deps, env, info, chain, new_chain, note, host),
+        ExecuteMsg::MigrateRemoteAccounts { chain, limit } => {
+            commands::execute_migrate_remote_accounts(deps, info, chain, limit)
+        }
         ExecuteMsg::RemoveHost { host_chain } => {
             commands::execute_remove_host(deps, info, host_chain)
         }
@@ -91,6 +143,23 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> I
             target_module,
             msg,
             callback,
+            None,
+        ),
+        ExecuteMsg::OrderedModuleIbcAction {
+            host_chain,
+            target_module,
+            msg,
+            resend_sequence,
+            callback,
+        } => commands::execute_send_ordered_module_to_module_packet(
+            deps,
+            env,
+            info,
+            host_chain,
+            target_module,
+            msg,
+            resend_sequence,
+            callback,
         ),
         ExecuteMsg::IbcQuery {
             host_chain,
@@ -118,6 +187,19 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> IbcClientResult<QueryRespon
         QueryMsg::ListIbcInfrastructures {} => {
             to_json_binary(&queries::list_ibc_counterparts(deps)?)
         }
+        QueryMsg::ModuleIbcSequence {
+            source_module,
+            host_chain,
+            target_module,
+        } => to_json_binary(&queries::module_ibc_sequence(
+            deps,
+            source_module,
+            host_chain,
+            target_module,
+        )?),
+        QueryMsg::ListAccountsMigrations {} => {
+            to_json_binary(&queries::list_accounts_migrations(deps)?)
+        }
         QueryMsg::ListRemoteAccountsByAccountId { account_id } => {
             to_json_binary(&queries::list_proxies_by_account_id(deps, account_id)?)
         }
@@ -663,6 +745,754 @@ mod tests {
         }
     }
 
+    mod multihop {
+        use super::*;
+
+        use crate::commands::PACKET_LIFETIME;
+        use abstract_std::{
+            account,
+            ibc::{
+                polytone_callbacks::{
+                    Callback, CallbackMessage, CallbackRequest, ExecutionResponse,
+                },
+                Callback as ModuleCallback, IbcResponseMsg, IbcResult,
+            },
+            ibc_client::{MultihopAction, MultihopPacket, MultihopRegisteredAccount},
+            ibc_host::{self, HostAction, InternalAction},
+            objects::{
+                module::ModuleInfo, module_reference::ModuleReference, AccountId, AccountTrace,
+                TruncatedChainId,
+            },
+            ABSTRACT_VERSION, IBC_HOST,
+        };
+        use cosmwasm_std::{wasm_execute, Binary, CosmosMsg, Event, StdResult, SubMsgResponse};
+        use std::str::FromStr;
+
+        fn setup_hop(deps: &mut MockDeps, chain: &TruncatedChainId) -> (Addr, String) {
+            let note_addr = deps.api.addr_make(&format!("{chain}_note"));
+            let remote_ibc_host = format!("{chain}_remote_host");
+
+            IBC_INFRA
+                .save(
+                    deps.as_mut().storage,
+                    chain,
+                    &IbcInfrastructure {
+                        polytone_note: note_addr.clone(),
+                        remote_abstract_host: remote_ibc_host.clone(),
+                        remote_proxy: None,
+                    },
+                )
+                .unwrap();
+            REVERSE_POLYTONE_NOTE
+                .save(deps.as_mut().storage, &note_addr, chain)
+                .unwrap();
+            (note_addr, remote_ibc_host)
+        }
+
+        fn note_message(
+            note: &Addr,
+            remote_ibc_host: String,
+            host_msg: &ibc_host::ExecuteMsg,
+            callback: Option<CallbackRequest>,
+        ) -> StdResult<CosmosMsg> {
+            Ok(wasm_execute(
+                note,
+                &PolytoneNoteExecuteMsg::Execute {
+                    msgs: vec![wasm_execute(remote_ibc_host, host_msg, vec![])?.into()],
+                    callback,
+                    timeout_seconds: PACKET_LIFETIME.into(),
+                },
+                vec![],
+            )?
+            .into())
+        }
+
+        fn callback_request(deps: &MockDeps, callback: &IbcClientCallback) -> CallbackRequest {
+            CallbackRequest {
+                receiver: mock_env_validated(deps.api).contract.address.to_string(),
+                msg: to_json_binary(callback).unwrap(),
+            }
+        }
+
+        fn local_ibc_host(deps: &MockDeps) -> (Addr, (ModuleInfo, ModuleReference)) {
+            let ibc_host = deps.api.addr_make("ibc_host");
+            let module_info = ModuleInfo::from_id(IBC_HOST, ABSTRACT_VERSION.into()).unwrap();
+            (
+                ibc_host.clone(),
+                (module_info, ModuleReference::Native(ibc_host)),
+            )
+        }
+
+        fn executed() -> Callback {
+            Callback::Execute(Ok(ExecutionResponse {
+                executed_by: String::from("remote_proxy"),
+                result: vec![],
+            }))
+        }
+
+        #[coverage_helper::test]
+        fn rejects_single_hop_path() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .build();
+            mock_init(&mut deps)?;
+
+            let msg = ExecuteMsg::RegisterMultihop {
+                path: vec![TruncatedChainId::from_str("juno")?],
+                namespace: None,
+                install_modules: vec![],
+                callback: None,
+            };
+
+            let res = execute_as(&mut deps, account.addr(), msg);
+            assert!(matches!(res, Err(IbcClientError::InvalidMultihopPath(_))));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn rejects_repeated_chain() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .build();
+            mock_init(&mut deps)?;
+
+            // Loops back to juno
+            let msg = ExecuteMsg::MultihopRemoteAction {
+                path: vec![
+                    TruncatedChainId::from_str("juno")?,
+                    TruncatedChainId::from_str("osmosis")?,
+                    TruncatedChainId::from_str("juno")?,
+                ],
+                action: HostAction::Helpers(ibc_host::HelperAction::SendAllBack),
+                callback: None,
+            };
+
+            let res = execute_as(&mut deps, account.addr(), msg);
+            assert!(matches!(res, Err(IbcClientError::InvalidMultihopPath(_))));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn cannot_make_internal_call() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .build();
+            mock_init(&mut deps)?;
+
+            let msg = ExecuteMsg::MultihopRemoteAction {
+                path: vec![
+                    TruncatedChainId::from_str("juno")?,
+                    TruncatedChainId::from_str("osmosis")?,
+                ],
+                action: HostAction::Internal(InternalAction::Register {
+                    name: None,
+                    description: None,
+                    link: None,
+                    namespace: None,
+                    install_modules: vec![],
+                }),
+                callback: None,
+            };
+
+            let res = execute_as(&mut deps, account.addr(), msg);
+            assert!(matches!(res, Err(IbcClientError::ForbiddenInternalCall {})));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn register_is_routed_to_first_hop() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .build();
+            mock_init(&mut deps)?;
+
+            let path = vec![
+                TruncatedChainId::from_str("juno")?,
+                TruncatedChainId::from_str("osmosis")?,
+                TruncatedChainId::from_str("neutron")?,
+            ];
+            let (note_addr, remote_ibc_host) = setup_hop(&mut deps, &path[0]);
+
+            let msg = ExecuteMsg::RegisterMultihop {
+                path: path.clone(),
+                namespace: None,
+                install_modules: vec![],
+                callback: None,
+            };
+            let res = execute_as(&mut deps, account.addr(), msg)?;
+
+            let host_msg = ibc_host::ExecuteMsg::Multihop {
+                packet: MultihopPacket::Route {
+                    route_id: 1,
+                    path: path[1..].to_vec(),
+                    account_id: Some(TEST_ACCOUNT_ID),
+                    action: MultihopAction::Register {
+                        namespace: None,
+                        install_modules: vec![],
+                    },
+                },
+            };
+            let callback =
+                callback_request(&deps, &IbcClientCallback::MultihopRoute { route_id: 1 });
+            assert_eq!(
+                IbcClientResponse::action("handle_register_multihop").add_message(note_message(
+                    &note_addr,
+                    remote_ibc_host,
+                    &host_msg,
+                    Some(callback)
+                )?),
+                res
+            );
+
+            let route = MULTIHOP_ROUTES.load(&deps.storage, 1)?;
+            assert_eq!(route.next_chain, path[0]);
+            assert!(matches!(
+                route.origin,
+                MultihopOrigin::Local { sender, callback: None, .. } if sender == *account.addr()
+            ));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn only_local_host_relays_packets() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let (_, host_module) = local_ibc_host(&deps);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .with_contract_map_entry(
+                    &abstr.registry,
+                    registry::state::REGISTERED_MODULES,
+                    (&host_module.0, host_module.1),
+                )
+                .build();
+            mock_init(&mut deps)?;
+
+            let msg = ExecuteMsg::ReceiveMultihop {
+                src_chain: TruncatedChainId::from_str("juno")?,
+                packet: MultihopPacket::Result {
+                    route_id: 1,
+                    result: executed(),
+                    registered_account: None,
+                },
+            };
+            let not_host = deps.api.addr_make("not_host");
+            let res = execute_as(&mut deps, &not_host, msg);
+            assert!(matches!(res, Err(IbcClientError::Unauthorized {})));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn route_final_hop_acts_for_routed_account() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let (ibc_host, host_module) = local_ibc_host(&deps);
+            // Remote account of the origin account on this chain
+            let routed_account = test_account(deps.api);
+            let routed_account_id = AccountId::new(
+                TEST_ACCOUNT_ID.seq(),
+                AccountTrace::Remote(vec![TruncatedChainId::from_str("juno")?]),
+            )?;
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&routed_account, routed_account_id.clone())
+                .with_contract_map_entry(
+                    &abstr.registry,
+                    registry::state::REGISTERED_MODULES,
+                    (&host_module.0, host_module.1),
+                )
+                .build();
+            mock_init(&mut deps)?;
+
+            let next_chain = TruncatedChainId::from_str("osmosis")?;
+            let (note_addr, remote_ibc_host) = setup_hop(&mut deps, &next_chain);
+
+            let action = HostAction::Dispatch {
+                account_msgs: vec![account::ExecuteMsg::UpdateInfo {
+                    name: None,
+                    description: None,
+                    link: None,
+                }],
+            };
+            let msg = ExecuteMsg::ReceiveMultihop {
+                src_chain: TruncatedChainId::from_str("juno")?,
+                packet: MultihopPacket::Route {
+                    route_id: 7,
+                    path: vec![next_chain.clone()],
+                    account_id: Some(routed_account_id.clone()),
+                    action: MultihopAction::HostAction(action.clone()),
+                },
+            };
+            let res = execute_as(&mut deps, &ibc_host, msg)?;
+
+            let host_msg = ibc_host::ExecuteMsg::Execute {
+                account_address: routed_account.addr().to_string(),
+                account_id: routed_account_id,
+                action,
+            };
+            let callback = callback_request(
+                &deps,
+                &IbcClientCallback::MultihopFinalHop {
+                    route_id: 1,
+                    registered_account: None,
+                },
+            );
+            assert_eq!(
+                IbcClientResponse::action("route_multihop")
+                    .add_message(note_message(
+                        &note_addr,
+                        remote_ibc_host,
+                        &host_msg,
+                        Some(callback)
+                    )?)
+                    .add_attribute("chain", "juno"),
+                res
+            );
+
+            let route = MULTIHOP_ROUTES.load(&deps.storage, 1)?;
+            assert_eq!(route.next_chain, next_chain);
+            assert_eq!(
+                route.origin,
+                MultihopOrigin::Remote {
+                    chain: TruncatedChainId::from_str("juno")?,
+                    route_id: 7
+                }
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn final_hop_result_is_sent_back_along_the_route() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let env = mock_env_validated(deps.api);
+
+            let previous_chain = TruncatedChainId::from_str("juno")?;
+            let next_chain = TruncatedChainId::from_str("osmosis")?;
+            let (previous_note, previous_host) = setup_hop(&mut deps, &previous_chain);
+            let (next_note, _) = setup_hop(&mut deps, &next_chain);
+            MULTIHOP_ROUTES.save(
+                deps.as_mut().storage,
+                1,
+                &MultihopRoute {
+                    next_chain,
+                    origin: MultihopOrigin::Remote {
+                        chain: previous_chain,
+                        route_id: 7,
+                    },
+                },
+            )?;
+
+            let msg = ExecuteMsg::Callback(CallbackMessage {
+                initiator: env.contract.address,
+                initiator_msg: to_json_binary(&IbcClientCallback::MultihopFinalHop {
+                    route_id: 1,
+                    registered_account: None,
+                })?,
+                result: executed(),
+            });
+            let res = execute_as(&mut deps, &next_note, msg)?;
+
+            let host_msg = ibc_host::ExecuteMsg::Multihop {
+                packet: MultihopPacket::Result {
+                    route_id: 7,
+                    result: executed(),
+                    registered_account: None,
+                },
+            };
+            assert_eq!(
+                IbcClientResponse::action("multihop_result")
+                    .add_attribute("route_id", "1")
+                    .add_message(note_message(
+                        &previous_note,
+                        previous_host,
+                        &host_msg,
+                        None
+                    )?)
+                    .add_attribute("chain", "juno"),
+                res
+            );
+            assert!(MULTIHOP_ROUTES.is_empty(&deps.storage));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn failed_route_is_reported_to_origin() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let env = mock_env_validated(deps.api);
+
+            let next_chain = TruncatedChainId::from_str("juno")?;
+            let (next_note, _) = setup_hop(&mut deps, &next_chain);
+            let module = deps.api.addr_make("module");
+            let callback = ModuleCallback::new(&"multihop")?;
+            MULTIHOP_ROUTES.save(
+                deps.as_mut().storage,
+                1,
+                &MultihopRoute {
+                    next_chain,
+                    origin: MultihopOrigin::Local {
+                        sender: module.clone(),
+                        account_id: None,
+                        callback: Some(callback.clone()),
+                        initiator_msg: Binary::default(),
+                    },
+                },
+            )?;
+
+            let result = Callback::Execute(Err(String::from("timeout")));
+            let msg = ExecuteMsg::Callback(CallbackMessage {
+                initiator: env.contract.address,
+                initiator_msg: to_json_binary(&IbcClientCallback::MultihopRoute { route_id: 1 })?,
+                result: result.clone(),
+            });
+            let res = execute_as(&mut deps, &next_note, msg)?;
+
+            let resp_msg = IbcResponseMsg {
+                callback,
+                result: IbcResult::from_execute(result, Binary::default())?,
+            };
+            assert_eq!(
+                IbcClientResponse::action("multihop_result")
+                    .add_attribute("route_id", "1")
+                    .add_message(resp_msg.into_cosmos_msg(module)?),
+                res
+            );
+            assert!(MULTIHOP_ROUTES.is_empty(&deps.storage));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn result_only_accepted_from_next_chain() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let (ibc_host, host_module) = local_ibc_host(&deps);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .with_contract_map_entry(
+                    &abstr.registry,
+                    registry::state::REGISTERED_MODULES,
+                    (&host_module.0, host_module.1),
+                )
+                .build();
+            mock_init(&mut deps)?;
+
+            let module = deps.api.addr_make("module");
+            MULTIHOP_ROUTES.save(
+                deps.as_mut().storage,
+                1,
+                &MultihopRoute {
+                    next_chain: TruncatedChainId::from_str("juno")?,
+                    origin: MultihopOrigin::Local {
+                        sender: module,
+                        account_id: None,
+                        callback: None,
+                        initiator_msg: Binary::default(),
+                    },
+                },
+            )?;
+
+            let msg = ExecuteMsg::ReceiveMultihop {
+                src_chain: TruncatedChainId::from_str("osmosis")?,
+                packet: MultihopPacket::Result {
+                    route_id: 1,
+                    result: executed(),
+                    registered_account: None,
+                },
+            };
+            let res = execute_as(&mut deps, &ibc_host, msg);
+            assert!(matches!(res, Err(IbcClientError::Unauthorized {})));
+
+            let msg = ExecuteMsg::ReceiveMultihop {
+                src_chain: TruncatedChainId::from_str("juno")?,
+                packet: MultihopPacket::Result {
+                    route_id: 1,
+                    result: executed(),
+                    registered_account: None,
+                },
+            };
+            execute_as(&mut deps, &ibc_host, msg)?;
+            assert!(MULTIHOP_ROUTES.is_empty(&deps.storage));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn send_funds_requires_forward_channel_per_hop() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .build();
+            mock_init(&mut deps)?;
+
+            let msg = ExecuteMsg::SendFundsMultihop {
+                path: vec![
+                    TruncatedChainId::from_str("juno")?,
+                    TruncatedChainId::from_str("osmosis")?,
+                ],
+                forward_channels: vec![],
+                receiver: "osmo_receiver".to_owned(),
+            };
+
+            let res = execute_as_funds(
+                &mut deps,
+                account.addr(),
+                msg,
+                &[Coin::new(100u128, "ujuno")],
+            );
+            assert!(matches!(res, Err(IbcClientError::InvalidMultihopPath(_))));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn register_result_is_delivered_to_origin() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let account = test_account(deps.api);
+            let (ibc_host, host_module) = local_ibc_host(&deps);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .with_contract_map_entry(
+                    &abstr.registry,
+                    registry::state::REGISTERED_MODULES,
+                    (&host_module.0, host_module.1),
+                )
+                .build();
+            mock_init(&mut deps)?;
+
+            let path = vec![
+                TruncatedChainId::from_str("juno")?,
+                TruncatedChainId::from_str("osmosis")?,
+                TruncatedChainId::from_str("neutron")?,
+            ];
+            setup_hop(&mut deps, &path[0]);
+
+            let callback = ModuleCallback::new(&"register")?;
+            let msg = ExecuteMsg::RegisterMultihop {
+                path: path.clone(),
+                namespace: None,
+                install_modules: vec![],
+                callback: Some(callback.clone()),
+            };
+            execute_as(&mut deps, account.addr(), msg)?;
+
+            let registered_account = MultihopRegisteredAccount {
+                chain: path[2].clone(),
+                address: String::from("neutron_account"),
+            };
+            let msg = ExecuteMsg::ReceiveMultihop {
+                src_chain: path[0].clone(),
+                packet: MultihopPacket::Result {
+                    route_id: 1,
+                    result: executed(),
+                    registered_account: Some(registered_account.clone()),
+                },
+            };
+            let res = execute_as(&mut deps, &ibc_host, msg)?;
+
+            let initiator_msg = to_json_binary(&MultihopAction::Register {
+                namespace: None,
+                install_modules: vec![],
+            })?;
+            let resp_msg = IbcResponseMsg {
+                callback,
+                result: IbcResult::from_execute(executed(), initiator_msg)?,
+            };
+            assert_eq!(
+                IbcClientResponse::action("multihop_result")
+                    .add_attribute("route_id", "1")
+                    .add_message(resp_msg.into_cosmos_msg(account.addr())?),
+                res
+            );
+
+            // The origin knows the address of its new remote account
+            let saved_account = ACCOUNTS.load(
+                &deps.storage,
+                (TEST_ACCOUNT_ID.trace(), TEST_ACCOUNT_ID.seq(), &path[2]),
+            )?;
+            assert_eq!(saved_account, registered_account.address);
+            assert!(MULTIHOP_ROUTES.is_empty(&deps.storage));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn remote_action_result_is_delivered_to_origin() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let account = test_account(deps.api);
+            let (ibc_host, host_module) = local_ibc_host(&deps);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .with_contract_map_entry(
+                    &abstr.registry,
+                    registry::state::REGISTERED_MODULES,
+                    (&host_module.0, host_module.1),
+                )
+                .build();
+            mock_init(&mut deps)?;
+
+            let path = vec![
+                TruncatedChainId::from_str("juno")?,
+                TruncatedChainId::from_str("osmosis")?,
+            ];
+            setup_hop(&mut deps, &path[0]);
+
+            let callback = ModuleCallback::new(&"remote_action")?;
+            let action = HostAction::Helpers(ibc_host::HelperAction::SendAllBack);
+            let msg = ExecuteMsg::MultihopRemoteAction {
+                path: path.clone(),
+                action: action.clone(),
+                callback: Some(callback.clone()),
+            };
+            execute_as(&mut deps, account.addr(), msg)?;
+
+            let result = Callback::Execute(Err(String::from("failed")));
+            let msg = ExecuteMsg::ReceiveMultihop {
+                src_chain: path[0].clone(),
+                packet: MultihopPacket::Result {
+                    route_id: 1,
+                    result: result.clone(),
+                    registered_account: None,
+                },
+            };
+            let res = execute_as(&mut deps, &ibc_host, msg)?;
+
+            let initiator_msg = to_json_binary(&MultihopAction::HostAction(action))?;
+            let resp_msg = IbcResponseMsg {
+                callback,
+                result: IbcResult::from_execute(result, initiator_msg)?,
+            };
+            assert_eq!(
+                IbcClientResponse::action("multihop_result")
+                    .add_attribute("route_id", "1")
+                    .add_message(resp_msg.into_cosmos_msg(account.addr())?),
+                res
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn final_hop_registered_account_is_sent_back() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let env = mock_env_validated(deps.api);
+
+            let previous_chain = TruncatedChainId::from_str("juno")?;
+            let next_chain = TruncatedChainId::from_str("osmosis")?;
+            let (previous_note, previous_host) = setup_hop(&mut deps, &previous_chain);
+            let (next_note, _) = setup_hop(&mut deps, &next_chain);
+            MULTIHOP_ROUTES.save(
+                deps.as_mut().storage,
+                1,
+                &MultihopRoute {
+                    next_chain: next_chain.clone(),
+                    origin: MultihopOrigin::Remote {
+                        chain: previous_chain,
+                        route_id: 7,
+                    },
+                },
+            )?;
+
+            let routed_account_id = AccountId::new(
+                TEST_ACCOUNT_ID.seq(),
+                AccountTrace::Remote(vec![TruncatedChainId::from_str("juno")?]),
+            )?;
+            let registered_address = String::from("osmosis_account");
+            let result = Callback::Execute(Ok(ExecutionResponse {
+                executed_by: String::from("remote_proxy"),
+                #[allow(deprecated)]
+                result: vec![SubMsgResponse {
+                    events: vec![Event::new(String::from("wasm-abstract"))
+                        .add_attribute("action", "create_account")
+                        .add_attribute("account_address", registered_address.clone())],
+                    data: None,
+                    msg_responses: vec![],
+                }],
+            }));
+            let msg = ExecuteMsg::Callback(CallbackMessage {
+                initiator: env.contract.address,
+                initiator_msg: to_json_binary(&IbcClientCallback::MultihopFinalHop {
+                    route_id: 1,
+                    registered_account: Some(routed_account_id.clone()),
+                })?,
+                result: result.clone(),
+            });
+            let res = execute_as(&mut deps, &next_note, msg)?;
+
+            let host_msg = ibc_host::ExecuteMsg::Multihop {
+                packet: MultihopPacket::Result {
+                    route_id: 7,
+                    result,
+                    registered_account: Some(MultihopRegisteredAccount {
+                        chain: next_chain.clone(),
+                        address: registered_address.clone(),
+                    }),
+                },
+            };
+            assert_eq!(
+                IbcClientResponse::action("multihop_result")
+                    .add_attribute("route_id", "1")
+                    .add_message(note_message(
+                        &previous_note,
+                        previous_host,
+                        &host_msg,
+                        None
+                    )?)
+                    .add_attribute("chain", "juno"),
+                res
+            );
+            let saved_account = ACCOUNTS.load(
+                &deps.storage,
+                (
+                    routed_account_id.trace(),
+                    routed_account_id.seq(),
+                    &next_chain,
+                ),
+            )?;
+            assert_eq!(saved_account, registered_address);
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn send_funds_requires_remote_account_on_first_hop() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .build();
+            mock_init(&mut deps)?;
+
+            let msg = ExecuteMsg::SendFundsMultihop {
+                path: vec![
+                    TruncatedChainId::from_str("juno")?,
+                    TruncatedChainId::from_str("osmosis")?,
+                ],
+                forward_channels: vec!["channel-1".to_owned()],
+                receiver: "osmo_receiver".to_owned(),
+            };
+
+            let res = execute_as_funds(
+                &mut deps,
+                account.addr(),
+                msg,
+                &[Coin::new(100u128, "ujuno")],
+            );
+            assert_eq!(
+                res,
+                Err(IbcClientError::NoRemoteAccount {
+                    account_id: TEST_ACCOUNT_ID,
+                    chain: "juno".to_owned(),
+                })
+            );
+            Ok(())
+        }
+    }
+
     mod send_funds {
         use super::*;
 
@@ -996,6 +1826,282 @@ mod tests {
         }
     }
 
+    mod update_infrastructure {
+        use std::str::FromStr;
+
+        use abstract_std::objects::{AccountTrace, TruncatedChainId};
+
+        use super::*;
+
+        fn register(deps: &mut MockDeps, chain: &str, note: &Addr) -> IbcClientTestResult {
+            IBC_INFRA.save(
+                deps.as_mut().storage,
+                &TruncatedChainId::from_str(chain)?,
+                &IbcInfrastructure {
+                    polytone_note: note.clone(),
+                    remote_abstract_host: "test_remote_host".into(),
+                    remote_proxy: Some("remote_proxy".into()),
+                },
+            )?;
+            REVERSE_POLYTONE_NOTE.save(
+                deps.as_mut().storage,
+                note,
+                &TruncatedChainId::from_str(chain)?,
+            )?;
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn only_admin() -> IbcClientTestResult {
+            test_only_admin(ExecuteMsg::UpdateInfrastructure {
+                chain: "host-chain".parse().unwrap(),
+                new_chain: None,
+                note: None,
+                host: Some(String::from("host")),
+            })?;
+            test_only_admin(ExecuteMsg::MigrateRemoteAccounts {
+                chain: "host-chain".parse().unwrap(),
+                limit: None,
+            })
+        }
+
+        #[coverage_helper::test]
+        fn unregistered_chain() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let abstr = AbstractMockAddrs::new(deps.api);
+
+            let res = execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateInfrastructure {
+                    chain: TEST_CHAIN.parse().unwrap(),
+                    new_chain: None,
+                    note: None,
+                    host: Some(String::from("host")),
+                },
+            );
+            assert!(matches!(res, Err(IbcClientError::UnregisteredChain(_))));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn new_note_resets_remote_proxy() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let old_note = deps.api.addr_make("old_note");
+            let new_note = deps.api.addr_make("new_note");
+            register(&mut deps, TEST_CHAIN, &old_note)?;
+
+            let res = execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateInfrastructure {
+                    chain: TEST_CHAIN.parse().unwrap(),
+                    new_chain: None,
+                    note: Some(new_note.to_string()),
+                    host: Some(String::from("new_host")),
+                },
+            )?;
+            // WhoAmI request to the new note
+            assert_eq!(res.messages.len(), 1);
+
+            let chain = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let ibc_infra = IBC_INFRA.load(&deps.storage, &chain)?;
+            assert_eq!(
+                ibc_infra,
+                IbcInfrastructure {
+                    polytone_note: new_note.clone(),
+                    remote_abstract_host: "new_host".into(),
+                    remote_proxy: None,
+                }
+            );
+            assert!(!REVERSE_POLYTONE_NOTE.has(&deps.storage, &old_note));
+            assert_eq!(REVERSE_POLYTONE_NOTE.load(&deps.storage, &new_note)?, chain);
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn rename_migrates_accounts_in_batches() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let note = deps.api.addr_make("note");
+            register(&mut deps, TEST_CHAIN, &note)?;
+
+            let old_chain = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let new_chain = TruncatedChainId::from_str("new-chain")?;
+            let other_chain = TruncatedChainId::from_str("other-chain")?;
+            for seq in 0..3 {
+                ACCOUNTS.save(
+                    deps.as_mut().storage,
+                    (&AccountTrace::Local, seq, &old_chain),
+                    &format!("remote_account_{seq}"),
+                )?;
+            }
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                (&AccountTrace::Local, 0, &other_chain),
+                &String::from("other_account"),
+            )?;
+
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateInfrastructure {
+                    chain: old_chain.clone(),
+                    new_chain: Some(new_chain.clone()),
+                    note: None,
+                    host: None,
+                },
+            )?;
+            assert!(!IBC_INFRA.has(&deps.storage, &old_chain));
+            assert!(IBC_INFRA.has(&deps.storage, &new_chain));
+            assert_eq!(REVERSE_POLYTONE_NOTE.load(&deps.storage, &note)?, new_chain);
+
+            let migrations: ListAccountsMigrationsResponse = from_json(query(
+                deps.as_ref(),
+                mock_env_validated(deps.api),
+                QueryMsg::ListAccountsMigrations {},
+            )?)?;
+            assert_eq!(
+                migrations.migrations,
+                vec![(
+                    old_chain.clone(),
+                    AccountsMigration {
+                        to_chain: new_chain.clone(),
+                        cursor: None
+                    }
+                )]
+            );
+
+            // The old name is no longer registered
+            let res = execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateInfrastructure {
+                    chain: old_chain.clone(),
+                    new_chain: Some(other_chain.clone()),
+                    note: None,
+                    host: None,
+                },
+            );
+            assert!(matches!(res, Err(IbcClientError::UnregisteredChain(_))));
+
+            // First batch only scans two records
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::MigrateRemoteAccounts {
+                    chain: old_chain.clone(),
+                    limit: Some(2),
+                },
+            )?;
+            let migration = ACCOUNTS_MIGRATIONS.load(&deps.storage, &old_chain)?;
+            assert!(migration.cursor.is_some());
+
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::MigrateRemoteAccounts {
+                    chain: old_chain.clone(),
+                    limit: Some(10),
+                },
+            )?;
+            assert!(ACCOUNTS_MIGRATIONS.is_empty(&deps.storage));
+
+            for seq in 0..3 {
+                assert!(!ACCOUNTS.has(&deps.storage, (&AccountTrace::Local, seq, &old_chain)));
+                assert_eq!(
+                    ACCOUNTS.load(&deps.storage, (&AccountTrace::Local, seq, &new_chain))?,
+                    format!("remote_account_{seq}")
+                );
+            }
+            assert!(ACCOUNTS.has(&deps.storage, (&AccountTrace::Local, 0, &other_chain)));
+
+            let res = execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::MigrateRemoteAccounts {
+                    chain: old_chain,
+                    limit: None,
+                },
+            );
+            assert!(matches!(res, Err(IbcClientError::NoAccountsMigration(_))));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn cannot_rename_chain_with_pending_incoming_migration() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let note = deps.api.addr_make("note");
+            register(&mut deps, TEST_CHAIN, &note)?;
+
+            let first_chain = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let second_chain = TruncatedChainId::from_str("second-chain")?;
+            let third_chain = TruncatedChainId::from_str("third-chain")?;
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                (&AccountTrace::Local, 0, &first_chain),
+                &String::from("remote_account"),
+            )?;
+
+            // Rename A -> B, the records of A are not migrated yet
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateInfrastructure {
+                    chain: first_chain.clone(),
+                    new_chain: Some(second_chain.clone()),
+                    note: None,
+                    host: None,
+                },
+            )?;
+
+            // Rename B -> C would strand the records moving to B
+            let res = execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateInfrastructure {
+                    chain: second_chain.clone(),
+                    new_chain: Some(third_chain.clone()),
+                    note: None,
+                    host: None,
+                },
+            );
+            assert_eq!(
+                res,
+                Err(IbcClientError::PendingAccountsMigration(
+                    first_chain.to_string()
+                ))
+            );
+
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::MigrateRemoteAccounts {
+                    chain: first_chain,
+                    limit: None,
+                },
+            )?;
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateInfrastructure {
+                    chain: second_chain,
+                    new_chain: Some(third_chain.clone()),
+                    note: None,
+                    host: None,
+                },
+            )?;
+            assert!(IBC_INFRA.has(&deps.storage, &third_chain));
+            Ok(())
+        }
+    }
+
     mod callback {
         use std::str::FromStr;
 
@@ -33,6 +33,12 @@ pub enum IbcClientError {
     #[error("No account for chain {0}")]
     UnregisteredChain(String),
 
+    #[error("Invalid multi-hop path: {0}")]
+    InvalidMultihopPath(String),
+
+    #[error("Unknown multi-hop route {0}")]
+    UnknownMultihopRoute(u64),
+
     #[error("Calling internal actions externally is not allowed")]
     ForbiddenInternalCall {},
 
@@ -45,9 +51,24 @@ pub enum IbcClientError {
     #[error("Chain or host address already registered.")]
     HostAddressExists {},
 
+    #[error("Account {account_id} has no remote account on {chain}")]
+    NoRemoteAccount {
+        account_id: AccountId,
+        chain: String,
+    },
+
     #[error("IBC Client is not installed on {account_id}")]
     IbcClientNotInstalled { account_id: AccountId },
 
+    #[error("Remote accounts of {0} have to be migrated first")]
+    PendingAccountsMigration(String),
+
+    #[error("No remote accounts migration for {0}")]
+    NoAccountsMigration(String),
+
+    #[error("Can't re-send module ibc sequence {sequence}, last sent sequence is {last_sent}")]
+    UnknownModuleIbcSequence { sequence: u64, last_sent: u64 },
+
     #[error("Contract got an unexpected Reply")]
     UnexpectedReply {},
 }
@@ -1,18 +1,19 @@
 use abstract_std::{
     ibc::{
-        polytone_callbacks::{Callback as PolytoneCallback, CallbackMessage},
+        polytone_callbacks::{Callback as PolytoneCallback, CallbackMessage, ExecutionResponse},
         IbcResponseMsg, IbcResult,
     },
     ibc_client::{
-        state::{ACCOUNTS, IBC_INFRA, REVERSE_POLYTONE_NOTE},
-        IbcClientCallback,
+        state::{ACCOUNTS, IBC_INFRA, MULTIHOP_ROUTES, REVERSE_POLYTONE_NOTE},
+        IbcClientCallback, MultihopRegisteredAccount,
     },
     objects::TruncatedChainId,
     ABSTRACT_EVENT_TYPE,
 };
-use cosmwasm_std::{from_json, Attribute, DepsMut, Env, MessageInfo};
+use cosmwasm_std::{from_json, DepsMut, Env, MessageInfo};
 
 use crate::{
+    commands,
     contract::{IbcClientResponse, IbcClientResult},
     error::IbcClientError,
 };
@@ -60,26 +61,14 @@ pub fn receive_action_callback(
         IbcClientCallback::CreateAccount { account_id } => {
             // We need to get the address of the remote proxy from the account creation response
             if let PolytoneCallback::Execute(Ok(response)) = &polytone_callback.result {
-                let account_creation_result = response.result[0].clone();
-
-                let wasm_abstract_attributes: Vec<Attribute> = account_creation_result
-                    .events
-                    .into_iter()
-                    .filter(|e| e.ty == ABSTRACT_EVENT_TYPE)
-                    .flat_map(|e| e.attributes)
-                    .collect();
-
-                let remote_account_address = &wasm_abstract_attributes
-                    .iter()
-                    .find(|e| e.key == "account_address")
-                    .ok_or(IbcClientError::IbcFailed(polytone_callback))?
-                    .value;
+                let remote_account_address = created_account_address(response)
+                    .ok_or_else(|| IbcClientError::IbcFailed(polytone_callback.clone()))?;
 
                 // We need to store the account address in the IBC client for interactions that may need it locally
                 ACCOUNTS.save(
                     deps.storage,
                     (account_id.trace(), account_id.seq(), &host_chain),
-                    remote_account_address,
+                    &remote_account_address,
                 )?;
             } else {
                 return Err(IbcClientError::IbcFailed(polytone_callback));
@@ -90,6 +79,48 @@ pub fn receive_action_callback(
                     .add_attribute("chain", host_chain.to_string()),
             )
         }
+        IbcClientCallback::MultihopRoute { route_id } => {
+            // The route got forwarded, the result comes back once the final hop is acknowledged
+            if let PolytoneCallback::Execute(Ok(_)) = &polytone_callback.result {
+                return Ok(IbcClientResponse::action("acknowledge_multihop_route")
+                    .add_attribute("route_id", route_id.to_string())
+                    .add_attribute("chain", host_chain.to_string()));
+            }
+            let route = MULTIHOP_ROUTES.load(deps.storage, route_id)?;
+            MULTIHOP_ROUTES.remove(deps.storage, route_id);
+            commands::multihop_result(deps, route_id, route, polytone_callback.result, None)
+        }
+        IbcClientCallback::MultihopFinalHop {
+            route_id,
+            registered_account,
+        } => {
+            let mut multihop_registered_account = None;
+            if let Some(account_id) = registered_account {
+                if let PolytoneCallback::Execute(Ok(response)) = &polytone_callback.result {
+                    let remote_account_address = created_account_address(response)
+                        .ok_or_else(|| IbcClientError::IbcFailed(polytone_callback.clone()))?;
+                    ACCOUNTS.save(
+                        deps.storage,
+                        (account_id.trace(), account_id.seq(), &host_chain),
+                        &remote_account_address,
+                    )?;
+                    // The origin of the route stores the address for its own account
+                    multihop_registered_account = Some(MultihopRegisteredAccount {
+                        chain: host_chain,
+                        address: remote_account_address,
+                    });
+                }
+            }
+            let route = MULTIHOP_ROUTES.load(deps.storage, route_id)?;
+            MULTIHOP_ROUTES.remove(deps.storage, route_id);
+            commands::multihop_result(
+                deps,
+                route_id,
+                route,
+                polytone_callback.result,
+                multihop_registered_account,
+            )
+        }
         IbcClientCallback::ModuleRemoteAction {
             callback,
             sender_address,
@@ -118,3 +149,16 @@ pub fn receive_action_callback(
         }
     }
 }
+
+/// Address of the account created by the first message of an execution
+fn created_account_address(response: &ExecutionResponse) -> Option<String> {
+    response
+        .result
+        .first()?
+        .events
+        .iter()
+        .filter(|e| e.ty == ABSTRACT_EVENT_TYPE)
+        .flat_map(|e| e.attributes.iter())
+        .find(|attr| attr.key == "account_address")
+        .map(|attr| attr.value.clone())
+}
@@ -3,10 +3,12 @@ use std::str::FromStr;
 use abstract_sdk::feature_objects::{AnsHost, RegistryContract};
 use abstract_std::{
     ibc_client::{
-        state::{ACCOUNTS, IBC_INFRA},
-        AccountResponse, ConfigResponse, HostResponse, ListAccountsResponse,
-        ListIbcInfrastructureResponse, ListRemoteAccountsResponse, ListRemoteHostsResponse,
+        state::{ACCOUNTS, ACCOUNTS_MIGRATIONS, IBC_INFRA, MODULE_IBC_SEQUENCES},
+        AccountResponse, ConfigResponse, HostResponse, InstalledModuleIdentification,
+        ListAccountsMigrationsResponse, ListAccountsResponse, ListIbcInfrastructureResponse,
+        ListRemoteAccountsResponse, ListRemoteHostsResponse, ModuleIbcSequenceResponse,
     },
+    ibc_host::module_ibc_source_key,
     native_addrs,
     objects::{
         account::{AccountSequence, AccountTrace},
@@ -91,6 +93,30 @@ pub fn list_ibc_counterparts(deps: Deps) -> IbcClientResult<ListIbcInfrastructur
     Ok(ListIbcInfrastructureResponse { counterparts })
 }
 
+// No need for pagination here, not a lot of chains
+pub fn list_accounts_migrations(deps: Deps) -> IbcClientResult<ListAccountsMigrationsResponse> {
+    let migrations = ACCOUNTS_MIGRATIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    Ok(ListAccountsMigrationsResponse { migrations })
+}
+
+pub fn module_ibc_sequence(
+    deps: Deps,
+    source_module: InstalledModuleIdentification,
+    host_chain: TruncatedChainId,
+    target_module: String,
+) -> IbcClientResult<ModuleIbcSequenceResponse> {
+    let source_key = module_ibc_source_key(&source_module);
+    let last_sent = MODULE_IBC_SEQUENCES
+        .may_load(
+            deps.storage,
+            (source_key.as_str(), &host_chain, target_module.as_str()),
+        )?
+        .unwrap_or_default();
+    Ok(ModuleIbcSequenceResponse { last_sent })
+}
+
 pub fn config(deps: Deps, env: &Env) -> IbcClientResult<ConfigResponse> {
     let abstract_code_id =
         native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
@@ -51,7 +51,7 @@ pub fn reply(deps: DepsMut, env: Env, reply_msg: Reply) -> HostResult {
     if reply_msg.id == INIT_BEFORE_ACTION_REPLY_ID {
         reply_execute_action(deps, env, reply_msg)
     } else if reply_msg.id == RESPONSE_REPLY_ID {
-        reply_forward_response_data(reply_msg)
+        reply_forward_response_data(deps, reply_msg)
     } else {
         Err(HostError::Std(StdError::generic_err("Not implemented")))
     }
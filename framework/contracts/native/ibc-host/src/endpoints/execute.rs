@@ -1,11 +1,19 @@
 use abstract_sdk::std::ibc_host::ExecuteMsg;
 use abstract_std::{
-    ibc_host::state::{CHAIN_PROXIES, REVERSE_CHAIN_PROXIES},
+    ibc_host::{
+        state::{
+            CHAIN_PROXIES, CHAIN_QUOTAS, CHAIN_QUOTA_USAGE, CLIENT_CHAIN_TRACES, PAUSED_CHAINS,
+            REVERSE_CHAIN_PROXIES,
+        },
+        ChainQuota,
+    },
     objects::TruncatedChainId,
 };
-use cosmwasm_std::{BankMsg, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{ensure, BankMsg, DepsMut, Env, MessageInfo, Response};
 
-use super::packet::{handle_host_action, handle_module_execute};
+use super::packet::{
+    handle_host_action, handle_module_execute, handle_multihop_packet, trace_chain,
+};
 use crate::{
     account_commands::{self, receive_register},
     contract::{HostResponse, HostResult},
@@ -29,6 +37,12 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
 
             handle_host_action(deps, env, src_chain, account_address, account_id, action)
         }
+        ExecuteMsg::SetChainQuota { chain, quota } => set_chain_quota(deps, info, chain, quota),
+        ExecuteMsg::PauseChain { chain, reason } => pause_chain(deps, info, chain, reason),
+        ExecuteMsg::UnpauseChain { chain } => unpause_chain(deps, info, chain),
+        ExecuteMsg::RenameClientChain { chain, new_chain } => {
+            rename_client_chain(deps, info, chain, new_chain)
+        }
         ExecuteMsg::UpdateOwnership(action) => {
             cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
             Ok(HostResponse::action("update_ownership"))
@@ -37,11 +51,26 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
             msg,
             source_module,
             target_module,
+            sequence,
         } => {
             let src_chain: TruncatedChainId =
                 REVERSE_CHAIN_PROXIES.load(deps.storage, &info.sender)?;
 
-            handle_module_execute(deps, env, src_chain, source_module, target_module, msg)
+            handle_module_execute(
+                deps,
+                env,
+                src_chain,
+                source_module,
+                target_module,
+                msg,
+                sequence,
+            )
+        }
+        ExecuteMsg::Multihop { packet } => {
+            let src_chain: TruncatedChainId =
+                REVERSE_CHAIN_PROXIES.load(deps.storage, &info.sender)?;
+
+            handle_multihop_packet(deps, env, src_chain, packet)
         }
         ExecuteMsg::Fund {
             src_account,
@@ -50,7 +79,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
             // Push the client chain to the account trace
             let account_id = {
                 let mut account_id = src_account.clone();
-                account_id.push_chain(src_chain.clone());
+                account_id.push_chain(trace_chain(deps.storage, &src_chain)?);
                 account_id
             };
             if let Ok(account) = account_commands::get_account(deps.as_ref(), &env, &account_id) {
@@ -123,3 +152,127 @@ fn remove_chain_proxy(deps: DepsMut, info: MessageInfo, chain: TruncatedChainId)
     CHAIN_PROXIES.remove(deps.storage, &chain);
     Ok(HostResponse::action("register_chain_client"))
 }
+
+/// Set or remove the quota of a client chain
+fn set_chain_quota(
+    deps: DepsMut,
+    info: MessageInfo,
+    chain: TruncatedChainId,
+    quota: Option<ChainQuota>,
+) -> HostResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    chain.verify()?;
+
+    // Usage of the previous quota is not relevant for the new one
+    CHAIN_QUOTA_USAGE.remove(deps.storage, &chain);
+    match quota {
+        Some(quota) => {
+            ensure!(
+                quota.window_blocks > 0,
+                HostError::InvalidQuota("window must be at least one block".to_owned())
+            );
+            let mut denoms: Vec<&str> = quota
+                .max_outflows
+                .iter()
+                .map(|c| c.denom.as_str())
+                .collect();
+            denoms.sort_unstable();
+            denoms.dedup();
+            ensure!(
+                denoms.len() == quota.max_outflows.len(),
+                HostError::InvalidQuota("duplicate outflow denom".to_owned())
+            );
+            CHAIN_QUOTAS.save(deps.storage, &chain, &quota)?;
+        }
+        None => CHAIN_QUOTAS.remove(deps.storage, &chain),
+    }
+
+    Ok(HostResponse::new(
+        "set_chain_quota",
+        vec![("chain", chain.to_string())],
+    ))
+}
+
+/// Circuit-breaker: stop accepting actions from a client chain
+fn pause_chain(
+    deps: DepsMut,
+    info: MessageInfo,
+    chain: TruncatedChainId,
+    reason: String,
+) -> HostResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    chain.verify()?;
+
+    PAUSED_CHAINS.save(deps.storage, &chain, &reason)?;
+    Ok(HostResponse::new(
+        "pause_chain",
+        vec![("chain", chain.to_string()), ("reason", reason)],
+    ))
+}
+
+fn unpause_chain(deps: DepsMut, info: MessageInfo, chain: TruncatedChainId) -> HostResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    chain.verify()?;
+
+    PAUSED_CHAINS.remove(deps.storage, &chain);
+    Ok(HostResponse::new(
+        "unpause_chain",
+        vec![("chain", chain.to_string())],
+    ))
+}
+
+/// Move the proxy, quota and pause status of a client chain to its new name.
+/// The accounts of the client chain stay reachable through the chain name of their trace.
+fn rename_client_chain(
+    deps: DepsMut,
+    info: MessageInfo,
+    chain: TruncatedChainId,
+    new_chain: TruncatedChainId,
+) -> HostResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    chain.verify()?;
+    new_chain.verify()?;
+
+    let proxy = CHAIN_PROXIES
+        .may_load(deps.storage, &chain)?
+        .ok_or_else(|| HostError::UnregisteredChain(chain.to_string()))?;
+    if CHAIN_PROXIES.has(deps.storage, &new_chain) {
+        return Err(HostError::ProxyAddressExists {});
+    }
+    CHAIN_PROXIES.remove(deps.storage, &chain);
+    CHAIN_PROXIES.save(deps.storage, &new_chain, &proxy)?;
+    REVERSE_CHAIN_PROXIES.save(deps.storage, &proxy, &new_chain)?;
+
+    if let Some(quota) = CHAIN_QUOTAS.may_load(deps.storage, &chain)? {
+        CHAIN_QUOTAS.remove(deps.storage, &chain);
+        CHAIN_QUOTAS.save(deps.storage, &new_chain, &quota)?;
+    }
+    if let Some(usage) = CHAIN_QUOTA_USAGE.may_load(deps.storage, &chain)? {
+        CHAIN_QUOTA_USAGE.remove(deps.storage, &chain);
+        CHAIN_QUOTA_USAGE.save(deps.storage, &new_chain, &usage)?;
+    }
+    if let Some(reason) = PAUSED_CHAINS.may_load(deps.storage, &chain)? {
+        PAUSED_CHAINS.remove(deps.storage, &chain);
+        PAUSED_CHAINS.save(deps.storage, &new_chain, &reason)?;
+    }
+
+    // Chain renamed multiple times keeps the name of the first registration
+    let trace = trace_chain(deps.storage, &chain)?;
+    CLIENT_CHAIN_TRACES.remove(deps.storage, &chain);
+    if trace != new_chain {
+        CLIENT_CHAIN_TRACES.save(deps.storage, &new_chain, &trace)?;
+    }
+
+    Ok(HostResponse::new(
+        "rename_client_chain",
+        vec![
+            ("chain", chain.to_string()),
+            ("new_chain", new_chain.to_string()),
+            ("trace_chain", trace.to_string()),
+        ],
+    ))
+}
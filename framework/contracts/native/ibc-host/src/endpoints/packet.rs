@@ -2,9 +2,13 @@ use abstract_sdk::feature_objects::RegistryContract;
 use abstract_std::{
     base::ExecuteMsg as MiddlewareExecMsg,
     ibc::{ModuleIbcInfo, ModuleIbcMsg},
-    ibc_client::InstalledModuleIdentification,
+    ibc_client::{self, InstalledModuleIdentification, MultihopPacket},
     ibc_host::{
-        state::{ActionAfterCreationCache, TEMP_ACTION_AFTER_CREATION},
+        module_ibc_source_key,
+        state::{
+            ActionAfterCreationCache, CLIENT_CHAIN_TRACES, MODULE_IBC_SEQUENCES,
+            TEMP_ACTION_AFTER_CREATION,
+        },
         HelperAction, HostAction, InternalAction,
     },
     native_addrs,
@@ -12,16 +16,17 @@ use abstract_std::{
         account::AccountTrace, module::ModuleInfo, module_reference::ModuleReference, AccountId,
         TruncatedChainId,
     },
+    ABSTRACT_VERSION, IBC_CLIENT,
 };
 use cosmwasm_std::{
-    to_json_vec, wasm_execute, Binary, ContractResult, Deps, DepsMut, Empty, Env, QueryRequest,
-    Response, StdError, SystemResult, WasmQuery,
+    ensure, to_json_vec, wasm_execute, Binary, ContractResult, Deps, DepsMut, Empty, Env,
+    QueryRequest, Response, StdError, StdResult, Storage, SystemResult, WasmQuery,
 };
 
 use crate::{
     account_commands::{self, receive_dispatch, receive_register, receive_send_all_back},
     contract::HostResult,
-    HostError,
+    rate_limit, HostError,
 };
 
 /// Handle actions that are passed to the IBC host contract
@@ -34,11 +39,43 @@ pub fn handle_host_action(
     account_address: String,
     received_account_id: AccountId,
     host_action: HostAction,
+) -> HostResult {
+    rate_limit::assert_not_paused(deps.storage, &src_chain)?;
+    // Charged once here, the action is not charged again if it's executed after account creation
+    rate_limit::consume_msgs(deps.storage, &env, &src_chain, action_msgs(&host_action))?;
+
+    execute_host_action(
+        deps,
+        env,
+        src_chain,
+        account_address,
+        received_account_id,
+        host_action,
+    )
+}
+
+/// Amount of messages an action counts for against the quota of the client chain
+fn action_msgs(host_action: &HostAction) -> u32 {
+    match host_action {
+        HostAction::Dispatch { account_msgs } => account_msgs.len() as u32,
+        _ => 1,
+    }
+}
+
+/// Execute a host action on the remote account, creating the account first if it doesn't exist.
+/// The quota of the client chain must be charged by the caller.
+pub(crate) fn execute_host_action(
+    mut deps: DepsMut,
+    env: Env,
+    src_chain: TruncatedChainId,
+    account_address: String,
+    received_account_id: AccountId,
+    host_action: HostAction,
 ) -> HostResult {
     // Push the client chain to the account trace
     let account_id = {
         let mut account_id = received_account_id.clone();
-        account_id.push_chain(src_chain.clone());
+        account_id.push_chain(trace_chain(deps.storage, &src_chain)?);
         account_id
     };
 
@@ -68,10 +105,30 @@ pub fn handle_host_action(
             if let Ok(account) = account_commands::get_account(deps.as_ref(), &env, &account_id) {
                 match action {
                     HostAction::Dispatch { account_msgs } => {
+                        let msgs_count = account_msgs.len() as u32;
+                        rate_limit::track_outflows(
+                            deps.branch(),
+                            &env,
+                            &src_chain,
+                            account.addr(),
+                            msgs_count,
+                        )?;
                         receive_dispatch(deps, account, account_msgs)
                     }
                     HostAction::Helpers(helper_action) => match helper_action {
                         HelperAction::SendAllBack => {
+                            // All the funds leave the account
+                            let outflows = rate_limit::limited_balances(
+                                deps.as_ref(),
+                                &src_chain,
+                                account.addr(),
+                            )?;
+                            rate_limit::consume_outflows(
+                                deps.branch(),
+                                &env,
+                                &src_chain,
+                                outflows,
+                            )?;
                             receive_send_all_back(deps, env, account, account_address, src_chain)
                         }
                         _ => unimplemented!(""),
@@ -127,7 +184,36 @@ pub fn handle_module_execute(
     source_module: InstalledModuleIdentification,
     target_module: ModuleInfo,
     msg: Binary,
+    sequence: Option<u64>,
 ) -> HostResult {
+    rate_limit::assert_not_paused(deps.storage, &src_chain)?;
+
+    let trace_chain = trace_chain(deps.storage, &src_chain)?;
+    if let Some(sequence) = sequence {
+        let source_key = module_ibc_source_key(&source_module);
+        let target_id = target_module.id();
+        let key = (&trace_chain, source_key.as_str(), target_id.as_str());
+        let last_processed = MODULE_IBC_SEQUENCES
+            .may_load(deps.storage, key)?
+            .unwrap_or_default();
+        // Re-sent message that got processed already
+        if sequence <= last_processed {
+            return Ok(Response::new()
+                .add_attribute("action", "module-ibc-call")
+                .add_attribute("duplicate_sequence", sequence.to_string()));
+        }
+        ensure!(
+            sequence == last_processed + 1,
+            HostError::ModuleIbcSequenceGap {
+                expected: last_processed + 1,
+                received: sequence,
+            }
+        );
+        MODULE_IBC_SEQUENCES.save(deps.storage, key, &sequence)?;
+    }
+
+    rate_limit::consume_msgs(deps.storage, &env, &src_chain, 1)?;
+
     // We resolve the target module
     let abstract_code_id =
         native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
@@ -140,7 +226,7 @@ pub fn handle_module_execute(
         // If the calling module is account-specific then we map the calling account-id to the host.
         account_id: source_module
             .account_id
-            .map(|a| client_to_host_module_account_id(&env, src_chain.clone(), a)),
+            .map(|a| client_to_host_module_account_id(&env, trace_chain.clone(), a)),
     };
 
     let target_module_resolved = target_module.addr(deps.as_ref(), registry)?;
@@ -163,6 +249,7 @@ pub fn handle_module_execute(
             src_module_info: ModuleIbcInfo {
                 chain: src_chain,
                 module: source_module.module_info,
+                sequence,
             },
             msg,
         }),
@@ -172,6 +259,45 @@ pub fn handle_module_execute(
     Ok(response.add_message(msg))
 }
 
+/// Relay a packet of a multi-hop route to the local ibc-client, which forwards it along the route
+pub fn handle_multihop_packet(
+    deps: DepsMut,
+    env: Env,
+    src_chain: TruncatedChainId,
+    mut packet: MultihopPacket,
+) -> HostResult {
+    rate_limit::assert_not_paused(deps.storage, &src_chain)?;
+
+    if let MultihopPacket::Route {
+        account_id: Some(account_id),
+        ..
+    } = &mut packet
+    {
+        // Push the client chain to the account trace
+        account_id.push_chain(trace_chain(deps.storage, &src_chain)?);
+    }
+    rate_limit::consume_msgs(deps.storage, &env, &src_chain, 1)?;
+
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
+    let ibc_client = registry
+        .query_module_reference_raw(
+            &ModuleInfo::from_id(IBC_CLIENT, ABSTRACT_VERSION.into())?,
+            &deps.querier,
+        )?
+        .unwrap_native()?;
+
+    let msg = wasm_execute(
+        ibc_client,
+        &ibc_client::ExecuteMsg::ReceiveMultihop { src_chain, packet },
+        vec![],
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "multihop-packet")
+        .add_message(msg))
+}
+
 /// Handle actions that are passed to the IBC host contract and originate from a registered module
 pub fn handle_host_module_query(
     deps: Deps,
@@ -203,6 +329,17 @@ pub fn handle_host_module_query(
     Ok(bin)
 }
 
+/// Chain name used in the account traces of a client chain.
+/// Differs from the client chain name if the client chain got renamed.
+pub fn trace_chain(
+    storage: &dyn Storage,
+    src_chain: &TruncatedChainId,
+) -> StdResult<TruncatedChainId> {
+    Ok(CLIENT_CHAIN_TRACES
+        .may_load(storage, src_chain)?
+        .unwrap_or_else(|| src_chain.clone()))
+}
+
 /// We need to figure what trace module is implying here
 pub fn client_to_host_module_account_id(
     env: &Env,
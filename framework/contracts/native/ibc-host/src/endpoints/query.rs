@@ -5,7 +5,15 @@ use abstract_sdk::{
     std::ibc_host::QueryMsg,
 };
 use abstract_std::{
-    ibc_host::{state::CHAIN_PROXIES, ClientProxiesResponse, ClientProxyResponse, ConfigResponse},
+    ibc_client::InstalledModuleIdentification,
+    ibc_host::{
+        module_ibc_source_key,
+        state::{
+            CHAIN_PROXIES, CHAIN_QUOTAS, CHAIN_QUOTA_USAGE, MODULE_IBC_SEQUENCES, PAUSED_CHAINS,
+        },
+        ChainQuotaResponse, ChainQuotasResponse, ClientProxiesResponse, ClientProxyResponse,
+        ConfigResponse, ModuleIbcSequenceResponse, PausedChainsResponse,
+    },
     native_addrs,
     objects::{module_factory::ModuleFactoryContract, TruncatedChainId},
 };
@@ -24,6 +32,23 @@ pub fn query(deps: Deps, env: Env, query: QueryMsg) -> HostResult<Binary> {
         }
         QueryMsg::ClientProxy { chain } => to_json_binary(&associated_client(deps, chain)?),
         QueryMsg::Ownership {} => to_json_binary(&cw_ownable::get_ownership(deps.storage)?),
+        QueryMsg::ChainQuota { chain } => to_json_binary(&chain_quota(deps, chain)?),
+        QueryMsg::ChainQuotas { start_after, limit } => {
+            to_json_binary(&chain_quotas(deps, start_after, limit)?)
+        }
+        QueryMsg::PausedChains { start_after, limit } => {
+            to_json_binary(&paused_chains(deps, start_after, limit)?)
+        }
+        QueryMsg::ModuleIbcSequence {
+            src_chain,
+            source_module,
+            target_module,
+        } => to_json_binary(&module_ibc_sequence(
+            deps,
+            src_chain,
+            source_module,
+            target_module,
+        )?),
         QueryMsg::ModuleQuery { target_module, msg } => {
             return packet::handle_host_module_query(deps, env, target_module, msg);
         }
@@ -65,6 +90,63 @@ fn associated_client(deps: Deps, chain: String) -> HostResult<ClientProxyRespons
     Ok(ClientProxyResponse { proxy })
 }
 
+fn chain_quota(deps: Deps, chain: TruncatedChainId) -> HostResult<ChainQuotaResponse> {
+    Ok(ChainQuotaResponse {
+        quota: CHAIN_QUOTAS.may_load(deps.storage, &chain)?,
+        usage: CHAIN_QUOTA_USAGE.may_load(deps.storage, &chain)?,
+        paused: PAUSED_CHAINS.may_load(deps.storage, &chain)?,
+    })
+}
+
+fn chain_quotas(
+    deps: Deps,
+    start_after: Option<TruncatedChainId>,
+    limit: Option<u32>,
+) -> HostResult<ChainQuotasResponse> {
+    let quotas = cw_paginate::paginate_map(
+        &CHAIN_QUOTAS,
+        deps.storage,
+        start_after.as_ref().map(Bound::exclusive),
+        limit,
+        |name, quota| Ok::<_, HostError>((name, quota)),
+    )?;
+
+    Ok(ChainQuotasResponse { quotas })
+}
+
+fn paused_chains(
+    deps: Deps,
+    start_after: Option<TruncatedChainId>,
+    limit: Option<u32>,
+) -> HostResult<PausedChainsResponse> {
+    let chains = cw_paginate::paginate_map(
+        &PAUSED_CHAINS,
+        deps.storage,
+        start_after.as_ref().map(Bound::exclusive),
+        limit,
+        |name, reason| Ok::<_, HostError>((name, reason)),
+    )?;
+
+    Ok(PausedChainsResponse { chains })
+}
+
+fn module_ibc_sequence(
+    deps: Deps,
+    src_chain: TruncatedChainId,
+    source_module: InstalledModuleIdentification,
+    target_module: String,
+) -> HostResult<ModuleIbcSequenceResponse> {
+    let trace_chain = packet::trace_chain(deps.storage, &src_chain)?;
+    let source_key = module_ibc_source_key(&source_module);
+    let last_processed = MODULE_IBC_SEQUENCES
+        .may_load(
+            deps.storage,
+            (&trace_chain, source_key.as_str(), target_module.as_str()),
+        )?
+        .unwrap_or_default();
+    Ok(ModuleIbcSequenceResponse { last_processed })
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::needless_borrows_for_generic_args)]
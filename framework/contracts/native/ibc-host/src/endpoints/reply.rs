@@ -2,10 +2,10 @@ use abstract_std::ibc_host::state::TEMP_ACTION_AFTER_CREATION;
 use cosmwasm_std::{DepsMut, Env, Reply, Response};
 use cw_utils::MsgExecuteContractResponse;
 
-use super::packet::handle_host_action;
+use super::packet::execute_host_action;
 use crate::{
     contract::{HostResponse, HostResult},
-    HostError,
+    rate_limit, HostError,
 };
 
 pub const INIT_BEFORE_ACTION_REPLY_ID: u64 = 28379;
@@ -17,7 +17,8 @@ pub fn reply_execute_action(deps: DepsMut, env: Env, _reply: Reply) -> Result<Re
     let action_cache = TEMP_ACTION_AFTER_CREATION.load(deps.storage)?;
     TEMP_ACTION_AFTER_CREATION.remove(deps.storage);
 
-    handle_host_action(
+    // The quota was already charged when the action was received
+    execute_host_action(
         deps,
         env,
         action_cache.chain_name,
@@ -28,7 +29,10 @@ pub fn reply_execute_action(deps: DepsMut, env: Env, _reply: Reply) -> Result<Re
 }
 
 /// Add the message's data to the response, if any
-pub fn reply_forward_response_data(reply: Reply) -> HostResult {
+pub fn reply_forward_response_data(deps: DepsMut, reply: Reply) -> HostResult {
+    // Funds that left the account can only be measured after execution
+    rate_limit::check_outflows(deps, &reply)?;
+
     // get the result from the reply
     if let cosmwasm_std::SubMsgResult::Ok(response) = reply.result {
         #[allow(deprecated)]
@@ -2,7 +2,7 @@ use abstract_std::{
     objects::{ans_host::AnsHostError, registry::RegistryError},
     AbstractError,
 };
-use cosmwasm_std::{Instantiate2AddressError, StdError};
+use cosmwasm_std::{Coin, Instantiate2AddressError, StdError};
 use cw_ownable::OwnershipError;
 use thiserror::Error;
 
@@ -34,6 +34,24 @@ pub enum HostError {
 
     #[error("Can't send a module-to-module packet to {0}, wrong module type")]
     WrongModuleAction(String),
+
+    #[error("Actions from {chain} are paused: {reason}")]
+    ChainPaused { chain: String, reason: String },
+
+    #[error("Message quota of {chain} exceeded, maximum {max_msgs} messages per window")]
+    MsgQuotaExceeded { chain: String, max_msgs: u32 },
+
+    #[error("Outflow quota of {chain} exceeded, maximum {limit} per window")]
+    OutflowQuotaExceeded { chain: String, limit: Coin },
+
+    #[error("Invalid quota: {0}")]
+    InvalidQuota(String),
+
+    #[error("Client chain {0} is not registered")]
+    UnregisteredChain(String),
+
+    #[error("Module ibc message out of order, expected sequence {expected}, received {received}")]
+    ModuleIbcSequenceGap { expected: u64, received: u64 },
 }
 
 impl From<semver::Error> for HostError {
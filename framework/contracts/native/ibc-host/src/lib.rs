@@ -6,5 +6,6 @@ pub mod account_commands;
 pub mod chains;
 pub mod endpoints;
 pub mod error;
+pub mod rate_limit;
 
 pub mod contract;
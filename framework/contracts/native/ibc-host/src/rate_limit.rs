@@ -0,0 +1,324 @@
+//! Per client chain quotas and circuit-breaker.
+//!
+//! Quotas limit the amount of messages that can be dispatched and the amount of funds that can leave the
+//! remote accounts of a client chain within a window of blocks.
+//! Outflows are measured from the funds the account sends or spends, like staking delegations, while its
+//! messages are dispatched. Funds received in the meantime don't offset them.
+
+use abstract_std::{
+    ibc_host::{
+        state::{
+            OutflowCheckCache, CHAIN_QUOTAS, CHAIN_QUOTA_USAGE, PAUSED_CHAINS, TEMP_OUTFLOW_CHECK,
+        },
+        ChainQuota, QuotaUsage,
+    },
+    objects::TruncatedChainId,
+};
+use cosmwasm_std::{
+    ensure, Addr, Coin, Deps, DepsMut, Env, Event, Reply, StdError, StdResult, Storage,
+    SubMsgResult,
+};
+
+use crate::{contract::HostResult, HostError};
+
+/// Errors if the client chain is paused by the circuit-breaker
+pub fn assert_not_paused(storage: &dyn Storage, chain: &TruncatedChainId) -> HostResult<()> {
+    if let Some(reason) = PAUSED_CHAINS.may_load(storage, chain)? {
+        return Err(HostError::ChainPaused {
+            chain: chain.to_string(),
+            reason,
+        });
+    }
+    Ok(())
+}
+
+/// Load the usage of the current window, starts a new window if the last one expired
+fn current_usage(
+    storage: &dyn Storage,
+    env: &Env,
+    chain: &TruncatedChainId,
+    quota: &ChainQuota,
+) -> StdResult<QuotaUsage> {
+    let usage = CHAIN_QUOTA_USAGE
+        .may_load(storage, chain)?
+        .filter(|usage| env.block.height < usage.window_start + quota.window_blocks)
+        .unwrap_or(QuotaUsage {
+            window_start: env.block.height,
+            msgs: 0,
+            outflows: vec![],
+        });
+    Ok(usage)
+}
+
+/// Count `msgs` dispatched messages against the quota of the client chain
+pub fn consume_msgs(
+    storage: &mut dyn Storage,
+    env: &Env,
+    chain: &TruncatedChainId,
+    msgs: u32,
+) -> HostResult<()> {
+    let Some(quota) = CHAIN_QUOTAS.may_load(storage, chain)? else {
+        return Ok(());
+    };
+    let mut usage = current_usage(storage, env, chain, &quota)?;
+    usage.msgs += msgs;
+    if let Some(max_msgs) = quota.max_msgs {
+        ensure!(
+            usage.msgs <= max_msgs,
+            HostError::MsgQuotaExceeded {
+                chain: chain.to_string(),
+                max_msgs,
+            }
+        );
+    }
+    CHAIN_QUOTA_USAGE.save(storage, chain, &usage)?;
+    Ok(())
+}
+
+/// Count the funds that directly leave an account against the quota of the client chain
+pub fn consume_outflows(
+    deps: DepsMut,
+    env: &Env,
+    chain: &TruncatedChainId,
+    outflows: Vec<Coin>,
+) -> HostResult<()> {
+    let Some(quota) = CHAIN_QUOTAS.may_load(deps.storage, chain)? else {
+        return Ok(());
+    };
+    let mut usage = current_usage(deps.storage, env, chain, &quota)?;
+    usage.outflows = checked_outflows(chain, &quota, &usage.outflows, &outflows)?;
+    CHAIN_QUOTA_USAGE.save(deps.storage, chain, &usage)?;
+    Ok(())
+}
+
+/// Balances of the rate-limited denoms of the account
+pub fn limited_balances(
+    deps: Deps,
+    chain: &TruncatedChainId,
+    account: &Addr,
+) -> HostResult<Vec<Coin>> {
+    let Some(quota) = CHAIN_QUOTAS.may_load(deps.storage, chain)? else {
+        return Ok(vec![]);
+    };
+    quota
+        .max_outflows
+        .iter()
+        .map(|limit| deps.querier.query_balance(account, &limit.denom))
+        .collect::<StdResult<_>>()
+        .map_err(Into::into)
+}
+
+/// Track the funds that leave the account while dispatching `pending_replies` messages.
+/// The outflows are checked in the reply of every dispatched message with [`check_outflows`].
+pub fn track_outflows(
+    deps: DepsMut,
+    env: &Env,
+    chain: &TruncatedChainId,
+    account: &Addr,
+    pending_replies: u32,
+) -> HostResult<()> {
+    let Some(quota) = CHAIN_QUOTAS.may_load(deps.storage, chain)? else {
+        return Ok(());
+    };
+    if quota.max_outflows.is_empty() || pending_replies == 0 {
+        return Ok(());
+    }
+    let usage = current_usage(deps.storage, env, chain, &quota)?;
+
+    CHAIN_QUOTA_USAGE.save(deps.storage, chain, &usage)?;
+    TEMP_OUTFLOW_CHECK.save(
+        deps.storage,
+        &OutflowCheckCache {
+            chain_name: chain.clone(),
+            account: account.clone(),
+            pending_replies,
+        },
+    )?;
+    Ok(())
+}
+
+/// Check the funds the account sent in the replied message against the quota of the client chain
+pub fn check_outflows(deps: DepsMut, reply: &Reply) -> HostResult<()> {
+    let Some(mut cache) = TEMP_OUTFLOW_CHECK.may_load(deps.storage)? else {
+        return Ok(());
+    };
+    let chain = &cache.chain_name;
+    let quota = CHAIN_QUOTAS.load(deps.storage, chain)?;
+
+    let outflows = match &reply.result {
+        SubMsgResult::Ok(response) => sent_funds(&response.events, &cache.account)?,
+        // Failed messages revert their transfers
+        SubMsgResult::Err(_) => vec![],
+    };
+
+    // Usage window got saved when the tracking started
+    let mut usage = CHAIN_QUOTA_USAGE.load(deps.storage, chain)?;
+    usage.outflows = checked_outflows(chain, &quota, &usage.outflows, &outflows)?;
+    CHAIN_QUOTA_USAGE.save(deps.storage, chain, &usage)?;
+
+    cache.pending_replies -= 1;
+    if cache.pending_replies == 0 {
+        TEMP_OUTFLOW_CHECK.remove(deps.storage);
+    } else {
+        TEMP_OUTFLOW_CHECK.save(deps.storage, &cache)?;
+    }
+    Ok(())
+}
+
+/// Funds sent by `account` in the bank events of `events`.
+///
+/// Bank sends, ICS20 transfers and funds attached to contract calls emit a transfer event, but
+/// moves to module accounts like staking delegations only emit a coin_spent event. On chain every
+/// transfer also emits a coin_spent event, so the larger total of both is taken for each denom.
+fn sent_funds(events: &[Event], account: &Addr) -> StdResult<Vec<Coin>> {
+    let attribute = |event: &Event, key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.clone())
+            .unwrap_or_default()
+    };
+
+    let (mut transferred, mut spent) = (vec![], vec![]);
+    for event in events {
+        match event.ty.as_str() {
+            "transfer"
+                if attribute(event, "sender") == account.as_str()
+                    && attribute(event, "recipient") != account.as_str() =>
+            {
+                add_coins(&mut transferred, &attribute(event, "amount"))?
+            }
+            "coin_spent" if attribute(event, "spender") == account.as_str() => {
+                add_coins(&mut spent, &attribute(event, "amount"))?
+            }
+            _ => {}
+        }
+    }
+
+    for coin in spent {
+        match transferred.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(c) => c.amount = c.amount.max(coin.amount),
+            None => transferred.push(coin),
+        }
+    }
+    Ok(transferred)
+}
+
+/// Adds the coins of a bank event amount, like `100ujuno,5uatom`, to `coins`
+fn add_coins(coins: &mut Vec<Coin>, amount: &str) -> StdResult<()> {
+    for coin in amount.split(',').filter(|coin| !coin.is_empty()) {
+        let coin: Coin = coin
+            .parse()
+            .map_err(|err| StdError::generic_err(format!("invalid bank event amount: {err}")))?;
+        match coins.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(c) => c.amount = c.amount.checked_add(coin.amount)?,
+            None => coins.push(coin),
+        }
+    }
+    Ok(())
+}
+
+/// Adds `outflows` to the usage and checks the totals against the quota
+fn checked_outflows(
+    chain: &TruncatedChainId,
+    quota: &ChainQuota,
+    outflows_before: &[Coin],
+    outflows: &[Coin],
+) -> HostResult<Vec<Coin>> {
+    let amount_of = |coins: &[Coin], denom: &str| {
+        coins
+            .iter()
+            .find(|c| c.denom == denom)
+            .map(|c| c.amount)
+            .unwrap_or_default()
+    };
+
+    quota
+        .max_outflows
+        .iter()
+        .map(|limit| {
+            let total = amount_of(outflows_before, &limit.denom)
+                .checked_add(amount_of(outflows, &limit.denom))
+                .map_err(StdError::from)?;
+            ensure!(
+                total <= limit.amount,
+                HostError::OutflowQuotaExceeded {
+                    chain: chain.to_string(),
+                    limit: limit.clone(),
+                }
+            );
+            Ok(Coin::new(total, limit.denom.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::coin;
+
+    use super::*;
+
+    fn bank_event(ty: &str, attrs: &[(&str, &str)]) -> Event {
+        Event::new(ty).add_attributes(attrs.iter().copied())
+    }
+
+    #[test]
+    fn counts_transfers_and_spends_once() {
+        let account = Addr::unchecked("account");
+        let events = [
+            // Bank send, emitting both events on chain
+            bank_event(
+                "coin_spent",
+                &[("spender", "account"), ("amount", "60ujuno,5uatom")],
+            ),
+            bank_event(
+                "transfer",
+                &[
+                    ("recipient", "attacker"),
+                    ("sender", "account"),
+                    ("amount", "60ujuno,5uatom"),
+                ],
+            ),
+            // Delegation to the bonded pool, without a transfer event
+            bank_event(
+                "coin_spent",
+                &[("spender", "account"), ("amount", "40ujuno")],
+            ),
+            // Funds received or sent by others
+            bank_event(
+                "transfer",
+                &[
+                    ("recipient", "account"),
+                    ("sender", "faucet"),
+                    ("amount", "100ujuno"),
+                ],
+            ),
+            bank_event(
+                "coin_spent",
+                &[("spender", "faucet"), ("amount", "100ujuno")],
+            ),
+        ];
+        assert_eq!(
+            sent_funds(&events, &account).unwrap(),
+            vec![coin(100, "ujuno"), coin(5, "uatom")]
+        );
+    }
+
+    #[test]
+    fn counts_transfers_without_spend_events() {
+        let account = Addr::unchecked("account");
+        let events = [bank_event(
+            "transfer",
+            &[
+                ("recipient", "attacker"),
+                ("sender", "account"),
+                ("amount", "60ujuno"),
+            ],
+        )];
+        assert_eq!(
+            sent_funds(&events, &account).unwrap(),
+            vec![coin(60, "ujuno")]
+        );
+    }
+}
@@ -5,8 +5,11 @@ use abstract_interface::{
 };
 use abstract_std::{
     account::ModuleInstallConfig,
+    ibc::polytone_callbacks::Callback,
+    ibc_client::MultihopPacket,
     ibc_host::{
-        ClientProxyResponse, ConfigResponse, ExecuteMsgFns, HostAction, InternalAction, QueryMsgFns,
+        ChainQuota, ClientProxyResponse, ConfigResponse, ExecuteMsgFns, HostAction, InternalAction,
+        QueryMsgFns,
     },
     objects::{
         gov_type::{GovAction, GovernanceDetails},
@@ -15,8 +18,8 @@ use abstract_std::{
     },
     ACCOUNT, ICS20, REGISTRY,
 };
-use cosmwasm_std::Event;
-use cw_orch::prelude::*;
+use cosmwasm_std::{coin, to_json_binary, BankMsg, Empty, Event, WasmMsg};
+use cw_orch::{mock::cw_multi_test::Executor, prelude::*};
 use cw_ownable::OwnershipError;
 
 use crate::mock_adapter::{MockAdapter, MOCK_ADAPTER_ID};
@@ -30,6 +33,39 @@ mod mock_adapter {
     gen_adapter_mock!(MockAdapter, MOCK_ADAPTER_ID, "1.0.0", &[]);
 }
 
+/// Contract that sends 100ujuno to every caller
+mod faucet {
+    use cosmwasm_std::{
+        coins, BankMsg, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError,
+        StdResult,
+    };
+
+    pub fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    pub fn execute(
+        _deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new().add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(100, "ujuno"),
+        }))
+    }
+
+    pub fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        Err(StdError::generic_err("faucet has no queries"))
+    }
+}
+
 #[test]
 fn account_creation() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -415,3 +451,471 @@ fn execute_send_all_back_action() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+fn register_remote_account(abstr: &Abstract<MockBech32>, proxy: &Addr) -> anyhow::Result<()> {
+    abstr.ibc.host.call_as(proxy).ibc_execute(
+        proxy.to_string(),
+        AccountId::local(1),
+        HostAction::Internal(InternalAction::Register {
+            name: None,
+            description: None,
+            link: None,
+            namespace: None,
+            install_modules: vec![],
+        }),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn paused_chain_rejects_actions() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+    let chain: TruncatedChainId = "juno".parse()?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+
+    abstr
+        .ibc
+        .host
+        .pause_chain(chain.clone(), "compromised".to_owned())?;
+    assert_eq!(
+        abstr.ibc.host.chain_quota(chain.clone())?.paused,
+        Some("compromised".to_owned())
+    );
+
+    let err: CwOrchError = register_remote_account(&abstr, &polytone_proxy)
+        .unwrap_err()
+        .downcast()?;
+    assert_eq!(
+        HostError::ChainPaused {
+            chain: chain.to_string(),
+            reason: "compromised".to_owned()
+        },
+        err.downcast()?
+    );
+
+    abstr.ibc.host.unpause_chain(chain)?;
+    register_remote_account(&abstr, &polytone_proxy)?;
+
+    Ok(())
+}
+
+#[test]
+fn only_owner_pauses_chain() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+
+    let err: CwOrchError = abstr
+        .ibc
+        .host
+        .call_as(&mock.addr_make("user"))
+        .pause_chain("juno".parse()?, "compromised".to_owned())
+        .unwrap_err();
+
+    assert_eq!(
+        HostError::OwnershipError(OwnershipError::NotOwner),
+        err.downcast()?
+    );
+    Ok(())
+}
+
+#[test]
+fn message_quota_resets_every_window() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+    let chain: TruncatedChainId = "juno".parse()?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+    abstr.ibc.host.set_chain_quota(
+        chain.clone(),
+        Some(ChainQuota {
+            window_blocks: 10,
+            max_msgs: Some(2),
+            max_outflows: vec![],
+        }),
+    )?;
+
+    // Registration counts as one message
+    register_remote_account(&abstr, &polytone_proxy)?;
+
+    let dispatch = HostAction::Dispatch {
+        account_msgs: vec![
+            abstract_std::account::ExecuteMsg::UpdateInfo {
+                name: Some("name".to_owned()),
+                description: None,
+                link: None,
+            },
+            abstract_std::account::ExecuteMsg::UpdateInfo {
+                name: Some("other name".to_owned()),
+                description: None,
+                link: None,
+            },
+        ],
+    };
+    let err: CwOrchError = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(
+            polytone_proxy.to_string(),
+            AccountId::local(1),
+            dispatch.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        HostError::MsgQuotaExceeded {
+            chain: chain.to_string(),
+            max_msgs: 2
+        },
+        err.downcast()?
+    );
+
+    mock.wait_blocks(10)?;
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        polytone_proxy.to_string(),
+        AccountId::local(1),
+        dispatch,
+    )?;
+    assert_eq!(abstr.ibc.host.chain_quota(chain)?.usage.unwrap().msgs, 2);
+
+    Ok(())
+}
+
+#[test]
+fn action_with_account_creation_is_charged_once() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+    let chain: TruncatedChainId = "juno".parse()?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+    abstr.ibc.host.set_chain_quota(
+        chain.clone(),
+        Some(ChainQuota {
+            window_blocks: 10,
+            max_msgs: Some(2),
+            max_outflows: vec![],
+        }),
+    )?;
+
+    // The account doesn't exist yet, the dispatch is executed after its creation
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        polytone_proxy.to_string(),
+        AccountId::local(1),
+        HostAction::Dispatch {
+            account_msgs: vec![
+                abstract_std::account::ExecuteMsg::UpdateInfo {
+                    name: Some("name".to_owned()),
+                    description: None,
+                    link: None,
+                },
+                abstract_std::account::ExecuteMsg::UpdateInfo {
+                    name: Some("other name".to_owned()),
+                    description: None,
+                    link: None,
+                },
+            ],
+        },
+    )?;
+    assert_eq!(abstr.ibc.host.chain_quota(chain)?.usage.unwrap().msgs, 2);
+
+    Ok(())
+}
+
+#[test]
+fn outflow_quota_limits_funds_leaving_accounts() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+    let chain: TruncatedChainId = "juno".parse()?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+    abstr.ibc.host.set_chain_quota(
+        chain.clone(),
+        Some(ChainQuota {
+            window_blocks: 10,
+            max_msgs: None,
+            max_outflows: vec![coin(100, "ujuno")],
+        }),
+    )?;
+
+    register_remote_account(&abstr, &polytone_proxy)?;
+    let remote_account_id = AccountId::new(1, AccountTrace::Remote(vec![chain.clone()]))?;
+    let remote_account = abstr.registry.account(remote_account_id)?;
+    mock.set_balance(remote_account.addr(), vec![coin(1_000, "ujuno")])?;
+
+    let send = |amount: u128| HostAction::Dispatch {
+        account_msgs: vec![abstract_std::account::ExecuteMsg::Execute {
+            msgs: vec![BankMsg::Send {
+                to_address: mock.addr_make("attacker").to_string(),
+                amount: vec![coin(amount, "ujuno")],
+            }
+            .into()],
+        }],
+    };
+
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        polytone_proxy.to_string(),
+        AccountId::local(1),
+        send(60),
+    )?;
+
+    let err: CwOrchError = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(polytone_proxy.to_string(), AccountId::local(1), send(60))
+        .unwrap_err();
+    assert_eq!(
+        HostError::OutflowQuotaExceeded {
+            chain: chain.to_string(),
+            limit: coin(100, "ujuno")
+        },
+        err.downcast()?
+    );
+
+    let usage = abstr.ibc.host.chain_quota(chain)?.usage.unwrap();
+    assert_eq!(usage.outflows, vec![coin(60, "ujuno")]);
+    assert_eq!(
+        mock.query_balance(remote_account.addr(), "ujuno")?.u128(),
+        940
+    );
+
+    Ok(())
+}
+
+#[test]
+fn outflow_quota_ignores_funds_received_in_the_same_message() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+    let chain: TruncatedChainId = "juno".parse()?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+    abstr.ibc.host.set_chain_quota(
+        chain.clone(),
+        Some(ChainQuota {
+            window_blocks: 10,
+            max_msgs: None,
+            max_outflows: vec![coin(100, "ujuno")],
+        }),
+    )?;
+
+    register_remote_account(&abstr, &polytone_proxy)?;
+    let remote_account_id = AccountId::new(1, AccountTrace::Remote(vec![chain.clone()]))?;
+    let remote_account = abstr.registry.account(remote_account_id)?;
+    mock.set_balance(remote_account.addr(), vec![coin(1_000, "ujuno")])?;
+
+    let faucet_id = mock
+        .app
+        .borrow_mut()
+        .store_code(Box::new(ContractWrapper::new(
+            faucet::execute,
+            faucet::instantiate,
+            faucet::query,
+        )));
+    let faucet = mock.app.borrow_mut().instantiate_contract(
+        faucet_id,
+        mock.sender_addr(),
+        &Empty {},
+        &[],
+        "faucet",
+        None,
+    )?;
+    mock.set_balance(&faucet, vec![coin(1_000, "ujuno")])?;
+
+    // Receive 100ujuno from the faucet and send `amount` away in the same message
+    let claim_and_send = |amount: u128| -> anyhow::Result<HostAction> {
+        Ok(HostAction::Dispatch {
+            account_msgs: vec![abstract_std::account::ExecuteMsg::Execute {
+                msgs: vec![
+                    WasmMsg::Execute {
+                        contract_addr: faucet.to_string(),
+                        msg: to_json_binary(&Empty {})?,
+                        funds: vec![],
+                    }
+                    .into(),
+                    BankMsg::Send {
+                        to_address: mock.addr_make("attacker").to_string(),
+                        amount: vec![coin(amount, "ujuno")],
+                    }
+                    .into(),
+                ],
+            }],
+        })
+    };
+
+    // The balance of the account grows, but 90ujuno left it
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        polytone_proxy.to_string(),
+        AccountId::local(1),
+        claim_and_send(90)?,
+    )?;
+    let usage = abstr.ibc.host.chain_quota(chain.clone())?.usage.unwrap();
+    assert_eq!(usage.outflows, vec![coin(90, "ujuno")]);
+    assert_eq!(
+        mock.query_balance(remote_account.addr(), "ujuno")?.u128(),
+        1_010
+    );
+
+    // Received funds don't offset the sent ones
+    let err: CwOrchError = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(
+            polytone_proxy.to_string(),
+            AccountId::local(1),
+            claim_and_send(20)?,
+        )
+        .unwrap_err();
+    assert_eq!(
+        HostError::OutflowQuotaExceeded {
+            chain: chain.to_string(),
+            limit: coin(100, "ujuno")
+        },
+        err.downcast()?
+    );
+    assert_eq!(
+        mock.query_balance(remote_account.addr(), "ujuno")?.u128(),
+        1_010
+    );
+
+    Ok(())
+}
+
+#[test]
+fn renamed_client_chain_keeps_account_traces() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+    let chain: TruncatedChainId = "juno".parse()?;
+    let new_chain: TruncatedChainId = "junotwo".parse()?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+    register_remote_account(&abstr, &polytone_proxy)?;
+    abstr
+        .ibc
+        .host
+        .pause_chain(chain.clone(), "chain upgrade".to_owned())?;
+
+    // Only the owner can rename
+    let err: CwOrchError = abstr
+        .ibc
+        .host
+        .call_as(&mock.addr_make("user"))
+        .rename_client_chain(chain.clone(), new_chain.clone())
+        .unwrap_err();
+    assert_eq!(
+        HostError::OwnershipError(OwnershipError::NotOwner),
+        err.downcast()?
+    );
+
+    abstr
+        .ibc
+        .host
+        .rename_client_chain(chain.clone(), new_chain.clone())?;
+
+    let client_proxy_response: ClientProxyResponse =
+        abstr.ibc.host.client_proxy(new_chain.to_string())?;
+    assert_eq!(polytone_proxy, client_proxy_response.proxy);
+    assert!(abstr.ibc.host.client_proxy(chain.to_string()).is_err());
+    // Pause status moved with the chain
+    assert_eq!(
+        abstr.ibc.host.chain_quota(new_chain.clone())?.paused,
+        Some("chain upgrade".to_owned())
+    );
+    abstr.ibc.host.unpause_chain(new_chain)?;
+
+    // Account is still resolved with the old chain name, so it can't be registered twice
+    assert!(register_remote_account(&abstr, &polytone_proxy).is_err());
+    let response = abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        polytone_proxy.to_string(),
+        AccountId::local(1),
+        HostAction::Dispatch {
+            account_msgs: vec![abstract_std::account::ExecuteMsg::UpdateInfo {
+                name: Some("renamed".to_owned()),
+                description: None,
+                link: None,
+            }],
+        },
+    )?;
+    assert!(!response.has_event(
+        &Event::new("wasm-abstract")
+            .add_attribute("contract", REGISTRY)
+            .add_attribute("action", "add_account")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn multihop_packets_are_relayed_to_the_client() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+    let chain: TruncatedChainId = "juno".parse()?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+
+    let packet = MultihopPacket::Result {
+        route_id: 1,
+        result: Callback::Execute(Err("failed".to_owned())),
+        registered_account: None,
+    };
+
+    // Only registered client proxies can relay packets
+    assert!(abstr
+        .ibc
+        .host
+        .call_as(&mock.addr_make("user"))
+        .multihop(packet.clone())
+        .is_err());
+
+    // The client doesn't know the route, so it got the packet
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .multihop(packet.clone())
+        .unwrap_err();
+    assert!(err.root().to_string().contains("Unknown multi-hop route 1"));
+
+    abstr
+        .ibc
+        .host
+        .pause_chain(chain, "compromised".to_owned())?;
+    let err: CwOrchError = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .multihop(packet)
+        .unwrap_err();
+    assert!(matches!(err.downcast()?, HostError::ChainPaused { .. }));
+
+    Ok(())
+}
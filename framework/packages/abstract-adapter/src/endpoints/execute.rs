@@ -8,7 +8,9 @@ use abstract_std::{
     adapter::{AdapterBaseMsg, AdapterExecuteMsg, AdapterRequestMsg, BaseExecuteMsg, ExecuteMsg},
     objects::ownership::nested_admin::query_top_level_owner_addr,
 };
-use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response, StdResult};
+use cosmwasm_std::{
+    Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response, StdResult, Storage,
+};
 use schemars::JsonSchema;
 use serde::Serialize;
 
@@ -101,12 +103,16 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
             AdapterBaseMsg::UpdateAuthorizedAddresses { to_add, to_remove } => {
                 self.update_authorized_addresses(deps, info, to_add, to_remove)
             }
+            AdapterBaseMsg::UpdateAuthorizedScopes { address, scopes } => {
+                self.update_authorized_scopes(deps, info, address, scopes)
+            }
         }
     }
 
     /// Handle a custom execution message sent to this api.
     /// Two success scenarios are possible:
     /// 1. The sender is an authorized address of the given account address and has provided the account address in the message.
+    ///    If the authorized address is scoped, the request's action must be in its scopes.
     /// 2. The sender is a account of the given account address.
     fn handle_app_msg(
         mut self,
@@ -139,12 +145,19 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                         .authorized_addresses
                         .load(deps.storage, account_address)
                         .unwrap_or_default();
-                    if authorized.contains(sender)
-                        || is_top_level_owner(&deps.querier, requested_core.addr().clone(), sender)
-                            .unwrap_or(false)
+                    if is_top_level_owner(&deps.querier, requested_core.addr().clone(), sender)
+                        .unwrap_or(false)
                     {
-                        // If the sender is an authorized address,
-                        // or top level account return the account.
+                        // If the sender is the top level account owner return the account.
+                        requested_core
+                    } else if authorized.contains(sender) {
+                        // If the sender is an authorized address, it must be allowed to perform this request.
+                        self.assert_request_in_scope(
+                            deps.storage,
+                            requested_core.addr(),
+                            sender,
+                            &request.request,
+                        )?;
                         requested_core
                     } else {
                         // If not, we error, this call is not permitted
@@ -157,7 +170,7 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                 .map_err(|_| unauthorized_sender())?,
         };
         self.target_account = Some(account);
-        self.execute_handler()?(deps, env, info, self, request.request)
+        self.execute_with_middleware(deps, env, info, request.request)
     }
 
     /// Update authorized addresses from the adapter.
@@ -207,6 +220,8 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                 });
             } else {
                 authorized_addrs.retain(|addr| deauthorized_addr.ne(addr));
+                self.authorized_scopes
+                    .remove(deps.storage, (account_addr.clone(), deauthorized_addr));
             }
         }
 
@@ -223,6 +238,89 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
             vec![("account", account_addr.as_str())],
         ))
     }
+
+    /// Restrict an authorized address to a subset of the declared request actions, or lift the restriction.
+    fn update_authorized_scopes(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+        scopes: Option<Vec<String>>,
+    ) -> AdapterResult {
+        let account = self.target_account.as_ref().unwrap();
+        let account_addr = account.addr().clone();
+
+        let authorized_addr =
+            get_addr_from_module_id_or_addr(deps.as_ref(), info.sender, address.clone())?;
+        let authorized_addrs = self
+            .authorized_addresses
+            .may_load(deps.storage, account_addr.clone())?
+            .unwrap_or_default();
+        if !authorized_addrs.contains(&authorized_addr) {
+            return Err(AdapterError::AuthorizedAddressOrModuleIdNotPresent {
+                addr_or_module_id: address,
+            });
+        }
+
+        let key = (account_addr.clone(), authorized_addr.clone());
+        match scopes {
+            Some(mut scopes) => {
+                if self.request_action_fn.is_none() {
+                    return Err(AdapterError::RequestActionsNotDeclared {
+                        adapter: self.module_id().to_string(),
+                    });
+                }
+                if let Some(unknown) = scopes
+                    .iter()
+                    .find(|scope| !self.request_actions.contains(&scope.as_str()))
+                {
+                    return Err(AdapterError::UnknownRequestAction {
+                        adapter: self.module_id().to_string(),
+                        action: unknown.clone(),
+                    });
+                }
+                scopes.sort();
+                scopes.dedup();
+                self.authorized_scopes.save(deps.storage, key, &scopes)?;
+            }
+            None => self.authorized_scopes.remove(deps.storage, key),
+        }
+
+        Ok(self.custom_response(
+            "update_authorized_scopes",
+            vec![
+                ("account", account_addr.as_str()),
+                ("authorized_address", authorized_addr.as_str()),
+            ],
+        ))
+    }
+
+    /// Assert that a scoped authorized address is allowed to perform the request.
+    fn assert_request_in_scope(
+        &self,
+        storage: &dyn Storage,
+        account_addr: &Addr,
+        sender: &Addr,
+        request: &CustomExecMsg,
+    ) -> Result<(), AdapterError> {
+        let (Some(scopes), Some(action_fn)) = (
+            self.authorized_scopes
+                .may_load(storage, (account_addr.clone(), sender.clone()))?,
+            self.request_action_fn,
+        ) else {
+            return Ok(());
+        };
+        let action = action_fn(request);
+        if scopes.iter().any(|scope| scope == action) {
+            Ok(())
+        } else {
+            Err(AdapterError::UnauthorizedRequestAction {
+                adapter: self.module_id().to_string(),
+                sender: sender.to_string(),
+                action: action.to_string(),
+            })
+        }
+    }
 }
 
 /// This function is a helper to get a contract address from a module ir or from an address.
@@ -472,6 +570,179 @@ mod tests {
         }
     }
 
+    mod update_authorized_scopes {
+        use super::*;
+        use crate::mock::{MockAdapterContract, TEST_AUTHORIZED_ADDR};
+        use abstract_std::registry::Account;
+
+        const SCOPED_ADAPTER: MockAdapterContract =
+            MOCK_ADAPTER.with_request_actions(&["mock", "other"], |_| "mock");
+
+        fn scoped_execute_as(
+            deps: &mut MockDeps,
+            sender: &Addr,
+            msg: ExecuteMsg<MockExecMsg>,
+        ) -> Result<Response, MockError> {
+            let env = mock_env_validated(deps.api);
+            SCOPED_ADAPTER.execute(deps.as_mut(), env, message_info(sender, &[]), msg)
+        }
+
+        fn update_scopes_msg(address: &Addr, scopes: Option<Vec<&str>>) -> ExecuteMsg<MockExecMsg> {
+            ExecuteMsg::Base(BaseExecuteMsg {
+                account_address: None,
+                msg: AdapterBaseMsg::UpdateAuthorizedScopes {
+                    address: address.to_string(),
+                    scopes: scopes.map(|s| s.into_iter().map(String::from).collect()),
+                },
+            })
+        }
+
+        /// Sets up TEST_ACCOUNT with a single authorized address
+        fn setup(deps: &mut MockDeps) -> (Account, Addr) {
+            let account = test_account(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .set_account_admin_call_to(&account)
+                .build();
+            mock_init(deps).unwrap();
+
+            let authorized = deps.api.addr_make(TEST_AUTHORIZED_ADDR);
+            let msg = ExecuteMsg::Base(BaseExecuteMsg {
+                account_address: None,
+                msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
+                    to_add: vec![authorized.to_string()],
+                    to_remove: vec![],
+                },
+            });
+            scoped_execute_as(deps, account.addr(), msg).unwrap();
+            (account, authorized)
+        }
+
+        fn module_request(account: &Account) -> ExecuteMsg<MockExecMsg> {
+            ExecuteMsg::Module(AdapterRequestMsg {
+                account_address: Some(account.addr().to_string()),
+                request: MockExecMsg {},
+            })
+        }
+
+        #[coverage_helper::test]
+        fn requires_declared_actions() {
+            let mut deps = mock_dependencies();
+            let (account, authorized) = setup(&mut deps);
+
+            let res = execute_as(
+                &mut deps,
+                account.addr(),
+                update_scopes_msg(&authorized, Some(vec!["mock"])),
+            );
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(
+                    AdapterError::RequestActionsNotDeclared {
+                        adapter: TEST_MODULE_ID.to_owned()
+                    }
+                ))
+            );
+        }
+
+        #[coverage_helper::test]
+        fn rejects_unknown_actions_and_addresses() {
+            let mut deps = mock_dependencies();
+            let (account, authorized) = setup(&mut deps);
+
+            let res = scoped_execute_as(
+                &mut deps,
+                account.addr(),
+                update_scopes_msg(&authorized, Some(vec!["swap"])),
+            );
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(AdapterError::UnknownRequestAction {
+                    adapter: TEST_MODULE_ID.to_owned(),
+                    action: "swap".to_owned()
+                }))
+            );
+
+            let not_authorized = deps.api.addr_make("not_authorized");
+            let res = scoped_execute_as(
+                &mut deps,
+                account.addr(),
+                update_scopes_msg(&not_authorized, Some(vec!["mock"])),
+            );
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(
+                    AdapterError::AuthorizedAddressOrModuleIdNotPresent {
+                        addr_or_module_id: not_authorized.to_string()
+                    }
+                ))
+            );
+        }
+
+        #[coverage_helper::test]
+        fn scoped_address_limited_to_its_actions() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let (account, authorized) = setup(&mut deps);
+
+            scoped_execute_as(
+                &mut deps,
+                account.addr(),
+                update_scopes_msg(&authorized, Some(vec!["other"])),
+            )?;
+            let res = scoped_execute_as(&mut deps, &authorized, module_request(&account));
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(
+                    AdapterError::UnauthorizedRequestAction {
+                        adapter: TEST_MODULE_ID.to_owned(),
+                        sender: authorized.to_string(),
+                        action: "mock".to_owned()
+                    }
+                ))
+            );
+
+            scoped_execute_as(
+                &mut deps,
+                account.addr(),
+                update_scopes_msg(&authorized, Some(vec!["mock"])),
+            )?;
+            scoped_execute_as(&mut deps, &authorized, module_request(&account))?;
+
+            // Lifting the scopes allows all requests again
+            scoped_execute_as(
+                &mut deps,
+                account.addr(),
+                update_scopes_msg(&authorized, None),
+            )?;
+            assert!(SCOPED_ADAPTER.authorized_scopes.is_empty(&deps.storage));
+            scoped_execute_as(&mut deps, &authorized, module_request(&account))?;
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn removing_authorization_clears_scopes() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let (account, authorized) = setup(&mut deps);
+
+            scoped_execute_as(
+                &mut deps,
+                account.addr(),
+                update_scopes_msg(&authorized, Some(vec!["other"])),
+            )?;
+            let msg = ExecuteMsg::Base(BaseExecuteMsg {
+                account_address: None,
+                msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
+                    to_add: vec![],
+                    to_remove: vec![authorized.to_string()],
+                },
+            });
+            scoped_execute_as(&mut deps, account.addr(), msg)?;
+
+            assert!(SCOPED_ADAPTER.authorized_scopes.is_empty(&deps.storage));
+            Ok(())
+        }
+    }
+
     mod execute_app {
         use super::*;
 
@@ -4,7 +4,8 @@ use abstract_sdk::{
 };
 use abstract_std::{
     adapter::{
-        AdapterConfigResponse, AdapterQueryMsg, AuthorizedAddressesResponse, BaseQueryMsg, QueryMsg,
+        AdapterConfigResponse, AdapterQueryMsg, AuthorizedAddressesResponse,
+        AuthorizedScopesResponse, BaseQueryMsg, QueryMsg, RequestActionsResponse,
     },
     objects::module_version::{ModuleDataResponse, MODULE},
 };
@@ -52,6 +53,26 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                 })
                 .map_err(Into::into)
             }
+            BaseQueryMsg::AuthorizedScopes {
+                account_address,
+                address,
+            } => {
+                let account_address = deps.api.addr_validate(&account_address)?;
+                let address = deps.api.addr_validate(&address)?;
+                let scopes = self
+                    .authorized_scopes
+                    .may_load(deps.storage, (account_address, address))?;
+
+                to_json_binary(&AuthorizedScopesResponse { scopes }).map_err(Into::into)
+            }
+            BaseQueryMsg::RequestActions {} => to_json_binary(&RequestActionsResponse {
+                actions: self
+                    .request_actions
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            })
+            .map_err(Into::into),
             BaseQueryMsg::ModuleData {} => {
                 to_json_binary(&self.module_data(deps).map_err(Error::from)?).map_err(Into::into)
             }
@@ -36,4 +36,17 @@ pub enum AdapterError {
 
     #[error("Maximum authorized addresses ({}) reached", max)]
     TooManyAuthorizedAddresses { max: u32 },
+
+    #[error("Adapter {adapter} does not declare request actions to scope authorized addresses to")]
+    RequestActionsNotDeclared { adapter: String },
+
+    #[error("Action {action} is not a request action of adapter {adapter}")]
+    UnknownRequestAction { adapter: String, action: String },
+
+    #[error("Authorized address {sender} is not allowed to perform {action} on {adapter}")]
+    UnauthorizedRequestAction {
+        adapter: String,
+        sender: String,
+        action: String,
+    },
 }
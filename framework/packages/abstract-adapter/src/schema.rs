@@ -1,6 +1,9 @@
 use std::path::Path;
 
-use abstract_sdk::base::{ExecuteEndpoint, InstantiateEndpoint, QueryEndpoint};
+use abstract_sdk::{
+    base::{ExecuteEndpoint, InstantiateEndpoint, QueryEndpoint},
+    module_events_schema,
+};
 use abstract_std::{
     adapter,
     adapter::{AdapterExecuteMsg, AdapterQueryMsg},
@@ -102,6 +105,17 @@ impl<
             "QueryMsg",
         );
     }
+
+    /// Export the `ModuleEvents` schema of the [`ModuleEvent`](abstract_sdk::ModuleEvent)s
+    /// registered with `with_events`, next to the schema of the messages.
+    /// Modules without registered events don't get one, as an empty `oneOf` would match no event
+    /// at all.
+    pub fn export_events_schema(&self, out_dir: &Path) {
+        let events = self.contract.events();
+        if !events.is_empty() {
+            export_schema_with_title(&module_events_schema(&events), out_dir, "ModuleEvents");
+        }
+    }
 }
 
 fn assert_have_endpoint(
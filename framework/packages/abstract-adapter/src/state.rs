@@ -1,12 +1,13 @@
 use abstract_sdk::features::ModuleIdentification;
 use abstract_sdk::{
     base::{
-        AbstractContract, ExecuteHandlerFn, Handler, IbcCallbackHandlerFn, InstantiateHandlerFn,
-        ModuleIbcHandlerFn, QueryHandlerFn, ReplyHandlerFn, SudoHandlerFn,
+        AbstractContract, ExecuteHandlerFn, ExecuteMiddleware, Handler, IbcCallbackHandlerFn,
+        InstantiateHandlerFn, ModuleIbcHandlerFn, QueryHandlerFn, ReplyHandlerFn, ReplyPayload,
+        SudoHandlerFn,
     },
     namespaces::BASE_STATE,
     std::registry::Account,
-    AbstractSdkError,
+    AbstractSdkError, ModuleEventSchemaFn,
 };
 use abstract_std::{
     adapter::AdapterState,
@@ -19,8 +20,12 @@ use cw_storage_plus::{Item, Map};
 use crate::AdapterError;
 
 pub const AUTHORIZED_ADDRESSES_NAMESPACE: &str = "authorized_addresses";
+pub const AUTHORIZED_SCOPES_NAMESPACE: &str = "authorized_scopes";
 pub const MAXIMUM_AUTHORIZED_ADDRESSES: u32 = 15;
 
+/// Function that maps an adapter request to the name of its action, used to check the scopes of authorized addresses.
+pub type RequestActionFn<CustomExecMsg> = fn(&CustomExecMsg) -> &'static str;
+
 pub trait ContractError:
     From<cosmwasm_std::StdError>
     + From<AdapterError>
@@ -52,6 +57,12 @@ pub struct AdapterContract<
     pub(crate) base_state: Item<AdapterState>,
     /// Map AccountAddr -> AuthorizedAddrs
     pub authorized_addresses: Map<Addr, Vec<Addr>>,
+    /// Map (AccountAddr, AuthorizedAddr) -> Allowed request actions
+    /// Authorized addresses without an entry can perform all requests.
+    pub authorized_scopes: Map<(Addr, Addr), Vec<String>>,
+    /// Request actions declared by the adapter that authorized addresses can be scoped to.
+    pub(crate) request_actions: &'static [&'static str],
+    pub(crate) request_action_fn: Option<RequestActionFn<CustomExecMsg>>,
     /// The Account on which commands are executed. Set each time in the [`abstract_std::adapter::ExecuteMsg::Base`] handler.
     pub target_account: Option<Account>,
 }
@@ -69,6 +80,9 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
             contract: AbstractContract::new(name, version, metadata),
             base_state: Item::new(BASE_STATE),
             authorized_addresses: Map::new(AUTHORIZED_ADDRESSES_NAMESPACE),
+            authorized_scopes: Map::new(AUTHORIZED_SCOPES_NAMESPACE),
+            request_actions: &[],
+            request_action_fn: None,
             target_account: None,
         }
     }
@@ -94,6 +108,18 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
             .ok_or_else(|| StdError::generic_err("No target Account specified to execute on."))?
             .addr())
     }
+    /// Declare the request actions of this adapter so authorized addresses can be scoped to them.
+    /// `action_fn` must return one of the declared `actions` for every request.
+    pub const fn with_request_actions(
+        mut self,
+        actions: &'static [&'static str],
+        action_fn: RequestActionFn<CustomExecMsg>,
+    ) -> Self {
+        self.request_actions = actions;
+        self.request_action_fn = Some(action_fn);
+        self
+    }
+
     /// add dependencies to the contract
     pub const fn with_dependencies(mut self, dependencies: &'static [StaticDependency]) -> Self {
         self.contract = self.contract.with_dependencies(dependencies);
@@ -116,6 +142,15 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
         self
     }
 
+    /// Middleware that runs in order around the execute handler, see [`ExecuteMiddleware`].
+    pub const fn with_execute_middleware(
+        mut self,
+        middleware: &'static [ExecuteMiddleware<Self, CustomExecMsg, Error>],
+    ) -> Self {
+        self.contract = self.contract.with_execute_middleware(middleware);
+        self
+    }
+
     pub const fn with_query(
         mut self,
         query_handler: QueryHandlerFn<Self, CustomQueryMsg, Error>,
@@ -124,6 +159,12 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
         self
     }
 
+    /// [`ModuleEvent`](abstract_sdk::ModuleEvent)s emitted by the module, exported with its schema.
+    pub const fn with_events(mut self, events: &'static [ModuleEventSchemaFn]) -> Self {
+        self.contract = self.contract.with_events(events);
+        self
+    }
+
     pub const fn with_replies(
         mut self,
         reply_handlers: &'static [(u64, ReplyHandlerFn<Self, Error>)],
@@ -132,6 +173,12 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
         self
     }
 
+    /// Route replies of sub-messages sent with a typed payload, see [`ReplyPayload`].
+    pub const fn with_payload_replies<Payload: ReplyPayload<Self, Error>>(mut self) -> Self {
+        self.contract = self.contract.with_payload_replies::<Payload>();
+        self
+    }
+
     pub const fn with_sudo(mut self, sudo_handler: SudoHandlerFn<Self, SudoMsg, Error>) -> Self {
         self.contract = self.contract.with_sudo(sudo_handler);
         self
@@ -184,6 +231,7 @@ mod tests {
             .with_replies(&[(1u64, |_, _, _, msg| {
                 #[allow(deprecated)]
                 Ok(Response::new().set_data(msg.result.unwrap().data.unwrap()))
-            })]);
+            })])
+            .with_request_actions(&["mock"], |_| "mock");
     }
 }
@@ -1,6 +1,6 @@
 use abstract_sdk::base::ModuleIbcEndpoint;
-use abstract_std::app::{AppExecuteMsg, BaseExecuteMsg, ExecuteMsg};
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use abstract_std::app::{AppExecuteMsg, BaseExecuteMsg, ExecuteMsg, PauseStatus};
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response};
 use schemars::JsonSchema;
 use serde::Serialize;
 
@@ -33,7 +33,10 @@ impl<
         msg: Self::ExecuteMsg,
     ) -> Result<Response, Error> {
         match msg {
-            ExecuteMsg::Module(request) => self.execute_handler()?(deps, env, info, self, request),
+            ExecuteMsg::Module(request) => {
+                self.assert_not_paused(deps.as_ref(), &request)?;
+                self.execute_with_middleware(deps, env, info, request)
+            }
             ExecuteMsg::Base(exec_msg) => self
                 .base_execute(deps, env, info, exec_msg)
                 .map_err(From::from),
@@ -54,11 +57,388 @@ impl<
 {
     fn base_execute(
         &self,
-        _deps: DepsMut,
-        _env: Env,
-        _info: MessageInfo,
-        _message: BaseExecuteMsg,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        message: BaseExecuteMsg,
+    ) -> AppResult {
+        match message {
+            BaseExecuteMsg::SetPaused { paused, reason } => {
+                self.set_paused(deps, env, info, paused, reason)
+            }
+        }
+    }
+
+    fn set_paused(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        paused: bool,
+        reason: Option<String>,
     ) -> AppResult {
-        unreachable!("App BaseExecuteMsg could not be constructed")
+        self.admin.assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+        if paused {
+            self.pause_status.save(
+                deps.storage,
+                &PauseStatus {
+                    paused,
+                    reason: reason.clone(),
+                },
+            )?;
+        } else {
+            self.pause_status.remove(deps.storage);
+        }
+
+        Ok(Response::new()
+            .add_attribute("action", "set_paused")
+            .add_attribute("paused", paused.to_string())
+            .add_attribute("reason", reason.unwrap_or_default()))
+    }
+
+    /// Errors if the app is paused, unless the message is on the pause allow-list
+    fn assert_not_paused(&self, deps: Deps, msg: &CustomExecMsg) -> Result<(), AppError> {
+        let status = self.load_pause_status(deps.storage)?;
+        if status.paused && !self.pause_allow_list.is_some_and(|allowed| allowed(msg)) {
+            return Err(AppError::Paused {
+                reason: status.reason,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use abstract_std::app::{BaseQueryMsg, PauseStatusResponse, QueryMsg};
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{from_json, testing::message_info, Response};
+    use cw_controllers::AdminError;
+
+    use super::*;
+    use crate::{mock::*, QueryEndpoint};
+
+    type AppExecuteMsg = ExecuteMsg<MockExecMsg>;
+
+    fn pausable_app() -> MockAppContract {
+        BASIC_MOCK_APP
+            .with_execute(|_, _, _, _, _| Ok(Response::new().set_data("mock_exec".as_bytes())))
+            .with_pause_allow_list(|msg| matches!(msg, MockExecMsg::DoSomething {}))
+    }
+
+    #[coverage_helper::test]
+    fn only_admin_pauses() {
+        let mut deps = mock_init();
+        let env = mock_env_validated(deps.api);
+        let not_admin = deps.api.addr_make("not_admin");
+
+        let res = pausable_app().execute(
+            deps.as_mut(),
+            env,
+            message_info(&not_admin, &[]),
+            AppExecuteMsg::Base(BaseExecuteMsg::SetPaused {
+                paused: true,
+                reason: None,
+            }),
+        );
+        assert_eq!(
+            res,
+            Err(MockError::DappError(AppError::Admin(
+                AdminError::NotAdmin {}
+            )))
+        );
+    }
+
+    #[coverage_helper::test]
+    fn paused_app_rejects_module_msgs() -> AppTestResult {
+        let mut deps = mock_init();
+        let env = mock_env_validated(deps.api);
+        let sender = deps.api.addr_make("user");
+
+        let reason = Some("incident".to_owned());
+        pausable_app().pause_status.save(
+            deps.as_mut().storage,
+            &PauseStatus {
+                paused: true,
+                reason: reason.clone(),
+            },
+        )?;
+
+        let status: PauseStatusResponse = from_json(pausable_app().query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Base(BaseQueryMsg::PauseStatus {}),
+        )?)?;
+        assert_eq!(
+            status,
+            PauseStatus {
+                paused: true,
+                reason: reason.clone()
+            }
+        );
+
+        let res = pausable_app().execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&sender, &[]),
+            AppExecuteMsg::Module(MockExecMsg::DoSomethingAdmin {}),
+        );
+        assert_eq!(res, Err(MockError::DappError(AppError::Paused { reason })));
+
+        // Allow-listed messages are still executed
+        pausable_app().execute(
+            deps.as_mut(),
+            env,
+            message_info(&sender, &[]),
+            AppExecuteMsg::Module(MockExecMsg::DoSomething {}),
+        )?;
+        Ok(())
+    }
+
+    mod middleware {
+        use abstract_sdk::{
+            base::ExecuteMiddleware,
+            middleware::{
+                call_fee, rate_limit, sender_allow_list, update_allowed_senders, CallFee,
+                RateLimit, CALL_FEE, RATE_LIMIT,
+            },
+            AbstractSdkError,
+        };
+        use cosmwasm_std::{coin, coins, Attribute, BankMsg, Coin, Order, Record, Storage};
+
+        use super::*;
+
+        type Middleware = ExecuteMiddleware<MockAppContract, MockExecMsg, MockError>;
+
+        fn tag_before(
+            _deps: DepsMut,
+            _env: &Env,
+            _info: &mut MessageInfo,
+            _app: &MockAppContract,
+            _msg: &MockExecMsg,
+        ) -> Result<Response, MockError> {
+            Ok(Response::new()
+                .add_attribute("before", "tagged")
+                .add_message(BankMsg::Burn {
+                    amount: coins(1, "before"),
+                }))
+        }
+
+        fn tag_after(
+            _deps: DepsMut,
+            _env: &Env,
+            _info: &MessageInfo,
+            response: Response,
+        ) -> Result<Response, MockError> {
+            Ok(response.add_attribute("after", "tagged"))
+        }
+
+        const TAGGING: &[Middleware] =
+            &[ExecuteMiddleware::before(tag_before).and_after(tag_after)];
+        const STANDARD: &[Middleware] = &[
+            sender_allow_list::<MockAppContract>(),
+            rate_limit::<MockAppContract>(),
+            call_fee::<MockAppContract>(),
+        ];
+
+        fn middleware_app(middleware: &'static [Middleware]) -> MockAppContract {
+            BASIC_MOCK_APP
+                .with_execute(|_, _, _, _, _| {
+                    Ok(Response::new()
+                        .add_attribute("handler", "executed")
+                        .add_message(BankMsg::Burn {
+                            amount: coins(1, "handler"),
+                        }))
+                })
+                .with_execute_middleware(middleware)
+        }
+
+        /// Executes `do_something` with the standard middleware and reverts the storage writes
+        /// on an error, like the chain does.
+        fn execute_standard(
+            deps: &mut MockDeps,
+            env: Env,
+            info: MessageInfo,
+        ) -> Result<Response, MockError> {
+            let snapshot: Vec<Record> = deps.storage.range(None, None, Order::Ascending).collect();
+            let res = middleware_app(STANDARD).execute(deps.as_mut(), env, info, do_something());
+            if res.is_err() {
+                deps.storage = MockStorage::new();
+                for (key, value) in snapshot {
+                    deps.storage.set(&key, &value);
+                }
+            }
+            res
+        }
+
+        fn do_something() -> AppExecuteMsg {
+            AppExecuteMsg::Module(MockExecMsg::DoSomething {})
+        }
+
+        #[coverage_helper::test]
+        fn wraps_handler() -> AppTestResult {
+            let mut deps = mock_init();
+            let env = mock_env_validated(deps.api);
+            let sender = deps.api.addr_make("user");
+
+            let res = middleware_app(TAGGING).execute(
+                deps.as_mut(),
+                env,
+                message_info(&sender, &[]),
+                do_something(),
+            )?;
+
+            assert_eq!(
+                res,
+                Response::new()
+                    .add_message(BankMsg::Burn {
+                        amount: coins(1, "before"),
+                    })
+                    .add_message(BankMsg::Burn {
+                        amount: coins(1, "handler"),
+                    })
+                    .add_attribute("before", "tagged")
+                    .add_attribute("handler", "executed")
+                    .add_attribute("after", "tagged")
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn handler_receives_funds_without_call_fee() -> AppTestResult {
+            const CALL_FEE_ONLY: &[Middleware] = &[call_fee::<MockAppContract>()];
+            fn app() -> MockAppContract {
+                BASIC_MOCK_APP
+                    .with_execute(|_, _, info, _, _| {
+                        let funds: Vec<String> = info.funds.iter().map(Coin::to_string).collect();
+                        Ok(Response::new().add_attribute("funds", funds.join(",")))
+                    })
+                    .with_execute_middleware(CALL_FEE_ONLY)
+            }
+            let mut deps = mock_init();
+            let env = mock_env_validated(deps.api);
+            let user = deps.api.addr_make("user");
+            CALL_FEE.save(
+                deps.as_mut().storage,
+                &CallFee {
+                    fee: coin(10, "fee"),
+                    recipient: deps.api.addr_make("treasury"),
+                },
+            )?;
+
+            let res = app().execute(
+                deps.as_mut(),
+                env.clone(),
+                message_info(&user, &[coin(15, "fee"), coin(3, "other")]),
+                do_something(),
+            )?;
+            assert!(res
+                .attributes
+                .contains(&Attribute::new("funds", "5fee,3other")));
+
+            // Fully spent fee funds are removed
+            let res = app().execute(
+                deps.as_mut(),
+                env,
+                message_info(&user, &[coin(10, "fee"), coin(3, "other")]),
+                do_something(),
+            )?;
+            assert!(res.attributes.contains(&Attribute::new("funds", "3other")));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn standard_middleware() -> AppTestResult {
+            let mut deps = mock_init();
+            let mut env = mock_env_validated(deps.api);
+            let user = deps.api.addr_make("user");
+            let stranger = deps.api.addr_make("stranger");
+            let treasury = deps.api.addr_make("treasury");
+
+            // Unconfigured middleware lets every sender through
+            execute_standard(&mut deps, env.clone(), message_info(&stranger, &[]))?;
+
+            update_allowed_senders(deps.as_mut().storage, &[user.clone()], &[])?;
+            RATE_LIMIT.save(
+                deps.as_mut().storage,
+                &RateLimit {
+                    max_calls: 2,
+                    window: 60,
+                },
+            )?;
+            CALL_FEE.save(
+                deps.as_mut().storage,
+                &CallFee {
+                    fee: coin(10, "fee"),
+                    recipient: treasury.clone(),
+                },
+            )?;
+
+            let res = execute_standard(
+                &mut deps,
+                env.clone(),
+                message_info(&stranger, &coins(10, "fee")),
+            );
+            assert_eq!(
+                res,
+                Err(MockError::AbstractSdk(AbstractSdkError::SenderNotAllowed {
+                    sender: stranger
+                }))
+            );
+
+            // Calls rejected by the call fee are reverted and don't use up the rate limit
+            for _ in 0..3 {
+                let res = execute_standard(
+                    &mut deps,
+                    env.clone(),
+                    message_info(&user, &coins(5, "fee")),
+                );
+                assert_eq!(
+                    res,
+                    Err(MockError::AbstractSdk(AbstractSdkError::CallFeeNotPaid {
+                        fee: coin(10, "fee")
+                    }))
+                );
+            }
+
+            let res = execute_standard(
+                &mut deps,
+                env.clone(),
+                message_info(&user, &coins(10, "fee")),
+            )?;
+            assert_eq!(
+                res.messages[0].msg,
+                BankMsg::Send {
+                    to_address: treasury.to_string(),
+                    amount: coins(10, "fee"),
+                }
+                .into()
+            );
+            execute_standard(
+                &mut deps,
+                env.clone(),
+                message_info(&user, &coins(10, "fee")),
+            )?;
+
+            let res = execute_standard(
+                &mut deps,
+                env.clone(),
+                message_info(&user, &coins(10, "fee")),
+            );
+            assert_eq!(
+                res,
+                Err(MockError::AbstractSdk(AbstractSdkError::RateLimited {
+                    sender: user.clone(),
+                    max_calls: 2,
+                    window: 60,
+                }))
+            );
+
+            // The limit resets after the window
+            env.block.time = env.block.time.plus_seconds(60);
+            execute_standard(&mut deps, env, message_info(&user, &coins(10, "fee")))?;
+            Ok(())
+        }
     }
 }
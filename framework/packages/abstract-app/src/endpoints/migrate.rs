@@ -1,9 +1,10 @@
 use abstract_std::{
     app::MigrateMsg,
     objects::module_version::{assert_contract_upgrade, set_module_data},
+    AbstractError,
 };
-use cosmwasm_std::Response;
-use cw2::set_contract_version;
+use cosmwasm_std::{Attribute, Response};
+use cw2::{get_contract_version, set_contract_version};
 use schemars::JsonSchema;
 use serde::Serialize;
 
@@ -23,13 +24,17 @@ impl<
 
     fn migrate(
         self,
-        deps: cosmwasm_std::DepsMut,
+        mut deps: cosmwasm_std::DepsMut,
         env: cosmwasm_std::Env,
         msg: Self::MigrateMsg,
     ) -> Result<cosmwasm_std::Response, Self::Error> {
         let (name, version_string, metadata) = self.info();
         let to_version = version_string.parse().unwrap();
         assert_contract_upgrade(deps.storage, name, to_version)?;
+        let from_version = get_contract_version(deps.storage)?
+            .version
+            .parse()
+            .map_err(AbstractError::from)?;
         set_module_data(
             deps.storage,
             name,
@@ -38,9 +43,149 @@ impl<
             metadata,
         )?;
         set_contract_version(deps.storage, name, version_string)?;
-        if let Some(migrate_fn) = self.maybe_migrate_handler() {
-            return migrate_fn(deps, env, self, msg.module);
+        let migrations: Vec<Attribute> = self
+            .run_migrations(deps.branch(), &env, &from_version)?
+            .into_iter()
+            .map(|(from, to)| Attribute::new("migration", format!("{from}->{to}")))
+            .collect();
+        let response = match self.maybe_migrate_handler() {
+            Some(migrate_fn) => migrate_fn(deps, env, self, msg.module)?,
+            None => Response::default(),
+        };
+        Ok(response.add_attributes(migrations))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use abstract_sdk::{
+        base::{replay_migrations, MigrationStep},
+        AbstractSdkError,
+    };
+    use abstract_std::{
+        app::{BaseMigrateMsg, MigrateMsg},
+        AbstractError,
+    };
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{DepsMut, Env, Response, StdError};
+    use cw2::set_contract_version;
+    use cw_storage_plus::Item;
+
+    use crate::{mock::*, MigrateEndpoint};
+
+    const OLD_FEE: Item<u64> = Item::new("fee");
+    const FEE_PERCENT: Item<u64> = Item::new("fee_percent");
+    const FEE_RECIPIENT: Item<String> = Item::new("fee_recipient");
+
+    fn fee_to_percent(deps: DepsMut, _env: &Env, _app: &MockAppContract) -> Result<(), MockError> {
+        let fee = OLD_FEE.load(deps.storage)?;
+        OLD_FEE.remove(deps.storage);
+        FEE_PERCENT.save(deps.storage, &(fee * 100))?;
+        Ok(())
+    }
+
+    fn add_recipient(deps: DepsMut, _env: &Env, _app: &MockAppContract) -> Result<(), MockError> {
+        FEE_RECIPIENT.save(deps.storage, &"treasury".to_owned())?;
+        Ok(())
+    }
+
+    const MIGRATIONS: &[MigrationStep<MockAppContract, MockError>] = &[
+        MigrationStep::new("0.1.0", "0.2.0", fee_to_percent),
+        MigrationStep::new("0.2.0", "0.3.0", add_recipient),
+    ];
+
+    const MIGRATING_APP: MockAppContract =
+        MockAppContract::new(TEST_MODULE_ID, "0.3.0", None).with_migrations(MIGRATIONS);
+
+    fn migrate_msg() -> MigrateMsg<MockMigrateMsg> {
+        MigrateMsg {
+            base: BaseMigrateMsg {},
+            module: MockMigrateMsg,
         }
-        Ok(Response::default())
+    }
+
+    #[coverage_helper::test]
+    fn runs_pending_migrations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+        set_contract_version(deps.as_mut().storage, TEST_MODULE_ID, "0.2.0").unwrap();
+        OLD_FEE.save(deps.as_mut().storage, &1).unwrap();
+
+        let res = MIGRATING_APP.migrate(deps.as_mut(), env, migrate_msg());
+
+        assert_eq!(
+            res,
+            Ok(Response::new().add_attribute("migration", "0.2.0->0.3.0"))
+        );
+        // Only the storage of 0.2.0 was migrated
+        assert_eq!(OLD_FEE.load(&deps.storage).unwrap(), 1);
+        assert_eq!(
+            FEE_RECIPIENT.load(&deps.storage).unwrap(),
+            "treasury".to_owned()
+        );
+    }
+
+    #[coverage_helper::test]
+    fn replays_all_migrations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+
+        let res = replay_migrations(&MIGRATING_APP, deps.as_mut(), &env, |deps| {
+            OLD_FEE.save(deps.storage, &3).unwrap();
+        });
+
+        assert_eq!(res, Ok(vec![("0.1.0", "0.2.0"), ("0.2.0", "0.3.0")]));
+        assert!(OLD_FEE.may_load(&deps.storage).unwrap().is_none());
+        assert_eq!(FEE_PERCENT.load(&deps.storage).unwrap(), 300);
+        assert_eq!(
+            FEE_RECIPIENT.load(&deps.storage).unwrap(),
+            "treasury".to_owned()
+        );
+    }
+
+    #[coverage_helper::test]
+    fn failed_migration() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+        set_contract_version(deps.as_mut().storage, TEST_MODULE_ID, "0.1.0").unwrap();
+
+        // Storage of 0.1.0 is missing its fee
+        let res = MIGRATING_APP.migrate(deps.as_mut(), env, migrate_msg());
+        assert!(matches!(
+            res,
+            Err(MockError::Std(StdError::NotFound { .. }))
+        ));
+    }
+
+    #[coverage_helper::test]
+    fn invalid_stored_version() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+        set_contract_version(deps.as_mut().storage, TEST_MODULE_ID, "not-a-version").unwrap();
+
+        let res = MIGRATING_APP.migrate(deps.as_mut(), env, migrate_msg());
+        assert!(matches!(
+            res,
+            Err(MockError::Abstract(AbstractError::Semver(_)))
+        ));
+    }
+
+    #[coverage_helper::test]
+    fn invalid_migrations() {
+        const INVALID_MIGRATIONS: &[MigrationStep<MockAppContract, MockError>] =
+            &[MigrationStep::new("0.2.0", "0.1.0", add_recipient)];
+        const INVALID_APP: MockAppContract =
+            MockAppContract::new(TEST_MODULE_ID, "0.3.0", None).with_migrations(INVALID_MIGRATIONS);
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+        set_contract_version(deps.as_mut().storage, TEST_MODULE_ID, "0.1.0").unwrap();
+
+        let res = INVALID_APP.migrate(deps.as_mut(), env, migrate_msg());
+        assert!(matches!(
+            res,
+            Err(MockError::AbstractSdk(
+                AbstractSdkError::InvalidMigrationStep { .. }
+            ))
+        ));
     }
 }
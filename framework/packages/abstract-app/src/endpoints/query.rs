@@ -52,6 +52,7 @@ impl<
             BaseQueryMsg::BaseAdmin {} => to_json_binary(&self.admin(deps)?),
             BaseQueryMsg::ModuleData {} => to_json_binary(&self.module_data(deps)?),
             BaseQueryMsg::TopLevelOwner {} => to_json_binary(&self.top_level_owner(deps)?),
+            BaseQueryMsg::PauseStatus {} => to_json_binary(&self.load_pause_status(deps.storage)?),
         }
     }
 
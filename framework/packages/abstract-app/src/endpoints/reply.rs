@@ -11,3 +11,100 @@ impl<
     for AppContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, CustomMigrateMsg, SudoMsg>
 {
 }
+
+#[cfg(test)]
+mod test {
+    use abstract_sdk::{
+        base::{ReplyEndpoint, ReplyPayload, PAYLOAD_REPLY_ID},
+        AbstractSdkError,
+    };
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{to_json_binary, Binary, DepsMut, Env, Reply, Response, SubMsgResult};
+
+    use crate::mock::*;
+
+    #[cosmwasm_schema::cw_serde]
+    enum SwapPayload {
+        Swapped { deposit_into: String },
+        Deposited {},
+    }
+
+    impl ReplyPayload<MockAppContract, MockError> for SwapPayload {
+        fn handle(
+            self,
+            _deps: DepsMut,
+            _env: Env,
+            _app: MockAppContract,
+            _reply: Reply,
+        ) -> Result<Response, MockError> {
+            match self {
+                SwapPayload::Swapped { deposit_into } => {
+                    Ok(Response::new().add_attribute("deposit_into", deposit_into))
+                }
+                SwapPayload::Deposited {} => Ok(Response::new().add_attribute("deposited", "true")),
+            }
+        }
+    }
+
+    const PAYLOAD_APP: MockAppContract = BASIC_MOCK_APP.with_payload_replies::<SwapPayload>();
+
+    fn payload_reply(payload: Binary) -> Reply {
+        Reply {
+            id: PAYLOAD_REPLY_ID,
+            result: SubMsgResult::Err("test".into()),
+            payload,
+            gas_used: 0,
+        }
+    }
+
+    #[coverage_helper::test]
+    fn routes_on_payload() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+
+        let payload = to_json_binary(&SwapPayload::Swapped {
+            deposit_into: "vault".to_owned(),
+        })
+        .unwrap();
+        let res = PAYLOAD_APP.reply(deps.as_mut(), env.clone(), payload_reply(payload));
+        assert_eq!(
+            res,
+            Ok(Response::new().add_attribute("deposit_into", "vault"))
+        );
+
+        let payload = to_json_binary(&SwapPayload::Deposited {}).unwrap();
+        let res = PAYLOAD_APP.reply(deps.as_mut(), env, payload_reply(payload));
+        assert_eq!(res, Ok(Response::new().add_attribute("deposited", "true")));
+    }
+
+    #[coverage_helper::test]
+    fn invalid_payload() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+
+        let res = PAYLOAD_APP.reply(
+            deps.as_mut(),
+            env,
+            payload_reply(Binary::from(b"not a payload")),
+        );
+        assert!(matches!(
+            res,
+            Err(MockError::AbstractSdk(AbstractSdkError::Std(_)))
+        ));
+    }
+
+    #[coverage_helper::test]
+    fn missing_payload_handler() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+
+        let payload = to_json_binary(&SwapPayload::Deposited {}).unwrap();
+        let res = BASIC_MOCK_APP.reply(deps.as_mut(), env, payload_reply(payload));
+        assert_eq!(
+            res,
+            Err(MockError::AbstractSdk(AbstractSdkError::MissingHandler {
+                endpoint: "reply with payload".to_string()
+            }))
+        );
+    }
+}
@@ -1,8 +1,15 @@
+use cosmwasm_std::StdError;
 use cw_controllers::AdminError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
 pub enum AppError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
     #[error(transparent)]
     Admin(#[from] AdminError),
+
+    #[error("App is paused: {}", reason.as_deref().unwrap_or("no reason given"))]
+    Paused { reason: Option<String> },
 }
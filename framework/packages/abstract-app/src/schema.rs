@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use abstract_sdk::module_events_schema;
 use abstract_std::app::{self, AppExecuteMsg, AppQueryMsg};
 use cosmwasm_schema::{export_schema_with_title, schema_for, write_api, QueryResponses};
 use schemars::JsonSchema;
@@ -107,6 +108,17 @@ impl<
             "MigrateMsg",
         );
     }
+
+    /// Export the `ModuleEvents` schema of the [`ModuleEvent`](abstract_sdk::ModuleEvent)s
+    /// registered with `with_events`, next to the schema of the messages.
+    /// Modules without registered events don't get one, as an empty `oneOf` would match no event
+    /// at all.
+    pub fn export_events_schema(&self, out_dir: &Path) {
+        let events = self.contract.events();
+        if !events.is_empty() {
+            export_schema_with_title(&module_events_schema(&events), out_dir, "ModuleEvents");
+        }
+    }
 }
 
 fn assert_have_endpoint(
@@ -1,10 +1,10 @@
 use abstract_sdk::{
-    base::{ModuleIbcHandlerFn, SudoHandlerFn},
-    namespaces::{ADMIN_NAMESPACE, BASE_STATE},
-    AbstractSdkError,
+    base::{ExecuteMiddleware, MigrationStep, ModuleIbcHandlerFn, ReplyPayload, SudoHandlerFn},
+    namespaces::{ADMIN_NAMESPACE, BASE_STATE, PAUSE_STATUS},
+    AbstractSdkError, ModuleEventSchemaFn,
 };
 use abstract_std::{
-    app::AppState,
+    app::{AppState, PauseStatus},
     objects::{
         dependency::StaticDependency, module::ModuleInfo, ownership::nested_admin::NestedAdmin,
     },
@@ -36,6 +36,9 @@ impl<T> ContractError for T where
 {
 }
 
+/// Function that decides if a module message is still executed while the app is paused.
+pub type PauseAllowListFn<CustomExecMsg> = fn(&CustomExecMsg) -> bool;
+
 /// The state variables for our AppContract.
 pub struct AppContract<
     Error: ContractError,
@@ -48,6 +51,8 @@ pub struct AppContract<
     // Custom state for every App
     pub admin: NestedAdmin,
     pub(crate) base_state: Item<AppState>,
+    pub(crate) pause_status: Item<PauseStatus>,
+    pub(crate) pause_allow_list: Option<PauseAllowListFn<CustomExecMsg>>,
 
     // Scaffolding contract that handles type safety and provides helper methods
     pub(crate) contract: AbstractContract<Self, Error>,
@@ -70,6 +75,8 @@ impl<
     ) -> Self {
         Self {
             base_state: Item::new(BASE_STATE),
+            pause_status: Item::new(PAUSE_STATUS),
+            pause_allow_list: None,
             admin: NestedAdmin::new(ADMIN_NAMESPACE),
             contract: AbstractContract::new(name, version, metadata),
         }
@@ -91,6 +98,11 @@ impl<
         self.base_state.load(store)
     }
 
+    /// Pause status of the app, not paused if it was never set
+    pub fn load_pause_status(&self, store: &dyn Storage) -> StdResult<PauseStatus> {
+        Ok(self.pause_status.may_load(store)?.unwrap_or_default())
+    }
+
     /// add dependencies to the contract
     pub const fn with_dependencies(mut self, dependencies: &'static [StaticDependency]) -> Self {
         self.contract = self.contract.with_dependencies(dependencies);
@@ -113,6 +125,15 @@ impl<
         self
     }
 
+    /// Middleware that runs in order around the execute handler, see [`ExecuteMiddleware`].
+    pub const fn with_execute_middleware(
+        mut self,
+        middleware: &'static [ExecuteMiddleware<Self, CustomExecMsg, Error>],
+    ) -> Self {
+        self.contract = self.contract.with_execute_middleware(middleware);
+        self
+    }
+
     pub const fn with_query(
         mut self,
         query_handler: QueryHandlerFn<Self, CustomQueryMsg, Error>,
@@ -129,6 +150,21 @@ impl<
         self
     }
 
+    /// Ordered storage migrations, run on migration before the migrate handler.
+    pub const fn with_migrations(
+        mut self,
+        migrations: &'static [MigrationStep<Self, Error>],
+    ) -> Self {
+        self.contract = self.contract.with_migrations(migrations);
+        self
+    }
+
+    /// [`ModuleEvent`](abstract_sdk::ModuleEvent)s emitted by the module, exported with its schema.
+    pub const fn with_events(mut self, events: &'static [ModuleEventSchemaFn]) -> Self {
+        self.contract = self.contract.with_events(events);
+        self
+    }
+
     pub const fn with_replies(
         mut self,
         reply_handlers: &'static [(u64, ReplyHandlerFn<Self, Error>)],
@@ -137,6 +173,12 @@ impl<
         self
     }
 
+    /// Route replies of sub-messages sent with a typed payload, see [`ReplyPayload`].
+    pub const fn with_payload_replies<Payload: ReplyPayload<Self, Error>>(mut self) -> Self {
+        self.contract = self.contract.with_payload_replies::<Payload>();
+        self
+    }
+
     pub const fn with_sudo(mut self, sudo_handler: SudoHandlerFn<Self, SudoMsg, Error>) -> Self {
         self.contract = self.contract.with_sudo(sudo_handler);
         self
@@ -148,6 +190,16 @@ impl<
         self
     }
 
+    /// Module messages for which `allow_list` returns `true` are still executed while the app is paused,
+    /// for example withdrawals.
+    pub const fn with_pause_allow_list(
+        mut self,
+        allow_list: PauseAllowListFn<CustomExecMsg>,
+    ) -> Self {
+        self.pause_allow_list = Some(allow_list);
+        self
+    }
+
     /// add Module IBC to contract
     pub const fn with_module_ibc(
         mut self,
@@ -10,9 +10,10 @@ use abstract_interface::{
 use abstract_std::{
     account::{
         self, state::AccountInfo, AccountModuleInfo, InfoResponse, ModuleAddressesResponse,
-        ModuleInfosResponse, ModuleInstallConfig,
+        ModuleInfosResponse, ModuleInstallConfig, ModuleVersionsResponse,
     },
-    ibc_client::{self, QueryMsgFns as _},
+    ibc::{remote_query::RemoteAccountQuery, ModuleQuery},
+    ibc_client::{self, InstalledModuleIdentification, QueryMsgFns as _},
     ibc_host,
     objects::{
         module::{ModuleId, ModuleInfo, ModuleVersion},
@@ -21,7 +22,7 @@ use abstract_std::{
     },
     IBC_CLIENT,
 };
-use cosmwasm_std::{to_json_binary, CosmosMsg, Uint128};
+use cosmwasm_std::{to_json_binary, CosmosMsg, QueryRequest, Uint128};
 use cw_orch::{
     contract::Contract,
     environment::{Environment as _, MutCwEnv},
@@ -508,6 +509,52 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
         RemoteApplication::new(self.clone(), module)
     }
 
+    /// Build a typed ibc query request of this account.
+    /// Modules on the origin chain can send it with the `IbcClient` api
+    /// and decode the callback with [`IbcResult::remote_account_responses`](abstract_std::ibc::IbcResult::remote_account_responses),
+    /// given the sent queries.
+    pub fn remote_query_request(
+        &self,
+        query: RemoteAccountQuery,
+    ) -> AbstractClientResult<QueryRequest<ModuleQuery>> {
+        query
+            .into_query_request(self.address()?)
+            .map_err(|e| CwOrchError::from(e).into())
+    }
+
+    /// Build an ibc query request of a module installed on this account.
+    /// Modules on the origin chain can send it with the `IbcClient` api
+    /// and decode the callback with [`IbcResult::query_response`](abstract_std::ibc::IbcResult::query_response).
+    pub fn module_query_request<Q: Serialize>(
+        &self,
+        module_id: ModuleId,
+        msg: &Q,
+    ) -> AbstractClientResult<QueryRequest<ModuleQuery>> {
+        let account = self.address()?;
+        let mut versions: ModuleVersionsResponse = self
+            .host_chain()
+            .query(
+                &account::QueryMsg::ModuleVersions {
+                    ids: vec![module_id.to_owned()],
+                },
+                &account,
+            )
+            .map_err(Into::into)?;
+        let version = versions
+            .versions
+            .pop()
+            .ok_or(AbstractClientError::ModuleNotInstalled {})?
+            .version;
+
+        Ok(QueryRequest::Custom(ModuleQuery {
+            target_module: InstalledModuleIdentification {
+                module_info: ModuleInfo::from_id(module_id, ModuleVersion::Version(version))?,
+                account_id: Some(self.id()),
+            },
+            msg: to_json_binary(msg).map_err(Into::<CwOrchError>::into)?,
+        }))
+    }
+
     /// Install module on account
     fn install_module_host_internal<
         M: RegisteredModule
@@ -28,6 +28,11 @@ pub enum MockExecMsg {
         remote_chain: TruncatedChainId,
         target_module: ModuleInfo,
     },
+    DoSomethingOrderedIbc {
+        remote_chain: TruncatedChainId,
+        target_module: ModuleInfo,
+        resend_sequence: Option<u64>,
+    },
     QuerySomethingIbc {
         remote_chain: TruncatedChainId,
         address: String,
@@ -50,6 +55,9 @@ pub enum MockQueryMsg {
     #[returns(ReceivedIbcModuleStatus)]
     GetReceivedIbcModuleStatus {},
 
+    #[returns(Vec<u64>)]
+    GetReceivedIbcModuleSequences {},
+
     #[returns(String)]
     Foo {},
 
@@ -130,6 +138,9 @@ pub struct IbcModuleToModuleMsg {
 pub const IBC_CALLBACK_RECEIVED: Item<bool> = Item::new("ibc_callback_received");
 // Easy way to see if an module ibc called was actually received.
 pub const MODULE_IBC_RECEIVED: Item<ModuleInfo> = Item::new("module_ibc_received");
+// Sequences of the ordered module ibc calls that were received.
+pub const MODULE_IBC_SEQUENCES_RECEIVED: Item<Vec<u64>> =
+    Item::new("module_ibc_sequences_received");
 
 // Easy way to see if an ibc-callback was actually received.
 pub const IBC_CALLBACK_QUERY_RECEIVED: Item<Vec<Coin>> = Item::new("ibc_callback_query_received");
@@ -174,6 +185,23 @@ pub const fn mock_app(id: &'static str, version: &'static str) -> MockAppContrac
 
                 Ok(Response::new().add_message(msg))
             }
+            MockExecMsg::DoSomethingOrderedIbc {
+                remote_chain,
+                target_module,
+                resend_sequence,
+            } => {
+                let msg = app.ibc_client(deps.as_ref()).ordered_module_ibc_action(
+                    remote_chain,
+                    target_module,
+                    &IbcModuleToModuleMsg {
+                        ibc_msg: "module_to_module:ordered_msg".to_string(),
+                    },
+                    resend_sequence,
+                    None,
+                )?;
+
+                Ok(Response::new().add_message(msg))
+            }
             MockExecMsg::QuerySomethingIbc {
                 address,
                 remote_chain,
@@ -239,6 +267,12 @@ pub const fn mock_app(id: &'static str, version: &'static str) -> MockAppContrac
                 })
                 .map_err(Into::into)
             }
+            MockQueryMsg::GetReceivedIbcModuleSequences {} => to_json_binary(
+                &MODULE_IBC_SEQUENCES_RECEIVED
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            )
+            .map_err(Into::into),
             MockQueryMsg::Foo {} => to_json_binary("bar").map_err(Into::into),
             MockQueryMsg::GetReceivedModuleIbcQueryCallbackStatus {} => Ok(to_json_binary(
                 &IBC_CALLBACK_MODULE_QUERY_RECEIVED.load(deps.storage)?,
@@ -287,6 +321,13 @@ pub const fn mock_app(id: &'static str, version: &'static str) -> MockAppContrac
         .with_module_ibc(|deps, _, _, src_module_info, _| {
             // We save the module info status
             MODULE_IBC_RECEIVED.save(deps.storage, &src_module_info.module)?;
+            if let Some(sequence) = src_module_info.sequence {
+                let mut sequences = MODULE_IBC_SEQUENCES_RECEIVED
+                    .may_load(deps.storage)?
+                    .unwrap_or_default();
+                sequences.push(sequence);
+                MODULE_IBC_SEQUENCES_RECEIVED.save(deps.storage, &sequences)?;
+            }
             Ok(Response::new().add_attribute("mock_module_ibc", "executed"))
         })
         .with_dependencies(&[IBC_CLIENT_DEP])
@@ -565,6 +606,120 @@ pub mod test {
         Ok(())
     }
 
+    #[test]
+    fn ordered_module_calls_are_processed_once() -> AnyResult<()> {
+        use abstract_std::{ibc_client::QueryMsgFns as _, ibc_host::QueryMsgFns as _};
+
+        logger_test_init();
+        let mock_interchain =
+            MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+
+        let (abstr_origin, abstr_remote) = ibc_abstract_setup(&mock_interchain, JUNO, STARGAZE)?;
+
+        let remote_name = TruncatedChainId::from_chain_id(STARGAZE);
+
+        let (origin_account, remote_account_id) =
+            create_test_remote_account(&abstr_origin, JUNO, STARGAZE, &mock_interchain, vec![])?;
+
+        let (remote_account, _) =
+            create_test_remote_account(&abstr_remote, STARGAZE, JUNO, &mock_interchain, vec![])?;
+
+        // Install local app
+        let app = MockAppOriginI::new(TEST_MODULE_ID, abstr_origin.registry.environment().clone());
+
+        abstr_origin
+            .registry
+            .claim_namespace(origin_account.id()?, TEST_NAMESPACE.to_owned())?;
+
+        app.deploy(TEST_VERSION.parse()?, DeployStrategy::Try)?;
+
+        origin_account.install_app(&app, &MockInitMsg {}, &[])?;
+
+        // Install remote app
+        let app_remote = MockAppRemoteI::new(
+            TEST_MODULE_ID_REMOTE,
+            abstr_remote.registry.environment().clone(),
+        );
+
+        abstr_remote
+            .registry
+            .claim_namespace(remote_account.id()?, TEST_NAMESPACE.to_owned())?;
+
+        app_remote.deploy(TEST_VERSION_REMOTE.parse()?, DeployStrategy::Try)?;
+
+        let remote_install_response = origin_account.execute_on_remote(
+            remote_name.clone(),
+            account::ExecuteMsg::InstallModules {
+                modules: vec![ModuleInstallConfig::new(
+                    ModuleInfo::from_id_latest(TEST_MODULE_ID_REMOTE)?,
+                    Some(to_json_binary(&MockInitMsg {})?),
+                )],
+            },
+        )?;
+
+        mock_interchain.await_and_check_packets(JUNO, remote_install_response)?;
+
+        let remote_account = abstr_remote.registry.account(remote_account_id)?;
+        let account = AccountI::new(
+            "remote-account-account",
+            abstr_remote.registry.environment().clone(),
+        );
+        account.set_address(remote_account.addr());
+        let module_address = account.module_info(TEST_MODULE_ID_REMOTE)?.unwrap().address;
+        let remote_account_app = MockAppRemoteI::new(
+            "remote-account-app",
+            abstr_remote.registry.environment().clone(),
+        );
+        remote_account_app.set_address(&module_address);
+
+        let target_module_info =
+            ModuleInfo::from_id(TEST_MODULE_ID_REMOTE, TEST_VERSION_REMOTE.into())?;
+        for _ in 0..2 {
+            let ibc_action_result = app.do_something_ordered_ibc(
+                remote_name.clone(),
+                None,
+                target_module_info.clone(),
+            )?;
+            mock_interchain.await_and_check_packets(JUNO, ibc_action_result)?;
+        }
+
+        // Re-sent message is not processed twice
+        let ibc_action_result =
+            app.do_something_ordered_ibc(remote_name.clone(), Some(1), target_module_info.clone())?;
+        mock_interchain.await_and_check_packets(JUNO, ibc_action_result)?;
+
+        // Can't re-send a sequence that was never sent
+        assert!(app
+            .do_something_ordered_ibc(remote_name.clone(), Some(3), target_module_info.clone())
+            .is_err());
+
+        assert_eq!(
+            remote_account_app.get_received_ibc_module_sequences()?,
+            vec![1, 2]
+        );
+        // Both sides track the sequence of the same source module
+        let source_module = InstalledModuleIdentification {
+            module_info: ModuleInfo::from_id(TEST_MODULE_ID, TEST_VERSION.into())?,
+            account_id: Some(origin_account.id()?),
+        };
+        let last_sent: ibc_client::ModuleIbcSequenceResponse =
+            abstr_origin.ibc.client.module_ibc_sequence(
+                remote_name,
+                source_module.clone(),
+                TEST_MODULE_ID_REMOTE.to_owned(),
+            )?;
+        assert_eq!(last_sent.last_sent, 2);
+
+        let last_processed: abstract_std::ibc_host::ModuleIbcSequenceResponse =
+            abstr_remote.ibc.host.module_ibc_sequence(
+                source_module,
+                TruncatedChainId::from_chain_id(JUNO),
+                TEST_MODULE_ID_REMOTE.to_owned(),
+            )?;
+        assert_eq!(last_processed.last_processed, 2);
+        Ok(())
+    }
+
     pub const REMOTE_AMOUNT: u128 = 5674309;
     pub const REMOTE_DENOM: &str = "remote_denom";
 
@@ -1,7 +1,10 @@
 extern crate proc_macro2;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, AttributeArgs, Fields, Item};
+use syn::{parse_macro_input, AttributeArgs, DeriveInput, Fields, Item};
+
+mod module_client;
+mod module_event;
 
 const DELIMITER: &str = ",";
 
@@ -74,3 +77,74 @@ pub fn abstract_response(attrs: TokenStream, input: TokenStream) -> TokenStream
 
     struct_def.into()
 }
+
+/// Generate a typed execute client trait for the custom execute message of an App or Adapter.
+///
+/// The `{Msg}Client` trait has a method per variant, named after the variant in snake_case and
+/// taking its fields as arguments. It is implemented for `abstract_sdk::ModuleClient`s of modules
+/// that use this message as their `ClientModule::ExecuteMsg`. Requires `cosmwasm_std` in scope,
+/// abstract-sdk is resolved directly or through the `sdk` re-export of abstract-app, abstract-adapter
+/// or abstract-standalone.
+///
+/// ```rust,ignore
+/// #[cosmwasm_schema::cw_serde]
+/// #[derive(abstract_macros::ModuleExecuteClient)]
+/// pub enum PaymentExecuteMsg {
+///     Tip {},
+/// }
+///
+/// let msg: CosmosMsg = module.app::<PaymentApp>(deps).tip()?;
+/// ```
+#[proc_macro_derive(ModuleExecuteClient)]
+pub fn module_execute_client(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    module_client::execute_client(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generate a typed query client trait for the custom query message of an App or Adapter.
+///
+/// Like [`ModuleExecuteClient`], with the response of each method taken from its `#[returns(..)]` attribute.
+///
+/// ```rust,ignore
+/// #[cosmwasm_schema::cw_serde]
+/// #[derive(QueryResponses, abstract_macros::ModuleQueryClient)]
+/// pub enum PaymentQueryMsg {
+///     #[returns(TipCountResponse)]
+///     TipCount {},
+/// }
+///
+/// let count: TipCountResponse = module.app::<PaymentApp>(deps).tip_count()?;
+/// ```
+#[proc_macro_derive(ModuleQueryClient, attributes(returns))]
+pub fn module_query_client(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    module_client::query_client(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implement `abstract_sdk::ModuleEvent` for a struct with named fields.
+///
+/// The event name defaults to the struct name in snake_case and the version to `1`.
+/// Each field is emitted as an attribute with its JSON-encoded value. Requires `cosmwasm_std` in
+/// scope, abstract-sdk is resolved like for [`ModuleExecuteClient`].
+///
+/// ```rust,ignore
+/// #[derive(schemars::JsonSchema, abstract_sdk::ModuleEvent)]
+/// #[module_event(name = "tip", version = 1)]
+/// pub struct TipEvent {
+///     pub tipper: String,
+///     pub amount: Uint128,
+/// }
+///
+/// let response = app.event_response(&TipEvent { tipper, amount })?;
+/// ```
+#[proc_macro_derive(ModuleEvent, attributes(module_event))]
+pub fn module_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    module_event::module_event(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,198 @@
+use proc_macro2::TokenStream;
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, Type};
+
+/// A typed client method generated for an enum variant.
+struct ClientFn {
+    name: Ident,
+    docs: Vec<Attribute>,
+    args: Vec<(Ident, Type)>,
+    msg: TokenStream,
+    returns: Option<Type>,
+}
+
+impl ClientFn {
+    fn signature(&self, sdk: &TokenStream, output: TokenStream) -> TokenStream {
+        let Self {
+            name, docs, args, ..
+        } = self;
+        let args = args.iter().map(|(arg, ty)| quote!(#arg: #ty));
+        quote!(
+            #(#docs)*
+            fn #name(&self, #(#args),*) -> #sdk::AbstractSdkResult<#output>
+        )
+    }
+}
+
+/// Path to abstract-sdk, depended on directly or through the re-export of the app, adapter or
+/// standalone crate of the module.
+pub(crate) fn sdk_path() -> TokenStream {
+    match crate_name("abstract-sdk") {
+        Ok(FoundCrate::Itself) => return quote!(crate),
+        Ok(FoundCrate::Name(name)) => {
+            let name = format_ident!("{}", name);
+            return quote!(::#name);
+        }
+        Err(_) => {}
+    }
+    for base in ["abstract-app", "abstract-adapter", "abstract-standalone"] {
+        if let Ok(FoundCrate::Name(name)) = crate_name(base) {
+            let name = format_ident!("{}", name);
+            return quote!(::#name::sdk);
+        }
+    }
+    quote!(::abstract_sdk)
+}
+
+pub fn execute_client(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fns = client_fns(&input, false)?;
+    let sdk = sdk_path();
+    let msg_name = &input.ident;
+    let vis = &input.vis;
+    let trait_name = format_ident!("{}Client", msg_name);
+
+    let signatures: Vec<_> = fns
+        .iter()
+        .map(|f| f.signature(&sdk, quote!(cosmwasm_std::CosmosMsg)))
+        .collect();
+    let bodies = fns.iter().map(|f| {
+        let msg = &f.msg;
+        quote!({ self.execute(#msg) })
+    });
+
+    Ok(quote!(
+        /// Typed execute client, implemented for the module clients of modules with this execute message.
+        #vis trait #trait_name {
+            #(#signatures;)*
+        }
+
+        impl<T, M> #trait_name for #sdk::ModuleClient<'_, T, M>
+        where
+            T: #sdk::ModuleClientInterface,
+            M: #sdk::ClientModule<ExecuteMsg = #msg_name>,
+        {
+            #(#signatures #bodies)*
+        }
+    ))
+}
+
+pub fn query_client(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fns = client_fns(&input, true)?;
+    let sdk = sdk_path();
+    let msg_name = &input.ident;
+    let vis = &input.vis;
+    let trait_name = format_ident!("{}Client", msg_name);
+
+    let signatures: Vec<_> = fns
+        .iter()
+        .map(|f| {
+            let returns = f.returns.as_ref().unwrap();
+            f.signature(&sdk, quote!(#returns))
+        })
+        .collect();
+    let bodies = fns.iter().map(|f| {
+        let msg = &f.msg;
+        quote!({ self.query(#msg) })
+    });
+
+    Ok(quote!(
+        /// Typed query client, implemented for the module clients of modules with this query message.
+        #vis trait #trait_name {
+            #(#signatures;)*
+        }
+
+        impl<T, M> #trait_name for #sdk::ModuleClient<'_, T, M>
+        where
+            T: #sdk::ModuleClientInterface,
+            M: #sdk::ClientModule<QueryMsg = #msg_name>,
+        {
+            #(#signatures #bodies)*
+        }
+    ))
+}
+
+fn client_fns(input: &DeriveInput, query: bool) -> syn::Result<Vec<ClientFn>> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "module clients can only be derived for enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "module clients can not be derived for generic messages",
+        ));
+    }
+    let msg_name = &input.ident;
+
+    data.variants
+        .iter()
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            let (args, msg) = match &variant.fields {
+                Fields::Named(fields) => {
+                    let args: Vec<(Ident, Type)> = fields
+                        .named
+                        .iter()
+                        .map(|field| (field.ident.clone().unwrap(), field.ty.clone()))
+                        .collect();
+                    let names = args.iter().map(|(name, _)| name);
+                    (
+                        args.clone(),
+                        quote!(#msg_name::#variant_name { #(#names),* }),
+                    )
+                }
+                Fields::Unit => (vec![], quote!(#msg_name::#variant_name)),
+                Fields::Unnamed(_) => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "module clients only support unit and struct variants",
+                    ))
+                }
+            };
+
+            let returns = if query {
+                let returns = variant
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path.is_ident("returns"))
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(variant, "missing #[returns(..)] attribute")
+                    })?;
+                Some(returns.parse_args::<Type>()?)
+            } else {
+                None
+            };
+
+            Ok(ClientFn {
+                name: format_ident!("{}", to_snake_case(&variant_name.to_string())),
+                docs: variant
+                    .attrs
+                    .iter()
+                    .filter(|attr| attr.path.is_ident("doc"))
+                    .cloned()
+                    .collect(),
+                args,
+                msg,
+                returns,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
@@ -0,0 +1,70 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+use crate::module_client::{sdk_path, to_snake_case};
+
+pub fn module_event(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "module events can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "module events must have named fields",
+        ));
+    };
+
+    let mut name = to_snake_case(&input.ident.to_string());
+    let mut version = 1u32;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("module_event"))
+    {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "expected #[module_event(name = \"..\", version = ..)]",
+            ));
+        };
+        for nested in list.nested {
+            let NestedMeta::Meta(Meta::NameValue(pair)) = &nested else {
+                return Err(syn::Error::new_spanned(nested, "expected `key = value`"));
+            };
+            match (&pair.lit, pair.path.get_ident()) {
+                (Lit::Str(lit), Some(key)) if key == "name" => name = lit.value(),
+                (Lit::Int(lit), Some(key)) if key == "version" => version = lit.base10_parse()?,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        pair,
+                        "unknown module event argument, expected `name = \"..\"` or `version = ..`",
+                    ))
+                }
+            }
+        }
+    }
+
+    let sdk = sdk_path();
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let attributes = fields.named.iter().map(|field| {
+        let field = field.ident.as_ref().unwrap();
+        let key = field.to_string();
+        quote!(cosmwasm_std::Attribute::new(#key, cosmwasm_std::to_json_string(&self.#field)?))
+    });
+
+    Ok(quote!(
+        impl #impl_generics #sdk::ModuleEvent for #ident #ty_generics #where_clause {
+            const NAME: &'static str = #name;
+            const VERSION: u32 = #version;
+
+            fn attributes(&self) -> cosmwasm_std::StdResult<::std::vec::Vec<cosmwasm_std::Attribute>> {
+                Ok(vec![#(#attributes),*])
+            }
+        }
+    ))
+}
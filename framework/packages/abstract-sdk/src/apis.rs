@@ -6,9 +6,11 @@ pub mod bank;
 pub mod execution;
 pub mod ibc;
 pub mod ibc_memo;
+pub mod module_client;
+pub mod module_event;
 pub mod modules;
 pub mod respond;
-mod splitter;
+pub mod splitter;
 mod traits;
 pub mod verify;
 pub mod version_registry;
@@ -22,7 +24,11 @@ pub mod distribution;
 #[cfg(feature = "stargate")]
 pub mod feegrant;
 #[cfg(feature = "stargate")]
+pub mod gov;
+#[cfg(feature = "stargate")]
 pub mod stargate;
+#[cfg(feature = "stargate")]
+pub mod token_factory;
 
 #[cfg(feature = "stargate")]
 pub(crate) fn stargate_msg(
@@ -4,10 +4,12 @@
 
 use abstract_macros::with_abstract_event;
 use abstract_std::account::ExecuteMsg;
-use cosmwasm_std::{Binary, Coin, CosmosMsg, Deps, ReplyOn, Response, SubMsg};
+use cosmwasm_std::{to_json_binary, Binary, Coin, CosmosMsg, Deps, ReplyOn, Response, SubMsg};
+use serde::Serialize;
 
 use super::AbstractApi;
 use crate::{
+    base::PAYLOAD_REPLY_ID,
     features::{AccountExecutor, ModuleIdentification},
     AbstractSdkResult, AccountAction,
 };
@@ -134,6 +136,26 @@ impl<T: Execution> Executor<'_, T> {
         Ok(sub_msg)
     }
 
+    /// Execute the msgs on the Account with a typed `payload` that routes the reply.
+    /// The reply is handled by the [`ReplyPayload`](crate::base::ReplyPayload) registered with `with_payload_replies`,
+    /// which receives the decoded `payload`.
+    pub fn execute_with_reply_and_payload(
+        &self,
+        actions: impl IntoIterator<Item = impl Into<AccountAction>>,
+        reply_on: ReplyOn,
+        payload: &impl Serialize,
+    ) -> AbstractSdkResult<SubMsg> {
+        let msg = self.execute(actions)?;
+        let sub_msg = SubMsg {
+            id: PAYLOAD_REPLY_ID,
+            msg: msg.into(),
+            gas_limit: None,
+            reply_on,
+            payload: to_json_binary(payload)?,
+        };
+        Ok(sub_msg)
+    }
+
     /// Execute a single msg on the Account.
     /// This message will be executed on the account contract. Any data returned from the execution will be forwarded to the account's response through a reply.
     /// The resulting data should be available in the reply of the specified ID.
@@ -325,6 +347,46 @@ mod test {
         }
     }
 
+    mod execute_with_reply_and_payload {
+
+        use super::*;
+
+        #[cosmwasm_schema::cw_serde]
+        enum TestPayload {
+            Swapped { deposit_into: String },
+        }
+
+        #[coverage_helper::test]
+        fn with_actions() {
+            let (deps, account, stub) = mock_module_setup();
+            let executor = stub.executor(deps.as_ref());
+
+            let action = vec![mock_bank_send(coins(1, "denom"))];
+            let payload = TestPayload::Swapped {
+                deposit_into: "vault".to_owned(),
+            };
+
+            let actual_res =
+                executor.execute_with_reply_and_payload(action.clone(), ReplyOn::Success, &payload);
+
+            let expected = SubMsg {
+                id: PAYLOAD_REPLY_ID,
+                msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: account.addr().to_string(),
+                    msg: to_json_binary(&ExecuteMsg::<Empty>::Execute {
+                        msgs: flatten_actions(action),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+                payload: to_json_binary(&payload).unwrap(),
+            };
+            assert_eq!(actual_res, Ok(expected));
+        }
+    }
+
     mod execute_with_response {
         use super::*;
 
@@ -0,0 +1,308 @@
+//! # Gov
+//! Interacts with the gov module of cosmos
+//!
+
+use cosmos_sdk_proto::{
+    cosmos::{
+        base::query::v1beta1::PageRequest,
+        gov::v1::{
+            MsgDeposit, MsgSubmitProposal, MsgVote, MsgVoteWeighted, Proposal, ProposalStatus,
+            QueryProposalRequest, QueryProposalResponse, QueryProposalsRequest,
+            QueryProposalsResponse, QueryTallyResultRequest, QueryTallyResultResponse, TallyResult,
+            WeightedVoteOption,
+        },
+    },
+    traits::{Message, Name},
+    Any,
+};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Deps, StdError, VoteOption};
+
+use super::stargate::{convert_coins, gov::vote_to_option};
+use crate::{features::AccountExecutor, AbstractSdkResult, AccountAction};
+
+/// Interact with the Cosmos SDK Gov module.
+/// Requires `Stargate` feature.
+pub trait GovInterface: AccountExecutor {
+    /**
+        API for accessing the Cosmos SDK gov module.
+        Proposals, deposits and votes are made by the Account.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::{mock_module::MockModule, AbstractSdkResult};
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+
+        let gov: Gov = module.gov(deps.as_ref())?;
+        # AbstractSdkResult::Ok(())
+        ```
+    */
+    fn gov<'a>(&'a self, deps: Deps<'a>) -> AbstractSdkResult<Gov<'a>> {
+        let account = self.account(deps)?.into_addr();
+        Ok(Gov { deps, account })
+    }
+}
+
+impl<T> GovInterface for T where T: AccountExecutor {}
+
+/**
+    API for accessing the Cosmos SDK gov module.
+
+    # Example
+    ```
+    use abstract_sdk::prelude::*;
+    # use cosmwasm_std::testing::mock_dependencies;
+    # use abstract_sdk::{mock_module::MockModule, AbstractSdkResult};
+    # use abstract_testing::prelude::*;
+    # let deps = mock_dependencies();
+    # let account = admin_account(deps.api);
+    # let module = MockModule::new(deps.api, account);
+
+    let gov: Gov = module.gov(deps.as_ref())?;
+    # AbstractSdkResult::Ok(())
+    ```
+*/
+#[derive(Clone)]
+pub struct Gov<'a> {
+    deps: Deps<'a>,
+    account: Addr,
+}
+
+impl Gov<'_> {
+    /// Submit a v1 proposal that executes the `messages` when it passes.
+    /// The messages must be signed by the gov module account (i.e. use it as `authority`).
+    pub fn submit_proposal(
+        &self,
+        messages: Vec<Any>,
+        initial_deposit: Vec<Coin>,
+        title: impl Into<String>,
+        summary: impl Into<String>,
+        metadata: Option<String>,
+        expedited: bool,
+    ) -> AbstractSdkResult<AccountAction> {
+        let msg = MsgSubmitProposal {
+            messages,
+            initial_deposit: convert_coins(initial_deposit),
+            proposer: self.account.to_string(),
+            metadata: metadata.unwrap_or_default(),
+            title: title.into(),
+            summary: summary.into(),
+            expedited,
+        };
+
+        Ok(stargate_action(MsgSubmitProposal::type_url(), msg))
+    }
+
+    /// Add a deposit to a proposal in its deposit period.
+    pub fn deposit(&self, proposal_id: u64, amount: Vec<Coin>) -> AbstractSdkResult<AccountAction> {
+        let msg = MsgDeposit {
+            proposal_id,
+            depositor: self.account.to_string(),
+            amount: convert_coins(amount),
+        };
+
+        Ok(stargate_action(MsgDeposit::type_url(), msg))
+    }
+
+    /// Vote on a proposal in its voting period.
+    pub fn vote(
+        &self,
+        proposal_id: u64,
+        option: VoteOption,
+        metadata: Option<String>,
+    ) -> AbstractSdkResult<AccountAction> {
+        let msg = MsgVote {
+            proposal_id,
+            voter: self.account.to_string(),
+            option: vote_to_option(option),
+            metadata: metadata.unwrap_or_default(),
+        };
+
+        Ok(stargate_action(MsgVote::type_url(), msg))
+    }
+
+    /// Split the Account's voting power over multiple options.
+    /// The weights must add up to one.
+    pub fn vote_weighted(
+        &self,
+        proposal_id: u64,
+        options: Vec<(VoteOption, Decimal)>,
+        metadata: Option<String>,
+    ) -> AbstractSdkResult<AccountAction> {
+        let total_weight = options
+            .iter()
+            .try_fold(Decimal::zero(), |total, (_, weight)| {
+                total.checked_add(*weight)
+            })
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        if total_weight != Decimal::one() {
+            return Err(StdError::generic_err(format!(
+                "weighted vote options must sum to 1, got {total_weight}"
+            ))
+            .into());
+        }
+
+        let msg = MsgVoteWeighted {
+            proposal_id,
+            voter: self.account.to_string(),
+            options: options
+                .into_iter()
+                .map(|(option, weight)| WeightedVoteOption {
+                    option: vote_to_option(option),
+                    weight: weight.to_string(),
+                })
+                .collect(),
+            metadata: metadata.unwrap_or_default(),
+        };
+
+        Ok(stargate_action(MsgVoteWeighted::type_url(), msg))
+    }
+
+    /// Query a proposal by its id.
+    pub fn proposal(&self, proposal_id: u64) -> AbstractSdkResult<Proposal> {
+        let response: QueryProposalResponse = self.query(
+            "/cosmos.gov.v1.Query/Proposal",
+            QueryProposalRequest { proposal_id },
+        )?;
+        response
+            .proposal
+            .ok_or_else(|| StdError::not_found(format!("proposal {proposal_id}")).into())
+    }
+
+    /// Query proposals, optionally filtered by status.
+    /// Use the `next_key` of the returned pagination to request the next page.
+    pub fn proposals(
+        &self,
+        status: Option<ProposalStatus>,
+        pagination: Option<PageRequest>,
+    ) -> AbstractSdkResult<QueryProposalsResponse> {
+        self.query(
+            "/cosmos.gov.v1.Query/Proposals",
+            QueryProposalsRequest {
+                proposal_status: status.unwrap_or(ProposalStatus::Unspecified).into(),
+                voter: String::new(),
+                depositor: String::new(),
+                pagination,
+            },
+        )
+    }
+
+    /// Query the current tally of a proposal.
+    pub fn tally(&self, proposal_id: u64) -> AbstractSdkResult<TallyResult> {
+        let response: QueryTallyResultResponse = self.query(
+            "/cosmos.gov.v1.Query/TallyResult",
+            QueryTallyResultRequest { proposal_id },
+        )?;
+        response
+            .tally
+            .ok_or_else(|| StdError::not_found(format!("tally of proposal {proposal_id}")).into())
+    }
+
+    fn query<Req: Message, Res: Message + Default>(
+        &self,
+        path: &str,
+        request: Req,
+    ) -> AbstractSdkResult<Res> {
+        let response = self
+            .deps
+            .querier
+            .query_grpc(path.to_owned(), Binary::new(request.encode_to_vec()))?;
+        Res::decode(response.as_slice())
+            .map_err(|e| StdError::parse_err(path, e.to_string()).into())
+    }
+}
+
+fn stargate_action(type_url: String, msg: impl Message) -> AccountAction {
+    super::stargate_msg(type_url, Binary::new(msg.encode_to_vec())).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock_module::*;
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{coins, testing::mock_dependencies, CosmosMsg};
+
+    fn decode_action<M: Message + Name + Default>(action: AccountAction) -> M {
+        let msgs = action.messages();
+        #[allow(deprecated)]
+        let CosmosMsg::Stargate { type_url, value } = &msgs[0] else {
+            panic!("expected stargate message");
+        };
+        assert_eq!(type_url, &M::type_url());
+        M::decode(value.as_slice()).unwrap()
+    }
+
+    #[coverage_helper::test]
+    fn submit_proposal() {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+        let gov = app.gov(deps.as_ref()).unwrap();
+
+        let message = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+            value: vec![1, 2, 3],
+        };
+        let action = gov
+            .submit_proposal(
+                vec![message.clone()],
+                coins(100, "ustake"),
+                "title",
+                "summary",
+                None,
+                false,
+            )
+            .unwrap();
+
+        let msg: MsgSubmitProposal = decode_action(action);
+        assert_eq!(msg.proposer, account.addr().to_string());
+        assert_eq!(msg.messages, vec![message]);
+        assert_eq!(msg.title, "title");
+        assert_eq!(msg.initial_deposit, convert_coins(coins(100, "ustake")));
+    }
+
+    #[coverage_helper::test]
+    fn deposit_and_vote() {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+        let gov = app.gov(deps.as_ref()).unwrap();
+
+        let msg: MsgDeposit = decode_action(gov.deposit(1, coins(100, "ustake")).unwrap());
+        assert_eq!(msg.depositor, account.addr().to_string());
+        assert_eq!(msg.proposal_id, 1);
+
+        let msg: MsgVote = decode_action(gov.vote(1, VoteOption::Yes, None).unwrap());
+        assert_eq!(msg.voter, account.addr().to_string());
+        assert_eq!(msg.option, vote_to_option(VoteOption::Yes));
+    }
+
+    #[coverage_helper::test]
+    fn vote_weighted() {
+        let deps = mock_dependencies();
+        let app = MockModule::new(deps.api, test_account(deps.api));
+        let gov = app.gov(deps.as_ref()).unwrap();
+
+        let action = gov
+            .vote_weighted(
+                1,
+                vec![
+                    (VoteOption::Yes, Decimal::percent(70)),
+                    (VoteOption::Abstain, Decimal::percent(30)),
+                ],
+                None,
+            )
+            .unwrap();
+        let msg: MsgVoteWeighted = decode_action(action);
+        assert_eq!(msg.options.len(), 2);
+        assert_eq!(msg.options[0].weight, "0.7");
+
+        let res = gov.vote_weighted(1, vec![(VoteOption::Yes, Decimal::percent(70))], None);
+        assert!(res.is_err());
+    }
+}
@@ -6,7 +6,10 @@ use abstract_std::{
     account::ExecuteMsg,
     account::ModuleInstallConfig,
     base,
-    ibc::{Callback, ModuleQuery},
+    ibc::{
+        remote_query::{RemoteAccountQuery, RemoteAccountQueryCallback},
+        Callback, ModuleQuery,
+    },
     ibc_client::{self, ExecuteMsg as IbcClientMsg, InstalledModuleIdentification},
     ibc_host::HostAction,
     objects::{module::ModuleInfo, TruncatedChainId},
@@ -20,7 +23,7 @@ use serde::Serialize;
 use super::AbstractApi;
 use crate::{
     features::{AccountExecutor, AccountIdentification, ModuleIdentification},
-    AbstractSdkResult, ModuleInterface, ModuleRegistryInterface,
+    AbstractSdkError, AbstractSdkResult, ModuleInterface, ModuleRegistryInterface,
 };
 
 /// Interact with other chains over IBC.
@@ -121,6 +124,57 @@ impl<T: IbcInterface> IbcClient<'_, T> {
         Ok(msg.into())
     }
 
+    /// Send module action from this module to the target module over an ordered channel.
+    /// The target module receives the messages of this module in order and exactly once,
+    /// the sequence of the message is available in [`abstract_std::ibc::ModuleIbcInfo::sequence`].
+    /// Use `resend_sequence` to re-send the message of a sequence that failed or timed out.
+    pub fn ordered_module_ibc_action<M: Serialize>(
+        &self,
+        host_chain: TruncatedChainId,
+        target_module: ModuleInfo,
+        exec_msg: &M,
+        resend_sequence: Option<u64>,
+        callback: Option<Callback>,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        let ibc_client_addr = self.module_address()?;
+        let msg = wasm_execute(
+            ibc_client_addr,
+            &ibc_client::ExecuteMsg::OrderedModuleIbcAction {
+                host_chain,
+                target_module,
+                msg: to_json_binary(exec_msg)?,
+                resend_sequence,
+                callback,
+            },
+            vec![],
+        )?;
+        Ok(msg.into())
+    }
+
+    /// Send module action from this module to the target module on the last chain of `path`.
+    /// The action is routed through the ibc-clients of the intermediate chains,
+    /// the callback is sent once the final hop got acknowledged.
+    pub fn multihop_module_ibc_action<M: Serialize>(
+        &self,
+        path: Vec<TruncatedChainId>,
+        target_module: ModuleInfo,
+        exec_msg: &M,
+        callback: Option<Callback>,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        let ibc_client_addr = self.module_address()?;
+        let msg = wasm_execute(
+            ibc_client_addr,
+            &ibc_client::ExecuteMsg::MultihopModuleIbcAction {
+                path,
+                target_module,
+                msg: to_json_binary(exec_msg)?,
+                callback,
+            },
+            vec![],
+        )?;
+        Ok(msg.into())
+    }
+
     /// Send module query from this module to the target module
     /// Use [`abstract_std::ibc::IbcResponseMsg::module_query_response`] to parse response
     pub fn module_ibc_query<B: Serialize, M: Serialize>(
@@ -186,6 +240,27 @@ impl<T: IbcInterface> IbcClient<'_, T> {
         Ok(msg.into())
     }
 
+    /// Send typed queries of this Account's remote account on `host_chain`.
+    /// The callback message is wrapped in a [`RemoteAccountQueryCallback`] that records the queries.
+    /// Use [`abstract_std::ibc::IbcResult::remote_account_responses`] with its queries to decode the response
+    pub fn remote_account_queries(
+        &self,
+        host_chain: TruncatedChainId,
+        queries: Vec<RemoteAccountQuery>,
+        callback: Callback,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        let remote_account_addr = self.remote_account_addr(&host_chain)?.ok_or_else(|| {
+            AbstractSdkError::generic_err(format!("No remote account on {host_chain}"))
+        })?;
+        let requests = queries
+            .iter()
+            .cloned()
+            .map(|q| q.into_query_request(remote_account_addr.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let callback = Callback::new(&RemoteAccountQueryCallback::new(queries, callback))?;
+        self.ibc_queries(host_chain, requests, callback)
+    }
+
     /// Address of the remote account
     /// Note: only Accounts that are remote to *this* chain are queryable
     pub fn remote_account_addr(
@@ -240,6 +315,43 @@ impl<T: IbcInterface + AccountExecutor> IbcClient<'_, T> {
         )
     }
 
+    /// A simple helper to create and register a remote account on a chain that is multiple hops away.
+    /// The remote account on the second to last chain must exist.
+    pub fn create_multihop_remote_account(
+        &self,
+        // The chains to route through, the account is created on the last one
+        path: Vec<TruncatedChainId>,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        self.execute(
+            &IbcClientMsg::RegisterMultihop {
+                path,
+                namespace: None,
+                install_modules: vec![],
+                callback: None,
+            },
+            vec![],
+        )
+    }
+
+    /// Call a [`HostAction`] on the host of the last chain of `path`.
+    /// The action is routed through the ibc-clients of the intermediate chains,
+    /// the `callback` is sent to the account once the final hop got acknowledged.
+    pub fn multihop_host_action(
+        &self,
+        path: Vec<TruncatedChainId>,
+        action: HostAction,
+        callback: Option<Callback>,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        self.execute(
+            &IbcClientMsg::MultihopRemoteAction {
+                path,
+                action,
+                callback,
+            },
+            vec![],
+        )
+    }
+
     /// Call a [`HostAction`] on the host of the provided `host_chain`.
     pub fn host_action(
         &self,
@@ -267,6 +379,25 @@ impl<T: IbcInterface + AccountExecutor> IbcClient<'_, T> {
         )
     }
 
+    /// Forward the provided coins from the Account to the `receiver` on the last chain of `path`.
+    /// `forward_channels[i]` is the ICS20 channel on `path[i]` that leads to `path[i + 1]`.
+    pub fn multihop_ics20_transfer(
+        &self,
+        path: Vec<TruncatedChainId>,
+        forward_channels: Vec<String>,
+        funds: Vec<Coin>,
+        receiver: String,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        self.execute(
+            &IbcClientMsg::SendFundsMultihop {
+                path,
+                forward_channels,
+                receiver,
+            },
+            funds,
+        )
+    }
+
     /// A simple helper to install an app on an account
     pub fn install_remote_app<M: Serialize>(
         &self,
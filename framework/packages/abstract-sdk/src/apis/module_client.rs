@@ -0,0 +1,329 @@
+//! # Module Client
+//! Typed interaction with the App and Adapter dependencies of a module.
+//! Typed methods are generated for the custom messages of a module with the
+//! [`ModuleExecuteClient`](abstract_macros::ModuleExecuteClient) and [`ModuleQueryClient`](abstract_macros::ModuleQueryClient) derives.
+
+use std::marker::PhantomData;
+
+use abstract_std::{
+    adapter::{self, AdapterRequestMsg},
+    app,
+    objects::module::ModuleId,
+};
+use cosmwasm_std::{wasm_execute, Addr, CosmosMsg, Deps};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::AbstractApi;
+use crate::{
+    cw_helpers::ApiQuery, features::ModuleIdentification, AbstractSdkResult, ModuleInterface,
+};
+
+/// A module with typed custom messages that can be called through a [`ModuleClient`].
+///
+/// Implemented on a marker type of the module, usually next to its messages.
+/// ```
+/// use abstract_sdk::{AppKind, ClientModule};
+/// # #[cosmwasm_schema::cw_serde]
+/// # pub enum PaymentExecuteMsg { Tip {} }
+/// # #[cosmwasm_schema::cw_serde]
+/// # pub enum PaymentQueryMsg { TipCount {} }
+///
+/// pub struct PaymentApp;
+///
+/// impl ClientModule for PaymentApp {
+///     const MODULE_ID: &'static str = "abstract:payment";
+///     type Kind = AppKind;
+///     type ExecuteMsg = PaymentExecuteMsg;
+///     type QueryMsg = PaymentQueryMsg;
+/// }
+/// ```
+pub trait ClientModule {
+    /// Id of the module
+    const MODULE_ID: ModuleId<'static>;
+    /// Whether the module is an App or an Adapter
+    type Kind: ClientModuleKind;
+    /// Custom execute message of the module
+    type ExecuteMsg: Serialize;
+    /// Custom query message of the module
+    type QueryMsg: Serialize;
+}
+
+/// Wraps the custom messages of a [`ClientModule`] in the entrypoint messages of its module type.
+pub trait ClientModuleKind {
+    /// Entrypoint execute message
+    type ExecuteMsg<M: Serialize>: Serialize;
+    /// Entrypoint query message
+    type QueryMsg<Q: Serialize>: Serialize;
+
+    /// Wrap a custom execute message sent on behalf of `account`.
+    fn execute_msg<M: Serialize>(account: Addr, msg: M) -> Self::ExecuteMsg<M>;
+    /// Wrap a custom query message.
+    fn query_msg<Q: Serialize>(msg: Q) -> Self::QueryMsg<Q>;
+}
+
+/// [`ClientModuleKind`] of Apps
+pub struct AppKind;
+
+impl ClientModuleKind for AppKind {
+    type ExecuteMsg<M: Serialize> = app::ExecuteMsg<M>;
+    type QueryMsg<Q: Serialize> = app::QueryMsg<Q>;
+
+    fn execute_msg<M: Serialize>(_account: Addr, msg: M) -> Self::ExecuteMsg<M> {
+        app::ExecuteMsg::Module(msg)
+    }
+
+    fn query_msg<Q: Serialize>(msg: Q) -> Self::QueryMsg<Q> {
+        app::QueryMsg::Module(msg)
+    }
+}
+
+/// [`ClientModuleKind`] of Adapters, requests are made for the Account of the calling module.
+pub struct AdapterKind;
+
+impl ClientModuleKind for AdapterKind {
+    type ExecuteMsg<M: Serialize> = adapter::ExecuteMsg<M>;
+    type QueryMsg<Q: Serialize> = adapter::QueryMsg<Q>;
+
+    fn execute_msg<M: Serialize>(account: Addr, msg: M) -> Self::ExecuteMsg<M> {
+        adapter::ExecuteMsg::Module(AdapterRequestMsg::new(Some(account.into_string()), msg))
+    }
+
+    fn query_msg<Q: Serialize>(msg: Q) -> Self::QueryMsg<Q> {
+        adapter::QueryMsg::Module(msg)
+    }
+}
+
+/// Typed interaction with the App and Adapter dependencies of a module.
+pub trait ModuleClientInterface: ModuleInterface + ModuleIdentification {
+    /**
+        API for interacting with an App dependency through its typed client.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::{mock_module::*, AppKind, ClientModule, ModuleClient};
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+        # struct MockApp;
+        # impl ClientModule for MockApp {
+        #     const MODULE_ID: &'static str = TEST_MODULE_ID;
+        #     type Kind = AppKind;
+        #     type ExecuteMsg = MockModuleExecuteMsg;
+        #     type QueryMsg = MockModuleQueryMsg;
+        # }
+
+        let app: ModuleClient<MockModule, MockApp> = module.app::<MockApp>(deps.as_ref());
+        ```
+    */
+    fn app<'a, M: ClientModule<Kind = AppKind>>(
+        &'a self,
+        deps: Deps<'a>,
+    ) -> ModuleClient<'a, Self, M> {
+        ModuleClient::new(self, deps)
+    }
+
+    /**
+        API for interacting with an Adapter dependency through its typed client.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::{mock_module::*, AdapterKind, ClientModule, ModuleClient};
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+        # struct MockAdapter;
+        # impl ClientModule for MockAdapter {
+        #     const MODULE_ID: &'static str = TEST_MODULE_ID;
+        #     type Kind = AdapterKind;
+        #     type ExecuteMsg = MockModuleExecuteMsg;
+        #     type QueryMsg = MockModuleQueryMsg;
+        # }
+
+        let adapter: ModuleClient<MockModule, MockAdapter> =
+            module.adapter::<MockAdapter>(deps.as_ref());
+        ```
+    */
+    fn adapter<'a, M: ClientModule<Kind = AdapterKind>>(
+        &'a self,
+        deps: Deps<'a>,
+    ) -> ModuleClient<'a, Self, M> {
+        ModuleClient::new(self, deps)
+    }
+}
+
+impl<T> ModuleClientInterface for T where T: ModuleInterface + ModuleIdentification {}
+
+impl<T: ModuleClientInterface, M: ClientModule> AbstractApi<T> for ModuleClient<'_, T, M> {
+    const API_ID: &'static str = "ModuleClient";
+
+    fn base(&self) -> &T {
+        self.base
+    }
+    fn deps(&self) -> Deps {
+        self.deps
+    }
+}
+
+/// Typed client of a [`ClientModule`] that the calling module depends on.
+/// The typed methods are implemented by the traits generated with the
+/// [`ModuleExecuteClient`](abstract_macros::ModuleExecuteClient) and [`ModuleQueryClient`](abstract_macros::ModuleQueryClient) derives.
+pub struct ModuleClient<'a, T: ModuleClientInterface, M: ClientModule> {
+    base: &'a T,
+    deps: Deps<'a>,
+    _module: PhantomData<M>,
+}
+
+impl<'a, T: ModuleClientInterface, M: ClientModule> ModuleClient<'a, T, M> {
+    fn new(base: &'a T, deps: Deps<'a>) -> Self {
+        Self {
+            base,
+            deps,
+            _module: PhantomData,
+        }
+    }
+
+    /// Address of the module, which must be a declared dependency.
+    pub fn address(&self) -> AbstractSdkResult<Addr> {
+        let modules = self.base.modules(self.deps);
+        modules.assert_module_dependency(M::MODULE_ID)?;
+        modules.module_address(M::MODULE_ID)
+    }
+
+    /// Construct a message that executes `msg` on the module.
+    pub fn execute(&self, msg: M::ExecuteMsg) -> AbstractSdkResult<CosmosMsg> {
+        let account = self.base.account(self.deps)?.into_addr();
+        let msg = M::Kind::execute_msg(account, msg);
+        Ok(wasm_execute(self.address()?, &msg, vec![])?.into())
+    }
+
+    /// Smart query the module.
+    pub fn query<R: DeserializeOwned>(&self, msg: M::QueryMsg) -> AbstractSdkResult<R> {
+        let msg = M::Kind::query_msg(msg);
+        self.smart_query(self.address()?, &msg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{testing::*, *};
+
+    use super::*;
+    use crate::{apis::traits::test::abstract_api_test, mock_module::*};
+
+    #[cosmwasm_schema::cw_serde]
+    #[derive(abstract_macros::ModuleExecuteClient)]
+    enum TestExecuteMsg {
+        /// Unit variant
+        Ping,
+        UpdateConfig {
+            fee: Uint128,
+            recipient: Option<String>,
+        },
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    #[derive(cosmwasm_schema::QueryResponses, abstract_macros::ModuleQueryClient)]
+    enum TestQueryMsg {
+        #[returns(String)]
+        TipCount {},
+    }
+
+    struct TestApp;
+    impl ClientModule for TestApp {
+        const MODULE_ID: ModuleId<'static> = TEST_MODULE_ID;
+        type Kind = AppKind;
+        type ExecuteMsg = TestExecuteMsg;
+        type QueryMsg = TestQueryMsg;
+    }
+
+    struct TestAdapter;
+    impl ClientModule for TestAdapter {
+        const MODULE_ID: ModuleId<'static> = TEST_MODULE_ID;
+        type Kind = AdapterKind;
+        type ExecuteMsg = TestExecuteMsg;
+        type QueryMsg = TestQueryMsg;
+    }
+
+    struct UnknownApp;
+    impl ClientModule for UnknownApp {
+        const MODULE_ID: ModuleId<'static> = FAKE_MODULE_ID;
+        type Kind = AppKind;
+        type ExecuteMsg = TestExecuteMsg;
+        type QueryMsg = TestQueryMsg;
+    }
+
+    #[coverage_helper::test]
+    fn typed_app_execute() {
+        let (deps, account, app) = mock_module_setup();
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let client = app.app::<TestApp>(deps.as_ref());
+
+        let res = client.update_config(Uint128::one(), None);
+
+        let expected_msg: abstract_std::app::ExecuteMsg<_> =
+            abstract_std::app::ExecuteMsg::Module(TestExecuteMsg::UpdateConfig {
+                fee: Uint128::one(),
+                recipient: None,
+            });
+        assert_eq!(
+            res,
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: abstr.module_address.to_string(),
+                msg: to_json_binary(&expected_msg).unwrap(),
+                funds: vec![],
+            }))
+        );
+
+        let res = app.adapter::<TestAdapter>(deps.as_ref()).ping();
+        let expected_msg: abstract_std::adapter::ExecuteMsg<_> =
+            abstract_std::adapter::ExecuteMsg::Module(AdapterRequestMsg::new(
+                Some(account.addr().to_string()),
+                TestExecuteMsg::Ping,
+            ));
+        assert_eq!(
+            res,
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: abstr.module_address.to_string(),
+                msg: to_json_binary(&expected_msg).unwrap(),
+                funds: vec![],
+            }))
+        );
+    }
+
+    #[coverage_helper::test]
+    fn typed_query() {
+        let (deps, _, app) = mock_module_setup();
+
+        let res = app.app::<TestApp>(deps.as_ref()).tip_count();
+        assert_eq!(res, Ok(TEST_MODULE_RESPONSE.to_string()));
+
+        let res = app.adapter::<TestAdapter>(deps.as_ref()).tip_count();
+        assert_eq!(res, Ok(TEST_MODULE_RESPONSE.to_string()));
+    }
+
+    #[coverage_helper::test]
+    fn should_return_err_if_not_dependency() {
+        let (deps, _, app) = mock_module_setup();
+
+        let res = app.app::<UnknownApp>(deps.as_ref()).ping();
+        assert!(res.unwrap_err().to_string().contains(FAKE_MODULE_ID));
+
+        let res = app.app::<UnknownApp>(deps.as_ref()).tip_count();
+        assert!(res.unwrap_err().to_string().contains(FAKE_MODULE_ID));
+    }
+
+    #[coverage_helper::test]
+    fn abstract_api() {
+        let (deps, _, app) = mock_module_setup();
+        let client = app.app::<TestApp>(deps.as_ref());
+
+        abstract_api_test(client);
+    }
+}
@@ -0,0 +1,209 @@
+//! # Module Events
+//! Typed events that a module emits and describes in its JSON schema.
+//! Events are declared with the [`ModuleEvent`](abstract_macros::ModuleEvent) derive and emitted with
+//! [`AbstractResponse::event_response`](crate::AbstractResponse::event_response).
+
+use cosmwasm_std::{Attribute, Event, StdResult};
+use schemars::{
+    schema::{InstanceType, Metadata, RootSchema, Schema, SchemaObject, SubschemaValidation},
+    JsonSchema,
+};
+
+/// Prefix of the event type of module events. The chain prefixes it with `wasm-`.
+pub const MODULE_EVENT_PREFIX: &str = "abstract-";
+/// Attribute that holds the [`ModuleEvent::NAME`].
+pub const EVENT_NAME_ATTRIBUTE: &str = "event";
+/// Attribute that holds the [`ModuleEvent::VERSION`].
+pub const EVENT_VERSION_ATTRIBUTE: &str = "event_version";
+
+/// A typed event emitted by a module.
+///
+/// Every field of the event is emitted as an attribute with its JSON-encoded value.
+/// Bump the version when the attributes of the event change in a breaking way.
+/// ```
+/// use abstract_sdk::ModuleEvent;
+/// use cosmwasm_std::Uint128;
+///
+/// #[derive(schemars::JsonSchema, ModuleEvent)]
+/// #[module_event(name = "tip", version = 1)]
+/// pub struct TipEvent {
+///     pub tipper: String,
+///     pub amount: Uint128,
+/// }
+/// ```
+pub trait ModuleEvent: JsonSchema {
+    /// Name of the event, emitted as the `event` attribute.
+    const NAME: &'static str;
+    /// Version of the event attributes, emitted as the `event_version` attribute.
+    const VERSION: u32;
+
+    /// Attributes of the event, excluding its name and version.
+    fn attributes(&self) -> StdResult<Vec<Attribute>>;
+
+    /// Construct the [`Event`] of this module event for the module with `module_id`.
+    fn to_event(&self, module_id: &str) -> StdResult<Event> {
+        Ok(Event::new(format!("{MODULE_EVENT_PREFIX}{module_id}"))
+            .add_attribute(EVENT_NAME_ATTRIBUTE, Self::NAME)
+            .add_attribute(EVENT_VERSION_ATTRIBUTE, Self::VERSION.to_string())
+            .add_attributes(self.attributes()?))
+    }
+}
+
+/// Schema of a [`ModuleEvent`], exported with the schema of a module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleEventSchema {
+    /// Name of the event
+    pub name: &'static str,
+    /// Version of the event
+    pub version: u32,
+    /// Schema of the event fields
+    pub schema: RootSchema,
+}
+
+impl ModuleEventSchema {
+    /// Schema of the event `E`.
+    pub fn of<E: ModuleEvent>() -> Self {
+        Self {
+            name: E::NAME,
+            version: E::VERSION,
+            schema: schemars::schema_for!(E),
+        }
+    }
+}
+
+/// Constructor of a [`ModuleEventSchema`], registered on a module with `with_events`.
+///
+/// ```rust,ignore
+/// const APP: MyApp = MyApp::new(APP_ID, APP_VERSION, None)
+///     .with_events(&[ModuleEventSchema::of::<TipEvent>]);
+/// ```
+pub type ModuleEventSchemaFn = fn() -> ModuleEventSchema;
+
+/// Combine the schemas of the events of a module into a single `ModuleEvents` schema.
+///
+/// Each event is described by its attributes, including the constant `event` and `event_version` attributes.
+pub fn module_events_schema(events: &[ModuleEventSchema]) -> RootSchema {
+    let mut definitions = schemars::Map::new();
+    let variants = events
+        .iter()
+        .map(|event| {
+            definitions.extend(event.schema.definitions.clone());
+            let mut schema = event.schema.schema.clone();
+            let object = schema.object();
+            object.properties.insert(
+                EVENT_NAME_ATTRIBUTE.to_owned(),
+                const_string_schema(event.name.to_owned()),
+            );
+            object.properties.insert(
+                EVENT_VERSION_ATTRIBUTE.to_owned(),
+                const_string_schema(event.version.to_string()),
+            );
+            object.required.insert(EVENT_NAME_ATTRIBUTE.to_owned());
+            object.required.insert(EVENT_VERSION_ATTRIBUTE.to_owned());
+            schema.metadata().title = Some(event.name.to_owned());
+            Schema::Object(schema)
+        })
+        .collect();
+
+    RootSchema {
+        meta_schema: Some("http://json-schema.org/draft-07/schema#".to_owned()),
+        schema: SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                title: Some("ModuleEvents".to_owned()),
+                description: Some(
+                    "Events emitted by the module. Attribute values are JSON-encoded, except for `event` and `event_version`."
+                        .to_owned(),
+                ),
+                ..Default::default()
+            })),
+            subschemas: Some(Box::new(SubschemaValidation {
+                one_of: Some(variants),
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+        definitions,
+    }
+}
+
+fn const_string_schema(value: String) -> Schema {
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        const_value: Some(value.into()),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Uint128;
+
+    use super::*;
+
+    #[derive(schemars::JsonSchema, abstract_macros::ModuleEvent)]
+    #[module_event(name = "tip", version = 2)]
+    struct TipEvent {
+        tipper: String,
+        amount: Uint128,
+    }
+
+    #[derive(schemars::JsonSchema, abstract_macros::ModuleEvent)]
+    struct ConfigUpdated {
+        fee: Option<u64>,
+    }
+
+    #[coverage_helper::test]
+    fn event_attributes() {
+        let event = TipEvent {
+            tipper: "alice".to_owned(),
+            amount: Uint128::new(100),
+        }
+        .to_event("abstract:payment")
+        .unwrap();
+
+        assert_eq!(
+            event,
+            Event::new("abstract-abstract:payment").add_attributes(vec![
+                ("event", "tip"),
+                ("event_version", "2"),
+                ("tipper", "\"alice\""),
+                ("amount", "\"100\""),
+            ])
+        );
+    }
+
+    #[coverage_helper::test]
+    fn default_name_and_version() {
+        assert_eq!(ConfigUpdated::NAME, "config_updated");
+        assert_eq!(ConfigUpdated::VERSION, 1);
+        assert_eq!(
+            ConfigUpdated { fee: None }.attributes().unwrap(),
+            vec![Attribute::new("fee", "null")]
+        );
+    }
+
+    #[coverage_helper::test]
+    fn events_schema() {
+        let schema = module_events_schema(&[
+            ModuleEventSchema::of::<TipEvent>(),
+            ModuleEventSchema::of::<ConfigUpdated>(),
+        ]);
+
+        let variants = schema.schema.subschemas.unwrap().one_of.unwrap();
+        assert_eq!(variants.len(), 2);
+        let tip = variants[0].clone().into_object();
+        assert_eq!(tip.metadata.unwrap().title.unwrap(), "tip");
+        let object = tip.object.unwrap();
+        assert!(object.properties.contains_key("amount"));
+        assert_eq!(
+            object.properties["event_version"]
+                .clone()
+                .into_object()
+                .const_value,
+            Some("2".into())
+        );
+        assert!(object.required.contains("event"));
+        // Definitions of the field types are kept
+        assert!(schema.definitions.contains_key("Uint128"));
+    }
+}
@@ -1,7 +1,8 @@
 use abstract_macros::with_abstract_event;
-use cosmwasm_std::{Attribute, Response};
+use cosmwasm_std::{Attribute, Event, Response};
 
-use crate::features::ModuleIdentification;
+use super::module_event::ModuleEvent;
+use crate::{features::ModuleIdentification, AbstractSdkResult};
 
 /// Construct a [`Response`] with an abstract-specific event.
 /// The event contains the contract name and the action, and any additional attributes.
@@ -20,6 +21,14 @@ pub trait AbstractResponse: ModuleIdentification {
         let response = Response::new();
         with_abstract_event!(response, module_id, action, attributes)
     }
+    /// Construct the typed `abstract-<module_id>` event of a [`ModuleEvent`].
+    fn module_event(&self, event: &impl ModuleEvent) -> AbstractSdkResult<Event> {
+        Ok(event.to_event(self.module_id())?)
+    }
+    /// Respond with the typed event of a [`ModuleEvent`].
+    fn event_response(&self, event: &impl ModuleEvent) -> AbstractSdkResult<Response> {
+        Ok(Response::new().add_event(self.module_event(event)?))
+    }
 }
 
 impl<T> AbstractResponse for T where T: ModuleIdentification {}
@@ -1,16 +1,21 @@
-#![allow(unused)]
-use abstract_std::objects::AnsAsset;
-use cosmwasm_std::{Addr, CosmosMsg, Deps, Env, StdResult, Uint128};
+//! # Splitter
+//! Splits assets of the Account between multiple recipients.
+
+use abstract_std::objects::{AccountId, AnsAsset};
+use cosmwasm_std::{Addr, Deps, Uint128, Uint256};
 
 use super::AbstractApi;
 use crate::{
     features::{AccountExecutor, ModuleIdentification},
-    AbstractSdkResult, AccountAction, TransferInterface,
+    AbstractSdkError, AbstractSdkResult, AccountAction, AccountVerification, ModuleInterface,
+    TransferInterface,
 };
 // ANCHOR: splitter
 // Trait to retrieve the Splitter object
 // Depends on the ability to transfer funds
+/// Split assets of the Account between multiple recipients.
 pub trait SplitterInterface: TransferInterface + AccountExecutor + ModuleIdentification {
+    /// API for splitting assets of the Account.
     fn splitter<'a>(&'a self, deps: Deps<'a>) -> Splitter<'a, Self> {
         Splitter { base: self, deps }
     }
@@ -30,6 +35,7 @@ impl<T: SplitterInterface> AbstractApi<T> for Splitter<'_, T> {
     }
 }
 
+/// API for splitting assets of the Account.
 #[derive(Clone)]
 pub struct Splitter<'a, T: SplitterInterface> {
     base: &'a T,
@@ -67,15 +73,186 @@ impl<T: SplitterInterface> Splitter<'_, T> {
 }
 // ANCHOR_END: splitter
 
+/// Recipient of a share of a payout.
+#[cosmwasm_schema::cw_serde]
+pub enum PayoutRecipient {
+    /// Address
+    Addr(Addr),
+    /// Abstract Account, resolved through the registry
+    Account(AccountId),
+    /// Module installed on the Account
+    Module(String),
+}
+
+/// Weighted share of a payout.
+#[cosmwasm_schema::cw_serde]
+pub struct PayoutShare {
+    /// Recipient of the share
+    pub recipient: PayoutRecipient,
+    /// Weight of the share relative to the other shares
+    pub weight: u64,
+}
+
+impl PayoutShare {
+    /// Share with `weight` for `recipient`.
+    pub fn new(recipient: PayoutRecipient, weight: u64) -> Self {
+        Self { recipient, weight }
+    }
+}
+
+/// What to do with the amount that is left after rounding down all the shares.
+#[cosmwasm_schema::cw_serde]
+#[derive(Copy, Default)]
+pub enum PayoutRemainder {
+    /// Keep the remainder on the Account
+    #[default]
+    Keep,
+    /// Add the remainder to the first share
+    First,
+    /// Hand out the remainder one unit at a time, to the shares with the largest rounding loss first.
+    /// Ties go to the share that was listed first.
+    LargestFraction,
+}
+
+/// Weighted payout of assets to multiple recipients.
+/// Can be stored by a module or accepted in its messages.
+#[cosmwasm_schema::cw_serde]
+pub struct SplitterPayout {
+    shares: Vec<PayoutShare>,
+    remainder: PayoutRemainder,
+    min_payout: Uint128,
+}
+
+impl SplitterPayout {
+    /// Payout with the weighted `shares`, keeping the remainder on the Account.
+    pub fn new(shares: Vec<PayoutShare>) -> Self {
+        Self {
+            shares,
+            remainder: PayoutRemainder::default(),
+            min_payout: Uint128::zero(),
+        }
+    }
+
+    /// Set how the remainder after rounding is handled.
+    pub fn with_remainder(mut self, remainder: PayoutRemainder) -> Self {
+        self.remainder = remainder;
+        self
+    }
+
+    /// Don't pay out amounts smaller than `min_payout`, they are kept on the Account.
+    pub fn with_min_payout(mut self, min_payout: Uint128) -> Self {
+        self.min_payout = min_payout;
+        self
+    }
+
+    /// Amount of each share when paying out `total`.
+    /// Amounts below the minimum payout are zero.
+    pub fn amounts(&self, total: Uint128) -> AbstractSdkResult<Vec<Uint128>> {
+        let total_weight: u128 = self.shares.iter().map(|share| share.weight as u128).sum();
+        if total_weight == 0 {
+            return Err(AbstractSdkError::generic_err(
+                "payout needs at least one share with a non-zero weight",
+            ));
+        }
+
+        let mut amounts: Vec<Uint128> = self
+            .shares
+            .iter()
+            .map(|share| total.multiply_ratio(share.weight as u128, total_weight))
+            .collect();
+        let mut remainder = total - amounts.iter().sum::<Uint128>();
+
+        match self.remainder {
+            PayoutRemainder::Keep => {}
+            PayoutRemainder::First => amounts[0] += remainder,
+            PayoutRemainder::LargestFraction => {
+                let mut by_fraction: Vec<(usize, Uint256)> = self
+                    .shares
+                    .iter()
+                    .map(|share| {
+                        Uint256::from(total) * Uint256::from(share.weight)
+                            % Uint256::from(total_weight)
+                    })
+                    .enumerate()
+                    .collect();
+                // Stable sort keeps ties in the order of the shares
+                by_fraction.sort_by(|(_, a), (_, b)| b.cmp(a));
+                for (index, _) in by_fraction {
+                    if remainder.is_zero() {
+                        break;
+                    }
+                    amounts[index] += Uint128::one();
+                    remainder -= Uint128::one();
+                }
+            }
+        }
+
+        Ok(amounts
+            .into_iter()
+            .map(|amount| {
+                if amount < self.min_payout {
+                    Uint128::zero()
+                } else {
+                    amount
+                }
+            })
+            .collect())
+    }
+}
+
+// Resolving Account and module recipients needs the registry and the installed modules
+impl<T: SplitterInterface + AccountVerification + ModuleInterface> Splitter<'_, T> {
+    /// Pay out the `assets` of the Account according to the weighted shares of the `payout`.
+    pub fn payout(
+        &self,
+        assets: Vec<AnsAsset>,
+        payout: &SplitterPayout,
+    ) -> AbstractSdkResult<AccountAction> {
+        let recipients = payout
+            .shares
+            .iter()
+            .map(|share| self.resolve(&share.recipient))
+            .collect::<AbstractSdkResult<Vec<Addr>>>()?;
+
+        let bank = self.base.bank(self.deps);
+        let mut action = AccountAction::default();
+        for asset in assets {
+            let amounts = payout.amounts(asset.amount)?;
+            for (recipient, amount) in recipients.iter().zip(amounts) {
+                if amount.is_zero() {
+                    continue;
+                }
+                let share = AnsAsset::new(asset.name.clone(), amount);
+                action.merge(bank.transfer(vec![share], recipient)?);
+            }
+        }
+        Ok(action)
+    }
+
+    /// Resolve the address of a payout recipient.
+    pub fn resolve(&self, recipient: &PayoutRecipient) -> AbstractSdkResult<Addr> {
+        match recipient {
+            PayoutRecipient::Addr(addr) => Ok(addr.clone()),
+            PayoutRecipient::Account(account_id) => Ok(self
+                .base
+                .account_registry(self.deps)?
+                .account(account_id)?
+                .into_addr()),
+            PayoutRecipient::Module(module_id) => {
+                self.base.modules(self.deps).module_address(module_id)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::needless_borrows_for_generic_args)]
     use abstract_std::objects::{AnsAsset, AssetEntry};
     use abstract_testing::{abstract_mock_querier_builder, prelude::*};
     use cosmwasm_std::{
-        coins,
-        testing::{mock_dependencies, mock_env},
-        Addr, BankMsg, CosmosMsg, Empty, Response, StdError, SubMsg, Uint128, WasmMsg,
+        coins, testing::mock_dependencies, Addr, BankMsg, CosmosMsg, Empty, Response, SubMsg,
+        Uint128, WasmMsg,
     };
     use cw_asset::AssetInfo;
 
@@ -88,9 +265,7 @@ mod test {
     #[coverage_helper::test]
     fn split() -> Result<(), AbstractSdkError> {
         let mut deps = mock_dependencies();
-        let env = mock_env_validated(deps.api);
         let account = test_account(deps.api);
-        let abstr = AbstractMockAddrs::new(deps.api);
         deps.querier = abstract_mock_querier_builder(deps.api)
             .account(&account, TEST_ACCOUNT_ID)
             .assets(vec![(&AssetEntry::new("usd"), AssetInfo::native("usd"))])
@@ -148,12 +323,130 @@ mod test {
         Ok(())
     }
 
+    mod payout {
+        use super::*;
+        use crate::apis::splitter::{
+            PayoutRecipient, PayoutRemainder, PayoutShare, SplitterPayout,
+        };
+
+        fn shares(weights: &[u64]) -> Vec<PayoutShare> {
+            weights
+                .iter()
+                .map(|weight| {
+                    PayoutShare::new(PayoutRecipient::Addr(Addr::unchecked("r")), *weight)
+                })
+                .collect()
+        }
+
+        fn amounts(payout: SplitterPayout, total: u128) -> Vec<u128> {
+            payout
+                .amounts(Uint128::new(total))
+                .unwrap()
+                .into_iter()
+                .map(Uint128::u128)
+                .collect()
+        }
+
+        #[coverage_helper::test]
+        fn weighted_remainders() {
+            let payout = SplitterPayout::new(shares(&[1, 1, 1]));
+            assert_eq!(amounts(payout.clone(), 100), vec![33, 33, 33]);
+            assert_eq!(
+                amounts(payout.with_remainder(PayoutRemainder::First), 100),
+                vec![34, 33, 33]
+            );
+
+            // 3/6, 2/6 and 1/6 of 11 are 5.5, 3.67 and 1.83
+            let payout = SplitterPayout::new(shares(&[3, 2, 1]))
+                .with_remainder(PayoutRemainder::LargestFraction);
+            assert_eq!(amounts(payout, 11), vec![5, 4, 2]);
+
+            // Ties go to the first share
+            let payout = SplitterPayout::new(shares(&[1, 1]))
+                .with_remainder(PayoutRemainder::LargestFraction);
+            assert_eq!(amounts(payout, 3), vec![2, 1]);
+        }
+
+        #[coverage_helper::test]
+        fn min_payout() {
+            let payout = SplitterPayout::new(shares(&[90, 9, 1])).with_min_payout(Uint128::new(5));
+            assert_eq!(amounts(payout, 100), vec![90, 9, 0]);
+        }
+
+        #[coverage_helper::test]
+        fn payout_is_stored_as_json() -> Result<(), AbstractSdkError> {
+            let payout = SplitterPayout::new(vec![PayoutShare::new(
+                PayoutRecipient::Module(TEST_MODULE_ID.to_owned()),
+                1,
+            )])
+            .with_remainder(PayoutRemainder::LargestFraction);
+
+            let stored = to_json_binary(&payout)?;
+            assert_eq!(cosmwasm_std::from_json::<SplitterPayout>(&stored)?, payout);
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn zero_weight() {
+            assert!(SplitterPayout::new(shares(&[0]))
+                .amounts(Uint128::one())
+                .is_err());
+            assert!(SplitterPayout::new(vec![]).amounts(Uint128::one()).is_err());
+        }
+
+        #[coverage_helper::test]
+        fn resolves_recipients() -> Result<(), AbstractSdkError> {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            let abstr = AbstractMockAddrs::new(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .assets(vec![
+                    (&AssetEntry::new("usd"), AssetInfo::native("usd")),
+                    (&AssetEntry::new("eur"), AssetInfo::native("eur")),
+                ])
+                .build();
+            let module = MockModule::new(deps.api, account.clone());
+            let contributor = deps.api.addr_make("contributor");
+
+            let payout = SplitterPayout::new(vec![
+                PayoutShare::new(PayoutRecipient::Addr(contributor.clone()), 2),
+                PayoutShare::new(PayoutRecipient::Account(TEST_ACCOUNT_ID), 1),
+                PayoutShare::new(PayoutRecipient::Module(TEST_MODULE_ID.to_owned()), 1),
+            ])
+            .with_remainder(PayoutRemainder::First);
+
+            let action = module.splitter(deps.as_ref()).payout(
+                vec![AnsAsset::new("usd", 10u128), AnsAsset::new("eur", 2u128)],
+                &payout,
+            )?;
+
+            let send = |to: &Addr, amount: u128, denom: &str| -> CosmosMsg {
+                BankMsg::Send {
+                    to_address: to.to_string(),
+                    amount: coins(amount, denom),
+                }
+                .into()
+            };
+            assert_eq!(
+                action.messages(),
+                vec![
+                    send(&contributor, 6, "usd"),
+                    send(account.addr(), 2, "usd"),
+                    send(&abstr.module_address, 2, "usd"),
+                    // Zero amounts are skipped
+                    send(&contributor, 2, "eur"),
+                ]
+            );
+            Ok(())
+        }
+    }
+
     #[coverage_helper::test]
     fn abstract_api() {
-        let mut deps = mock_dependencies();
+        let deps = mock_dependencies();
         let account = test_account(deps.api);
-        let module = MockModule::new(deps.api, account.clone());
-        let env = mock_env_validated(deps.api);
+        let module = MockModule::new(deps.api, account);
         let splitter = module.splitter(deps.as_ref());
 
         abstract_api_test(splitter);
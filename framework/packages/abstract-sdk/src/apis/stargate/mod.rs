@@ -1,6 +1,7 @@
 pub mod authz;
 pub mod feegrant;
 pub mod gov;
+pub mod token_factory;
 use cosmos_sdk_proto::{cosmos::base, traits::Message, Any};
 use cosmwasm_std::{Coin, Timestamp};
 
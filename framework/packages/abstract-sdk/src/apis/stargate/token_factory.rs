@@ -0,0 +1,74 @@
+//! Token factory messages.
+//! The chain flavours share the field layout of these messages, only their proto package differs.
+//! @see <https://github.com/osmosis-labs/osmosis/blob/main/proto/osmosis/tokenfactory/v1beta1/tx.proto>
+
+use cosmos_sdk_proto::cosmos::{bank::v1beta1::Metadata, base::v1beta1::Coin};
+
+/// Create a `factory/{sender}/{subdenom}` denom.
+/// On Kujira the subdenom is called `nonce`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub subdenom: String,
+}
+
+/// Mint tokens of a denom administered by the sender.
+/// On Kujira the recipient is called `recipient`, on Injective `receiver`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgMint {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+    #[prost(string, tag = "3")]
+    pub mint_to_address: String,
+}
+
+/// Burn tokens of a denom administered by the sender.
+/// Kujira has no `burn_from_address`, it must be left empty.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgBurn {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+    #[prost(string, tag = "3")]
+    pub burn_from_address: String,
+}
+
+/// Transfer the admin rights of a denom.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgChangeAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub denom: String,
+    #[prost(string, tag = "3")]
+    pub new_admin: String,
+}
+
+/// Set the bank metadata of a denom.
+/// Not available on Kujira.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSetDenomMetadata {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: Option<Metadata>,
+}
+
+/// Move tokens of a denom between two addresses without their consent.
+/// Only available on Osmosis and Neutron.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgForceTransfer {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+    #[prost(string, tag = "3")]
+    pub transfer_from_address: String,
+    #[prost(string, tag = "4")]
+    pub transfer_to_address: String,
+}
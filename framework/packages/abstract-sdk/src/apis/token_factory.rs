@@ -0,0 +1,381 @@
+//! # Token Factory
+//! Interacts with the token factory module of the chain.
+//! The Account is the creator and admin of the denoms.
+//!
+
+use abstract_std::{
+    constants::{INJECTIVE, KUJIRA, NEUTRON, OSMOSIS},
+    objects::TruncatedChainId,
+};
+use cosmos_sdk_proto::{cosmos::bank::v1beta1::Metadata, traits::Message};
+use cosmwasm_std::{Addr, Binary, Coin, Deps, Env};
+
+use super::stargate::{
+    convert_coin,
+    token_factory::{
+        MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgForceTransfer, MsgMint, MsgSetDenomMetadata,
+    },
+};
+use crate::{features::AccountExecutor, AbstractSdkError, AbstractSdkResult, AccountAction};
+
+/// Interact with the token factory module of the chain.
+/// Requires `Stargate` feature.
+pub trait TokenFactoryInterface: AccountExecutor {
+    /**
+        API for accessing the token factory module of the chain.
+        The message flavour is selected from the chain id.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::{mock_module::MockModule, AbstractSdkResult};
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let mut env = mock_env_validated(deps.api);
+        # env.block.chain_id = "osmosis-1".to_owned();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+
+        let token_factory: TokenFactory = module.token_factory(deps.as_ref(), &env)?;
+        # AbstractSdkResult::Ok(())
+        ```
+    */
+    fn token_factory(&self, deps: Deps, env: &Env) -> AbstractSdkResult<TokenFactory> {
+        let flavour = TokenFactoryFlavour::from_chain_id(&env.block.chain_id)?;
+        let account = self.account(deps)?.into_addr();
+        Ok(TokenFactory { account, flavour })
+    }
+}
+
+impl<T> TokenFactoryInterface for T where T: AccountExecutor {}
+
+/// Token factory implementations supported by the [`TokenFactory`] API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenFactoryFlavour {
+    /// `osmosis.tokenfactory.v1beta1`
+    Osmosis,
+    /// Neutron uses the osmosis proto package
+    Neutron,
+    /// `kujira.denom`
+    Kujira,
+    /// `injective.tokenfactory.v1beta1`
+    Injective,
+}
+
+impl TokenFactoryFlavour {
+    /// Select the token factory flavour of a chain.
+    pub fn from_chain_id(chain_id: &str) -> AbstractSdkResult<Self> {
+        let chain_name = TruncatedChainId::from_chain_id(chain_id);
+        let chain_name = chain_name.as_str();
+        if OSMOSIS.contains(&chain_name) {
+            Ok(Self::Osmosis)
+        } else if NEUTRON.contains(&chain_name) {
+            Ok(Self::Neutron)
+        } else if KUJIRA.contains(&chain_name) {
+            Ok(Self::Kujira)
+        } else if INJECTIVE.contains(&chain_name) {
+            Ok(Self::Injective)
+        } else {
+            Err(AbstractSdkError::UnsupportedTokenFactory {
+                chain_id: chain_id.to_owned(),
+            })
+        }
+    }
+
+    /// Type url of a token factory message on this flavour.
+    pub fn type_url(&self, msg_name: &str) -> String {
+        let package = match self {
+            Self::Osmosis | Self::Neutron => "osmosis.tokenfactory.v1beta1",
+            Self::Kujira => "kujira.denom",
+            Self::Injective => "injective.tokenfactory.v1beta1",
+        };
+        format!("/{package}.{msg_name}")
+    }
+
+    fn assert_supports(
+        &self,
+        action: &str,
+        supported: &[TokenFactoryFlavour],
+    ) -> AbstractSdkResult<()> {
+        if supported.contains(self) {
+            Ok(())
+        } else {
+            Err(AbstractSdkError::UnsupportedTokenFactoryAction {
+                flavour: format!("{self:?}"),
+                action: action.to_owned(),
+            })
+        }
+    }
+}
+
+/**
+    API for accessing the token factory module of the chain.
+
+    # Example
+    ```
+    use abstract_sdk::prelude::*;
+    # use cosmwasm_std::testing::mock_dependencies;
+    # use abstract_sdk::{mock_module::MockModule, AbstractSdkResult};
+    # use abstract_testing::prelude::*;
+    # let deps = mock_dependencies();
+    # let mut env = mock_env_validated(deps.api);
+    # env.block.chain_id = "osmosis-1".to_owned();
+    # let account = admin_account(deps.api);
+    # let module = MockModule::new(deps.api, account);
+
+    let token_factory: TokenFactory = module.token_factory(deps.as_ref(), &env)?;
+    let denom = token_factory.denom("points");
+    # AbstractSdkResult::Ok(())
+    ```
+*/
+#[derive(Clone)]
+pub struct TokenFactory {
+    account: Addr,
+    flavour: TokenFactoryFlavour,
+}
+
+impl TokenFactory {
+    /// The token factory flavour of the chain.
+    pub fn flavour(&self) -> TokenFactoryFlavour {
+        self.flavour
+    }
+
+    /// Full denom of a subdenom created by the Account.
+    pub fn denom(&self, subdenom: &str) -> String {
+        format!("factory/{}/{subdenom}", self.account)
+    }
+
+    /// Create the `factory/{account}/{subdenom}` denom with the Account as admin.
+    pub fn create_denom(&self, subdenom: impl Into<String>) -> AbstractSdkResult<AccountAction> {
+        let msg = MsgCreateDenom {
+            sender: self.account.to_string(),
+            subdenom: subdenom.into(),
+        };
+        Ok(self.action("MsgCreateDenom", msg))
+    }
+
+    /// Mint tokens to the `recipient`, the Account if `None`.
+    pub fn mint(&self, amount: Coin, recipient: Option<&Addr>) -> AbstractSdkResult<AccountAction> {
+        let msg = MsgMint {
+            sender: self.account.to_string(),
+            amount: Some(convert_coin(amount)),
+            mint_to_address: recipient.unwrap_or(&self.account).to_string(),
+        };
+        Ok(self.action("MsgMint", msg))
+    }
+
+    /// Burn tokens held by `burn_from`, the Account if `None`.
+    /// Burning from another address is not supported on Kujira.
+    pub fn burn(&self, amount: Coin, burn_from: Option<&Addr>) -> AbstractSdkResult<AccountAction> {
+        let burn_from_address = match burn_from {
+            Some(burn_from) if burn_from != &self.account => {
+                self.flavour.assert_supports(
+                    "burn from",
+                    &[
+                        TokenFactoryFlavour::Osmosis,
+                        TokenFactoryFlavour::Neutron,
+                        TokenFactoryFlavour::Injective,
+                    ],
+                )?;
+                burn_from.to_string()
+            }
+            // Kujira has no burn-from field, so only set it where it is known.
+            _ if self.flavour == TokenFactoryFlavour::Kujira => String::new(),
+            _ => self.account.to_string(),
+        };
+        let msg = MsgBurn {
+            sender: self.account.to_string(),
+            amount: Some(convert_coin(amount)),
+            burn_from_address,
+        };
+        Ok(self.action("MsgBurn", msg))
+    }
+
+    /// Transfer the admin rights of `denom` to `new_admin`.
+    pub fn change_admin(
+        &self,
+        denom: impl Into<String>,
+        new_admin: &Addr,
+    ) -> AbstractSdkResult<AccountAction> {
+        let msg = MsgChangeAdmin {
+            sender: self.account.to_string(),
+            denom: denom.into(),
+            new_admin: new_admin.to_string(),
+        };
+        Ok(self.action("MsgChangeAdmin", msg))
+    }
+
+    /// Set the bank metadata of a denom administered by the Account.
+    /// Not supported on Kujira.
+    pub fn set_metadata(&self, metadata: Metadata) -> AbstractSdkResult<AccountAction> {
+        self.flavour.assert_supports(
+            "set metadata",
+            &[
+                TokenFactoryFlavour::Osmosis,
+                TokenFactoryFlavour::Neutron,
+                TokenFactoryFlavour::Injective,
+            ],
+        )?;
+        let msg = MsgSetDenomMetadata {
+            sender: self.account.to_string(),
+            metadata: Some(metadata),
+        };
+        Ok(self.action("MsgSetDenomMetadata", msg))
+    }
+
+    /// Move tokens of a denom administered by the Account from `from` to `to`.
+    /// Only supported on Osmosis and Neutron.
+    pub fn force_transfer(
+        &self,
+        amount: Coin,
+        from: &Addr,
+        to: &Addr,
+    ) -> AbstractSdkResult<AccountAction> {
+        self.flavour.assert_supports(
+            "force transfer",
+            &[TokenFactoryFlavour::Osmosis, TokenFactoryFlavour::Neutron],
+        )?;
+        let msg = MsgForceTransfer {
+            sender: self.account.to_string(),
+            amount: Some(convert_coin(amount)),
+            transfer_from_address: from.to_string(),
+            transfer_to_address: to.to_string(),
+        };
+        Ok(self.action("MsgForceTransfer", msg))
+    }
+
+    fn action(&self, msg_name: &str, msg: impl Message) -> AccountAction {
+        super::stargate_msg(
+            self.flavour.type_url(msg_name),
+            Binary::new(msg.encode_to_vec()),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock_module::*;
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{
+        coin,
+        testing::{mock_dependencies, MockApi},
+        CosmosMsg,
+    };
+
+    fn token_factory(chain_id: &str) -> (TokenFactory, Addr) {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+        let mut env = mock_env_validated(deps.api);
+        env.block.chain_id = chain_id.to_owned();
+        (
+            app.token_factory(deps.as_ref(), &env).unwrap(),
+            account.into_addr(),
+        )
+    }
+
+    fn decode_action<M: Message + Default>(action: AccountAction, expected_type_url: &str) -> M {
+        let msgs = action.messages();
+        #[allow(deprecated)]
+        let CosmosMsg::Stargate { type_url, value } = &msgs[0] else {
+            panic!("expected stargate message");
+        };
+        assert_eq!(type_url, expected_type_url);
+        M::decode(value.as_slice()).unwrap()
+    }
+
+    #[coverage_helper::test]
+    fn flavour_from_chain_id() {
+        for (chain_id, flavour) in [
+            ("osmosis-1", TokenFactoryFlavour::Osmosis),
+            ("osmo-test-5", TokenFactoryFlavour::Osmosis),
+            ("neutron-1", TokenFactoryFlavour::Neutron),
+            ("pion-1", TokenFactoryFlavour::Neutron),
+            ("kaiyo-1", TokenFactoryFlavour::Kujira),
+            ("harpoon-4", TokenFactoryFlavour::Kujira),
+            ("injective-1", TokenFactoryFlavour::Injective),
+            ("injective-888", TokenFactoryFlavour::Injective),
+        ] {
+            assert_eq!(TokenFactoryFlavour::from_chain_id(chain_id), Ok(flavour));
+        }
+        assert_eq!(
+            TokenFactoryFlavour::from_chain_id("juno-1"),
+            Err(AbstractSdkError::UnsupportedTokenFactory {
+                chain_id: "juno-1".to_owned()
+            })
+        );
+    }
+
+    #[coverage_helper::test]
+    fn create_and_mint() {
+        let (token_factory, account) = token_factory("osmosis-1");
+
+        let msg: MsgCreateDenom = decode_action(
+            token_factory.create_denom("points").unwrap(),
+            "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+        );
+        assert_eq!(msg.sender, account.to_string());
+        assert_eq!(msg.subdenom, "points");
+
+        let denom = token_factory.denom("points");
+        assert_eq!(denom, format!("factory/{account}/points"));
+        let msg: MsgMint = decode_action(
+            token_factory.mint(coin(100, &denom), None).unwrap(),
+            "/osmosis.tokenfactory.v1beta1.MsgMint",
+        );
+        assert_eq!(msg.mint_to_address, account.to_string());
+        assert_eq!(msg.amount, Some(convert_coin(coin(100, denom))));
+    }
+
+    #[coverage_helper::test]
+    fn kujira_flavour() {
+        let (token_factory, _) = token_factory("kaiyo-1");
+
+        let msg: MsgBurn = decode_action(
+            token_factory.burn(coin(100, "factory/a/b"), None).unwrap(),
+            "/kujira.denom.MsgBurn",
+        );
+        assert!(msg.burn_from_address.is_empty());
+
+        let other = MockApi::default().addr_make("other");
+        assert_eq!(
+            token_factory
+                .force_transfer(coin(100, "factory/a/b"), &other, &other)
+                .unwrap_err(),
+            AbstractSdkError::UnsupportedTokenFactoryAction {
+                flavour: "Kujira".to_owned(),
+                action: "force transfer".to_owned()
+            }
+        );
+        assert!(token_factory.set_metadata(Metadata::default()).is_err());
+        assert!(token_factory
+            .burn(coin(100, "factory/a/b"), Some(&other))
+            .is_err());
+    }
+
+    #[coverage_helper::test]
+    fn injective_flavour() {
+        let (token_factory, account) = token_factory("injective-1");
+        let new_admin = MockApi::default().addr_make("new_admin");
+
+        let msg: MsgChangeAdmin = decode_action(
+            token_factory
+                .change_admin("factory/a/b", &new_admin)
+                .unwrap(),
+            "/injective.tokenfactory.v1beta1.MsgChangeAdmin",
+        );
+        assert_eq!(msg.sender, account.to_string());
+        assert_eq!(msg.new_admin, new_admin.to_string());
+
+        decode_action::<MsgSetDenomMetadata>(
+            token_factory.set_metadata(Metadata::default()).unwrap(),
+            "/injective.tokenfactory.v1beta1.MsgSetDenomMetadata",
+        );
+        assert!(token_factory
+            .force_transfer(coin(1, "factory/a/b"), &account, &new_admin)
+            .is_err());
+    }
+}
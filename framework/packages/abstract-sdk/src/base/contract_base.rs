@@ -2,8 +2,16 @@ use abstract_std::ibc::{Callback, IbcResult, ModuleIbcInfo};
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, Storage};
 use cw2::{ContractVersion, CONTRACT};
 
-use super::handler::Handler;
-use crate::{std::objects::dependency::StaticDependency, AbstractSdkError, AbstractSdkResult};
+use super::{
+    endpoints::{route_payload, ReplyPayload},
+    handler::Handler,
+    migration::MigrationStep,
+};
+use crate::{
+    apis::module_event::{ModuleEventSchema, ModuleEventSchemaFn},
+    std::objects::dependency::StaticDependency,
+    AbstractSdkError, AbstractSdkResult,
+};
 
 /// ID of the module.
 pub type ModuleId = &'static str;
@@ -24,6 +32,51 @@ pub type ExecuteHandlerFn<Module, CustomExecMsg, Error> =
     fn(DepsMut, Env, MessageInfo, Module, CustomExecMsg) -> Result<Response, Error>;
 // ANCHOR_END: exec
 
+// ANCHOR: middleware
+/// Function signature for an execute middleware that runs before the execute handler.
+/// The messages, attributes and events of the returned response are added to the response of the handler.
+/// Changes to the [`MessageInfo`], like funds taken by the middleware, are seen by the handler.
+pub type BeforeExecuteFn<Module, CustomExecMsg, Error> =
+    fn(DepsMut, &Env, &mut MessageInfo, &Module, &CustomExecMsg) -> Result<Response, Error>;
+
+/// Function signature for an execute middleware that post-processes the response of the execute handler.
+pub type AfterExecuteFn<Error> =
+    fn(DepsMut, &Env, &MessageInfo, Response) -> Result<Response, Error>;
+// ANCHOR_END: middleware
+
+/// Middleware that wraps the execute handler of a contract.
+/// Middleware runs in the order in which it was registered.
+pub struct ExecuteMiddleware<Module, CustomExecMsg, Error> {
+    /// Runs before the handler, can reject the message or attach messages and attributes.
+    pub before: Option<BeforeExecuteFn<Module, CustomExecMsg, Error>>,
+    /// Runs after the handler, can post-process its response.
+    pub after: Option<AfterExecuteFn<Error>>,
+}
+
+impl<Module, CustomExecMsg, Error> ExecuteMiddleware<Module, CustomExecMsg, Error> {
+    /// Middleware that runs before the execute handler.
+    pub const fn before(before: BeforeExecuteFn<Module, CustomExecMsg, Error>) -> Self {
+        Self {
+            before: Some(before),
+            after: None,
+        }
+    }
+
+    /// Middleware that runs after the execute handler.
+    pub const fn after(after: AfterExecuteFn<Error>) -> Self {
+        Self {
+            before: None,
+            after: Some(after),
+        }
+    }
+
+    /// Also post-process the response of the execute handler.
+    pub const fn and_after(mut self, after: AfterExecuteFn<Error>) -> Self {
+        self.after = Some(after);
+        self
+    }
+}
+
 // ANCHOR: query
 /// Function signature for a query handler.
 pub type QueryHandlerFn<Module, CustomQueryMsg, Error> =
@@ -76,16 +129,25 @@ pub struct AbstractContract<Module: Handler + 'static, Error: From<AbstractSdkEr
     /// Handler of execute messages.
     pub(crate) execute_handler:
         Option<ExecuteHandlerFn<Module, <Module as Handler>::CustomExecMsg, Error>>,
+    /// Middleware around the execute handler.
+    pub(crate) execute_middleware:
+        &'static [ExecuteMiddleware<Module, <Module as Handler>::CustomExecMsg, Error>],
     /// Handler of query messages.
     pub(crate) query_handler:
         Option<QueryHandlerFn<Module, <Module as Handler>::CustomQueryMsg, Error>>,
     /// Handler for migrations.
     pub(crate) migrate_handler:
         Option<MigrateHandlerFn<Module, <Module as Handler>::CustomMigrateMsg, Error>>,
+    /// Ordered storage migrations.
+    pub(crate) migrations: &'static [MigrationStep<Module, Error>],
+    /// Events emitted by the module, exported with its schema.
+    pub(crate) events: &'static [ModuleEventSchemaFn],
     /// Handler for sudo messages.
     pub(crate) sudo_handler: Option<SudoHandlerFn<Module, <Module as Handler>::SudoMsg, Error>>,
     /// List of reply handlers per reply ID.
     pub reply_handlers: [&'static [(u64, ReplyHandlerFn<Module, Error>)]; MAX_REPLY_COUNT],
+    /// Reply handler of sub-messages routed on their payload.
+    pub(crate) payload_reply_handler: Option<ReplyHandlerFn<Module, Error>>,
     /// IBC callback handler following an IBC action
     pub(crate) ibc_callback_handler: Option<IbcCallbackHandlerFn<Module, Error>>,
     /// Module IBC handler for passing messages between a module on different chains.
@@ -102,9 +164,13 @@ where
             info: (name, version, metadata),
             ibc_callback_handler: None,
             reply_handlers: [&[], &[]],
+            payload_reply_handler: None,
             dependencies: &[],
             execute_handler: None,
+            execute_middleware: &[],
             migrate_handler: None,
+            migrations: &[],
+            events: &[],
             sudo_handler: None,
             instantiate_handler: None,
             query_handler: None,
@@ -133,6 +199,12 @@ where
         self
     }
 
+    /// Route replies with the [`PAYLOAD_REPLY_ID`](super::PAYLOAD_REPLY_ID) to the handler of their decoded `Payload`.
+    pub const fn with_payload_replies<Payload: ReplyPayload<Module, Error>>(mut self) -> Self {
+        self.payload_reply_handler = Some(route_payload::<Module, Error, Payload>);
+        self
+    }
+
     /// add IBC callback handler to contract
     pub const fn with_ibc_callback(
         mut self,
@@ -173,6 +245,27 @@ where
         self
     }
 
+    /// Add ordered storage migration steps to the contract.
+    /// They are run on migration, before the migrate handler.
+    pub const fn with_migrations(
+        mut self,
+        migrations: &'static [MigrationStep<Module, Error>],
+    ) -> Self {
+        self.migrations = migrations;
+        self
+    }
+
+    /// Register the [`ModuleEvent`](crate::ModuleEvent)s that the contract emits.
+    pub const fn with_events(mut self, events: &'static [ModuleEventSchemaFn]) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Schemas of the events that the contract emits.
+    pub fn events(&self) -> Vec<ModuleEventSchema> {
+        self.events.iter().map(|event| event()).collect()
+    }
+
     /// Add sudo handler to the contract.
     pub const fn with_sudo(
         mut self,
@@ -191,6 +284,19 @@ where
         self
     }
 
+    /// Add execute middleware to the contract, run in order around the execute handler.
+    pub const fn with_execute_middleware(
+        mut self,
+        middleware: &'static [ExecuteMiddleware<
+            Module,
+            <Module as Handler>::CustomExecMsg,
+            Error,
+        >],
+    ) -> Self {
+        self.execute_middleware = middleware;
+        self
+    }
+
     /// Add query handler to the contract.
     pub const fn with_query(
         mut self,
@@ -266,13 +372,17 @@ mod test {
             .with_dependencies(&[]);
 
         assert!(contract.reply_handlers.iter().all(|x| x.is_empty()));
+        assert!(contract.payload_reply_handler.is_none());
 
         assert!(contract.dependencies.is_empty());
         assert!(contract.ibc_callback_handler.is_none());
         assert!(contract.instantiate_handler.is_none());
         assert!(contract.execute_handler.is_none());
+        assert!(contract.execute_middleware.is_empty());
         assert!(contract.query_handler.is_none());
         assert!(contract.migrate_handler.is_none());
+        assert!(contract.migrations.is_empty());
+        assert!(contract.events.is_empty());
     }
 
     #[coverage_helper::test]
@@ -287,6 +397,23 @@ mod test {
         assert_eq!(contract.dependencies[0].clone(), DEPENDENCY);
     }
 
+    #[coverage_helper::test]
+    fn test_with_events() {
+        #[derive(schemars::JsonSchema, abstract_macros::ModuleEvent)]
+        #[module_event(name = "tested", version = 2)]
+        struct TestedEvent {
+            value: u64,
+        }
+
+        let contract = MockAppContract::new("test_contract", "0.1.0", ModuleMetadata::default())
+            .with_events(&[ModuleEventSchema::of::<TestedEvent>]);
+
+        let events = contract.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, "tested");
+        assert_eq!(events[0].version, 2);
+    }
+
     #[coverage_helper::test]
     fn test_with_instantiate() {
         let contract = MockAppContract::new("test_contract", "0.1.0", ModuleMetadata::default())
@@ -341,6 +468,31 @@ mod test {
         assert!(contract.reply_handlers[1].is_empty());
     }
 
+    #[coverage_helper::test]
+    fn test_with_payload_replies() {
+        #[cosmwasm_schema::cw_serde]
+        enum MockPayload {
+            Step {},
+        }
+
+        impl ReplyPayload<MockModule, MockError> for MockPayload {
+            fn handle(
+                self,
+                _deps: DepsMut,
+                _env: Env,
+                _module: MockModule,
+                _reply: Reply,
+            ) -> Result<Response, MockError> {
+                Ok(Response::default())
+            }
+        }
+
+        let contract = MockAppContract::new("test_contract", "0.1.0", ModuleMetadata::default())
+            .with_payload_replies::<MockPayload>();
+
+        assert!(contract.payload_reply_handler.is_some());
+    }
+
     #[coverage_helper::test]
     fn test_with_ibc_callback_handlers() {
         const HANDLER: IbcCallbackHandlerFn<MockModule, MockError> =
@@ -118,6 +118,7 @@
 //!
 //! ## Reply
 //! The reply endpoint is used to handle internal replies. Each reply handler is matched with a reply-id. Both are supplied to the contract builder.
+//! Alternatively, sub-messages sent with the [`PAYLOAD_REPLY_ID`] are routed on their typed [`ReplyPayload`], supplied with `with_payload_replies`.
 //!
 //! ## Sudo
 //! The sudo endpoint can only be called by the chain's governance address.
@@ -138,5 +139,6 @@ pub use instantiate::InstantiateEndpoint;
 pub use migrate::MigrateEndpoint;
 pub use modules_ibc::ModuleIbcEndpoint;
 pub use query::QueryEndpoint;
-pub use reply::ReplyEndpoint;
+pub(crate) use reply::route_payload;
+pub use reply::{ReplyEndpoint, ReplyPayload, PAYLOAD_REPLY_ID};
 pub use sudo::SudoEndpoint;
@@ -1,13 +1,68 @@
-use cosmwasm_std::{DepsMut, Env, Reply, Response};
+use cosmwasm_std::{from_json, DepsMut, Env, Reply, Response};
+use serde::de::DeserializeOwned;
 
-use crate::base::Handler;
+use crate::{base::Handler, AbstractSdkError};
+
+/// Reply id of sub-messages that are routed on their payload instead of their id.
+/// Sub-messages with this id are handled by the [`ReplyPayload`] of the contract.
+pub const PAYLOAD_REPLY_ID: u64 = u64::MAX;
+
+/// Typed payload of sub-messages with the [`PAYLOAD_REPLY_ID`].
+///
+/// The payload is decoded from [`Reply::payload`] and handles the reply, which lets a module carry
+/// context across sub-messages without coordinating reply ids or stashing it in storage.
+/// Registered on the contract with `with_payload_replies::<Payload>()`.
+/// ```ignore
+/// #[cosmwasm_schema::cw_serde]
+/// pub enum MyPayload {
+///     Swapped { deposit_into: String },
+/// }
+///
+/// impl ReplyPayload<MyApp, MyAppError> for MyPayload {
+///     fn handle(self, deps: DepsMut, env: Env, app: MyApp, reply: Reply) -> MyAppResult {
+///         match self {
+///             MyPayload::Swapped { deposit_into } => deposit_swapped(deps, env, app, reply, deposit_into),
+///         }
+///     }
+/// }
+/// ```
+pub trait ReplyPayload<Module, Error>: DeserializeOwned {
+    /// Handle the reply of the sub-message that carried this payload.
+    fn handle(
+        self,
+        deps: DepsMut,
+        env: Env,
+        module: Module,
+        reply: Reply,
+    ) -> Result<Response, Error>;
+}
+
+/// Decode the payload of the reply and pass it to its handler.
+pub(crate) fn route_payload<Module, Error, Payload>(
+    deps: DepsMut,
+    env: Env,
+    module: Module,
+    reply: Reply,
+) -> Result<Response, Error>
+where
+    Error: From<AbstractSdkError>,
+    Payload: ReplyPayload<Module, Error>,
+{
+    let payload: Payload = from_json(&reply.payload).map_err(AbstractSdkError::from)?;
+    payload.handle(deps, env, module, reply)
+}
 
 /// Trait for a contract's Reply entry point.
 pub trait ReplyEndpoint: Handler {
     /// Handler for the Reply endpoint.
+    /// Replies with the [`PAYLOAD_REPLY_ID`] are routed on their payload, others on their id.
     fn reply(self, deps: DepsMut, env: Env, msg: Reply) -> Result<Response, Self::Error> {
         let id = msg.id;
-        let handler = self.reply_handler(id)?;
+        let handler = if id == PAYLOAD_REPLY_ID {
+            self.payload_reply_handler()?
+        } else {
+            self.reply_handler(id)?
+        };
         handler(deps, env, self, msg)
     }
 }
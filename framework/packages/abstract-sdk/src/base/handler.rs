@@ -1,6 +1,7 @@
 use abstract_std::objects::dependency::StaticDependency;
-use cosmwasm_std::Storage;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, Storage};
 use cw2::{ContractVersion, CONTRACT};
+use semver::Version;
 
 use super::contract_base::{
     AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, InstantiateHandlerFn,
@@ -9,6 +10,7 @@ use super::contract_base::{
 use crate::{
     base::{
         contract_base::{ModuleId, ModuleMetadata, VersionString},
+        migration::{pending_migrations, MigrationStep},
         ReplyHandlerFn,
     },
     AbstractSdkError, AbstractSdkResult,
@@ -70,6 +72,40 @@ where
         Ok(handler)
     }
 
+    /// Run the execute handler on the custom message, wrapped in the execute middleware of the contract.
+    fn execute_with_middleware(
+        self,
+        mut deps: DepsMut,
+        env: Env,
+        mut info: MessageInfo,
+        msg: Self::CustomExecMsg,
+    ) -> Result<Response, Self::Error> {
+        let handler = self.execute_handler()?;
+        let middleware = self.contract().execute_middleware;
+
+        let mut before = Response::new();
+        for before_fn in middleware.iter().filter_map(|m| m.before) {
+            let response = before_fn(deps.branch(), &env, &mut info, &self, &msg)?;
+            before.messages.extend(response.messages);
+            before.attributes.extend(response.attributes);
+            before.events.extend(response.events);
+        }
+
+        let mut response = handler(deps.branch(), env.clone(), info.clone(), self, msg)?;
+        // Messages of the middleware are executed before those of the handler
+        before.messages.append(&mut response.messages);
+        response.messages = before.messages;
+        before.attributes.append(&mut response.attributes);
+        response.attributes = before.attributes;
+        before.events.append(&mut response.events);
+        response.events = before.events;
+
+        for after_fn in middleware.iter().filter_map(|m| m.after) {
+            response = after_fn(deps.branch(), &env, &info, response)?;
+        }
+        Ok(response)
+    }
+
     /// Get a instantiate handler if it exists.
     fn maybe_instantiate_handler(
         &self,
@@ -142,6 +178,29 @@ where
         Ok(handler)
     }
 
+    /// Get the ordered storage migrations of the contract.
+    fn migrations(&self) -> &'static [MigrationStep<Self, Self::Error>] {
+        let contract = self.contract();
+        contract.migrations
+    }
+    /// Run the storage migrations from version `from` to the version of the contract.
+    /// Returns the `(from, to)` versions of the steps that were run.
+    fn run_migrations(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        from: &Version,
+    ) -> Result<Vec<(VersionString, VersionString)>, Self::Error> {
+        let (_, version, _) = self.info();
+        let to: Version = version.parse().map_err(|e: semver::Error| {
+            AbstractSdkError::from(StdError::generic_err(e.to_string()))
+        })?;
+        let steps = pending_migrations(self.migrations(), from, &to)?;
+        for step in &steps {
+            (step.migrate)(deps.branch(), env, self)?;
+        }
+        Ok(steps.iter().map(|step| (step.from, step.to)).collect())
+    }
     /// Get an ibc callback handler if it exists.
     fn maybe_ibc_callback_handler(&self) -> Option<IbcCallbackHandlerFn<Self, Self::Error>> {
         let contract = self.contract();
@@ -164,6 +223,20 @@ where
         }
         None
     }
+    /// Get the payload reply handler if it exists.
+    fn maybe_payload_reply_handler(&self) -> Option<ReplyHandlerFn<Self, Self::Error>> {
+        let contract = self.contract();
+        contract.payload_reply_handler
+    }
+    /// Get the payload reply handler or return an error.
+    fn payload_reply_handler(&self) -> AbstractSdkResult<ReplyHandlerFn<Self, Self::Error>> {
+        let Some(handler) = self.maybe_payload_reply_handler() else {
+            return Err(AbstractSdkError::MissingHandler {
+                endpoint: "reply with payload".to_string(),
+            });
+        };
+        Ok(handler)
+    }
     /// Get a reply handler or return an error.
     fn reply_handler(&self, id: u64) -> AbstractSdkResult<ReplyHandlerFn<Self, Self::Error>> {
         let Some(handler) = self.maybe_reply_handler(id) else {
@@ -0,0 +1,153 @@
+use cosmwasm_std::{DepsMut, Env};
+use semver::Version;
+
+use super::{contract_base::VersionString, Handler};
+use crate::{AbstractSdkError, AbstractSdkResult};
+
+// ANCHOR: migration
+/// Function signature of a storage migration step.
+pub type MigrationFn<Module, Error> = fn(DepsMut, &Env, &Module) -> Result<(), Error>;
+// ANCHOR_END: migration
+
+/// Storage migration of a module from the storage layout of version `from` to that of version `to`.
+///
+/// Steps are registered in order with `with_migrations` and every step whose `to` version lies
+/// between the stored and the new version of the module is run on migration.
+pub struct MigrationStep<Module, Error> {
+    /// Version of the storage layout the step migrates from.
+    pub from: &'static str,
+    /// Version of the storage layout the step migrates to.
+    pub to: &'static str,
+    /// Rewrites the storage.
+    pub migrate: MigrationFn<Module, Error>,
+}
+
+impl<Module, Error> MigrationStep<Module, Error> {
+    /// Create a migration step.
+    pub const fn new(
+        from: &'static str,
+        to: &'static str,
+        migrate: MigrationFn<Module, Error>,
+    ) -> Self {
+        Self { from, to, migrate }
+    }
+
+    fn versions(&self) -> AbstractSdkResult<(Version, Version)> {
+        let parse = |version: &str| {
+            version
+                .parse::<Version>()
+                .map_err(|e| self.invalid(e.to_string()))
+        };
+        Ok((parse(self.from)?, parse(self.to)?))
+    }
+
+    fn invalid(&self, reason: impl Into<String>) -> AbstractSdkError {
+        AbstractSdkError::InvalidMigrationStep {
+            from: self.from.to_owned(),
+            to: self.to.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Select the steps that migrate the storage from version `from` to version `to`.
+///
+/// Errors if the steps are not ordered, a step doesn't upgrade or a step overlaps with the previous one.
+pub(crate) fn pending_migrations<'a, Module, Error>(
+    steps: &'a [MigrationStep<Module, Error>],
+    from: &Version,
+    to: &Version,
+) -> AbstractSdkResult<Vec<&'a MigrationStep<Module, Error>>> {
+    let mut previous_to: Option<Version> = None;
+    let mut pending = vec![];
+    for step in steps {
+        let (step_from, step_to) = step.versions()?;
+        if step_from >= step_to {
+            return Err(step.invalid("a step must upgrade the version"));
+        }
+        if previous_to.is_some_and(|previous_to| step_from < previous_to) {
+            return Err(step.invalid("steps must be ordered and not overlap"));
+        }
+        if &step_to > from && &step_to <= to {
+            pending.push(step);
+        }
+        previous_to = Some(step_to);
+    }
+    Ok(pending)
+}
+
+/// Test harness that replays all migration steps of `module` against a `fixture` of its storage.
+///
+/// The `fixture` writes the storage as it was at the `from` version of the first step,
+/// after which every step up to the version of the module is run.
+/// Returns the `(from, to)` versions of the steps that were run.
+pub fn replay_migrations<Module: Handler>(
+    module: &Module,
+    mut deps: DepsMut,
+    env: &Env,
+    fixture: impl FnOnce(DepsMut),
+) -> Result<Vec<(VersionString, VersionString)>, Module::Error> {
+    let Some(first) = module.migrations().first() else {
+        return Ok(vec![]);
+    };
+    let (from, _) = first.versions()?;
+    fixture(deps.branch());
+    module.run_migrations(deps, env, &from)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Step = MigrationStep<(), AbstractSdkError>;
+
+    fn noop(_: DepsMut, _: &Env, _: &()) -> Result<(), AbstractSdkError> {
+        Ok(())
+    }
+
+    const STEPS: &[Step] = &[
+        Step::new("0.1.0", "0.2.0", noop),
+        Step::new("0.2.0", "0.3.0", noop),
+        Step::new("0.4.0", "1.0.0", noop),
+    ];
+
+    fn pending(steps: &[Step], from: &str, to: &str) -> AbstractSdkResult<Vec<(&str, &str)>> {
+        pending_migrations(steps, &from.parse().unwrap(), &to.parse().unwrap())
+            .map(|steps| steps.iter().map(|step| (step.from, step.to)).collect())
+    }
+
+    #[coverage_helper::test]
+    fn runs_steps_in_range() {
+        assert_eq!(
+            pending(STEPS, "0.1.0", "1.0.0").unwrap(),
+            vec![("0.1.0", "0.2.0"), ("0.2.0", "0.3.0"), ("0.4.0", "1.0.0")]
+        );
+        // Patch releases keep the storage layout of their minor release
+        assert_eq!(
+            pending(STEPS, "0.2.1", "0.3.5").unwrap(),
+            vec![("0.2.0", "0.3.0")]
+        );
+        assert_eq!(pending(STEPS, "0.3.0", "0.4.0").unwrap(), vec![]);
+    }
+
+    #[coverage_helper::test]
+    fn invalid_steps() {
+        let err = pending(&[Step::new("0.2.0", "0.1.0", noop)], "0.1.0", "0.2.0").unwrap_err();
+        assert_eq!(
+            err,
+            AbstractSdkError::InvalidMigrationStep {
+                from: "0.2.0".to_owned(),
+                to: "0.1.0".to_owned(),
+                reason: "a step must upgrade the version".to_owned()
+            }
+        );
+
+        let overlapping = [
+            Step::new("0.1.0", "0.3.0", noop),
+            Step::new("0.2.0", "0.4.0", noop),
+        ];
+        assert!(pending(&overlapping, "0.1.0", "0.4.0").is_err());
+
+        assert!(pending(&[Step::new("one", "0.1.0", noop)], "0.1.0", "0.2.0").is_err());
+    }
+}
@@ -6,14 +6,17 @@ mod contract_base;
 mod endpoints;
 pub(crate) mod features;
 mod handler;
+mod migration;
 
 pub use contract_base::{
-    AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, InstantiateHandlerFn,
-    MigrateHandlerFn, ModuleIbcHandlerFn, ModuleId, ModuleMetadata, QueryHandlerFn, ReplyHandlerFn,
-    SudoHandlerFn, VersionString,
+    AbstractContract, AfterExecuteFn, BeforeExecuteFn, ExecuteHandlerFn, ExecuteMiddleware,
+    IbcCallbackHandlerFn, InstantiateHandlerFn, MigrateHandlerFn, ModuleIbcHandlerFn, ModuleId,
+    ModuleMetadata, QueryHandlerFn, ReplyHandlerFn, SudoHandlerFn, VersionString,
 };
 pub use endpoints::{
     CustomExecuteHandler, ExecuteEndpoint, IbcCallbackEndpoint, InstantiateEndpoint,
-    MigrateEndpoint, ModuleIbcEndpoint, QueryEndpoint, ReplyEndpoint, SudoEndpoint,
+    MigrateEndpoint, ModuleIbcEndpoint, QueryEndpoint, ReplyEndpoint, ReplyPayload, SudoEndpoint,
+    PAYLOAD_REPLY_ID,
 };
 pub use handler::Handler;
+pub use migration::{replay_migrations, MigrationFn, MigrationStep};
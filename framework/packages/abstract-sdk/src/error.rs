@@ -89,6 +89,32 @@ pub enum AbstractSdkError {
         "Only the admin can execute this action. An admin is either the owner of an account of an account called by its owner"
     )]
     OnlyAdmin {},
+
+    #[error("Token factory of chain {chain_id} is not supported")]
+    UnsupportedTokenFactory { chain_id: String },
+
+    #[error("{action} is not supported by the {flavour} token factory")]
+    UnsupportedTokenFactoryAction { flavour: String, action: String },
+
+    #[error("{sender} exceeded the rate limit of {max_calls} calls per {window} seconds")]
+    RateLimited {
+        sender: Addr,
+        max_calls: u32,
+        window: u64,
+    },
+
+    #[error("Call fee of {fee} not paid")]
+    CallFeeNotPaid { fee: cosmwasm_std::Coin },
+
+    #[error("Sender {sender} is not allowed")]
+    SenderNotAllowed { sender: Addr },
+
+    #[error("Invalid migration step from {from} to {to}: {reason}")]
+    InvalidMigrationStep {
+        from: String,
+        to: String,
+        reason: String,
+    },
 }
 
 impl AbstractSdkError {
@@ -18,20 +18,25 @@ pub mod base;
 pub mod cw_helpers;
 mod error;
 pub mod feature_objects;
+pub mod middleware;
 pub mod prelude;
 
 pub use account_action::AccountAction;
 pub use error::{AbstractSdkError, EndpointError};
 
+pub use crate::apis::splitter::{
+    PayoutRecipient, PayoutRemainder, PayoutShare, Splitter, SplitterInterface, SplitterPayout,
+};
 #[cfg(feature = "stargate")]
-pub use crate::apis::{authz::*, distribution::*, feegrant::*};
+pub use crate::apis::{authz::*, distribution::*, feegrant::*, gov::*, token_factory::*};
 pub use crate::{
     apis::{
-        adapter::*, app::*, bank::*, execution::*, ibc::*, ibc_memo::*, modules::*, respond::*,
-        verify::*, version_registry::*,
+        adapter::*, app::*, bank::*, execution::*, ibc::*, ibc_memo::*, module_client::*,
+        module_event::*, modules::*, respond::*, verify::*, version_registry::*,
     },
     features::AbstractNameServiceClient,
 };
+pub use abstract_macros::{ModuleEvent, ModuleExecuteClient, ModuleQueryClient};
 
 pub mod features {
     //! # Feature traits
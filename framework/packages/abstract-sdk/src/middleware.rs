@@ -0,0 +1,178 @@
+//! # Execute middleware
+//! Standard [`ExecuteMiddleware`]s for modules, registered with `with_execute_middleware`.
+//!
+//! The middleware is configured in storage, usually from the instantiate handler of the module.
+//! Middleware without configuration lets every message through, as does the
+//! [`sender_allow_list`] while no sender is allowed.
+//!
+//! The middleware runs in order and a call rejected by any of them reverts the storage writes of
+//! the others.
+//! ```ignore
+//! const MIDDLEWARE: &[ExecuteMiddleware<MyApp, MyExecuteMsg, MyError>] =
+//!     &[sender_allow_list::<MyApp>(), rate_limit::<MyApp>()];
+//!
+//! const APP: MyApp = MyApp::new(MY_APP_ID, MY_APP_VERSION, None)
+//!     .with_execute(handlers::execute_handler)
+//!     .with_execute_middleware(MIDDLEWARE);
+//! ```
+
+use abstract_std::objects::storage_namespaces::middleware as namespaces;
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, DepsMut, Empty, Env, MessageInfo, Response, StdResult, Storage,
+};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    base::{ExecuteMiddleware, Handler},
+    AbstractSdkError, AbstractSdkResult,
+};
+
+/// Execute middleware of a module.
+pub type ModuleMiddleware<Module> =
+    ExecuteMiddleware<Module, <Module as Handler>::CustomExecMsg, <Module as Handler>::Error>;
+
+/// Maximum number of calls a sender can make per window.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RateLimit {
+    /// Calls allowed per window
+    pub max_calls: u32,
+    /// Length of the window in seconds
+    pub window: u64,
+}
+
+/// Rate limit of the [`rate_limit`] middleware.
+pub const RATE_LIMIT: Item<RateLimit> = Item::new(namespaces::RATE_LIMIT);
+/// Start of the current window and the calls made in it, per sender.
+pub const RATE_LIMIT_USAGE: Map<&Addr, (u64, u32)> = Map::new(namespaces::RATE_LIMIT_USAGE);
+
+/// Fee charged by the [`call_fee`] middleware on every call.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CallFee {
+    /// Fee that must be sent with every call
+    pub fee: Coin,
+    /// Receiver of the fee
+    pub recipient: Addr,
+}
+
+/// Fee of the [`call_fee`] middleware.
+pub const CALL_FEE: Item<CallFee> = Item::new(namespaces::CALL_FEE);
+
+/// Senders allowed by the [`sender_allow_list`] middleware.
+pub const ALLOWED_SENDERS: Map<&Addr, Empty> = Map::new(namespaces::ALLOWED_SENDERS);
+
+/// Limit the number of calls per sender to the [`RATE_LIMIT`].
+pub const fn rate_limit<Module: Handler>() -> ModuleMiddleware<Module> {
+    ExecuteMiddleware::before(rate_limit_before::<Module>)
+}
+
+/// Charge the [`CALL_FEE`] from the funds sent with every call and forward it to its recipient.
+/// The execute handler receives the sent funds without the fee.
+pub const fn call_fee<Module: Handler>() -> ModuleMiddleware<Module> {
+    ExecuteMiddleware::before(call_fee_before::<Module>)
+}
+
+/// Only allow calls from the [`ALLOWED_SENDERS`]. Every sender is allowed while the list is empty.
+pub const fn sender_allow_list<Module: Handler>() -> ModuleMiddleware<Module> {
+    ExecuteMiddleware::before(sender_allow_list_before::<Module>)
+}
+
+/// Add or remove a sender of the [`sender_allow_list`] middleware.
+pub fn update_allowed_senders(
+    storage: &mut dyn Storage,
+    to_add: &[Addr],
+    to_remove: &[Addr],
+) -> StdResult<()> {
+    for sender in to_add {
+        ALLOWED_SENDERS.save(storage, sender, &Empty {})?;
+    }
+    for sender in to_remove {
+        ALLOWED_SENDERS.remove(storage, sender);
+    }
+    Ok(())
+}
+
+fn rate_limit_before<Module: Handler>(
+    deps: DepsMut,
+    env: &Env,
+    info: &mut MessageInfo,
+    _module: &Module,
+    _msg: &Module::CustomExecMsg,
+) -> Result<Response, Module::Error> {
+    assert_rate_limit(deps, env, &info.sender).map_err(Into::into)
+}
+
+fn call_fee_before<Module: Handler>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &mut MessageInfo,
+    _module: &Module,
+    _msg: &Module::CustomExecMsg,
+) -> Result<Response, Module::Error> {
+    charge_call_fee(deps, info).map_err(Into::into)
+}
+
+fn sender_allow_list_before<Module: Handler>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &mut MessageInfo,
+    _module: &Module,
+    _msg: &Module::CustomExecMsg,
+) -> Result<Response, Module::Error> {
+    assert_allowed_sender(deps.storage, &info.sender).map_err(Into::into)
+}
+
+fn assert_rate_limit(deps: DepsMut, env: &Env, sender: &Addr) -> AbstractSdkResult<Response> {
+    let Some(limit) = RATE_LIMIT.may_load(deps.storage)? else {
+        return Ok(Response::new());
+    };
+    let now = env.block.time.seconds();
+    let (window_start, calls) = RATE_LIMIT_USAGE
+        .may_load(deps.storage, sender)?
+        .filter(|(window_start, _)| now < window_start.saturating_add(limit.window))
+        .unwrap_or((now, 0));
+    if calls >= limit.max_calls {
+        return Err(AbstractSdkError::RateLimited {
+            sender: sender.clone(),
+            max_calls: limit.max_calls,
+            window: limit.window,
+        });
+    }
+    RATE_LIMIT_USAGE.save(deps.storage, sender, &(window_start, calls + 1))?;
+    Ok(Response::new())
+}
+
+fn charge_call_fee(deps: DepsMut, info: &mut MessageInfo) -> AbstractSdkResult<Response> {
+    let Some(CallFee { fee, recipient }) = CALL_FEE.may_load(deps.storage)? else {
+        return Ok(Response::new());
+    };
+    let Some(index) = info
+        .funds
+        .iter()
+        .position(|coin| coin.denom == fee.denom && coin.amount >= fee.amount)
+    else {
+        return Err(AbstractSdkError::CallFeeNotPaid { fee });
+    };
+    // The handler only receives the funds that are left after the fee
+    let paid = &mut info.funds[index];
+    paid.amount -= fee.amount;
+    if paid.amount.is_zero() {
+        info.funds.remove(index);
+    }
+    Ok(Response::new()
+        .add_attribute("call_fee", fee.to_string())
+        .add_message(BankMsg::Send {
+            to_address: recipient.into_string(),
+            amount: vec![fee],
+        }))
+}
+
+fn assert_allowed_sender(storage: &dyn Storage, sender: &Addr) -> AbstractSdkResult<Response> {
+    if !ALLOWED_SENDERS.is_empty(storage) && !ALLOWED_SENDERS.has(storage, sender) {
+        return Err(AbstractSdkError::SenderNotAllowed {
+            sender: sender.clone(),
+        });
+    }
+    Ok(Response::new())
+}
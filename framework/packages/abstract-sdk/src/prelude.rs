@@ -8,12 +8,15 @@
 //! use abstract_sdk::prelude::*;
 //! ```
 
+pub use crate::apis::splitter::{
+    PayoutRecipient, PayoutRemainder, PayoutShare, Splitter, SplitterInterface, SplitterPayout,
+};
 #[cfg(feature = "stargate")]
-pub use crate::apis::{distribution::*, stargate::feegrant::*};
+pub use crate::apis::{distribution::*, gov::*, stargate::feegrant::*, token_factory::*};
 pub use crate::{
     ans_resolve::Resolve,
     apis::{
-        adapter::*, app::*, bank::*, execution::*, ibc::*, modules::*, respond::*, verify::*,
-        version_registry::*,
+        adapter::*, app::*, bank::*, execution::*, ibc::*, module_client::*, module_event::*,
+        modules::*, respond::*, verify::*, version_registry::*,
     },
 };
@@ -112,6 +112,13 @@ pub enum AdapterBaseMsg {
         to_add: Vec<String>,
         to_remove: Vec<String>,
     },
+    /// Restrict an authorized address (or module id) to a subset of the adapter's request actions.
+    /// Setting `scopes` to `None` lifts the restriction, allowing all requests again.
+    /// Removing the address from the authorized addresses also removes its scopes.
+    UpdateAuthorizedScopes {
+        address: String,
+        scopes: Option<Vec<String>>,
+    },
 }
 
 /// Query adapter message
@@ -124,6 +131,17 @@ pub enum BaseQueryMsg {
     /// Returns [`AuthorizedAddressesResponse`].
     #[returns(AuthorizedAddressesResponse)]
     AuthorizedAddresses { account_address: String },
+    /// Returns the request actions an authorized address of the account is limited to.
+    /// Returns [`AuthorizedScopesResponse`].
+    #[returns(AuthorizedScopesResponse)]
+    AuthorizedScopes {
+        account_address: String,
+        address: String,
+    },
+    /// Returns the request actions declared by the adapter that authorized addresses can be scoped to.
+    /// Returns [`RequestActionsResponse`].
+    #[returns(RequestActionsResponse)]
+    RequestActions {},
     /// Returns module data
     /// Returns [`ModuleDataResponse`].
     #[returns(ModuleDataResponse)]
@@ -149,6 +167,18 @@ pub struct AuthorizedAddressesResponse {
     pub addresses: Vec<Addr>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct AuthorizedScopesResponse {
+    /// Request actions the address is allowed to perform, `None` if it is not restricted.
+    pub scopes: Option<Vec<String>>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct RequestActionsResponse {
+    /// Request actions declared by the adapter
+    pub actions: Vec<String>,
+}
+
 /// The BaseState contains the main addresses needed for sending and verifying messages
 /// Every DApp should use the provided **ans_host** contract for token/contract address resolution.
 #[cosmwasm_schema::cw_serde]
@@ -55,7 +55,14 @@ pub struct BaseInstantiateMsg {
 
 #[cosmwasm_schema::cw_serde]
 #[derive(cw_orch::ExecuteFns)]
-pub enum BaseExecuteMsg {}
+pub enum BaseExecuteMsg {
+    /// Admin method: Pause or resume the app.
+    /// While paused, only the module messages allowed by the app are executed.
+    SetPaused {
+        paused: bool,
+        reason: Option<String>,
+    },
+}
 
 impl<T> From<BaseExecuteMsg> for ExecuteMsg<T> {
     fn from(base: BaseExecuteMsg) -> Self {
@@ -81,6 +88,10 @@ pub enum BaseQueryMsg {
     /// Returns [`TopLevelOwnerResponse`]
     #[returns(TopLevelOwnerResponse)]
     TopLevelOwner {},
+    /// Returns whether the app is paused
+    /// Returns [`PauseStatusResponse`]
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
 }
 
 impl<T> From<BaseQueryMsg> for QueryMsg<T> {
@@ -96,6 +107,17 @@ pub struct AppConfigResponse {
     pub registry_address: Addr,
 }
 
+/// Pause status of an app
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct PauseStatus {
+    pub paused: bool,
+    /// Reason given by the admin when pausing
+    pub reason: Option<String>,
+}
+
+pub type PauseStatusResponse = PauseStatus;
+
 #[cosmwasm_schema::cw_serde]
 pub struct BaseMigrateMsg {}
 
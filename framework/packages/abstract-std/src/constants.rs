@@ -49,6 +49,12 @@ pub mod neutron {
     pub const NEUTRON: &[&str] = &[NEUTRON_MAINNET, NEUTRON_TESTNET];
 }
 
+pub mod injective {
+    pub const INJECTIVE_MAINNET: &str = "injective";
+    // The testnet chain-id is `injective-888`
+    pub const INJECTIVE: &[&str] = &[INJECTIVE_MAINNET];
+}
+
 pub mod archway {
     pub const ARCHWAY_MAINNET: &str = "archway";
     pub const ARCHWAY_TESTNET: &str = "constantine";
@@ -71,6 +77,7 @@ pub mod local {
 }
 
 pub use archway::ARCHWAY;
+pub use injective::INJECTIVE;
 pub use juno::JUNO;
 pub use kujira::KUJIRA;
 pub use local::LOCAL_CHAIN;
@@ -2,21 +2,23 @@ pub mod ibc_client;
 pub mod ibc_host;
 pub mod ica_client;
 pub mod polytone_callbacks;
+pub mod remote_query;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, wasm_execute, Binary, CosmosMsg, Empty, Event, QueryRequest, StdError,
-    StdResult,
+    from_json, to_json_binary, wasm_execute, Binary, CosmosMsg, Empty, Event, QueryRequest,
+    StdError, StdResult,
 };
 use cw_storage_plus::PrimaryKey;
 use schemars::JsonSchema;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     base::ExecuteMsg,
     objects::{module::ModuleInfo, TruncatedChainId},
 };
 use polytone_callbacks::{Callback as PolytoneCallback, ErrorResponse, ExecutionResponse};
+use remote_query::{RemoteAccountQuery, RemoteAccountQueryResponse};
 
 pub const PACKET_LIFETIME: u64 = 60 * 60;
 
@@ -143,6 +145,43 @@ impl IbcResult {
         }
     }
 
+    /// Get query result at `index` decoded into `T`
+    pub fn query_response<T: DeserializeOwned>(&self, index: usize) -> StdResult<T> {
+        let (_, result) = self.get_query_result(index)?;
+        from_json(result)
+    }
+
+    /// Get typed results of `queries` that were sent with [`RemoteAccountQuery::into_query_request`].
+    /// The queries are carried by the [`RemoteAccountQueryCallback`](remote_query::RemoteAccountQueryCallback) message.
+    pub fn remote_account_responses(
+        &self,
+        queries: &[RemoteAccountQuery],
+    ) -> StdResult<Vec<RemoteAccountQueryResponse>> {
+        match &self {
+            IbcResult::Query { results, .. } => {
+                let results = results
+                    .as_ref()
+                    .map_err(|err| StdError::generic_err(err.error.clone()))?;
+                if results.len() != queries.len() {
+                    return Err(StdError::generic_err(format!(
+                        "expected {} remote account query results, got {}",
+                        queries.len(),
+                        results.len()
+                    )));
+                }
+                queries
+                    .iter()
+                    .zip(results)
+                    .map(|(query, result)| query.decode_response(result))
+                    .collect()
+            }
+            IbcResult::Execute { .. } => Err(StdError::generic_err(
+                "expected query, got execute ibc result",
+            )),
+            IbcResult::FatalError(err) => Err(StdError::generic_err(err.to_owned())),
+        }
+    }
+
     /// Get execute result
     pub fn get_execute_events(&self) -> StdResult<Vec<Event>> {
         match &self {
@@ -180,6 +219,8 @@ pub struct ModuleIbcInfo {
     pub chain: TruncatedChainId,
     /// Information about the module that called ibc action on this module
     pub module: ModuleInfo,
+    /// Sequence of the message if it was sent over an ordered module channel
+    pub sequence: Option<u64>,
 }
 // ANCHOR_END: module_ibc_msg
 
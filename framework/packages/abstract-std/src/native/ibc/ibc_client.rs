@@ -21,9 +21,9 @@ pub mod state {
     use cw_storage_plus::{Item, Map};
 
     use crate::{
-        ibc::ICS20PacketIdentifier,
+        ibc::{Callback, ICS20PacketIdentifier},
         objects::{
-            account::{AccountSequence, AccountTrace},
+            account::{AccountId, AccountSequence, AccountTrace},
             storage_namespaces, TruncatedChainId,
         },
     };
@@ -62,6 +62,58 @@ pub mod state {
     pub const ACCOUNTS: Map<(&AccountTrace, AccountSequence, &TruncatedChainId), String> =
         Map::new(storage_namespaces::ibc_client::ACCOUNTS);
 
+    /// Remote-account records of a renamed chain that still have to be moved to the new chain name
+    #[cosmwasm_schema::cw_serde]
+    pub struct AccountsMigration {
+        /// New name of the chain
+        pub to_chain: TruncatedChainId,
+        /// Last record key that got processed
+        pub cursor: Option<(AccountTrace, AccountSequence, TruncatedChainId)>,
+    }
+
+    /// old chain name -> pending migration of its remote-account records
+    pub const ACCOUNTS_MIGRATIONS: Map<&TruncatedChainId, AccountsMigration> =
+        Map::new(storage_namespaces::ibc_client::ACCOUNTS_MIGRATIONS);
+
+    /// Where the result of a multi-hop route is delivered
+    #[cosmwasm_schema::cw_serde]
+    pub enum MultihopOrigin {
+        /// The route started on this chain, `callback` is sent to `sender` with the result
+        Local {
+            sender: Addr,
+            /// Account that started the route, remote accounts registered by the route are stored for it
+            account_id: Option<AccountId>,
+            callback: Option<Callback>,
+            initiator_msg: Binary,
+        },
+        /// The route got forwarded by the ibc-client of `chain`, the result is sent back to it
+        Remote {
+            chain: TruncatedChainId,
+            route_id: u64,
+        },
+    }
+
+    /// Multi-hop route that is waiting for the result of its final hop
+    #[cosmwasm_schema::cw_serde]
+    pub struct MultihopRoute {
+        /// Chain the route got forwarded to, only its ibc-client can send the result back
+        pub next_chain: TruncatedChainId,
+        pub origin: MultihopOrigin,
+    }
+
+    /// route id -> pending multi-hop route
+    pub const MULTIHOP_ROUTES: Map<u64, MultihopRoute> =
+        Map::new(storage_namespaces::ibc_client::MULTIHOP_ROUTES);
+    /// Last assigned multi-hop route id
+    pub const MULTIHOP_ROUTE_SEQUENCE: Item<u64> =
+        Item::new(storage_namespaces::ibc_client::MULTIHOP_ROUTE_SEQUENCE);
+
+    /// (source module, host chain, target module id) -> last sequence sent over the ordered module channel.
+    /// The source module key is built with [`module_ibc_source_key`](crate::ibc_host::module_ibc_source_key),
+    /// like the ibc-host does, so both sides track the same channel.
+    pub const MODULE_IBC_SEQUENCES: Map<(&str, &TruncatedChainId, &str), u64> =
+        Map::new(storage_namespaces::ibc_client::MODULE_IBC_SEQUENCES);
+
     // For callbacks tests
     pub const ACKS: Item<Vec<String>> = Item::new(storage_namespaces::ibc_client::ACKS);
     pub const ICS20_ACCOUNT_CALLBACKS: Map<ICS20PacketIdentifier, (Addr, Coin, Vec<Binary>)> =
@@ -139,8 +191,25 @@ pub enum ExecuteMsg {
         /// Callback info to identify the callback that is sent (acts similar to the reply ID)
         callback: Option<Callback>,
     },
-    /// Only callable by Account Module
     // ANCHOR_END: module-ibc-action
+    /// Only callable by Account Module
+    /// Same as [`ExecuteMsg::ModuleIbcAction`] but the message is delivered in order and exactly once.
+    /// The ibc-host skips sequences that got processed already and rejects sequences that skip a message.
+    OrderedModuleIbcAction {
+        /// host chain to be executed on
+        /// Example: "osmosis"
+        host_chain: TruncatedChainId,
+        /// Module of this account on host chain
+        target_module: ModuleInfo,
+        /// Json-encoded IbcMsg to the target module
+        msg: Binary,
+        /// Re-send the message of an already sent sequence, for example after a timeout.
+        /// A new sequence is assigned if `None`.
+        resend_sequence: Option<u64>,
+        /// Callback info to identify the callback that is sent (acts similar to the reply ID)
+        callback: Option<Callback>,
+    },
+    /// Only callable by Account Module
     IbcQuery {
         /// host chain to be executed on
         /// Example: "osmosis"
@@ -160,6 +229,86 @@ pub enum ExecuteMsg {
         /// execute the custom host function
         action: HostAction,
     },
+    /// Only callable by Account
+    /// Register an Account on a chain that is multiple hops away.
+    /// The message is routed through the ibc-clients of the intermediate chains.
+    /// Example: `path = ["juno", "osmosis"]` creates an Account on osmosis, owned by this Account's remote Account on juno.
+    /// Note: this Account's remote Account on the second to last chain must exist
+    RegisterMultihop {
+        /// Chains to route through, the last chain is the one the Account is created on
+        path: Vec<TruncatedChainId>,
+        namespace: Option<String>,
+        install_modules: Vec<ModuleInstallConfig>,
+        /// Callback info that is sent to the Account once the final hop got acknowledged
+        callback: Option<Callback>,
+    },
+    /// Only callable by Account
+    /// Action on the remote ibc host of the last chain of `path`.
+    /// The action is routed through the ibc-clients of the intermediate chains.
+    /// Note: this Account's remote Account on the second to last chain must exist
+    MultihopRemoteAction {
+        /// Chains to route through, the last chain is the one the action is executed on
+        path: Vec<TruncatedChainId>,
+        /// execute the custom host function
+        action: HostAction,
+        /// Callback info that is sent to the Account once the final hop got acknowledged
+        callback: Option<Callback>,
+    },
+    /// Only callable by Account Module
+    /// Same as [`ExecuteMsg::ModuleIbcAction`] but the target module is on the last chain of `path`.
+    /// The message is routed through the ibc-clients of the intermediate chains,
+    /// the callback is sent once the final hop got acknowledged.
+    MultihopModuleIbcAction {
+        /// Chains to route through, the last chain is the one the target module is on
+        path: Vec<TruncatedChainId>,
+        /// Module of this account on the last chain of the path
+        target_module: ModuleInfo,
+        /// Json-encoded IbcMsg to the target module
+        msg: Binary,
+        /// Callback info to identify the callback that is sent (acts similar to the reply ID)
+        callback: Option<Callback>,
+    },
+    /// Only callable by the local ibc-host
+    /// Packet of a multi-hop route, relayed by the ibc-host from the ibc-client of `src_chain`
+    ReceiveMultihop {
+        src_chain: TruncatedChainId,
+        packet: MultihopPacket,
+    },
+    /// Only callable by Account
+    /// Will attempt to forward the specified funds to the `receiver` on the last chain of `path`
+    /// using a packet-forward-middleware memo.
+    /// This Account's remote Account on the first chain of the path must exist, it is used as the receiver of the first hop.
+    /// Note: funds of a failed forward are refunded back along the path to the sender of the transfer,
+    /// which is the ibc-client and not the Account.
+    SendFundsMultihop {
+        /// Chains to route through, the last chain is the one the funds are received on
+        path: Vec<TruncatedChainId>,
+        /// ICS20 channels used to forward the funds between the chains of the path.
+        /// `forward_channels[i]` is the channel on `path[i]` that leads to `path[i + 1]`
+        forward_channels: Vec<String>,
+        /// Address of the token receiver on the last chain of the path
+        receiver: String,
+    },
+    /// Owner method: Re-point the infrastructure of a chain to a new polytone note and/or remote host.
+    /// When `new_chain` is set, the chain is renamed and its remote-account records
+    /// have to be moved with [`ExecuteMsg::MigrateRemoteAccounts`] afterwards.
+    UpdateInfrastructure {
+        /// Chain that is currently registered
+        chain: TruncatedChainId,
+        /// New name of the chain, if it changed its chain-id
+        new_chain: Option<TruncatedChainId>,
+        /// New polytone note (locally deployed)
+        note: Option<String>,
+        /// New address of the abstract host deployed on the remote chain
+        host: Option<String>,
+    },
+    /// Owner method: Move the remote-account records of a renamed chain to its new name.
+    /// Scans at most `limit` records per call, call it until the migration is no longer listed.
+    MigrateRemoteAccounts {
+        /// Former name of the chain
+        chain: TruncatedChainId,
+        limit: Option<u32>,
+    },
     /// Owner method: Remove connection for remote chain
     RemoveHost { host_chain: TruncatedChainId },
     /// Callback from the Polytone implementation
@@ -196,6 +345,57 @@ pub enum PolytoneNoteExecuteMsg {
     },
 }
 
+/// Action executed on the last chain of a multi-hop route
+#[cosmwasm_schema::cw_serde]
+pub enum MultihopAction {
+    /// Register an Account
+    Register {
+        namespace: Option<String>,
+        install_modules: Vec<ModuleInstallConfig>,
+    },
+    /// Action on the remote ibc host
+    HostAction(HostAction),
+    /// Module-to-module message from the module that started the route
+    ModuleIbc {
+        source_module: ModuleInfo,
+        target_module: ModuleInfo,
+        msg: Binary,
+    },
+}
+
+/// Packet sent between the ibc-clients of a multi-hop route, through the ibc-host of the receiving chain
+#[cosmwasm_schema::cw_serde]
+pub enum MultihopPacket {
+    /// Forward `action` to the next chain of `path`
+    Route {
+        /// Id of the route on the sending chain, the result is sent back with it
+        route_id: u64,
+        /// Remaining chains of the route, the action is executed on the last one
+        path: Vec<TruncatedChainId>,
+        /// Account that started the route, the receiving ibc-host pushes the sending chain to its trace.
+        /// `None` for adapters, as they are not bound to an Account
+        account_id: Option<AccountId>,
+        action: MultihopAction,
+    },
+    /// Result of the final hop, sent back along the route
+    Result {
+        /// Id of the route on the receiving chain
+        route_id: u64,
+        result: polytone_callbacks::Callback,
+        /// Account that got registered by the final hop
+        registered_account: Option<MultihopRegisteredAccount>,
+    },
+}
+
+/// Remote account registered by the final hop of a multi-hop route
+#[cosmwasm_schema::cw_serde]
+pub struct MultihopRegisteredAccount {
+    /// Last chain of the route, where the account got registered
+    pub chain: TruncatedChainId,
+    /// Address of the registered account
+    pub address: String,
+}
+
 /// This enum is used for sending callbacks to the note contract of the IBC client
 #[cosmwasm_schema::cw_serde]
 pub enum IbcClientCallback {
@@ -212,6 +412,16 @@ pub enum IbcClientCallback {
     CreateAccount {
         account_id: AccountId,
     },
+    /// Acknowledgement of a multi-hop route being forwarded to the next ibc-client
+    MultihopRoute {
+        route_id: u64,
+    },
+    /// Acknowledgement of the final hop of a multi-hop route
+    MultihopFinalHop {
+        route_id: u64,
+        /// Account that got registered by the final hop
+        registered_account: Option<AccountId>,
+    },
     WhoAmI {},
 }
 
@@ -342,6 +552,22 @@ pub enum QueryMsg {
     /// Returns [`ListIbcInfrastructureResponse`]
     #[returns(ListIbcInfrastructureResponse)]
     ListIbcInfrastructures {},
+
+    /// Get the pending remote-account migrations of renamed chains
+    /// Returns [`ListAccountsMigrationsResponse`]
+    #[returns(ListAccountsMigrationsResponse)]
+    ListAccountsMigrations {},
+
+    /// Get the last sequence sent by a module over the ordered module channel to a remote module
+    /// Returns [`ModuleIbcSequenceResponse`]
+    #[returns(ModuleIbcSequenceResponse)]
+    ModuleIbcSequence {
+        /// The sending module
+        source_module: InstalledModuleIdentification,
+        host_chain: TruncatedChainId,
+        /// Id of the module on the host chain
+        target_module: String,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -378,6 +604,17 @@ pub struct ListIbcInfrastructureResponse {
     pub counterparts: Vec<(TruncatedChainId, IbcInfrastructure)>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct ListAccountsMigrationsResponse {
+    pub migrations: Vec<(TruncatedChainId, state::AccountsMigration)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleIbcSequenceResponse {
+    /// Last sent sequence, 0 if nothing got sent yet
+    pub last_sent: u64,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct HostResponse {
     pub remote_host: String,
@@ -1,9 +1,9 @@
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Coin};
 
 use crate::{
     account::{self, ModuleInstallConfig},
-    ibc_client::InstalledModuleIdentification,
+    ibc_client::{InstalledModuleIdentification, MultihopPacket},
     objects::{account::AccountId, module::ModuleInfo, TruncatedChainId},
 };
 
@@ -19,6 +19,15 @@ pub mod state {
     pub const REVERSE_CHAIN_PROXIES: Map<&Addr, TruncatedChainId> =
         Map::new(storage_namespaces::ibc_host::REVERSE_CHAIN_PROXIES);
 
+    /// Maps a renamed client chain to the chain name used in the traces of its accounts
+    pub const CLIENT_CHAIN_TRACES: Map<&TruncatedChainId, TruncatedChainId> =
+        Map::new(storage_namespaces::ibc_host::CLIENT_CHAIN_TRACES);
+
+    /// (client chain, source module, target module id) -> last sequence processed over the ordered module channel.
+    /// The source module key is built with [`module_ibc_source_key`](super::module_ibc_source_key).
+    pub const MODULE_IBC_SEQUENCES: Map<(&TruncatedChainId, &str, &str), u64> =
+        Map::new(storage_namespaces::ibc_host::MODULE_IBC_SEQUENCES);
+
     // Temporary structure to hold actions to be executed after account creation
     pub const TEMP_ACTION_AFTER_CREATION: Item<ActionAfterCreationCache> =
         Item::new(storage_namespaces::ibc_host::TEMP_ACTION_AFTER_CREATION);
@@ -30,6 +39,28 @@ pub mod state {
         pub action: HostAction,
         pub chain_name: TruncatedChainId,
     }
+
+    /// Quotas enforced on the actions received from a client chain
+    pub const CHAIN_QUOTAS: Map<&TruncatedChainId, ChainQuota> =
+        Map::new(storage_namespaces::ibc_host::CHAIN_QUOTAS);
+    /// Usage of the quota in the current window of a client chain
+    pub const CHAIN_QUOTA_USAGE: Map<&TruncatedChainId, QuotaUsage> =
+        Map::new(storage_namespaces::ibc_host::CHAIN_QUOTA_USAGE);
+    /// Client chains that are paused by the circuit-breaker
+    pub const PAUSED_CHAINS: Map<&TruncatedChainId, String> =
+        Map::new(storage_namespaces::ibc_host::PAUSED_CHAINS);
+
+    // Temporary structure to track the funds that leave an account while its messages are dispatched
+    pub const TEMP_OUTFLOW_CHECK: Item<OutflowCheckCache> =
+        Item::new(storage_namespaces::ibc_host::TEMP_OUTFLOW_CHECK);
+
+    #[cosmwasm_schema::cw_serde]
+    pub struct OutflowCheckCache {
+        pub chain_name: TruncatedChainId,
+        pub account: Addr,
+        /// Amount of dispatched messages that didn't reply yet
+        pub pending_replies: u32,
+    }
 }
 /// Used by Abstract to instantiate the contract
 /// The contract is then registered on the registry contract using [`crate::registry::ExecuteMsg::ProposeModules`].
@@ -74,6 +105,30 @@ pub enum HostAction {
 }
 // ANCHOR_END: ibc-host-action
 
+/// Quotas on the actions that accounts of a client chain can do on this chain.
+/// Quotas are tracked in fixed windows of `window_blocks` blocks.
+#[cosmwasm_schema::cw_serde]
+pub struct ChainQuota {
+    /// Length of a quota window in blocks
+    pub window_blocks: u64,
+    /// Maximum amount of messages dispatched to accounts or modules per window
+    pub max_msgs: Option<u32>,
+    /// Maximum amount of each denom that can leave the remote accounts per window, sent or spent like
+    /// staking delegations
+    pub max_outflows: Vec<Coin>,
+}
+
+/// Quota usage of a client chain in the current window
+#[cosmwasm_schema::cw_serde]
+pub struct QuotaUsage {
+    /// Height at which the current window started
+    pub window_start: u64,
+    /// Messages dispatched in the current window
+    pub msgs: u32,
+    /// Funds that left the remote accounts in the current window
+    pub outflows: Vec<Coin>,
+}
+
 /// Interface to the Host.
 #[cosmwasm_schema::cw_serde]
 #[derive(cw_orch::ExecuteFns)]
@@ -105,12 +160,38 @@ pub enum ExecuteMsg {
         source_module: InstalledModuleIdentification,
         target_module: ModuleInfo,
         msg: Binary,
+        /// Sequence of the message if it's sent over an ordered module channel
+        sequence: Option<u64>,
+    },
+    /// Relays a packet of a multi-hop route to the local ibc-client
+    Multihop {
+        packet: MultihopPacket,
     },
     /// Sends the associated funds to the local account corresponding to the source account id
     Fund {
         src_account: AccountId,
         src_chain: TruncatedChainId,
     },
+    /// Owner method: Set or remove (with `None`) the quota of a client chain
+    SetChainQuota {
+        chain: TruncatedChainId,
+        quota: Option<ChainQuota>,
+    },
+    /// Owner method: Circuit-breaker that stops accepting actions from a client chain
+    PauseChain {
+        chain: TruncatedChainId,
+        reason: String,
+    },
+    /// Owner method: Resume accepting actions from a paused client chain
+    UnpauseChain {
+        chain: TruncatedChainId,
+    },
+    /// Owner method: Rename a client chain, for example after a chain-id change.
+    /// Accounts created by the client chain keep the old chain name in their trace.
+    RenameClientChain {
+        chain: TruncatedChainId,
+        new_chain: TruncatedChainId,
+    },
 }
 
 /// Query Host message
@@ -141,6 +222,33 @@ pub enum QueryMsg {
         target_module: InstalledModuleIdentification,
         msg: Binary,
     },
+    /// Returns the quota, its usage and the pause status of a client chain.
+    /// Returns [`ChainQuotaResponse`].
+    #[returns(ChainQuotaResponse)]
+    ChainQuota { chain: TruncatedChainId },
+    /// Lists the client chains that have a quota.
+    /// Returns [`ChainQuotasResponse`].
+    #[returns(ChainQuotasResponse)]
+    ChainQuotas {
+        start_after: Option<TruncatedChainId>,
+        limit: Option<u32>,
+    },
+    /// Lists the client chains paused by the circuit-breaker and the reason.
+    /// Returns [`PausedChainsResponse`].
+    #[returns(PausedChainsResponse)]
+    PausedChains {
+        start_after: Option<TruncatedChainId>,
+        limit: Option<u32>,
+    },
+    /// Returns the last sequence processed over the ordered module channel between two modules.
+    /// Returns [`ModuleIbcSequenceResponse`].
+    #[returns(ModuleIbcSequenceResponse)]
+    ModuleIbcSequence {
+        src_chain: TruncatedChainId,
+        source_module: InstalledModuleIdentification,
+        /// Id of the local module
+        target_module: String,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -159,3 +267,36 @@ pub struct ClientProxiesResponse {
 pub struct ClientProxyResponse {
     pub proxy: Addr,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct ChainQuotaResponse {
+    pub quota: Option<ChainQuota>,
+    /// Usage of the current window, if any
+    pub usage: Option<QuotaUsage>,
+    /// Reason of the pause if the chain is paused
+    pub paused: Option<String>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ChainQuotasResponse {
+    pub quotas: Vec<(TruncatedChainId, ChainQuota)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PausedChainsResponse {
+    pub chains: Vec<(TruncatedChainId, String)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleIbcSequenceResponse {
+    /// Last processed sequence, 0 if nothing got processed yet
+    pub last_processed: u64,
+}
+
+/// Key of the sending module of an ordered module channel
+pub fn module_ibc_source_key(source_module: &InstalledModuleIdentification) -> String {
+    match &source_module.account_id {
+        Some(account_id) => format!("{}:{account_id}", source_module.module_info.id()),
+        None => source_module.module_info.id(),
+    }
+}
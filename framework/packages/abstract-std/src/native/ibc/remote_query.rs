@@ -0,0 +1,227 @@
+//! Typed reads of a remote Account that are sent through the ibc-client with [`crate::ibc_client::ExecuteMsg::IbcQuery`].
+//!
+//! Build the request with [`RemoteAccountQuery::into_query_request`] and decode the results of the
+//! [`IbcResult::Query`](super::IbcResult::Query) callback with [`super::IbcResult::remote_account_responses`].
+//! The kinds of the queries are sent along in the callback message as a [`RemoteAccountQueryCallback`].
+
+use cosmwasm_std::{
+    from_json, to_json_binary, AllBalanceResponse, BalanceResponse, BankQuery, Binary, Coin,
+    QueryRequest, StdError, StdResult, WasmQuery,
+};
+
+use super::{Callback, ModuleQuery};
+use crate::{
+    account::{self, InfoResponse, ModuleInfosResponse},
+    objects::ownership::Ownership,
+};
+
+/// Common queries of a remote Account
+#[cosmwasm_schema::cw_serde]
+pub enum RemoteAccountQuery {
+    /// Installed modules of the remote Account
+    /// Returns [`ModuleInfosResponse`]
+    ModuleInfos {
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    /// Balances of all denoms held by the remote Account
+    /// Returns [`Vec<Coin>`]
+    Balances {},
+    /// Balance of a single denom held by the remote Account
+    /// Returns [`Coin`]
+    Balance { denom: String },
+    /// Ownership of the remote Account
+    /// Returns [`Ownership<String>`]
+    Ownership {},
+    /// Info of the remote Account
+    /// Returns [`InfoResponse`]
+    Info {},
+}
+
+impl RemoteAccountQuery {
+    /// Build the query request for the remote Account at `remote_account_addr`
+    pub fn into_query_request(
+        self,
+        remote_account_addr: impl Into<String>,
+    ) -> StdResult<QueryRequest<ModuleQuery>> {
+        let address = remote_account_addr.into();
+        let account_query = |msg: &account::QueryMsg| -> StdResult<QueryRequest<ModuleQuery>> {
+            Ok(QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: address.clone(),
+                msg: to_json_binary(msg)?,
+            }))
+        };
+        match self {
+            RemoteAccountQuery::ModuleInfos { start_after, limit } => {
+                account_query(&account::QueryMsg::ModuleInfos { start_after, limit })
+            }
+            #[allow(deprecated)]
+            RemoteAccountQuery::Balances {} => Ok(QueryRequest::Bank(BankQuery::AllBalances {
+                address: address.clone(),
+            })),
+            RemoteAccountQuery::Balance { denom } => Ok(QueryRequest::Bank(BankQuery::Balance {
+                address: address.clone(),
+                denom,
+            })),
+            RemoteAccountQuery::Ownership {} => account_query(&account::QueryMsg::Ownership {}),
+            RemoteAccountQuery::Info {} => account_query(&account::QueryMsg::Info {}),
+        }
+    }
+
+    /// Decode the raw result of this query
+    pub fn decode_response(&self, result: &Binary) -> StdResult<RemoteAccountQueryResponse> {
+        let response = match self {
+            RemoteAccountQuery::ModuleInfos { .. } => {
+                RemoteAccountQueryResponse::ModuleInfos(from_json(result)?)
+            }
+            RemoteAccountQuery::Balances {} => {
+                let response: AllBalanceResponse = from_json(result)?;
+                RemoteAccountQueryResponse::Balances(response.amount)
+            }
+            RemoteAccountQuery::Balance { .. } => {
+                let response: BalanceResponse = from_json(result)?;
+                RemoteAccountQueryResponse::Balance(response.amount)
+            }
+            RemoteAccountQuery::Ownership {} => {
+                RemoteAccountQueryResponse::Ownership(from_json(result)?)
+            }
+            RemoteAccountQuery::Info {} => RemoteAccountQueryResponse::Info(from_json(result)?),
+        };
+        Ok(response)
+    }
+}
+
+/// Callback message of [`RemoteAccountQuery`]s.
+/// Holds the kind of every query, in order, so their results can be decoded.
+#[cosmwasm_schema::cw_serde]
+pub struct RemoteAccountQueryCallback {
+    /// Queries sent to the remote Account
+    pub queries: Vec<RemoteAccountQuery>,
+    /// Callback message of the sender
+    pub msg: Binary,
+}
+
+impl RemoteAccountQueryCallback {
+    /// Wrap the `callback` of the sender together with the sent `queries`
+    pub fn new(queries: Vec<RemoteAccountQuery>, callback: Callback) -> Self {
+        Self {
+            queries,
+            msg: callback.msg,
+        }
+    }
+
+    /// Recover the callback message sent with [`RemoteAccountQueryCallback::new`]
+    pub fn from_callback(callback: &Callback) -> StdResult<Self> {
+        from_json(&callback.msg)
+    }
+
+    /// Callback of the sender
+    pub fn callback(&self) -> Callback {
+        Callback {
+            msg: self.msg.clone(),
+        }
+    }
+}
+
+/// Typed result of a [`RemoteAccountQuery`]
+#[cosmwasm_schema::cw_serde]
+pub enum RemoteAccountQueryResponse {
+    ModuleInfos(ModuleInfosResponse),
+    Balances(Vec<Coin>),
+    Balance(Coin),
+    Ownership(Ownership<String>),
+    Info(InfoResponse),
+}
+
+impl RemoteAccountQueryResponse {
+    /// Get the module infos, errors if this is a response to another query
+    pub fn module_infos(self) -> StdResult<ModuleInfosResponse> {
+        match self {
+            RemoteAccountQueryResponse::ModuleInfos(r) => Ok(r),
+            other => Err(unexpected_response("module infos", other)),
+        }
+    }
+
+    /// Get the balances, errors if this is a response to another query
+    pub fn balances(self) -> StdResult<Vec<Coin>> {
+        match self {
+            RemoteAccountQueryResponse::Balances(r) => Ok(r),
+            other => Err(unexpected_response("balances", other)),
+        }
+    }
+
+    /// Get the balance, errors if this is a response to another query
+    pub fn balance(self) -> StdResult<Coin> {
+        match self {
+            RemoteAccountQueryResponse::Balance(r) => Ok(r),
+            other => Err(unexpected_response("balance", other)),
+        }
+    }
+
+    /// Get the ownership, errors if this is a response to another query
+    pub fn ownership(self) -> StdResult<Ownership<String>> {
+        match self {
+            RemoteAccountQueryResponse::Ownership(r) => Ok(r),
+            other => Err(unexpected_response("ownership", other)),
+        }
+    }
+
+    /// Get the account info, errors if this is a response to another query
+    pub fn info(self) -> StdResult<InfoResponse> {
+        match self {
+            RemoteAccountQueryResponse::Info(r) => Ok(r),
+            other => Err(unexpected_response("info", other)),
+        }
+    }
+}
+
+fn unexpected_response(expected: &str, actual: RemoteAccountQueryResponse) -> StdError {
+    StdError::generic_err(format!(
+        "expected {expected} remote query response, got {actual:?}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, to_json_binary};
+
+    use super::*;
+
+    #[coverage_helper::test]
+    fn callback_round_trip() {
+        let queries = vec![
+            RemoteAccountQuery::ModuleInfos {
+                start_after: None,
+                limit: Some(5),
+            },
+            RemoteAccountQuery::Balances {},
+            RemoteAccountQuery::Balance {
+                denom: "ujuno".to_owned(),
+            },
+            RemoteAccountQuery::Ownership {},
+            RemoteAccountQuery::Info {},
+        ];
+        let sender_callback = Callback::new(&"sender_msg").unwrap();
+
+        let callback = Callback::new(&RemoteAccountQueryCallback::new(
+            queries.clone(),
+            sender_callback.clone(),
+        ))
+        .unwrap();
+        let decoded = RemoteAccountQueryCallback::from_callback(&callback).unwrap();
+        assert_eq!(decoded.queries, queries);
+        assert_eq!(decoded.callback(), sender_callback);
+    }
+
+    #[coverage_helper::test]
+    fn decode_balances() {
+        let balances = coins(42, "ujuno");
+        let raw = to_json_binary(&AllBalanceResponse::new(balances.clone())).unwrap();
+
+        let response = RemoteAccountQuery::Balances {}
+            .decode_response(&raw)
+            .unwrap();
+        assert_eq!(response.clone().balances().unwrap(), balances);
+        assert!(response.module_infos().is_err());
+    }
+}
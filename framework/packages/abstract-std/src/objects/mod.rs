@@ -51,7 +51,10 @@ pub mod account {
     mod account_id;
     mod account_trace;
 
-    pub use self::{account_id::AccountId, account_trace::AccountTrace};
+    pub use self::{
+        account_id::AccountId,
+        account_trace::{AccountTrace, MAX_TRACE_LENGTH},
+    };
 
     pub const ABSTRACT_ACCOUNT_ID: AccountId = AccountId::const_new(0, AccountTrace::Local);
 
@@ -104,7 +104,7 @@ pub fn assert_contract_upgrade(
         }
     );
 
-    let from_version = from_version.parse().unwrap();
+    let from_version: Version = from_version.parse()?;
 
     // Must be a version upgrade
     ensure!(
@@ -8,6 +8,8 @@ pub const OWNERSHIP_STORAGE_KEY: &str = "ownership";
 pub const MODULE_STORAGE_KEY: &str = "mod";
 /// Storage key for config in all modules
 pub const CONFIG_STORAGE_KEY: &str = "cfg";
+/// Storage key for the pause status of an app
+pub const PAUSE_STATUS: &str = "pause";
 
 pub mod account {
     pub const SUSPENSION_STATUS: &str = "aa";
@@ -60,12 +62,29 @@ pub mod ibc_client {
     pub const ACKS: &str = "ed";
     pub const ICS20_ACCOUNT_CALLBACKS: &str = "ee";
     pub const ICS20_ACCOUNT_CALLBACK_PAYLOAD: &str = "ef";
+    pub const ACCOUNTS_MIGRATIONS: &str = "eg";
+    pub const MODULE_IBC_SEQUENCES: &str = "eh";
+    pub const MULTIHOP_ROUTES: &str = "ei";
+    pub const MULTIHOP_ROUTE_SEQUENCE: &str = "ej";
 }
 
 pub mod ibc_host {
     pub const CHAIN_PROXIES: &str = "fa";
     pub const REVERSE_CHAIN_PROXIES: &str = "fb";
     pub const TEMP_ACTION_AFTER_CREATION: &str = "fc";
+    pub const CHAIN_QUOTAS: &str = "fd";
+    pub const CHAIN_QUOTA_USAGE: &str = "fe";
+    pub const PAUSED_CHAINS: &str = "ff";
+    pub const TEMP_OUTFLOW_CHECK: &str = "fg";
+    pub const CLIENT_CHAIN_TRACES: &str = "fh";
+    pub const MODULE_IBC_SEQUENCES: &str = "fi";
 }
 
 pub mod ica_client {}
+
+pub mod middleware {
+    pub const RATE_LIMIT: &str = "ga";
+    pub const RATE_LIMIT_USAGE: &str = "gb";
+    pub const CALL_FEE: &str = "gc";
+    pub const ALLOWED_SENDERS: &str = "gd";
+}
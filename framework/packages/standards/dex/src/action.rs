@@ -2,7 +2,7 @@
 //! # Dex Adapter Raw Action Definition
 
 use abstract_std::objects::pool_id::UncheckedPoolAddress;
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Uint128};
 use cw_asset::{AssetBase, AssetInfoBase};
 
 use crate::msg::SwapNode;
@@ -39,6 +39,9 @@ pub enum DexAction {
         max_spread: Option<Decimal>,
         /// The belief price when submitting the transaction.
         belief_price: Option<Decimal>,
+        /// Minimum amount of the ask asset the account must receive, checked by the adapter after the swap.
+        #[serde(default)]
+        min_receive: Option<Uint128>,
     },
     /// Swap by route between multiple assets
     RouteSwap {
@@ -50,5 +53,73 @@ pub enum DexAction {
         max_spread: Option<Decimal>,
         /// The belief price when submitting the transaction.
         belief_price: Option<Decimal>,
+        /// Minimum amount of the ask asset the account must receive, checked by the adapter after the swap.
+        #[serde(default)]
+        min_receive: Option<Uint128>,
+    },
+    /// Place a limit order on the order book of the DEX
+    PlaceLimitOrder {
+        /// Pool (order book) to place the order on
+        pool: UncheckedPoolAddress,
+        /// The asset to offer
+        offer_asset: AssetBase<String>,
+        /// The asset to receive
+        ask_asset: AssetInfoBase<String>,
+        /// Amount of ask asset to receive per unit of the offer asset
+        price: Decimal,
+    },
+    /// Cancel an open order, returning the unfilled part of the offer asset
+    CancelOrder {
+        /// Pool (order book) the order was placed on
+        pool: UncheckedPoolAddress,
+        /// Id of the order on the DEX
+        order_id: String,
+    },
+    /// Withdraw the filled part of an order
+    WithdrawFilledOrder {
+        /// Pool (order book) the order was placed on
+        pool: UncheckedPoolAddress,
+        /// Id of the order on the DEX
+        order_id: String,
+    },
+    /// Create a concentrated liquidity position in a price range
+    CreatePosition {
+        /// Pool to create the position in
+        pool: UncheckedPoolAddress,
+        /// Lower tick of the price range
+        lower_tick: i64,
+        /// Upper tick of the price range
+        upper_tick: i64,
+        /// Assets to add to the position
+        assets: Vec<AssetBase<String>>,
+        /// Minimum amounts of the assets that must be added, assets that aren't listed have no minimum
+        min_assets: Vec<AssetBase<String>>,
+    },
+    /// Add liquidity to an existing concentrated liquidity position
+    AddToPosition {
+        /// Pool of the position
+        pool: UncheckedPoolAddress,
+        /// Id of the position on the DEX
+        position_id: u64,
+        /// Assets to add to the position
+        assets: Vec<AssetBase<String>>,
+        /// Minimum amounts of the assets that must be added, assets that aren't listed have no minimum
+        min_assets: Vec<AssetBase<String>>,
+    },
+    /// Withdraw liquidity from a concentrated liquidity position
+    WithdrawPosition {
+        /// Pool of the position
+        pool: UncheckedPoolAddress,
+        /// Id of the position on the DEX
+        position_id: u64,
+        /// Share of the position liquidity to withdraw, between 0 and 1
+        share: Decimal,
+    },
+    /// Collect the spread fees and incentives of concentrated liquidity positions
+    CollectPositionRewards {
+        /// Pool of the positions
+        pool: UncheckedPoolAddress,
+        /// Ids of the positions on the DEX
+        position_ids: Vec<u64>,
     },
 }
@@ -6,7 +6,7 @@ use abstract_std::objects::{
     ans_host::AnsHostError, AnsAsset, AnsEntryConvertor, AssetEntry, DexAssetPairing, PoolAddress,
     PoolReference,
 };
-use cosmwasm_std::{Decimal, StdError};
+use cosmwasm_std::{Decimal, StdError, Uint128};
 use cw_asset::Asset;
 
 use crate::{
@@ -40,6 +40,9 @@ pub enum DexAnsAction {
         max_spread: Option<Decimal>,
         /// The belief price when submitting the transaction.
         belief_price: Option<Decimal>,
+        /// Minimum amount of the ask asset the account must receive, checked by the adapter after the swap.
+        #[serde(default)]
+        min_receive: Option<Uint128>,
     },
 }
 /// Structure created to be able to resolve an action using ANS
@@ -111,6 +114,7 @@ impl Resolve for WholeDexAction {
                 mut ask_asset,
                 max_spread,
                 belief_price,
+                min_receive,
             } => {
                 let AnsAsset {
                     name: mut offer_asset,
@@ -138,6 +142,7 @@ impl Resolve for WholeDexAction {
                         ask_asset: ask_asset_info.into(),
                         max_spread,
                         belief_price,
+                        min_receive,
                     },
                 })
             }
@@ -238,6 +243,7 @@ mod ans_resolve_interface {
                     mut ask_asset,
                     max_spread,
                     belief_price,
+                    min_receive,
                 } => {
                     let AnsAsset {
                         name: mut offer_asset,
@@ -261,6 +267,7 @@ mod ans_resolve_interface {
                             ask_asset: ask_asset_info.into(),
                             max_spread,
                             belief_price,
+                            min_receive,
                         },
                     })
                 }
@@ -0,0 +1,19 @@
+//! Typed client of the dex adapter, for modules that depend on it.
+
+use abstract_sdk::{AdapterKind, ClientModule};
+
+pub use crate::msg::{DexExecuteMsgClient, DexQueryMsgClient};
+use crate::{
+    msg::{DexExecuteMsg, DexQueryMsg},
+    DEX_ADAPTER_ID,
+};
+
+/// The dex adapter, called with `module.adapter::<DexAdapter>(deps)`.
+pub struct DexAdapter;
+
+impl ClientModule for DexAdapter {
+    const MODULE_ID: &'static str = DEX_ADAPTER_ID;
+    type Kind = AdapterKind;
+    type ExecuteMsg = DexExecuteMsg;
+    type QueryMsg = DexQueryMsg;
+}
@@ -1,10 +1,13 @@
 use abstract_adapter_utils::identity::Identify;
 use abstract_sdk::feature_objects::{AnsHost, RegistryContract};
 use abstract_std::objects::{AssetEntry, DexAssetPairing, PoolAddress, PoolReference};
-use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, Decimal256, Deps, Env, Uint128};
 use cw_asset::{Asset, AssetInfo};
 
-use crate::{error::DexError, msg::SwapNode};
+use crate::{
+    error::DexError,
+    msg::{LiquidityPosition, Order, PriceKind, SwapNode},
+};
 
 pub type Return = Uint128;
 pub type Spread = Uint128;
@@ -94,6 +97,133 @@ pub trait DexCommand: Identify {
         ask_asset: AssetInfo,
     ) -> Result<(Return, Spread, Fee, FeeOnInput), DexError>;
 
+    /// Place a limit order on the order book of the DEX.
+    /// The `price` is the amount of ask asset to receive per unit of the offer asset.
+    fn place_limit_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _offer_asset: Asset,
+        _ask_asset: AssetInfo,
+        _price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Cancel an open order on the DEX
+    fn cancel_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Withdraw the filled part of an order on the DEX
+    fn withdraw_filled_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Query the orders placed by `account` on the DEX
+    fn orders(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _account: Addr,
+    ) -> Result<Vec<Order>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Create a concentrated liquidity position between `lower_tick` and `upper_tick`.
+    /// The dex must add at least the `min_assets` amounts to the position.
+    fn create_position(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _lower_tick: i64,
+        _upper_tick: i64,
+        _assets: Vec<Asset>,
+        _min_assets: Vec<Asset>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Add liquidity to a concentrated liquidity position.
+    /// The dex must add at least the `min_assets` amounts to the position.
+    fn add_to_position(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _position_id: u64,
+        _assets: Vec<Asset>,
+        _min_assets: Vec<Asset>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Withdraw a `share` of the liquidity of a concentrated liquidity position
+    fn withdraw_position(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _position_id: u64,
+        _share: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Collect the spread fees and incentives of concentrated liquidity positions
+    fn collect_position_rewards(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _position_ids: Vec<u64>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Query the concentrated liquidity positions of `account` in the pool
+    fn positions(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _account: Addr,
+    ) -> Result<Vec<LiquidityPosition>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Price of `base` in units of `quote` in the pool, without fees or spread
+    fn price(
+        &self,
+        _deps: Deps,
+        _env: &Env,
+        _pool_id: PoolAddress,
+        _base: AssetInfo,
+        _quote: AssetInfo,
+        _kind: &PriceKind,
+    ) -> Result<Decimal, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Price of `base` in units of `quote` accumulated over time (price × seconds) by the pool up to the current block.
+    /// Returns `None` if the dex doesn't track a cumulative price, in which case it's accumulated from observed spot prices.
+    fn cumulative_price(
+        &self,
+        _deps: Deps,
+        _env: &Env,
+        _pool_id: PoolAddress,
+        _base: AssetInfo,
+        _quote: AssetInfo,
+    ) -> Result<Option<Decimal256>, DexError> {
+        Ok(None)
+    }
+
     /// Fetch data for execute methods
     fn fetch_data(
         &mut self,
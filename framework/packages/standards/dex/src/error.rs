@@ -4,7 +4,7 @@ use abstract_std::{
     objects::{ans_host::AnsHostError, DexAssetPairing},
     AbstractError,
 };
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Decimal, StdError, Uint128};
 use cw_asset::AssetError;
 use thiserror::Error;
 
@@ -73,6 +73,36 @@ pub enum DexError {
     #[error("Pool address not specified. You need to specify it when using raw asset addresses or denom")]
     PoolAddressEmpty,
 
+    #[error("No dex can swap {offer_asset} for {ask_asset}")]
+    NoSwapVenue {
+        offer_asset: String,
+        ask_asset: String,
+    },
+
+    #[error("Swap returned {return_amount}, less than the minimum of {min_receive}")]
+    MinReceiveNotMet {
+        min_receive: Uint128,
+        return_amount: Uint128,
+    },
+
+    #[error("Position share {0} must be between 0 and 1")]
+    InvalidPositionShare(Decimal),
+
+    #[error("Position {position_id} is not in pool {pool}")]
+    PositionNotInPool { position_id: u64, pool: String },
+
+    #[error("Order book {0} has no bids or asks to price the pair")]
+    EmptyOrderBook(String),
+
+    #[error("Price window of {0} seconds must be positive, start after the genesis and fit in the observed prices")]
+    InvalidPriceWindow(u64),
+
+    #[error("No price of {pair} was observed at least {window_seconds} seconds ago")]
+    NoPriceObservation {
+        pair: DexAssetPairing,
+        window_seconds: u64,
+    },
+
     #[error("Only account of abstract namespace can update configuration")]
     Unauthorized {},
 }
@@ -3,6 +3,7 @@ mod error;
 
 pub mod action;
 pub mod ans_action;
+pub mod client;
 pub mod msg;
 #[cfg(feature = "testing")]
 pub mod tests;
@@ -11,7 +11,7 @@ use abstract_std::{
     AbstractError, AbstractResult,
 };
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Api, CosmosMsg, Decimal, Uint128};
+use cosmwasm_std::{Addr, Api, CosmosMsg, Decimal, Decimal256, Uint128};
 use cw_asset::{AssetBase, AssetInfoBase};
 
 pub use crate::action::DexAction;
@@ -51,6 +51,97 @@ pub struct SimulateSwapResponse<A = AssetEntry> {
     pub usage_fee: Uint128,
 }
 
+/// Part of an aggregated swap that is routed to a single dex.
+#[cosmwasm_schema::cw_serde]
+pub struct SwapLeg {
+    /// Dex the leg is swapped on
+    pub dex: DexName,
+    /// Amount of the offer asset swapped on this dex, before the adapter fee
+    pub offer_amount: Uint128,
+    /// Simulated amount of the ask asset returned by this dex
+    pub return_amount: Uint128,
+}
+
+/// Response for simulating an aggregated swap.
+#[cosmwasm_schema::cw_serde]
+pub struct AggregatedSwapResponse {
+    /// Swaps the offer asset is split over
+    pub legs: Vec<SwapLeg>,
+    /// Total amount of the ask asset returned by the swaps
+    pub return_amount: Uint128,
+}
+
+/// Order placed on the order book of a dex.
+#[cosmwasm_schema::cw_serde]
+pub struct Order {
+    /// Id of the order on the dex
+    pub id: String,
+    /// Unfilled part of the offer asset
+    pub offer_asset: AssetBase<String>,
+    /// The asset to receive
+    pub ask_asset: AssetInfoBase<String>,
+    /// Filled amount of the ask asset that can be withdrawn, if reported by the dex
+    pub filled_amount: Option<Uint128>,
+}
+
+/// Response for the orders of an account.
+#[cosmwasm_schema::cw_serde]
+pub struct OrdersResponse {
+    /// Open orders of the account
+    pub orders: Vec<Order>,
+}
+
+/// Concentrated liquidity position on a dex.
+#[cosmwasm_schema::cw_serde]
+pub struct LiquidityPosition {
+    /// Id of the position on the dex
+    pub id: u64,
+    /// Lower tick of the price range
+    pub lower_tick: i64,
+    /// Upper tick of the price range
+    pub upper_tick: i64,
+    /// Liquidity of the position
+    pub liquidity: Decimal256,
+    /// Assets held by the position
+    pub assets: Vec<AssetBase<String>>,
+    /// Spread fees that can be collected
+    pub claimable_fees: Vec<AssetBase<String>>,
+    /// Incentives that can be collected
+    pub claimable_incentives: Vec<AssetBase<String>>,
+}
+
+/// Response for the concentrated liquidity positions of an account.
+#[cosmwasm_schema::cw_serde]
+pub struct PositionsResponse {
+    /// Positions of the account
+    pub positions: Vec<LiquidityPosition>,
+}
+
+/// Kind of price to query.
+#[cosmwasm_schema::cw_serde]
+pub enum PriceKind {
+    /// Current price of the pool
+    Spot,
+    /// Time weighted average price over a window ending now.
+    /// Dexes without a native TWAP average the prices observed with [`DexExecuteMsg::ObservePrice`],
+    /// from the latest observation made at least `window_seconds` ago, over a window of at most a day.
+    Twap {
+        /// Length of the averaging window in seconds
+        window_seconds: u64,
+    },
+}
+
+/// Response for a price query.
+#[cosmwasm_schema::cw_serde]
+pub struct PriceResponse {
+    /// Pair the price was queried for
+    pub pair: DexAssetPairing,
+    /// Kind of the price
+    pub kind: PriceKind,
+    /// Price of the first asset of the pair in units of the second asset
+    pub price: Decimal,
+}
+
 /// Response from GenerateMsgs
 #[cosmwasm_schema::cw_serde]
 pub struct GenerateMessagesResponse {
@@ -78,6 +169,7 @@ pub struct DexInstantiateMsg {
 
 /// Dex Execute msg
 #[cosmwasm_schema::cw_serde]
+#[derive(abstract_sdk::ModuleExecuteClient)]
 pub enum DexExecuteMsg {
     /// Update the fee
     UpdateFee {
@@ -93,6 +185,69 @@ pub enum DexExecuteMsg {
         /// The action to perform
         action: DexAction,
     },
+    /// Swap on the local dexes that return the most of the ask asset, splitting the swap over several of them if that returns more.
+    AggregatedSwap {
+        /// Dexes to consider, all dexes registered in the ANS host if `None`
+        dexes: Option<Vec<DexName>>,
+        /// The asset to offer
+        offer_asset: AnsAsset,
+        /// The asset to receive
+        ask_asset: AssetEntry,
+        /// Minimum amount of the ask asset the swaps must return in total
+        min_receive: Uint128,
+        /// Maximum number of dexes to split the swap over, defaults to 1 and is capped at 4
+        max_splits: Option<u8>,
+        /// The percentage of spread compared to pre-swap price, applied to every swap
+        max_spread: Option<Decimal>,
+    },
+    /// Record the current cumulative price of a pair, used for time weighted average prices on dexes without a native TWAP.
+    /// Prices are observed at most once every 5 minutes, observing more often is a no-op.
+    ObservePrice {
+        /// Pair to observe the price of, on the dex of the pairing
+        pair: DexAssetPairing,
+    },
+}
+
+impl DexExecuteMsg {
+    /// Request actions authorized addresses of the dex adapter can be scoped to.
+    pub const ACTIONS: &'static [&'static str] = &[
+        "update_fee",
+        "provide_liquidity",
+        "withdraw_liquidity",
+        "swap",
+        "route_swap",
+        "aggregated_swap",
+        "place_limit_order",
+        "cancel_order",
+        "withdraw_filled_order",
+        "create_position",
+        "add_to_position",
+        "withdraw_position",
+        "collect_position_rewards",
+        "observe_price",
+    ];
+
+    /// Name of the action performed by this request, one of [`DexExecuteMsg::ACTIONS`].
+    pub fn action_name(&self) -> &'static str {
+        match self {
+            DexExecuteMsg::UpdateFee { .. } => "update_fee",
+            DexExecuteMsg::Action { action, .. } => match action {
+                DexAction::ProvideLiquidity { .. } => "provide_liquidity",
+                DexAction::WithdrawLiquidity { .. } => "withdraw_liquidity",
+                DexAction::Swap { .. } => "swap",
+                DexAction::RouteSwap { .. } => "route_swap",
+                DexAction::PlaceLimitOrder { .. } => "place_limit_order",
+                DexAction::CancelOrder { .. } => "cancel_order",
+                DexAction::WithdrawFilledOrder { .. } => "withdraw_filled_order",
+                DexAction::CreatePosition { .. } => "create_position",
+                DexAction::AddToPosition { .. } => "add_to_position",
+                DexAction::WithdrawPosition { .. } => "withdraw_position",
+                DexAction::CollectPositionRewards { .. } => "collect_position_rewards",
+            },
+            DexExecuteMsg::AggregatedSwap { .. } => "aggregated_swap",
+            DexExecuteMsg::ObservePrice { .. } => "observe_price",
+        }
+    }
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -132,7 +287,7 @@ impl SwapNode<String> {
 
 /// Query messages for the dex adapter
 #[cosmwasm_schema::cw_serde]
-#[derive(QueryResponses, cw_orch::QueryFns)]
+#[derive(QueryResponses, cw_orch::QueryFns, abstract_sdk::ModuleQueryClient)]
 pub enum DexQueryMsg {
     /// Simulate a swap between two assets
     /// Returns [`SimulateSwapResponse`]
@@ -158,6 +313,19 @@ pub enum DexQueryMsg {
         /// Name of the dex to simulate the swap on
         dex: DexName,
     },
+    /// Simulate an aggregated swap over the local dexes
+    /// Returns [`AggregatedSwapResponse`]
+    #[returns(AggregatedSwapResponse)]
+    SimulateAggregatedSwap {
+        /// The asset to offer
+        offer_asset: AnsAsset,
+        /// The asset to receive
+        ask_asset: AssetEntry,
+        /// Dexes to consider, all dexes registered in the ANS host if `None`
+        dexes: Option<Vec<DexName>>,
+        /// Maximum number of dexes to split the swap over, defaults to 1 and is capped at 4
+        max_splits: Option<u8>,
+    },
     /// Endpoint can be used by front-end to easily interact with contracts.
     /// Returns [`GenerateMessagesResponse`]
     #[returns(GenerateMessagesResponse)]
@@ -167,6 +335,37 @@ pub enum DexQueryMsg {
         /// Sender Addr generate messages for
         addr_as_sender: String,
     },
+    /// Orders an account placed on the order book of a dex
+    /// Returns [`OrdersResponse`]
+    #[returns(OrdersResponse)]
+    Orders {
+        /// Address of the account that placed the orders
+        account: String,
+        /// Pool (order book) the orders were placed on
+        pool: UncheckedPoolAddress,
+        /// Name of the dex to query the orders on
+        dex: DexName,
+    },
+    /// Concentrated liquidity positions of an account in a pool
+    /// Returns [`PositionsResponse`]
+    #[returns(PositionsResponse)]
+    Positions {
+        /// Address of the account that owns the positions
+        account: String,
+        /// Pool of the positions
+        pool: UncheckedPoolAddress,
+        /// Name of the dex to query the positions on
+        dex: DexName,
+    },
+    /// Price of the first asset of a pair in units of the second asset, without fees or spread
+    /// Returns [`PriceResponse`]
+    #[returns(PriceResponse)]
+    Price {
+        /// Pair to query the price of, on the dex of the pairing
+        pair: DexAssetPairing,
+        /// Kind of price to query
+        kind: PriceKind,
+    },
     /// Fee info for using the different dex actions
     #[returns(DexFeesResponse)]
     Fees {},
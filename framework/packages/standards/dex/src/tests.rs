@@ -1,12 +1,12 @@
 use std::fmt::Debug;
 
 use abstract_std::objects::PoolAddress;
-use cosmwasm_std::{CosmosMsg, Decimal, StdError};
+use cosmwasm_std::{testing::mock_env, CosmosMsg, Decimal, Decimal256, StdError};
 use cw_asset::{Asset, AssetInfo};
 use cw_orch::daemon::live_mock::mock_dependencies;
 use cw_orch::prelude::*;
 
-use crate::{DexCommand, DexError, Fee, FeeOnInput, Return, Spread};
+use crate::{msg::PriceKind, DexCommand, DexError, Fee, FeeOnInput, Return, Spread};
 
 pub struct DexCommandTester {
     chain: ChainInfoOwned,
@@ -88,4 +88,69 @@ impl DexCommandTester {
             .simulate_swap(deps.as_ref(), pool_id, offer_asset, ask_asset)?;
         Ok(result)
     }
+
+    pub fn test_place_limit_order(
+        &self,
+        pool_id: PoolAddress,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self.adapter.place_limit_order(
+            deps.as_ref(),
+            pool_id,
+            offer_asset,
+            ask_asset,
+            price,
+        )?;
+        Ok(msgs)
+    }
+
+    pub fn test_cancel_order(
+        &self,
+        pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self
+            .adapter
+            .cancel_order(deps.as_ref(), pool_id, order_id)?;
+        Ok(msgs)
+    }
+
+    pub fn test_withdraw_filled_order(
+        &self,
+        pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self
+            .adapter
+            .withdraw_filled_order(deps.as_ref(), pool_id, order_id)?;
+        Ok(msgs)
+    }
+
+    pub fn test_cumulative_price(
+        &self,
+        pool_id: PoolAddress,
+        base: AssetInfo,
+        quote: AssetInfo,
+    ) -> Result<Option<Decimal256>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        self.adapter
+            .cumulative_price(deps.as_ref(), &mock_env(), pool_id, base, quote)
+    }
+
+    pub fn test_price(
+        &self,
+        pool_id: PoolAddress,
+        base: AssetInfo,
+        quote: AssetInfo,
+        kind: PriceKind,
+    ) -> Result<Decimal, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        self.adapter
+            .price(deps.as_ref(), &mock_env(), pool_id, base, quote, &kind)
+    }
 }
@@ -51,6 +51,15 @@ pub enum MoneyMarketAnsAction {
         /// Asset that identifies the market you want to borrow from
         collateral_asset: AssetEntry,
     },
+    /// Liquidate an unhealthy position of another user
+    Liquidate {
+        /// User whose position gets liquidated
+        user: String,
+        /// Debt asset to repay on behalf of the user
+        debt_asset: AnsAsset,
+        /// Collateral asset to receive
+        collateral_asset: AssetEntry,
+    },
 }
 
 /// Structure created to be able to resolve an action using ANS
@@ -173,6 +182,28 @@ impl Resolve for MoneyMarketActionResolveWrapper {
                     contract_addr: contract_addr.to_string(),
                 }
             }
+            MoneyMarketAnsAction::Liquidate {
+                user,
+                debt_asset,
+                collateral_asset,
+            } => {
+                let contract_addr = self.0.borrow_address(
+                    querier,
+                    ans_host,
+                    debt_asset.name.clone(),
+                    collateral_asset.clone(),
+                )?;
+                let debt_asset = debt_asset.resolve(querier, ans_host)?;
+                let collateral_asset = collateral_asset.resolve(querier, ans_host)?;
+                MoneyMarketRawAction {
+                    request: MoneyMarketRawRequest::Liquidate {
+                        user,
+                        debt_asset: debt_asset.into(),
+                        collateral_asset: collateral_asset.into(),
+                    },
+                    contract_addr: contract_addr.to_string(),
+                }
+            }
         };
 
         Ok(raw_action)
@@ -3,7 +3,7 @@ use abstract_adapter_utils::identity::Identify;
 use crate::error::MoneyMarketError;
 use abstract_sdk::feature_objects::AnsHost;
 use abstract_std::objects::{ans_host::AnsHostError, AssetEntry};
-use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps, QuerierWrapper, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, Decimal256, Deps, QuerierWrapper, StdError, Uint128};
 use cw_asset::{Asset, AssetInfo};
 
 pub type Return = Uint128;
@@ -96,6 +96,16 @@ pub trait MoneyMarketCommand: Identify {
         asset: Asset,
     ) -> Result<Vec<CosmosMsg>, MoneyMarketError>;
 
+    /// Liquidate the position of `user`, repaying (part of) its debt in `debt_asset`
+    fn liquidate(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        debt_asset: Asset,
+        collateral_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError>;
+
     //*****************   Queries   ****************/
     // This represents how much 1 unit of the base is worth in terms of the quote
     fn price(
@@ -164,4 +174,55 @@ pub trait MoneyMarketCommand: Identify {
         lending_asset: AssetEntry,
         collateral_asset: AssetEntry,
     ) -> Result<Addr, AnsHostError>;
+
+    /// Loan-to-Value ratio above which the position of the user can be liquidated
+    fn liquidation_threshold(
+        &self,
+        deps: Deps,
+        liquidation_threshold_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError>;
+
+    fn liquidation_threshold_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        borrowed_asset: AssetEntry,
+        collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError>;
+
+    /// Collateral and debt values of the user position, both in terms of the borrowed asset.
+    /// Defaults to the collateral and debt of the market identified by the asset pair.
+    fn position_value(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+    ) -> Result<(Decimal256, Decimal256), MoneyMarketError> {
+        let collateral = self.user_collateral(
+            deps,
+            contract_addr.clone(),
+            user.clone(),
+            borrowed_asset.clone(),
+            collateral_asset.clone(),
+        )?;
+        let debt = self.user_borrow(
+            deps,
+            contract_addr,
+            user,
+            borrowed_asset.clone(),
+            collateral_asset.clone(),
+        )?;
+        let collateral_price = self.price(deps, collateral_asset, borrowed_asset)?;
+
+        // Base units of 18 decimal tokens overflow a `Decimal` above ~340 tokens
+        let collateral_value = Decimal256::from_ratio(collateral, 1u128)
+            .checked_mul(collateral_price.into())
+            .map_err(StdError::from)?;
+        Ok((collateral_value, Decimal256::from_ratio(debt, 1u128)))
+    }
 }
@@ -2,7 +2,7 @@ use abstract_adapter::AdapterError;
 use abstract_sdk::AbstractSdkError;
 use abstract_std::{objects::ans_host::AnsHostError, AbstractError};
 use cosmwasm_std::{
-    CheckedFromRatioError, ConversionOverflowError, DecimalRangeExceeded, StdError,
+    CheckedFromRatioError, ConversionOverflowError, Decimal, DecimalRangeExceeded, StdError,
 };
 use cw_asset::AssetError;
 use thiserror::Error;
@@ -65,4 +65,19 @@ pub enum MoneyMarketError {
 
     #[error("Expected native asset")]
     ExpectedNative {},
+
+    #[error("Target LTV {target_ltv} must be below the max LTV {max_ltv}")]
+    TargetLtvTooHigh {
+        target_ltv: Decimal,
+        max_ltv: Decimal,
+    },
+
+    #[error("Position can't be deleveraged to LTV {0} without exceeding the max LTV")]
+    DeleverageNotPossible(Decimal),
+
+    #[error("Max spread {0} must be below one")]
+    MaxSpreadTooHigh(Decimal),
+
+    #[error("Leverage actions swap through the dex adapter, install it on the account first")]
+    DexAdapterNotInstalled {},
 }
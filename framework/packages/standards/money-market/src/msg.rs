@@ -1,11 +1,14 @@
 #![warn(missing_docs)]
 //! # MoneyMarket Adapter API
 // re-export response types
-use crate::{ans_action::MoneyMarketAnsAction, raw_action::MoneyMarketRawAction};
-use abstract_std::objects::AssetEntry;
+use crate::{
+    ans_action::MoneyMarketAnsAction,
+    raw_action::{MoneyMarketRawAction, MoneyMarketRawRequest},
+};
+use abstract_std::objects::{AnsAsset, AssetEntry};
 use abstract_std::{adapter, objects::fee::UsageFee};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{CosmosMsg, Decimal, StdError, StdResult, Uint128};
+use cosmwasm_std::{CosmosMsg, Decimal, Decimal256, StdError, StdResult, Uint128};
 use cw_asset::AssetInfoBase;
 
 /// Max fee for the dex adapter actions
@@ -14,6 +17,9 @@ pub const MAX_FEE: Decimal = Decimal::percent(5);
 /// The name of the dex to trade on.
 pub type MoneyMarketName = String;
 
+/// Maximum spread of the swaps of leverage and deleverage actions when none is provided
+pub const DEFAULT_LEVERAGE_MAX_SPREAD: Decimal = Decimal::percent(1);
+
 /// The callback id for interacting with a dex over ibc
 pub const IBC_DEX_PROVIDER_ID: &str = "IBC_DEX_ACTION";
 
@@ -70,6 +76,82 @@ pub enum MoneyMarketExecuteMsg {
         /// The action to perform
         action: MoneyMarketRawAction,
     },
+    /// Leverage a position by repeatedly borrowing, swapping the borrowed asset to collateral
+    /// through the dex adapter and providing it as collateral
+    Leverage {
+        /// The name of the money market to interact with
+        money_market: MoneyMarketName,
+        /// Loan-to-Value ratio to leverage up to, must be below the max LTV
+        target_ltv: Decimal,
+        /// Collateral to provide before leveraging
+        collateral: AnsAsset,
+        /// Asset to borrow
+        borrow_asset: AssetEntry,
+        /// Name of the dex to swap on
+        dex: String,
+        /// Maximum spread of every swap, below one. Defaults to [`DEFAULT_LEVERAGE_MAX_SPREAD`]
+        max_spread: Option<Decimal>,
+    },
+    /// Deleverage a position by repeatedly withdrawing collateral, swapping it to the borrowed
+    /// asset through the dex adapter and repaying the debt
+    Deleverage {
+        /// The name of the money market to interact with
+        money_market: MoneyMarketName,
+        /// Loan-to-Value ratio to deleverage down to
+        target_ltv: Decimal,
+        /// Collateral asset of the position
+        collateral_asset: AssetEntry,
+        /// Borrowed asset of the position
+        borrowed_asset: AssetEntry,
+        /// Name of the dex to swap on
+        dex: String,
+        /// Maximum spread of every swap, below one. Defaults to [`DEFAULT_LEVERAGE_MAX_SPREAD`]
+        max_spread: Option<Decimal>,
+    },
+}
+
+impl MoneyMarketExecuteMsg {
+    /// Request actions authorized addresses of the money-market adapter can be scoped to.
+    pub const ACTIONS: &'static [&'static str] = &[
+        "update_fee",
+        "deposit",
+        "withdraw",
+        "provide_collateral",
+        "withdraw_collateral",
+        "borrow",
+        "repay",
+        "liquidate",
+        "leverage",
+        "deleverage",
+    ];
+
+    /// Name of the action performed by this request, one of [`MoneyMarketExecuteMsg::ACTIONS`].
+    /// Ans and raw actions share the same names.
+    pub fn action_name(&self) -> &'static str {
+        match self {
+            MoneyMarketExecuteMsg::UpdateFee { .. } => "update_fee",
+            MoneyMarketExecuteMsg::Leverage { .. } => "leverage",
+            MoneyMarketExecuteMsg::Deleverage { .. } => "deleverage",
+            MoneyMarketExecuteMsg::AnsAction { action, .. } => match action {
+                MoneyMarketAnsAction::Deposit { .. } => "deposit",
+                MoneyMarketAnsAction::Withdraw { .. } => "withdraw",
+                MoneyMarketAnsAction::ProvideCollateral { .. } => "provide_collateral",
+                MoneyMarketAnsAction::WithdrawCollateral { .. } => "withdraw_collateral",
+                MoneyMarketAnsAction::Borrow { .. } => "borrow",
+                MoneyMarketAnsAction::Repay { .. } => "repay",
+                MoneyMarketAnsAction::Liquidate { .. } => "liquidate",
+            },
+            MoneyMarketExecuteMsg::RawAction { action, .. } => match action.request {
+                MoneyMarketRawRequest::Deposit { .. } => "deposit",
+                MoneyMarketRawRequest::Withdraw { .. } => "withdraw",
+                MoneyMarketRawRequest::ProvideCollateral { .. } => "provide_collateral",
+                MoneyMarketRawRequest::WithdrawCollateral { .. } => "withdraw_collateral",
+                MoneyMarketRawRequest::Borrow { .. } => "borrow",
+                MoneyMarketRawRequest::Repay { .. } => "repay",
+                MoneyMarketRawRequest::Liquidate { .. } => "liquidate",
+            },
+        }
+    }
 }
 
 /// Query messages for the dex adapter
@@ -158,6 +240,20 @@ pub enum MoneyMarketQueryMsg {
         /// Name of the MoneyMarket to interact with
         money_market: MoneyMarketName,
     },
+    #[returns(UserLiquidationThresholdResponse)]
+    /// Loan-to-Value ratio above which a user position can be liquidated
+    RawLiquidationThreshold {
+        /// User that has borrowed some funds
+        user: String,
+        /// Collateral asset to query
+        collateral_asset: AssetInfoBase<String>,
+        /// Borrowed asset to query
+        borrowed_asset: AssetInfoBase<String>,
+        /// Contract Address on which you execute the query
+        contract_addr: String,
+        /// Name of the MoneyMarket to interact with
+        money_market: MoneyMarketName,
+    },
     #[returns(PriceResponse)]
     /// Price of an asset compared to another asset
     /// The returned decimal corresponds to
@@ -233,6 +329,18 @@ pub enum MoneyMarketQueryMsg {
         /// Name of the MoneyMarket to interact with
         money_market: MoneyMarketName,
     },
+    #[returns(UserLiquidationThresholdResponse)]
+    /// Loan-to-Value ratio above which a user position can be liquidated
+    AnsLiquidationThreshold {
+        /// User that has borrowed some funds
+        user: String,
+        /// Collateral asset to query
+        collateral_asset: AssetEntry,
+        /// Borrowed asset to query
+        borrowed_asset: AssetEntry,
+        /// Name of the MoneyMarket to interact with
+        money_market: MoneyMarketName,
+    },
     #[returns(PriceResponse)]
     /// Price of an asset compared to another asset
     /// The returned decimal corresponds to
@@ -245,6 +353,21 @@ pub enum MoneyMarketQueryMsg {
         /// Name of the MoneyMarket to interact with
         money_market: MoneyMarketName,
     },
+    #[returns(HealthResponse)]
+    /// Health of a user position after the given actions, without executing them
+    /// Only collateral, borrow and repay actions on the queried market change the position
+    SimulateHealth {
+        /// Account that holds the position
+        account: String,
+        /// Actions to apply to the position, in order
+        after: Vec<MoneyMarketAnsAction>,
+        /// Collateral asset of the market
+        collateral_asset: AssetEntry,
+        /// Borrowed asset of the market
+        borrowed_asset: AssetEntry,
+        /// Name of the MoneyMarket to interact with
+        money_market: MoneyMarketName,
+    },
 
     /// Fee info for using the different dex actions
     #[returns(MoneyMarketFeesResponse)]
@@ -260,13 +383,16 @@ impl MoneyMarketQueryMsg {
             MoneyMarketQueryMsg::RawUserBorrow { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::RawCurrentLTV { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::RawMaxLTV { money_market, .. } => Ok(money_market),
+            MoneyMarketQueryMsg::RawLiquidationThreshold { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::RawPrice { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsUserDeposit { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsUserCollateral { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsUserBorrow { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsCurrentLTV { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsMaxLTV { money_market, .. } => Ok(money_market),
+            MoneyMarketQueryMsg::AnsLiquidationThreshold { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsPrice { money_market, .. } => Ok(money_market),
+            MoneyMarketQueryMsg::SimulateHealth { money_market, .. } => Ok(money_market),
 
             MoneyMarketQueryMsg::GenerateMessages { .. } => {
                 Err(StdError::generic_err("Wrong query type"))
@@ -306,6 +432,29 @@ pub struct UserMaxLTVResponse {
     /// Maximum LTV
     pub max_ltv: Decimal,
 }
+/// Response wrapper for user liquidation threshold query
+#[cw_serde]
+pub struct UserLiquidationThresholdResponse {
+    /// Liquidation threshold
+    pub liquidation_threshold: Decimal,
+}
+/// Response wrapper for the health simulation query
+#[cw_serde]
+pub struct HealthResponse {
+    /// Value of the collateral, in the borrowed asset
+    pub collateral_value: Decimal256,
+    /// Value of the debt, in the borrowed asset
+    pub debt_value: Decimal256,
+    /// Loan-to-Value ratio of the position
+    pub ltv: Decimal,
+    /// Maximum Loan-to-Value ratio to borrow up to
+    pub max_ltv: Decimal,
+    /// Loan-to-Value ratio above which the position can be liquidated
+    pub liquidation_threshold: Decimal,
+    /// Liquidation threshold divided by the LTV, the position is liquidatable below one.
+    /// `None` when the position has no debt
+    pub health_factor: Option<Decimal>,
+}
 
 /// Response wrapper for price query
 #[cw_serde]
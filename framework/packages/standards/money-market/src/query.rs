@@ -135,6 +135,30 @@ impl Resolve for MoneyMarketQueryResolveWrapper {
                     money_market,
                 }
             }
+            MoneyMarketQueryMsg::AnsLiquidationThreshold {
+                user,
+                collateral_asset,
+                borrowed_asset,
+                money_market,
+            } => {
+                let platform = self.0(&money_market).map_err(err)?;
+                let contract_addr = platform.liquidation_threshold_address(
+                    querier,
+                    ans_host,
+                    borrowed_asset.clone(),
+                    collateral_asset.clone(),
+                )?;
+                let collateral_asset = collateral_asset.resolve(querier, ans_host)?;
+                let borrowed_asset = borrowed_asset.resolve(querier, ans_host)?;
+                MoneyMarketQueryMsg::RawLiquidationThreshold {
+                    user,
+                    collateral_asset: collateral_asset.into(),
+                    borrowed_asset: borrowed_asset.into(),
+
+                    contract_addr: contract_addr.to_string(),
+                    money_market,
+                }
+            }
             MoneyMarketQueryMsg::AnsPrice {
                 quote,
                 base,
@@ -45,6 +45,15 @@ pub enum MoneyMarketRawRequest {
         /// Asset that identifies the market you want to borrow from
         collateral_asset: AssetInfoBase<String>,
     },
+    /// Liquidate an unhealthy position of another user
+    Liquidate {
+        /// User whose position gets liquidated
+        user: String,
+        /// Debt asset to repay on behalf of the user
+        debt_asset: AssetBase<String>,
+        /// Collateral asset to receive
+        collateral_asset: AssetInfoBase<String>,
+    },
 }
 
 /// Action to execute on a money_market
@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 
 use cosmwasm_std::{Addr, CosmosMsg, StdError};
-use cw_asset::Asset;
+use cw_asset::{Asset, AssetInfo};
 use cw_orch::daemon::live_mock::mock_dependencies;
 use cw_orch::prelude::*;
 
@@ -93,4 +93,22 @@ impl MoneyMarketCommandTester {
         let msgs = self.adapter.repay(deps.as_ref(), contract_addr, asset)?;
         Ok(msgs)
     }
+
+    pub fn test_liquidate(
+        &self,
+        contract_addr: Addr,
+        user: Addr,
+        debt_asset: Asset,
+        collateral_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self.adapter.liquidate(
+            deps.as_ref(),
+            contract_addr,
+            user,
+            debt_asset,
+            collateral_asset,
+        )?;
+        Ok(msgs)
+    }
 }
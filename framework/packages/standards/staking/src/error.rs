@@ -1,7 +1,7 @@
 use abstract_adapter::AdapterError;
 use abstract_sdk::AbstractSdkError;
 use abstract_std::{objects::ans_host::AnsHostError, AbstractError};
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use cw_asset::AssetError;
 use thiserror::Error;
 
@@ -76,4 +76,17 @@ pub enum CwStakingError {
 
     #[error("Pool type {0} not supported for dex {1}")]
     NotSupportedPoolType(String, String),
+
+    #[error("Compounding restaked {restaked} {asset}, less than the minimum of {min_receive}")]
+    CompoundMinReceiveNotMet {
+        asset: String,
+        min_receive: Uint128,
+        restaked: Uint128,
+    },
+
+    #[error("Compounding swaps through the dex adapter, install it on the account first")]
+    DexAdapterNotInstalled {},
+
+    #[error("Staking provider {0} has no rewards to claim, compound the rewards it distributed to the account with `distributed_rewards`")]
+    NoRewardsToClaim(String),
 }
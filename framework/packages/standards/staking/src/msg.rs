@@ -63,6 +63,25 @@ pub enum StakingAction {
         /// Unbonded staking assets to claim.
         assets: Vec<AssetEntry>,
     },
+    /// Claim rewards for a set of staked assets and stake them again.
+    /// Rewards are swapped to the staking token, or to the pool assets of an LP staking token
+    /// which are then provided as liquidity.
+    Compound {
+        /// Staked assets to compound the rewards of.
+        assets: Vec<AssetEntry>,
+        /// Name of the dex to swap rewards on.
+        /// Liquidity is provided on the dex of an LP staking token.
+        dex: String,
+        /// Minimum amount of the staking tokens to stake again.
+        /// Staking tokens without an entry have no minimum.
+        min_receive: Vec<AnsAsset>,
+        /// The unbonding period to stake the rewards with.
+        unbonding_period: Option<Duration>,
+        /// Rewards the provider already distributed to the account, compounded instead of
+        /// claiming rewards. For providers that distribute rewards without a claim, like Osmosis.
+        /// They're split evenly between the staked assets.
+        distributed_rewards: Vec<AnsAsset>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
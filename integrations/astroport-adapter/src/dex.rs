@@ -17,13 +17,16 @@ impl Identify for Astroport {
 #[cfg(feature = "full_integration")]
 use ::{
     abstract_dex_standard::{
-        coins_in_assets, cw_approve_msgs, DexCommand, DexError, Fee, FeeOnInput, Return, Spread,
-        SwapNode,
+        coins_in_assets, cw_approve_msgs, msg::PriceKind, DexCommand, DexError, Fee, FeeOnInput,
+        Return, Spread, SwapNode,
     },
     abstract_sdk::std::objects::PoolAddress,
     astroport::pair::SimulationResponse,
     astroport::router::SwapOperation,
-    cosmwasm_std::{to_json_binary, wasm_execute, Addr, CosmosMsg, Decimal, Deps, Uint128},
+    cosmwasm_std::{
+        to_json_binary, wasm_execute, Addr, CosmosMsg, Decimal, Decimal256, Deps, Env, StdError,
+        Uint128,
+    },
     cw20::Cw20ExecuteMsg,
     cw_asset::{Asset, AssetInfo, AssetInfoBase},
 };
@@ -254,6 +257,83 @@ impl DexCommand for Astroport {
         // commission paid in result asset
         Ok((return_amount, spread_amount, commission_amount, false))
     }
+
+    fn price(
+        &self,
+        deps: Deps,
+        _env: &Env,
+        pool_id: PoolAddress,
+        base: AssetInfo,
+        quote: AssetInfo,
+        kind: &PriceKind,
+    ) -> Result<Decimal, DexError> {
+        // Pairs only expose cumulative prices, the adapter averages them between its observations
+        let PriceKind::Spot = kind else {
+            return Err(DexError::NotImplemented(self.name().to_owned()));
+        };
+        let pair_address = pool_id.expect_contract()?;
+        let pair: astroport::asset::PairInfo = deps.querier.query_wasm_smart(
+            pair_address.to_string(),
+            &astroport::pair::QueryMsg::Pair {},
+        )?;
+        // The reserve ratio is only the price of constant product pairs
+        if !matches!(pair.pair_type, astroport::factory::PairType::Xyk {}) {
+            return Err(DexError::NotImplemented(self.name().to_owned()));
+        }
+        let pool: astroport::pair::PoolResponse = deps.querier.query_wasm_smart(
+            pair_address.to_string(),
+            &astroport::pair::QueryMsg::Pool {},
+        )?;
+
+        let reserve = |info: &AssetInfo| -> Result<Uint128, DexError> {
+            let astroport_info = cw_asset_info_to_astroport(info)?;
+            pool.assets
+                .iter()
+                .find(|asset| asset.info == astroport_info)
+                .map(|asset| asset.amount)
+                .ok_or_else(|| {
+                    DexError::ArgumentMismatch(
+                        info.to_string(),
+                        pool.assets.iter().map(|a| a.info.to_string()).collect(),
+                    )
+                })
+        };
+        Decimal::checked_from_ratio(reserve(&quote)?, reserve(&base)?)
+            .map_err(|e| StdError::generic_err(e.to_string()).into())
+    }
+
+    fn cumulative_price(
+        &self,
+        deps: Deps,
+        _env: &Env,
+        pool_id: PoolAddress,
+        base: AssetInfo,
+        quote: AssetInfo,
+    ) -> Result<Option<Decimal256>, DexError> {
+        let pair_address = pool_id.expect_contract()?;
+        let prices: astroport::pair::CumulativePricesResponse = deps.querier.query_wasm_smart(
+            pair_address.to_string(),
+            &astroport::pair::QueryMsg::CumulativePrices {},
+        )?;
+        let (base, quote) = (
+            cw_asset_info_to_astroport(&base)?,
+            cw_asset_info_to_astroport(&quote)?,
+        );
+        let (.., cumulative_price) = prices
+            .cumulative_prices
+            .into_iter()
+            .find(|(from, to, _)| from == &base && to == &quote)
+            .ok_or_else(|| {
+                DexError::ArgumentMismatch(
+                    format!("{base}/{quote}"),
+                    prices.assets.iter().map(|a| a.info.to_string()).collect(),
+                )
+            })?;
+        // Cumulative prices are scaled by the TWAP precision of the pair
+        Decimal256::from_atomics(cumulative_price, astroport::pair::TWAP_PRECISION.into())
+            .map(Some)
+            .map_err(|e| StdError::generic_err(e.to_string()).into())
+    }
 }
 
 #[cfg(feature = "full_integration")]
@@ -283,7 +363,10 @@ fn cw_asset_info_to_astroport(
 mod tests {
     use std::{assert_eq, str::FromStr};
 
-    use abstract_dex_standard::tests::{expect_eq, DexCommandTester};
+    use abstract_dex_standard::{
+        tests::{expect_eq, DexCommandTester},
+        DexError,
+    };
     use abstract_sdk::std::objects::PoolAddress;
     use cosmwasm_std::{coin, coins, to_json_binary, wasm_execute, Addr, Decimal};
     use cw20::Cw20ExecuteMsg;
@@ -470,4 +553,23 @@ mod tests {
             )
             .unwrap();
     }
+
+    #[test]
+    fn cumulative_price() {
+        let setup = create_setup();
+        let pool = PoolAddress::contract(Addr::unchecked(POOL_CONTRACT));
+        let price = setup
+            .test_cumulative_price(
+                pool.clone(),
+                AssetInfo::native(USDC),
+                AssetInfo::native(LUNA),
+            )
+            .unwrap();
+        assert!(price.is_some());
+
+        let err = setup
+            .test_cumulative_price(pool, AssetInfo::native(USDC), AssetInfo::native("uatom"))
+            .unwrap_err();
+        assert!(matches!(err, DexError::ArgumentMismatch(..)));
+    }
 }
@@ -20,15 +20,18 @@ impl Identify for Fin {
 #[cfg(feature = "full_integration")]
 use ::{
     abstract_dex_standard::{
-        coins_in_assets, DexCommand, DexError, Fee, FeeOnInput, Return, Spread,
+        coins_in_assets,
+        msg::{Order, PriceKind},
+        DexCommand, DexError, Fee, FeeOnInput, Return, Spread,
     },
     abstract_sdk::std::objects::PoolAddress,
     cosmwasm_std::{
-        wasm_execute, Addr, Coin, CosmosMsg, Decimal, Decimal256, Deps, StdError, StdResult,
+        wasm_execute, Addr, Coin, CosmosMsg, Decimal, Decimal256, Deps, Env, StdError, StdResult,
         Uint128,
     },
-    cw_asset::{Asset, AssetInfo, AssetInfoBase},
+    cw_asset::{Asset, AssetBase, AssetInfo, AssetInfoBase},
     kujira::{bow, fin},
+    std::str::FromStr,
 };
 
 #[cfg(feature = "full_integration")]
@@ -200,6 +203,198 @@ impl DexCommand for Fin {
             false,
         ))
     }
+
+    fn place_limit_order(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let fin_pair_address = fin_pair_address(pool_id);
+        let AssetInfo::Native(offer_denom) = &offer_asset.info else {
+            return Err(DexError::UnsupportedAssetType(offer_asset.info.to_string()));
+        };
+        let AssetInfo::Native(ask_denom) = &ask_asset else {
+            return Err(DexError::UnsupportedAssetType(ask_asset.to_string()));
+        };
+        // FIN prices orders in quote per base asset
+        let [base_denom, quote_denom] = fin_denoms(deps, &fin_pair_address)?;
+        let quote_price = if *offer_denom == base_denom && *ask_denom == quote_denom {
+            price
+        } else if *offer_denom == quote_denom && *ask_denom == base_denom {
+            price
+                .inv()
+                .ok_or_else(|| StdError::generic_err("Order price can't be zero"))?
+        } else {
+            let mismatched = if *offer_denom == base_denom || *offer_denom == quote_denom {
+                ask_denom
+            } else {
+                offer_denom
+            };
+            return Err(DexError::ArgumentMismatch(
+                mismatched.clone(),
+                vec![base_denom, quote_denom],
+            ));
+        };
+
+        let order_msg = wasm_execute(
+            fin_pair_address.to_string(),
+            &fin::ExecuteMsg::SubmitOrder {
+                price: decimal2decimal256(quote_price)?,
+                callback: None,
+            },
+            vec![offer_asset.try_into()?],
+        )?;
+        Ok(vec![order_msg.into()])
+    }
+
+    fn cancel_order(
+        &self,
+        _deps: Deps,
+        pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let cancel_msg = wasm_execute(
+            fin_pair_address(pool_id).to_string(),
+            &fin::ExecuteMsg::RetractOrder {
+                order_idx: Uint128::from_str(&order_id)?,
+                amount: None,
+                callback: None,
+            },
+            vec![],
+        )?;
+        Ok(vec![cancel_msg.into()])
+    }
+
+    fn withdraw_filled_order(
+        &self,
+        _deps: Deps,
+        pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let withdraw_msg = wasm_execute(
+            fin_pair_address(pool_id).to_string(),
+            &fin::ExecuteMsg::WithdrawOrders {
+                order_idxs: Some(vec![Uint128::from_str(&order_id)?]),
+                callback: None,
+            },
+            vec![],
+        )?;
+        Ok(vec![withdraw_msg.into()])
+    }
+
+    fn orders(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        account: Addr,
+    ) -> Result<Vec<Order>, DexError> {
+        let fin_pair_address = fin_pair_address(pool_id);
+        let denoms = fin_denoms(deps, &fin_pair_address)?;
+        let fin::OrdersResponse { orders } = deps.querier.query_wasm_smart(
+            fin_pair_address.to_string(),
+            &fin::QueryMsg::OrdersByUser {
+                address: account,
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        orders
+            .into_iter()
+            .map(|order| {
+                let offer_denom = order.offer_denom.to_string();
+                // The order receives the other asset of the pair
+                let ask_denom = if offer_denom == denoms[0] {
+                    denoms[1].clone()
+                } else {
+                    denoms[0].clone()
+                };
+                Ok(Order {
+                    id: order.idx.to_string(),
+                    offer_asset: AssetBase::native(
+                        offer_denom,
+                        Uint128::try_from(order.offer_amount).map_err(StdError::from)?,
+                    ),
+                    ask_asset: AssetInfoBase::native(ask_denom),
+                    filled_amount: Some(
+                        Uint128::try_from(order.filled_amount).map_err(StdError::from)?,
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    fn price(
+        &self,
+        deps: Deps,
+        _env: &Env,
+        pool_id: PoolAddress,
+        base: AssetInfo,
+        quote: AssetInfo,
+        kind: &PriceKind,
+    ) -> Result<Decimal, DexError> {
+        // FIN has no TWAP, the adapter averages the observed spot prices instead
+        if !matches!(kind, PriceKind::Spot) {
+            return Err(DexError::NotImplemented(self.name().to_owned()));
+        }
+        let fin_pair_address = fin_pair_address(pool_id);
+        let denoms = fin_denoms(deps, &fin_pair_address)?;
+        let (AssetInfo::Native(base_denom), AssetInfo::Native(quote_denom)) = (&base, &quote)
+        else {
+            return Err(DexError::UnsupportedAssetType(format!("{base} or {quote}")));
+        };
+
+        // Best ask and bid, priced in quote denom per base denom of the pair
+        let fin::BookResponse {
+            base: asks,
+            quote: bids,
+        } = deps.querier.query_wasm_smart(
+            fin_pair_address.to_string(),
+            &fin::QueryMsg::Book {
+                limit: Some(1),
+                offset: None,
+            },
+        )?;
+        let (Some(ask), Some(bid)) = (asks.first(), bids.first()) else {
+            return Err(DexError::EmptyOrderBook(fin_pair_address.to_string()));
+        };
+        let mid_price = (ask.quote_price + bid.quote_price) / Uint128::new(2);
+
+        if [base_denom, quote_denom] == [&denoms[0], &denoms[1]] {
+            Ok(mid_price)
+        } else if [base_denom, quote_denom] == [&denoms[1], &denoms[0]] {
+            mid_price
+                .inv()
+                .ok_or_else(|| DexError::EmptyOrderBook(fin_pair_address.to_string()))
+        } else {
+            Err(DexError::ArgumentMismatch(
+                format!("{base_denom}/{quote_denom}"),
+                denoms.to_vec(),
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "full_integration")]
+fn fin_pair_address(pool_id: PoolAddress) -> Addr {
+    match pool_id {
+        PoolAddress::SeparateAddresses { swap, liquidity: _ } => swap,
+        PoolAddress::Contract(swap) => swap,
+        _ => panic!("invalid address"),
+    }
+}
+
+#[cfg(feature = "full_integration")]
+/// Base and quote denom of a FIN pair
+fn fin_denoms(deps: Deps, fin_pair_address: &Addr) -> Result<[String; 2], DexError> {
+    let config: fin::ConfigResponse = deps
+        .querier
+        .query_wasm_smart(fin_pair_address.to_string(), &fin::QueryMsg::Config {})?;
+    let [base, quote] = config.denoms;
+    Ok([base.to_string(), quote.to_string()])
 }
 
 #[cfg(feature = "full_integration")]
@@ -230,9 +425,15 @@ pub fn decimal2decimal256(dec_value: Decimal) -> StdResult<Decimal256> {
 mod tests {
     use std::{assert_eq, str::FromStr};
 
-    use abstract_dex_standard::tests::{expect_eq, DexCommandTester};
+    use abstract_dex_standard::{
+        msg::PriceKind,
+        tests::{expect_eq, DexCommandTester},
+        DexError,
+    };
     use abstract_sdk::std::objects::PoolAddress;
-    use cosmwasm_std::{coin, coins, wasm_execute, Addr, Decimal, Decimal256};
+    use cosmwasm_std::{
+        coin, coins, wasm_execute, Addr, CosmosMsg, Decimal, Decimal256, Uint128, WasmMsg,
+    };
     use cw_asset::{Asset, AssetInfo};
     use cw_orch::daemon::networks::HARPOON_4;
     use kujira::{bow, fin};
@@ -382,6 +583,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn place_limit_order() {
+        let amount = 100_000u128;
+        let msgs = create_setup()
+            .test_place_limit_order(
+                pool_addr(),
+                Asset::new(AssetInfo::native(DEMO), amount),
+                AssetInfo::native(KUJI),
+                Decimal::from_str("0.2").unwrap(),
+            )
+            .unwrap();
+
+        // The submitted price depends on which asset is the base of the FIN pair
+        let [CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            funds,
+            ..
+        })] = msgs.as_slice()
+        else {
+            panic!("expected a single execute msg, got {msgs:?}");
+        };
+        assert_eq!(contract_addr, SWAP_CONTRACT);
+        assert_eq!(funds, &coins(amount, DEMO));
+    }
+
+    #[test]
+    fn place_limit_order_ask_asset_not_in_pair() {
+        let err = create_setup()
+            .test_place_limit_order(
+                pool_addr(),
+                Asset::new(AssetInfo::native(DEMO), 100_000u128),
+                AssetInfo::native("uusdc"),
+                Decimal::from_str("0.2").unwrap(),
+            )
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            DexError::ArgumentMismatch(denom, _) if denom == "uusdc"
+        ));
+    }
+
+    #[test]
+    fn cancel_order() {
+        let msgs = create_setup()
+            .test_cancel_order(pool_addr(), "12".to_owned())
+            .unwrap();
+
+        expect_eq(
+            vec![wasm_execute(
+                SWAP_CONTRACT,
+                &fin::ExecuteMsg::RetractOrder {
+                    order_idx: Uint128::new(12),
+                    amount: None,
+                    callback: None,
+                },
+                vec![],
+            )
+            .unwrap()
+            .into()],
+            msgs,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn withdraw_filled_order() {
+        let msgs = create_setup()
+            .test_withdraw_filled_order(pool_addr(), "12".to_owned())
+            .unwrap();
+
+        expect_eq(
+            vec![wasm_execute(
+                SWAP_CONTRACT,
+                &fin::ExecuteMsg::WithdrawOrders {
+                    order_idxs: Some(vec![Uint128::new(12)]),
+                    callback: None,
+                },
+                vec![],
+            )
+            .unwrap()
+            .into()],
+            msgs,
+        )
+        .unwrap();
+    }
+
     #[test]
     fn simulate_swap() {
         let amount = 100_000u128;
@@ -394,4 +682,38 @@ mod tests {
             )
             .unwrap();
     }
+
+    #[test]
+    fn spot_price() {
+        let price = create_setup()
+            .test_price(
+                pool_addr(),
+                AssetInfo::native(DEMO),
+                AssetInfo::native(KUJI),
+                PriceKind::Spot,
+            )
+            .unwrap();
+        let inverse_price = create_setup()
+            .test_price(
+                pool_addr(),
+                AssetInfo::native(KUJI),
+                AssetInfo::native(DEMO),
+                PriceKind::Spot,
+            )
+            .unwrap();
+        assert_eq!(price.inv().unwrap(), inverse_price);
+    }
+
+    #[test]
+    fn twap_not_implemented() {
+        let err = create_setup()
+            .test_price(
+                pool_addr(),
+                AssetInfo::native(DEMO),
+                AssetInfo::native(KUJI),
+                PriceKind::Twap { window_seconds: 60 },
+            )
+            .unwrap_err();
+        assert_eq!(err, DexError::NotImplemented(super::FIN.to_owned()));
+    }
 }
@@ -146,6 +146,23 @@ impl MoneyMarketCommand for Ghost {
         Ok(vec![msg.into()])
     }
 
+    fn liquidate(
+        &self,
+        _deps: Deps,
+        market_addr: Addr,
+        user: Addr,
+        _debt_asset: Asset, // The market sells the collateral to repay the debt itself
+        _collateral_asset: AssetInfo, // market_addr is already collateral asset specific
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let vault_msg = market::ExecuteMsg::Liquidate(market::LiquidateMsg {
+            position_holder: user,
+        });
+
+        let msg = wasm_execute(market_addr, &vault_msg, vec![])?;
+
+        Ok(vec![msg.into()])
+    }
+
     fn price(
         &self,
         deps: Deps,
@@ -278,6 +295,23 @@ impl MoneyMarketCommand for Ghost {
         Ok(query_response.max_ltv)
     }
 
+    fn liquidation_threshold(
+        &self,
+        deps: Deps,
+        market_addr: Addr,
+        _user: Addr,                // This info is not user specific in this money market
+        _borrowed_asset: AssetInfo, // market_addr is already borrowed asset specific
+        _collateral_asset: AssetInfo, // market_addr is already collateral asset specific
+    ) -> Result<Decimal, MoneyMarketError> {
+        let market_msg = market::QueryMsg::Config {};
+
+        let query_response: market::ConfigResponse =
+            deps.querier.query_wasm_smart(market_addr, &market_msg)?;
+
+        // Positions become liquidatable as soon as they exceed the max LTV
+        Ok(query_response.max_ltv)
+    }
+
     fn lending_address(
         &self,
         querier: &QuerierWrapper,
@@ -326,6 +360,16 @@ impl MoneyMarketCommand for Ghost {
     ) -> Result<Addr, AnsHostError> {
         self.market_address(querier, ans_host, borrowed_asset, collateral_asset)
     }
+
+    fn liquidation_threshold_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        borrowed_asset: AssetEntry,
+        collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        self.market_address(querier, ans_host, borrowed_asset, collateral_asset)
+    }
 }
 
 #[cfg(feature = "full_integration")]
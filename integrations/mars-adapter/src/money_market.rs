@@ -26,7 +26,7 @@ use {
         feature_objects::AnsHost,
         std::objects::{ans_host::AnsHostError, AssetEntry, ContractEntry},
     },
-    cosmwasm_std::{wasm_execute, CosmosMsg, Decimal, Deps, QuerierWrapper, Uint128},
+    cosmwasm_std::{wasm_execute, CosmosMsg, Decimal, Decimal256, Deps, QuerierWrapper, Uint128},
     cw_asset::{Asset, AssetInfo},
 };
 
@@ -140,6 +140,25 @@ impl MoneyMarketCommand for Mars {
         Ok(vec![msg.into()])
     }
 
+    fn liquidate(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        debt_asset: Asset,
+        collateral_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let vault_msg = mars_red_bank_types::red_bank::ExecuteMsg::Liquidate {
+            user: user.to_string(),
+            collateral_denom: unwrap_native(collateral_asset)?,
+            recipient: None,
+        };
+
+        let msg = wasm_execute(contract_addr, &vault_msg, vec![debt_asset.try_into()?])?;
+
+        Ok(vec![msg.into()])
+    }
+
     fn price(
         &self,
         deps: Deps,
@@ -271,6 +290,65 @@ impl MoneyMarketCommand for Mars {
         ))
     }
 
+    fn liquidation_threshold(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo, // Threshold is global on Mars and doesn't depend on borrowing asset
+        _collateral_asset: AssetInfo, // Threshold is global on Mars and doesn't depend on collateral asset
+    ) -> Result<Decimal, MoneyMarketError> {
+        let market_msg = mars_red_bank_types::red_bank::QueryMsg::UserPosition {
+            user: user.to_string(),
+        };
+
+        let query_response: mars_red_bank_types::red_bank::UserPositionResponse =
+            deps.querier.query_wasm_smart(contract_addr, &market_msg)?;
+
+        if query_response.total_enabled_collateral.is_zero() {
+            return Ok(Decimal::zero());
+        }
+
+        Ok(Decimal::from_ratio(
+            query_response.weighted_liquidation_threshold_collateral,
+            query_response.total_enabled_collateral,
+        ))
+    }
+
+    fn position_value(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        _collateral_asset: AssetInfo, // Positions are global on Mars
+    ) -> Result<(Decimal256, Decimal256), MoneyMarketError> {
+        let market_msg = mars_red_bank_types::red_bank::QueryMsg::UserPosition {
+            user: user.to_string(),
+        };
+
+        let query_response: mars_red_bank_types::red_bank::UserPositionResponse =
+            deps.querier.query_wasm_smart(contract_addr, &market_msg)?;
+
+        // Position values are in the oracle base currency
+        let oracle_contract = &self.oracle_contract.clone().unwrap();
+        let borrowed_price: mars_red_bank_types::oracle::PriceResponse =
+            deps.querier.query_wasm_smart(
+                oracle_contract,
+                &mars_red_bank_types::oracle::QueryMsg::Price {
+                    denom: unwrap_native(borrowed_asset)?,
+                },
+            )?;
+
+        let borrowed_price = Decimal256::from(borrowed_price.price);
+        Ok((
+            Decimal256::from_ratio(query_response.total_enabled_collateral, 1u128)
+                .checked_div(borrowed_price)?,
+            Decimal256::from_ratio(query_response.total_collateralized_debt, 1u128)
+                .checked_div(borrowed_price)?,
+        ))
+    }
+
     fn lending_address(
         &self,
         querier: &QuerierWrapper,
@@ -319,6 +397,16 @@ impl MoneyMarketCommand for Mars {
     ) -> Result<Addr, AnsHostError> {
         self.red_bank(querier, ans_host)
     }
+
+    fn liquidation_threshold_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        self.red_bank(querier, ans_host)
+    }
 }
 
 #[cfg(feature = "full_integration")]
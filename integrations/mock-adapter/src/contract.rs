@@ -0,0 +1,810 @@
+//! Mock protocol contract serving the mock dex, money-market and staking platforms.
+//!
+//! The contract has to hold the assets it pays out: swap returns, LP tokens, borrows and rewards.
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, BankMsg, Binary, CheckedFromRatioError, Coin,
+    ConversionOverflowError, Decimal, Decimal256, DecimalRangeExceeded, Deps, DepsMut, Env,
+    MessageInfo, Order, OverflowError, Response, StdError, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::{Item, Map};
+use cw_utils::PaymentError;
+
+use crate::{
+    lp_denom,
+    msg::{
+        ExecuteMsg, InstantiateMsg, MarketConfig, Pool, PositionResponse, QueryMsg, RewardRate,
+        SimulateSwapResponse,
+    },
+};
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const SWAP_FEE: Item<Decimal> = Item::new("swap_fee");
+pub const PRICES: Map<&str, Decimal> = Map::new("prices");
+pub const MARKETS: Map<&str, MarketConfig> = Map::new("markets");
+pub const REWARD_RATES: Map<&str, RewardRate> = Map::new("reward_rates");
+/// Liquidity pools by LP denom
+pub const POOLS: Map<&str, Pool> = Map::new("pools");
+pub const DEPOSITS: Map<(&Addr, &str), Uint128> = Map::new("deposits");
+pub const COLLATERAL: Map<(&Addr, &str), Uint128> = Map::new("collateral");
+pub const DEBTS: Map<(&Addr, &str), Uint128> = Map::new("debts");
+pub const STAKES: Map<(&Addr, &str), Stake> = Map::new("stakes");
+/// Accrued rewards of a user per reward denom
+pub const REWARDS: Map<(&Addr, &str), Uint128> = Map::new("rewards");
+
+#[cosmwasm_schema::cw_serde]
+pub struct Stake {
+    pub amount: Uint128,
+    /// Time up to which the rewards of the stake are accrued
+    pub accrued_at: Timestamp,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum MockError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Payment(#[from] PaymentError),
+
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error(transparent)]
+    FromRatio(#[from] CheckedFromRatioError),
+
+    #[error(transparent)]
+    DecimalRangeExceeded(#[from] DecimalRangeExceeded),
+
+    #[error(transparent)]
+    ConversionOverflow(#[from] ConversionOverflowError),
+
+    #[error("Only the owner can configure the mock protocol")]
+    Unauthorized {},
+
+    #[error("No price set for {0}")]
+    PriceNotSet(String),
+
+    #[error("No market for collateral {0}")]
+    MarketNotSet(String),
+
+    #[error("Swap returns {return_amount} before fees, below the minimum of {min_return}")]
+    MaxSpreadExceeded {
+        return_amount: Uint128,
+        min_return: Uint128,
+    },
+
+    #[error("Insufficient {denom}, {available} available")]
+    Insufficient { denom: String, available: Uint128 },
+
+    #[error("Position LTV {ltv} exceeds the max LTV {max_ltv}")]
+    MaxLtvExceeded { ltv: Decimal, max_ltv: Decimal },
+
+    #[error("Position of {0} can't be liquidated")]
+    NotLiquidatable(String),
+
+    #[error("Liquidity has to be provided in at least two denoms")]
+    TooFewAssets {},
+
+    #[error("Provided liquidity is worth less than one LP token")]
+    NoLiquidityMinted {},
+
+    #[error("No liquidity pool for LP token {0}")]
+    PoolNotFound(String),
+}
+
+pub type MockResult<T = Response> = Result<T, MockError>;
+
+pub fn instantiate(deps: DepsMut, _env: Env, info: MessageInfo, msg: InstantiateMsg) -> MockResult {
+    OWNER.save(deps.storage, &info.sender)?;
+    SWAP_FEE.save(deps.storage, &msg.swap_fee)?;
+    for (denom, price) in msg.prices {
+        PRICES.save(deps.storage, &denom, &price)?;
+    }
+    for (denom, market) in msg.markets {
+        MARKETS.save(deps.storage, &denom, &market)?;
+    }
+    for (denom, rate) in msg.reward_rates {
+        REWARD_RATES.save(deps.storage, &denom, &rate)?;
+    }
+    Ok(Response::new())
+}
+
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> MockResult {
+    let sender = info.sender.clone();
+    match msg {
+        ExecuteMsg::Swap {
+            ask_denom,
+            belief_price,
+            max_spread,
+        } => {
+            let offer = cw_utils::one_coin(&info)?;
+            let swap = simulate_swap(deps.as_ref(), &offer, &ask_denom)?;
+            if let Some(belief_price) = belief_price {
+                let tolerance = Decimal::one().saturating_sub(max_spread.unwrap_or_default());
+                let min_return: Uint128 = Decimal256::from_ratio(offer.amount, 1u128)
+                    .checked_div(belief_price.into())?
+                    .checked_mul(tolerance.into())?
+                    .to_uint_floor()
+                    .try_into()?;
+                let return_amount = swap.return_amount + swap.commission;
+                if return_amount < min_return {
+                    return Err(MockError::MaxSpreadExceeded {
+                        return_amount,
+                        min_return,
+                    });
+                }
+            }
+            Ok(send(&sender, coin(swap.return_amount.u128(), ask_denom))
+                .add_attribute("action", "swap"))
+        }
+        ExecuteMsg::ProvideLiquidity {} => {
+            if info.funds.len() < 2 {
+                return Err(MockError::TooFewAssets {});
+            }
+            let denoms: Vec<&str> = info.funds.iter().map(|c| c.denom.as_str()).collect();
+            let lp_denom = lp_denom(&denoms);
+            let mut pool = POOLS.may_load(deps.storage, &lp_denom)?.unwrap_or_default();
+            let provided = total_value(deps.storage, &info.funds)?;
+            // The first provider gets an LP token per unit of account
+            let minted = if pool.supply.is_zero() {
+                provided
+            } else {
+                provided
+                    .checked_mul(Decimal256::from_ratio(pool.supply, 1u128))?
+                    .checked_div(total_value(deps.storage, &pool.reserves)?)?
+            };
+            let minted: Uint128 = minted.to_uint_floor().try_into()?;
+            if minted.is_zero() {
+                return Err(MockError::NoLiquidityMinted {});
+            }
+            for asset in info.funds {
+                match pool.reserves.iter_mut().find(|r| r.denom == asset.denom) {
+                    Some(reserve) => reserve.amount = reserve.amount.checked_add(asset.amount)?,
+                    None => pool.reserves.push(asset),
+                }
+            }
+            pool.supply = pool.supply.checked_add(minted)?;
+            POOLS.save(deps.storage, &lp_denom, &pool)?;
+            Ok(send(&sender, coin(minted.u128(), lp_denom))
+                .add_attribute("action", "provide_liquidity"))
+        }
+        ExecuteMsg::WithdrawLiquidity {} => {
+            let lp_token = cw_utils::one_coin(&info)?;
+            let mut pool = POOLS
+                .may_load(deps.storage, &lp_token.denom)?
+                .ok_or_else(|| MockError::PoolNotFound(lp_token.denom.clone()))?;
+            let supply = pool.supply;
+            pool.supply = remaining(supply, &lp_token)?;
+            let mut withdrawn = vec![];
+            for reserve in pool.reserves.iter_mut() {
+                let amount = reserve.amount.multiply_ratio(lp_token.amount, supply);
+                reserve.amount -= amount;
+                if !amount.is_zero() {
+                    withdrawn.push(coin(amount.u128(), &reserve.denom));
+                }
+            }
+            POOLS.save(deps.storage, &lp_token.denom, &pool)?;
+            withdrawn.sort_by(|a, b| a.denom.cmp(&b.denom));
+            Ok(Response::new()
+                .add_message(BankMsg::Send {
+                    to_address: sender.into_string(),
+                    amount: withdrawn,
+                })
+                .add_attribute("action", "withdraw_liquidity"))
+        }
+        ExecuteMsg::Deposit {} => {
+            let asset = cw_utils::one_coin(&info)?;
+            add(deps.storage, DEPOSITS, &sender, &asset)?;
+            Ok(Response::new().add_attribute("action", "deposit"))
+        }
+        ExecuteMsg::Withdraw { asset } => {
+            subtract(deps.storage, DEPOSITS, &sender, &asset)?;
+            Ok(send(&sender, asset).add_attribute("action", "withdraw"))
+        }
+        ExecuteMsg::ProvideCollateral {} => {
+            let asset = cw_utils::one_coin(&info)?;
+            MARKETS
+                .may_load(deps.storage, &asset.denom)?
+                .ok_or_else(|| MockError::MarketNotSet(asset.denom.clone()))?;
+            add(deps.storage, COLLATERAL, &sender, &asset)?;
+            Ok(Response::new().add_attribute("action", "provide_collateral"))
+        }
+        ExecuteMsg::WithdrawCollateral { asset } => {
+            subtract(deps.storage, COLLATERAL, &sender, &asset)?;
+            assert_below_max_ltv(deps.as_ref(), &sender)?;
+            Ok(send(&sender, asset).add_attribute("action", "withdraw_collateral"))
+        }
+        ExecuteMsg::Borrow { asset } => {
+            add(deps.storage, DEBTS, &sender, &asset)?;
+            assert_below_max_ltv(deps.as_ref(), &sender)?;
+            Ok(send(&sender, asset).add_attribute("action", "borrow"))
+        }
+        ExecuteMsg::Repay {} => {
+            let asset = cw_utils::one_coin(&info)?;
+            subtract(deps.storage, DEBTS, &sender, &asset)?;
+            Ok(Response::new().add_attribute("action", "repay"))
+        }
+        ExecuteMsg::Liquidate {
+            user,
+            collateral_denom,
+        } => {
+            let user = deps.api.addr_validate(&user)?;
+            let position = position(deps.as_ref(), &user)?;
+            if position.debt_value.is_zero() || position.ltv <= position.liquidation_threshold {
+                return Err(MockError::NotLiquidatable(user.into_string()));
+            }
+            let repaid = cw_utils::one_coin(&info)?;
+            subtract(deps.storage, DEBTS, &user, &repaid)?;
+            // Collateral of the same value as the repaid debt
+            let rate = price(deps.storage, &repaid.denom, &collateral_denom)?;
+            let seized = coin(repaid.amount.mul_floor(rate).u128(), collateral_denom);
+            subtract(deps.storage, COLLATERAL, &user, &seized)?;
+            Ok(send(&sender, seized).add_attribute("action", "liquidate"))
+        }
+        ExecuteMsg::Stake {} => {
+            let asset = cw_utils::one_coin(&info)?;
+            accrue_rewards(deps.storage, &env, &sender)?;
+            let mut stake = load_stake(deps.storage, &env, &sender, &asset.denom)?;
+            stake.amount += asset.amount;
+            STAKES.save(deps.storage, (&sender, &asset.denom), &stake)?;
+            Ok(Response::new().add_attribute("action", "stake"))
+        }
+        ExecuteMsg::Unstake { asset } => {
+            accrue_rewards(deps.storage, &env, &sender)?;
+            let mut stake = load_stake(deps.storage, &env, &sender, &asset.denom)?;
+            stake.amount = remaining(stake.amount, &asset)?;
+            STAKES.save(deps.storage, (&sender, &asset.denom), &stake)?;
+            Ok(send(&sender, asset).add_attribute("action", "unstake"))
+        }
+        ExecuteMsg::ClaimRewards {} => {
+            accrue_rewards(deps.storage, &env, &sender)?;
+            let rewards = user_coins(deps.storage, REWARDS, &sender)?;
+            for reward in &rewards {
+                REWARDS.remove(deps.storage, (&sender, &reward.denom));
+            }
+            let mut response = Response::new().add_attribute("action", "claim_rewards");
+            if !rewards.is_empty() {
+                response = response.add_message(BankMsg::Send {
+                    to_address: sender.into_string(),
+                    amount: rewards,
+                });
+            }
+            Ok(response)
+        }
+        ExecuteMsg::SetPrice { denom, price } => {
+            assert_owner(deps.as_ref(), &sender)?;
+            PRICES.save(deps.storage, &denom, &price)?;
+            Ok(Response::new())
+        }
+        ExecuteMsg::SetSwapFee { fee } => {
+            assert_owner(deps.as_ref(), &sender)?;
+            SWAP_FEE.save(deps.storage, &fee)?;
+            Ok(Response::new())
+        }
+        ExecuteMsg::SetMarket { denom, market } => {
+            assert_owner(deps.as_ref(), &sender)?;
+            MARKETS.save(deps.storage, &denom, &market)?;
+            Ok(Response::new())
+        }
+        ExecuteMsg::SetRewardRate {
+            staking_denom,
+            rate,
+        } => {
+            assert_owner(deps.as_ref(), &sender)?;
+            REWARD_RATES.save(deps.storage, &staking_denom, &rate)?;
+            Ok(Response::new())
+        }
+    }
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> MockResult<Binary> {
+    let binary = match msg {
+        QueryMsg::Price { base, quote } => to_json_binary(&price(deps.storage, &base, &quote)?),
+        QueryMsg::SimulateSwap { offer, ask_denom } => {
+            to_json_binary(&simulate_swap(deps, &offer, &ask_denom)?)
+        }
+        QueryMsg::Pool { lp_denom } => {
+            to_json_binary(&POOLS.may_load(deps.storage, &lp_denom)?.unwrap_or_default())
+        }
+        QueryMsg::Position { user } => {
+            to_json_binary(&position(deps, &deps.api.addr_validate(&user)?)?)
+        }
+        QueryMsg::Market { denom } => to_json_binary(
+            &MARKETS
+                .may_load(deps.storage, &denom)?
+                .ok_or(MockError::MarketNotSet(denom))?,
+        ),
+        QueryMsg::Staked { user, denom } => {
+            let user = deps.api.addr_validate(&user)?;
+            to_json_binary(&load_stake(deps.storage, &env, &user, &denom)?.amount)
+        }
+        QueryMsg::PendingRewards { user } => {
+            let user = deps.api.addr_validate(&user)?;
+            let mut rewards = user_coins(deps.storage, REWARDS, &user)?;
+            for accrued in accrued_rewards(deps.storage, &env, &user)? {
+                match rewards.iter_mut().find(|r| r.denom == accrued.denom) {
+                    Some(reward) => reward.amount += accrued.amount,
+                    None => rewards.push(accrued),
+                }
+            }
+            to_json_binary(&rewards)
+        }
+        QueryMsg::RewardRate { staking_denom } => {
+            to_json_binary(&REWARD_RATES.may_load(deps.storage, &staking_denom)?)
+        }
+    }?;
+    Ok(binary)
+}
+
+fn send(to: &Addr, asset: Coin) -> Response {
+    Response::new().add_message(BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![asset],
+    })
+}
+
+fn assert_owner(deps: Deps, sender: &Addr) -> MockResult<()> {
+    if OWNER.load(deps.storage)? != *sender {
+        return Err(MockError::Unauthorized {});
+    }
+    Ok(())
+}
+
+/// Price of `base` in units of `quote`
+fn price(storage: &dyn Storage, base: &str, quote: &str) -> MockResult<Decimal> {
+    let load = |denom: &str| -> MockResult<Decimal> {
+        PRICES
+            .may_load(storage, denom)?
+            .ok_or_else(|| MockError::PriceNotSet(denom.to_string()))
+    };
+    Ok(load(base)?.checked_div(load(quote)?)?)
+}
+
+/// Value of `asset` in the unit of account
+fn value(storage: &dyn Storage, asset: &Coin) -> MockResult<Decimal256> {
+    let price = PRICES
+        .may_load(storage, &asset.denom)?
+        .ok_or_else(|| MockError::PriceNotSet(asset.denom.clone()))?;
+    Ok(Decimal256::from_ratio(asset.amount, 1u128).checked_mul(price.into())?)
+}
+
+fn total_value(storage: &dyn Storage, assets: &[Coin]) -> MockResult<Decimal256> {
+    assets.iter().try_fold(Decimal256::zero(), |total, asset| {
+        Ok(total.checked_add(value(storage, asset)?)?)
+    })
+}
+
+fn simulate_swap(deps: Deps, offer: &Coin, ask_denom: &str) -> MockResult<SimulateSwapResponse> {
+    let gross = offer
+        .amount
+        .mul_floor(price(deps.storage, &offer.denom, ask_denom)?);
+    let commission = gross.mul_ceil(SWAP_FEE.load(deps.storage)?);
+    Ok(SimulateSwapResponse {
+        return_amount: gross - commission,
+        commission,
+    })
+}
+
+fn add(
+    storage: &mut dyn Storage,
+    map: Map<(&Addr, &str), Uint128>,
+    user: &Addr,
+    asset: &Coin,
+) -> MockResult<()> {
+    map.update(storage, (user, &asset.denom), |amount| {
+        amount.unwrap_or_default().checked_add(asset.amount)
+    })?;
+    Ok(())
+}
+
+fn subtract(
+    storage: &mut dyn Storage,
+    map: Map<(&Addr, &str), Uint128>,
+    user: &Addr,
+    asset: &Coin,
+) -> MockResult<()> {
+    let available = map
+        .may_load(storage, (user, &asset.denom))?
+        .unwrap_or_default();
+    let remaining = remaining(available, asset)?;
+    if remaining.is_zero() {
+        map.remove(storage, (user, &asset.denom));
+    } else {
+        map.save(storage, (user, &asset.denom), &remaining)?;
+    }
+    Ok(())
+}
+
+/// Amount remaining of `available` after taking `asset`
+fn remaining(available: Uint128, asset: &Coin) -> MockResult<Uint128> {
+    available
+        .checked_sub(asset.amount)
+        .map_err(|_| MockError::Insufficient {
+            denom: asset.denom.clone(),
+            available,
+        })
+}
+
+fn user_coins(
+    storage: &dyn Storage,
+    map: Map<(&Addr, &str), Uint128>,
+    user: &Addr,
+) -> MockResult<Vec<Coin>> {
+    map.prefix(user)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin::new(amount, denom))
+        })
+        .collect()
+}
+
+/// Position of `user`, with LTVs over all of its collateral and debt.
+fn position(deps: Deps, user: &Addr) -> MockResult<PositionResponse> {
+    let collateral = user_coins(deps.storage, COLLATERAL, user)?;
+    let debts = user_coins(deps.storage, DEBTS, user)?;
+
+    let mut collateral_value = Decimal256::zero();
+    let mut borrow_limit = Decimal256::zero();
+    let mut liquidation_limit = Decimal256::zero();
+    for asset in &collateral {
+        let market = MARKETS
+            .may_load(deps.storage, &asset.denom)?
+            .ok_or_else(|| MockError::MarketNotSet(asset.denom.clone()))?;
+        let asset_value = value(deps.storage, asset)?;
+        collateral_value = collateral_value.checked_add(asset_value)?;
+        borrow_limit = borrow_limit.checked_add(asset_value.checked_mul(market.max_ltv.into())?)?;
+        liquidation_limit = liquidation_limit
+            .checked_add(asset_value.checked_mul(market.liquidation_threshold.into())?)?;
+    }
+    let debt_value = total_value(deps.storage, &debts)?;
+
+    let ratio = |part: Decimal256| -> MockResult<Decimal256> {
+        if collateral_value.is_zero() {
+            Ok(Decimal256::zero())
+        } else {
+            Ok(part.checked_div(collateral_value)?)
+        }
+    };
+    // The LTV of a position without collateral, or that exceeds the Decimal range, is capped
+    let ltv = if collateral_value.is_zero() && !debt_value.is_zero() {
+        Decimal::MAX
+    } else {
+        Decimal::try_from(ratio(debt_value)?).unwrap_or(Decimal::MAX)
+    };
+    Ok(PositionResponse {
+        deposits: user_coins(deps.storage, DEPOSITS, user)?,
+        collateral,
+        debts,
+        collateral_value,
+        debt_value,
+        ltv,
+        max_ltv: ratio(borrow_limit)?.try_into()?,
+        liquidation_threshold: ratio(liquidation_limit)?.try_into()?,
+    })
+}
+
+fn assert_below_max_ltv(deps: Deps, user: &Addr) -> MockResult<()> {
+    let position = position(deps, user)?;
+    if !position.debt_value.is_zero() && position.ltv > position.max_ltv {
+        return Err(MockError::MaxLtvExceeded {
+            ltv: position.ltv,
+            max_ltv: position.max_ltv,
+        });
+    }
+    Ok(())
+}
+
+fn load_stake(storage: &dyn Storage, env: &Env, user: &Addr, denom: &str) -> MockResult<Stake> {
+    Ok(STAKES.may_load(storage, (user, denom))?.unwrap_or(Stake {
+        amount: Uint128::zero(),
+        accrued_at: env.block.time,
+    }))
+}
+
+/// Rewards of the stakes of `user` since they were last accrued
+fn accrued_rewards(storage: &dyn Storage, env: &Env, user: &Addr) -> MockResult<Vec<Coin>> {
+    let mut rewards: Vec<Coin> = vec![];
+    for item in STAKES
+        .prefix(user)
+        .range(storage, None, None, Order::Ascending)
+    {
+        let (denom, stake) = item?;
+        let Some(rate) = REWARD_RATES.may_load(storage, &denom)? else {
+            continue;
+        };
+        let seconds = env.block.time.seconds() - stake.accrued_at.seconds();
+        let amount = stake
+            .amount
+            .checked_mul(seconds.into())?
+            .mul_floor(rate.per_second);
+        match rewards.iter_mut().find(|reward| reward.denom == rate.denom) {
+            Some(reward) => reward.amount += amount,
+            None => rewards.push(Coin::new(amount, rate.denom)),
+        }
+    }
+    Ok(rewards
+        .into_iter()
+        .filter(|reward| !reward.amount.is_zero())
+        .collect())
+}
+
+fn accrue_rewards(storage: &mut dyn Storage, env: &Env, user: &Addr) -> MockResult<()> {
+    for reward in accrued_rewards(storage, env, user)? {
+        add(storage, REWARDS, user, &reward)?;
+    }
+    let stakes = STAKES
+        .prefix(user)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+    for (denom, mut stake) in stakes {
+        stake.accrued_at = env.block.time;
+        STAKES.save(storage, (user, &denom), &stake)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{coins, from_json, OwnedDeps};
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn setup() -> (MockDeps, Addr) {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {
+                swap_fee: Decimal::percent(1),
+                prices: vec![
+                    ("atom".to_string(), Decimal::percent(1000)),
+                    ("usd".to_string(), Decimal::one()),
+                ],
+                markets: vec![(
+                    "atom".to_string(),
+                    MarketConfig {
+                        max_ltv: Decimal::percent(50),
+                        liquidation_threshold: Decimal::percent(80),
+                    },
+                )],
+                reward_rates: vec![(
+                    "atom".to_string(),
+                    RewardRate {
+                        denom: "usd".to_string(),
+                        per_second: Decimal::percent(10),
+                    },
+                )],
+            },
+        )
+        .unwrap();
+        (deps, owner)
+    }
+
+    fn exec(deps: &mut MockDeps, sender: &Addr, funds: &[Coin], msg: ExecuteMsg) -> MockResult {
+        execute(deps.as_mut(), mock_env(), message_info(sender, funds), msg)
+    }
+
+    /// Provide 10 atom, worth 100 usd, as collateral and borrow 50 usd
+    fn borrow_max(deps: &mut MockDeps) -> Addr {
+        let user = deps.api.addr_make("user");
+        exec(
+            deps,
+            &user,
+            &coins(10, "atom"),
+            ExecuteMsg::ProvideCollateral {},
+        )
+        .unwrap();
+        exec(
+            deps,
+            &user,
+            &[],
+            ExecuteMsg::Borrow {
+                asset: coin(50, "usd"),
+            },
+        )
+        .unwrap();
+        user
+    }
+
+    #[test]
+    fn swap_converts_at_price_minus_fee() {
+        let (deps, _) = setup();
+        let swap = simulate_swap(deps.as_ref(), &coin(10, "atom"), "usd").unwrap();
+        assert_eq!(
+            swap,
+            SimulateSwapResponse {
+                return_amount: Uint128::new(99),
+                commission: Uint128::one(),
+            }
+        );
+    }
+
+    #[test]
+    fn liquidity_is_minted_by_value_and_withdrawn_by_share() {
+        let (mut deps, _) = setup();
+        let user = deps.api.addr_make("user");
+        let lp_denom = lp_denom(&["usd", "atom"]);
+
+        // 10 atom and 100 usd are worth 200 usd
+        let res = exec(
+            &mut deps,
+            &user,
+            &[coin(10, "atom"), coin(100, "usd")],
+            ExecuteMsg::ProvideLiquidity {},
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            BankMsg::Send {
+                to_address: user.to_string(),
+                amount: coins(200, &lp_denom),
+            }
+            .into()
+        );
+
+        let res = exec(
+            &mut deps,
+            &user,
+            &coins(100, &lp_denom),
+            ExecuteMsg::WithdrawLiquidity {},
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            BankMsg::Send {
+                to_address: user.to_string(),
+                amount: vec![coin(5, "atom"), coin(50, "usd")],
+            }
+            .into()
+        );
+        let pool: Pool = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Pool {
+                    lp_denom: lp_denom.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pool.supply, Uint128::new(100));
+        assert_eq!(pool.reserves, vec![coin(5, "atom"), coin(50, "usd")]);
+
+        let err = exec(
+            &mut deps,
+            &user,
+            &coins(10, "atom"),
+            ExecuteMsg::ProvideLiquidity {},
+        )
+        .unwrap_err();
+        assert_eq!(err, MockError::TooFewAssets {});
+    }
+
+    #[test]
+    fn borrow_is_limited_by_max_ltv() {
+        let (mut deps, _) = setup();
+        let user = borrow_max(&mut deps);
+        let err = exec(
+            &mut deps,
+            &user,
+            &[],
+            ExecuteMsg::Borrow {
+                asset: coin(1, "usd"),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, MockError::MaxLtvExceeded { .. }));
+    }
+
+    #[test]
+    fn positions_of_18_decimal_tokens_are_valued() {
+        let (mut deps, _) = setup();
+        let user = deps.api.addr_make("user");
+        // 1M atom with 18 decimals, worth 10M usd
+        let collateral = 10u128.pow(24);
+        exec(
+            &mut deps,
+            &user,
+            &coins(collateral, "atom"),
+            ExecuteMsg::ProvideCollateral {},
+        )
+        .unwrap();
+        exec(
+            &mut deps,
+            &user,
+            &[],
+            ExecuteMsg::Borrow {
+                asset: coin(collateral * 5, "usd"),
+            },
+        )
+        .unwrap();
+
+        let position = position(deps.as_ref(), &user).unwrap();
+        assert_eq!(
+            position.collateral_value,
+            Decimal256::from_ratio(collateral * 10, 1u128)
+        );
+        assert_eq!(position.ltv, Decimal::percent(50));
+        assert_eq!(position.max_ltv, Decimal::percent(50));
+    }
+
+    #[test]
+    fn liquidation_above_threshold_seizes_collateral() {
+        let (mut deps, owner) = setup();
+        let user = borrow_max(&mut deps);
+        let liquidate = ExecuteMsg::Liquidate {
+            user: user.to_string(),
+            collateral_denom: "atom".to_string(),
+        };
+        let err = exec(&mut deps, &owner, &coins(10, "usd"), liquidate.clone()).unwrap_err();
+        assert_eq!(err, MockError::NotLiquidatable(user.to_string()));
+
+        // Collateral price halves, the LTV rises to 100%
+        exec(
+            &mut deps,
+            &owner,
+            &[],
+            ExecuteMsg::SetPrice {
+                denom: "atom".to_string(),
+                price: Decimal::percent(500),
+            },
+        )
+        .unwrap();
+        exec(&mut deps, &owner, &coins(10, "usd"), liquidate).unwrap();
+        let position = position(deps.as_ref(), &user).unwrap();
+        assert_eq!(position.collateral, coins(8, "atom"));
+        assert_eq!(position.debts, coins(40, "usd"));
+    }
+
+    #[test]
+    fn stakes_accrue_rewards_per_second() {
+        let (mut deps, _) = setup();
+        let user = deps.api.addr_make("user");
+        let mut env = mock_env();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&user, &coins(100, "atom")),
+            ExecuteMsg::Stake {},
+        )
+        .unwrap();
+        env.block.time = env.block.time.plus_seconds(5);
+
+        let pending: Vec<Coin> = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::PendingRewards {
+                    user: user.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pending, coins(50, "usd"));
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&user, &[]),
+            ExecuteMsg::ClaimRewards {},
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            BankMsg::Send {
+                to_address: user.to_string(),
+                amount: coins(50, "usd"),
+            }
+            .into()
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use abstract_dex_standard::Identify;
+
+use crate::{AVAILABLE_CHAINS, MOCK};
+
+/// Swaps at the configured prices of the mock protocol, without spread.
+/// Pools are registered on ANS with the mock protocol as their contract address, liquidity
+/// is provided at the configured prices as well.
+#[derive(Default)]
+pub struct MockDex {}
+
+impl Identify for MockDex {
+    fn name(&self) -> &'static str {
+        MOCK
+    }
+    fn is_available_on(&self, chain_name: &str) -> bool {
+        AVAILABLE_CHAINS.contains(&chain_name)
+    }
+}
+
+#[cfg(feature = "full_integration")]
+use ::{
+    abstract_dex_standard::{
+        msg::PriceKind, DexCommand, DexError, Fee, FeeOnInput, Return, Spread,
+    },
+    abstract_sdk::std::objects::PoolAddress,
+    cosmwasm_std::{wasm_execute, Coin, CosmosMsg, Decimal, Deps, Env, Uint128},
+    cw_asset::{Asset, AssetInfo},
+};
+
+#[cfg(feature = "full_integration")]
+use crate::msg::{ExecuteMsg, QueryMsg, SimulateSwapResponse};
+
+#[cfg(feature = "full_integration")]
+impl DexCommand for MockDex {
+    fn swap(
+        &self,
+        _deps: Deps,
+        pool_id: PoolAddress,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let protocol = pool_id.expect_contract()?;
+        let swap_msg = ExecuteMsg::Swap {
+            ask_denom: denom(&ask_asset)?,
+            belief_price,
+            max_spread,
+        };
+        let offer = Coin::try_from(offer_asset)?;
+        Ok(vec![wasm_execute(protocol, &swap_msg, vec![offer])?.into()])
+    }
+
+    fn provide_liquidity(
+        &self,
+        _deps: Deps,
+        pool_id: PoolAddress,
+        offer_assets: Vec<Asset>,
+        _max_spread: Option<Decimal>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let protocol = pool_id.expect_contract()?;
+        let mut funds = offer_assets
+            .into_iter()
+            .map(Coin::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+        Ok(vec![wasm_execute(
+            protocol,
+            &ExecuteMsg::ProvideLiquidity {},
+            funds,
+        )?
+        .into()])
+    }
+
+    fn withdraw_liquidity(
+        &self,
+        _deps: Deps,
+        pool_id: PoolAddress,
+        lp_token: Asset,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let protocol = pool_id.expect_contract()?;
+        let lp_token = Coin::try_from(lp_token)?;
+        Ok(vec![wasm_execute(
+            protocol,
+            &ExecuteMsg::WithdrawLiquidity {},
+            vec![lp_token],
+        )?
+        .into()])
+    }
+
+    fn simulate_swap(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+    ) -> Result<(Return, Spread, Fee, FeeOnInput), DexError> {
+        let protocol = pool_id.expect_contract()?;
+        let simulation: SimulateSwapResponse = deps.querier.query_wasm_smart(
+            protocol,
+            &QueryMsg::SimulateSwap {
+                offer: Coin::try_from(offer_asset)?,
+                ask_denom: denom(&ask_asset)?,
+            },
+        )?;
+        // The fee is taken from the returned asset
+        Ok((
+            simulation.return_amount,
+            Uint128::zero(),
+            simulation.commission,
+            false,
+        ))
+    }
+
+    /// Prices are set on the mock protocol, so the TWAP equals the spot price.
+    fn price(
+        &self,
+        deps: Deps,
+        _env: &Env,
+        pool_id: PoolAddress,
+        base: AssetInfo,
+        quote: AssetInfo,
+        _kind: &PriceKind,
+    ) -> Result<Decimal, DexError> {
+        let protocol = pool_id.expect_contract()?;
+        let price = deps.querier.query_wasm_smart(
+            protocol,
+            &QueryMsg::Price {
+                base: denom(&base)?,
+                quote: denom(&quote)?,
+            },
+        )?;
+        Ok(price)
+    }
+}
+
+#[cfg(feature = "full_integration")]
+fn denom(asset: &AssetInfo) -> Result<String, DexError> {
+    match asset {
+        AssetInfo::Native(denom) => Ok(denom.clone()),
+        _ => Err(DexError::UnsupportedAssetType(asset.to_string())),
+    }
+}
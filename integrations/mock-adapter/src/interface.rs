@@ -0,0 +1,87 @@
+//! cw-orch interface of the mock protocol, to deploy it in `MockBech32` tests.
+use abstract_interface::Abstract;
+use abstract_sdk::std::{
+    ans_host::ExecuteMsgFns,
+    objects::{
+        pool_id::PoolAddressBase, AssetEntry, LpToken, PoolMetadata, UncheckedContractEntry,
+    },
+};
+use cw_asset::AssetInfoBase;
+use cw_orch::{interface, prelude::*};
+
+use crate::{
+    contract, lp_denom,
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    protocol_entry, MOCK,
+};
+
+pub const MOCK_PROTOCOL: &str = "mock:protocol";
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+pub struct MockProtocol<Chain>;
+
+impl<Chain: CwEnv> Uploadable for MockProtocol<Chain> {
+    fn wrapper() -> <Mock as TxHandler>::ContractSource {
+        Box::new(ContractWrapper::new_with_empty(
+            contract::execute,
+            contract::instantiate,
+            contract::query,
+        ))
+    }
+}
+
+impl<Chain: CwEnv> MockProtocol<Chain> {
+    /// Upload and instantiate the mock protocol.
+    /// Fund it with the assets it pays out: swap returns, LP tokens, borrows and rewards.
+    pub fn deploy(chain: Chain, msg: &InstantiateMsg) -> Result<Self, CwOrchError> {
+        let protocol = Self::new(MOCK_PROTOCOL, chain);
+        protocol.upload()?;
+        protocol.instantiate(msg, None, &[])?;
+        Ok(protocol)
+    }
+
+    /// Register the mock protocol and its native `assets`, as `(name, denom)`, on ANS.
+    /// Every pair of assets gets a pool on the mock dex, with its LP token.
+    pub fn register_on_ans(
+        &self,
+        abstr: &Abstract<Chain>,
+        assets: &[(&str, &str)],
+    ) -> Result<(), CwOrchError> {
+        let protocol_entry = protocol_entry();
+        let mut asset_entries: Vec<_> = assets
+            .iter()
+            .map(|(name, denom)| (name.to_string(), AssetInfoBase::native(*denom)))
+            .collect();
+        for (i, (base, base_denom)) in assets.iter().enumerate() {
+            for (quote, quote_denom) in &assets[i + 1..] {
+                asset_entries.push((
+                    LpToken::new(MOCK, vec![*base, *quote]).to_string(),
+                    AssetInfoBase::native(lp_denom(&[*base_denom, *quote_denom])),
+                ));
+            }
+        }
+        abstr
+            .ans_host
+            .update_asset_addresses(asset_entries, vec![])?;
+        abstr.ans_host.update_contract_addresses(
+            vec![(
+                UncheckedContractEntry::new(protocol_entry.protocol, protocol_entry.contract),
+                self.addr_str()?,
+            )],
+            vec![],
+        )?;
+        abstr.ans_host.update_dexes(vec![MOCK.into()], vec![])?;
+
+        let mut pools = vec![];
+        for (i, (base, _)) in assets.iter().enumerate() {
+            for (quote, _) in &assets[i + 1..] {
+                pools.push((
+                    PoolAddressBase::contract(self.addr_str()?),
+                    PoolMetadata::stable(MOCK, vec![AssetEntry::new(base), AssetEntry::new(quote)]),
+                ));
+            }
+        }
+        abstr.ans_host.update_pools(pools, vec![])?;
+        Ok(())
+    }
+}
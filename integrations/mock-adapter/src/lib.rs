@@ -0,0 +1,36 @@
+//! Lightweight, deterministic platforms to test applications that depend on the dex,
+//! money-market or staking adapters without external protocol code.
+//!
+//! All platforms are served by a single [`contract`] that is registered on ANS under the
+//! [`protocol_entry`]. Enable the `testing` feature to deploy it with cw-orch.
+use abstract_sdk::std::objects::ContractEntry;
+
+pub const AVAILABLE_CHAINS: &[&str] = abstract_sdk::std::constants::LOCAL_CHAIN;
+pub const MOCK: &str = "mock";
+
+pub mod dex;
+pub mod money_market;
+pub mod staking;
+
+#[cfg(feature = "full_integration")]
+pub mod contract;
+#[cfg(feature = "full_integration")]
+pub mod msg;
+
+#[cfg(feature = "testing")]
+pub mod interface;
+
+/// Denom of the LP token of the mock pool of `denoms`, in any order
+pub fn lp_denom(denoms: &[&str]) -> String {
+    let mut denoms = denoms.to_vec();
+    denoms.sort_unstable();
+    format!("{MOCK}/lp/{}", denoms.join("/"))
+}
+
+/// ANS entry of the mock protocol contract
+pub fn protocol_entry() -> ContractEntry {
+    ContractEntry {
+        protocol: MOCK.to_string(),
+        contract: "protocol".to_string(),
+    }
+}
@@ -0,0 +1,341 @@
+use abstract_money_market_standard::Identify;
+use cosmwasm_std::Addr;
+
+use crate::{AVAILABLE_CHAINS, MOCK};
+
+/// Lends and borrows at the configured prices and LTVs of the mock protocol.
+/// LTVs are over all collateral and debt of the user, whatever the asset pair.
+#[derive(Default)]
+pub struct MockMoneyMarket {
+    /// Address of the mock protocol, prices are queried from it
+    pub protocol: Option<Addr>,
+}
+
+impl Identify for MockMoneyMarket {
+    fn name(&self) -> &'static str {
+        MOCK
+    }
+    fn is_available_on(&self, chain_name: &str) -> bool {
+        AVAILABLE_CHAINS.contains(&chain_name)
+    }
+}
+
+#[cfg(feature = "full_integration")]
+use ::{
+    abstract_money_market_standard::{MoneyMarketCommand, MoneyMarketError},
+    abstract_sdk::{
+        feature_objects::AnsHost,
+        std::objects::{ans_host::AnsHostError, AssetEntry},
+    },
+    cosmwasm_std::{wasm_execute, Coin, CosmosMsg, Decimal, Deps, QuerierWrapper, Uint128},
+    cw_asset::{Asset, AssetInfo},
+};
+
+#[cfg(feature = "full_integration")]
+use crate::{
+    msg::{ExecuteMsg, PositionResponse, QueryMsg},
+    protocol_entry,
+};
+
+#[cfg(feature = "full_integration")]
+impl MoneyMarketCommand for MockMoneyMarket {
+    fn fetch_data(
+        &mut self,
+        _addr_as_sender: Addr,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+    ) -> Result<(), MoneyMarketError> {
+        self.protocol = Some(ans_host.query_contract(querier, &protocol_entry())?);
+        Ok(())
+    }
+
+    fn lending_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &protocol_entry())
+    }
+
+    fn collateral_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _borrowed_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &protocol_entry())
+    }
+
+    fn borrow_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _borrowed_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &protocol_entry())
+    }
+
+    fn deposit(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        lending_asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = wasm_execute(
+            contract_addr,
+            &ExecuteMsg::Deposit {},
+            vec![lending_asset.try_into()?],
+        )?;
+        Ok(vec![msg.into()])
+    }
+
+    fn withdraw(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        receipt_asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        // Lending has no receipt token, the lent asset is withdrawn directly
+        let msg = wasm_execute(
+            contract_addr,
+            &ExecuteMsg::Withdraw {
+                asset: receipt_asset.try_into()?,
+            },
+            vec![],
+        )?;
+        Ok(vec![msg.into()])
+    }
+
+    fn provide_collateral(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = wasm_execute(
+            contract_addr,
+            &ExecuteMsg::ProvideCollateral {},
+            vec![asset.try_into()?],
+        )?;
+        Ok(vec![msg.into()])
+    }
+
+    fn withdraw_collateral(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = wasm_execute(
+            contract_addr,
+            &ExecuteMsg::WithdrawCollateral {
+                asset: asset.try_into()?,
+            },
+            vec![],
+        )?;
+        Ok(vec![msg.into()])
+    }
+
+    fn borrow(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = wasm_execute(
+            contract_addr,
+            &ExecuteMsg::Borrow {
+                asset: asset.try_into()?,
+            },
+            vec![],
+        )?;
+        Ok(vec![msg.into()])
+    }
+
+    fn repay(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = wasm_execute(
+            contract_addr,
+            &ExecuteMsg::Repay {},
+            vec![asset.try_into()?],
+        )?;
+        Ok(vec![msg.into()])
+    }
+
+    fn liquidate(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        debt_asset: Asset,
+        collateral_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = wasm_execute(
+            contract_addr,
+            &ExecuteMsg::Liquidate {
+                user: user.into_string(),
+                collateral_denom: denom(&collateral_asset)?,
+            },
+            vec![debt_asset.try_into()?],
+        )?;
+        Ok(vec![msg.into()])
+    }
+
+    fn price(
+        &self,
+        deps: Deps,
+        base: AssetInfo,
+        quote: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        let protocol = self
+            .protocol
+            .clone()
+            .ok_or(MoneyMarketError::ContractAddressEmpty)?;
+        let price = deps.querier.query_wasm_smart(
+            protocol,
+            &QueryMsg::Price {
+                base: denom(&base)?,
+                quote: denom(&quote)?,
+            },
+        )?;
+        Ok(price)
+    }
+
+    fn user_deposit(
+        &self,
+        deps: Deps,
+        lending_addr: Addr,
+        user: Addr,
+        asset: AssetInfo,
+    ) -> Result<Uint128, MoneyMarketError> {
+        let position = position(deps, lending_addr, user)?;
+        amount_of(&position.deposits, &asset)
+    }
+
+    fn user_collateral(
+        &self,
+        deps: Deps,
+        collateral_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+    ) -> Result<Uint128, MoneyMarketError> {
+        let position = position(deps, collateral_addr, user)?;
+        amount_of(&position.collateral, &collateral_asset)
+    }
+
+    fn user_borrow(
+        &self,
+        deps: Deps,
+        borrow_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        _collateral_asset: AssetInfo,
+    ) -> Result<Uint128, MoneyMarketError> {
+        let position = position(deps, borrow_addr, user)?;
+        amount_of(&position.debts, &borrowed_asset)
+    }
+
+    fn current_ltv(
+        &self,
+        deps: Deps,
+        current_ltv_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo,
+        _collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        Ok(position(deps, current_ltv_addr, user)?.ltv)
+    }
+
+    fn current_ltv_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _borrowed_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &protocol_entry())
+    }
+
+    fn max_ltv(
+        &self,
+        deps: Deps,
+        max_ltv_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo,
+        _collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        Ok(position(deps, max_ltv_addr, user)?.max_ltv)
+    }
+
+    fn max_ltv_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &protocol_entry())
+    }
+
+    fn liquidation_threshold(
+        &self,
+        deps: Deps,
+        liquidation_threshold_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo,
+        _collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        Ok(position(deps, liquidation_threshold_addr, user)?.liquidation_threshold)
+    }
+
+    fn liquidation_threshold_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _borrowed_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &protocol_entry())
+    }
+}
+
+#[cfg(feature = "full_integration")]
+fn position(
+    deps: Deps,
+    contract_addr: Addr,
+    user: Addr,
+) -> Result<PositionResponse, MoneyMarketError> {
+    let position = deps.querier.query_wasm_smart(
+        contract_addr,
+        &QueryMsg::Position {
+            user: user.into_string(),
+        },
+    )?;
+    Ok(position)
+}
+
+#[cfg(feature = "full_integration")]
+fn amount_of(coins: &[Coin], asset: &AssetInfo) -> Result<Uint128, MoneyMarketError> {
+    let denom = denom(asset)?;
+    Ok(coins
+        .iter()
+        .find(|coin| coin.denom == denom)
+        .map(|coin| coin.amount)
+        .unwrap_or_default())
+}
+
+#[cfg(feature = "full_integration")]
+fn denom(asset: &AssetInfo) -> Result<String, MoneyMarketError> {
+    match asset {
+        AssetInfo::Native(denom) => Ok(denom.clone()),
+        _ => Err(MoneyMarketError::UnsupportedAssetType(asset.to_string())),
+    }
+}
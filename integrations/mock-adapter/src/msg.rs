@@ -0,0 +1,144 @@
+//! Messages of the mock protocol contract.
+//!
+//! Prices are expressed in a common unit of account, so the price of `base` in `quote` is
+//! `price(base) / price(quote)`. Only native assets are supported.
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Coin, Decimal, Decimal256, Uint128};
+
+/// Loan-to-Value ratios of a collateral asset
+#[cosmwasm_schema::cw_serde]
+pub struct MarketConfig {
+    /// Maximum LTV a position can borrow up to with this collateral
+    pub max_ltv: Decimal,
+    /// LTV above which a position with this collateral can be liquidated
+    pub liquidation_threshold: Decimal,
+}
+
+/// Rewards distributed to the stakers of a staking token
+#[cosmwasm_schema::cw_serde]
+pub struct RewardRate {
+    /// Denom of the reward token
+    pub denom: String,
+    /// Reward tokens per staked token per second
+    pub per_second: Decimal,
+}
+
+/// Liquidity pool of a set of denoms, with the LP token [`lp_denom`](crate::lp_denom)
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct Pool {
+    pub reserves: Vec<Coin>,
+    /// LP tokens in circulation
+    pub supply: Uint128,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct InstantiateMsg {
+    /// Fee taken from the returned asset of a swap
+    pub swap_fee: Decimal,
+    /// Price of each denom
+    pub prices: Vec<(String, Decimal)>,
+    /// Market of each collateral denom
+    pub markets: Vec<(String, MarketConfig)>,
+    /// Reward rate of each staking denom
+    pub reward_rates: Vec<(String, RewardRate)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub enum ExecuteMsg {
+    /// Swap the sent funds to `ask_denom`.
+    /// The return before fees can be at most `max_spread` below the return at `belief_price`.
+    Swap {
+        ask_denom: String,
+        /// Price of the ask asset in units of the offer asset
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+    },
+    /// Provide the sent funds as liquidity to the pool of their denoms.
+    /// LP tokens are minted for the value of the funds, relative to the value of the pool.
+    ProvideLiquidity {},
+    /// Withdraw the share of the pool reserves of the sent LP tokens
+    WithdrawLiquidity {},
+    /// Lend the sent funds
+    Deposit {},
+    /// Withdraw lent funds
+    Withdraw { asset: Coin },
+    /// Provide the sent funds as collateral
+    ProvideCollateral {},
+    /// Withdraw collateral, the position must stay below its max LTV
+    WithdrawCollateral { asset: Coin },
+    /// Borrow against the collateral, the position must stay below its max LTV
+    Borrow { asset: Coin },
+    /// Repay debt with the sent funds
+    Repay {},
+    /// Repay debt of `user` with the sent funds, receiving collateral of the same value
+    Liquidate {
+        user: String,
+        collateral_denom: String,
+    },
+    /// Stake the sent funds
+    Stake {},
+    /// Unstake, without unbonding period
+    Unstake { asset: Coin },
+    /// Claim the rewards of all stakes
+    ClaimRewards {},
+    /// Owner: set the price of `denom`
+    SetPrice { denom: String, price: Decimal },
+    /// Owner: set the swap fee
+    SetSwapFee { fee: Decimal },
+    /// Owner: set the market of a collateral denom
+    SetMarket { denom: String, market: MarketConfig },
+    /// Owner: set the reward rate of a staking denom
+    SetRewardRate {
+        staking_denom: String,
+        rate: RewardRate,
+    },
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Price of `base` in units of `quote`
+    #[returns(Decimal)]
+    Price { base: String, quote: String },
+    #[returns(SimulateSwapResponse)]
+    SimulateSwap { offer: Coin, ask_denom: String },
+    /// Pool of the `lp_denom`, empty if no liquidity was provided
+    #[returns(Pool)]
+    Pool { lp_denom: String },
+    /// Lending, collateral and debt of `user`
+    #[returns(PositionResponse)]
+    Position { user: String },
+    #[returns(MarketConfig)]
+    Market { denom: String },
+    #[returns(Uint128)]
+    Staked { user: String, denom: String },
+    /// Rewards of `user` that can be claimed
+    #[returns(Vec<Coin>)]
+    PendingRewards { user: String },
+    #[returns(Option<RewardRate>)]
+    RewardRate { staking_denom: String },
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct SimulateSwapResponse {
+    pub return_amount: Uint128,
+    pub commission: Uint128,
+}
+
+/// Position of a user, values are in the unit of account.
+/// LTVs are over all collateral and debt of the user.
+#[cosmwasm_schema::cw_serde]
+pub struct PositionResponse {
+    pub deposits: Vec<Coin>,
+    pub collateral: Vec<Coin>,
+    pub debts: Vec<Coin>,
+    pub collateral_value: Decimal256,
+    pub debt_value: Decimal256,
+    /// Debt value over collateral value
+    pub ltv: Decimal,
+    /// Collateral weighted max LTV
+    pub max_ltv: Decimal,
+    /// Collateral weighted liquidation threshold
+    pub liquidation_threshold: Decimal,
+}
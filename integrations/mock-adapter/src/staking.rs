@@ -0,0 +1,233 @@
+use abstract_sdk::std::objects::AssetEntry;
+use abstract_staking_standard::Identify;
+use cosmwasm_std::Addr;
+
+use crate::{AVAILABLE_CHAINS, MOCK};
+
+/// Stakes on the mock protocol, which distributes rewards at the configured reward rates.
+/// Unstaking has no unbonding period.
+#[derive(Clone, Debug, Default)]
+pub struct MockStaking {
+    pub tokens: Vec<MockTokenContext>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MockTokenContext {
+    pub staking_token: AssetEntry,
+    pub denom: String,
+    pub protocol: Addr,
+}
+
+impl Identify for MockStaking {
+    fn name(&self) -> &'static str {
+        MOCK
+    }
+    fn is_available_on(&self, chain_name: &str) -> bool {
+        AVAILABLE_CHAINS.contains(&chain_name)
+    }
+}
+
+#[cfg(feature = "full_integration")]
+use {
+    crate::{
+        msg::{ExecuteMsg, QueryMsg, RewardRate},
+        protocol_entry,
+    },
+    abstract_sdk::{
+        feature_objects::{AnsHost, RegistryContract},
+        std::objects::AnsAsset,
+        Resolve,
+    },
+    abstract_staking_standard::msg::{
+        RewardTokensResponse, StakeResponse, StakingInfo, StakingInfoResponse, UnbondingResponse,
+    },
+    abstract_staking_standard::{CwStakingCommand, CwStakingError},
+    cosmwasm_std::{
+        coin, coins, wasm_execute, CosmosMsg, Deps, Env, QuerierWrapper, StdError, Uint128,
+    },
+    cw_asset::AssetInfo,
+};
+
+#[cfg(feature = "full_integration")]
+impl MockStaking {
+    fn token(&self, staking_token: &AssetEntry) -> Result<&MockTokenContext, CwStakingError> {
+        self.tokens
+            .iter()
+            .find(|t| &t.staking_token == staking_token)
+            .ok_or_else(|| {
+                StdError::generic_err(format!("staking token {staking_token} not fetched")).into()
+            })
+    }
+}
+
+#[cfg(feature = "full_integration")]
+impl CwStakingCommand for MockStaking {
+    /// All staking tokens are staked on the mock protocol
+    fn staking_contract_address(
+        &self,
+        deps: Deps,
+        ans_host: &AnsHost,
+        _token: &AssetEntry,
+    ) -> Result<Addr, CwStakingError> {
+        ans_host
+            .query_contract(&deps.querier, &protocol_entry())
+            .map_err(Into::into)
+    }
+
+    fn fetch_data(
+        &mut self,
+        deps: Deps,
+        _env: Env,
+        _addr_as_sender: Option<Addr>,
+        ans_host: &AnsHost,
+        _registry_contract: RegistryContract,
+        staking_assets: Vec<AssetEntry>,
+    ) -> Result<(), CwStakingError> {
+        self.tokens = staking_assets
+            .into_iter()
+            .map(|staking_token| {
+                let AssetInfo::Native(denom) = staking_token.resolve(&deps.querier, ans_host)?
+                else {
+                    return Err(
+                        StdError::generic_err("expected denom as token for staking.").into(),
+                    );
+                };
+                let protocol = self.staking_contract_address(deps, ans_host, &staking_token)?;
+                Ok(MockTokenContext {
+                    staking_token,
+                    denom,
+                    protocol,
+                })
+            })
+            .collect::<Result<_, CwStakingError>>()?;
+        Ok(())
+    }
+
+    fn stake(
+        &self,
+        _deps: Deps,
+        stake_request: Vec<AnsAsset>,
+        _unbonding_period: Option<cw_utils::Duration>,
+    ) -> Result<Vec<CosmosMsg>, CwStakingError> {
+        stake_request
+            .into_iter()
+            .map(|stake| {
+                let token = self.token(&stake.name)?;
+                let msg = wasm_execute(
+                    &token.protocol,
+                    &ExecuteMsg::Stake {},
+                    coins(stake.amount.u128(), &token.denom),
+                )?;
+                Ok(msg.into())
+            })
+            .collect()
+    }
+
+    fn unstake(
+        &self,
+        _deps: Deps,
+        unstake_request: Vec<AnsAsset>,
+        _unbonding_period: Option<cw_utils::Duration>,
+    ) -> Result<Vec<CosmosMsg>, CwStakingError> {
+        unstake_request
+            .into_iter()
+            .map(|unstake| {
+                let token = self.token(&unstake.name)?;
+                let msg = wasm_execute(
+                    &token.protocol,
+                    &ExecuteMsg::Unstake {
+                        asset: coin(unstake.amount.u128(), &token.denom),
+                    },
+                    vec![],
+                )?;
+                Ok(msg.into())
+            })
+            .collect()
+    }
+
+    /// Claims the rewards of all stakes on the mock protocol
+    fn claim_rewards(&self, _deps: Deps) -> Result<Vec<CosmosMsg>, CwStakingError> {
+        let Some(token) = self.tokens.first() else {
+            return Ok(vec![]);
+        };
+        let msg = wasm_execute(&token.protocol, &ExecuteMsg::ClaimRewards {}, vec![])?;
+        Ok(vec![msg.into()])
+    }
+
+    /// Unstaked tokens are returned immediately, there is nothing to claim
+    fn claim(&self, _deps: Deps) -> Result<Vec<CosmosMsg>, CwStakingError> {
+        Ok(vec![])
+    }
+
+    fn query_info(&self, _querier: &QuerierWrapper) -> Result<StakingInfoResponse, CwStakingError> {
+        let infos = self
+            .tokens
+            .iter()
+            .map(|t| StakingInfo {
+                staking_target: t.protocol.clone().into(),
+                staking_token: AssetInfo::Native(t.denom.clone()),
+                unbonding_periods: None,
+                max_claims: None,
+            })
+            .collect();
+
+        Ok(StakingInfoResponse { infos })
+    }
+
+    fn query_staked(
+        &self,
+        querier: &QuerierWrapper,
+        staker: Addr,
+        _stakes: Vec<AssetEntry>,
+        _unbonding_period: Option<cw_utils::Duration>,
+    ) -> Result<StakeResponse, CwStakingError> {
+        let amounts = self
+            .tokens
+            .iter()
+            .map(|t| {
+                querier.query_wasm_smart::<Uint128>(
+                    &t.protocol,
+                    &QueryMsg::Staked {
+                        user: staker.to_string(),
+                        denom: t.denom.clone(),
+                    },
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(StakeResponse { amounts })
+    }
+
+    fn query_unbonding(
+        &self,
+        _querier: &QuerierWrapper,
+        _staker: Addr,
+    ) -> Result<UnbondingResponse, CwStakingError> {
+        Ok(UnbondingResponse {
+            claims: vec![vec![]; self.tokens.len()],
+        })
+    }
+
+    fn query_rewards(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> Result<RewardTokensResponse, CwStakingError> {
+        let tokens = self
+            .tokens
+            .iter()
+            .map(|t| {
+                let rate: Option<RewardRate> = querier.query_wasm_smart(
+                    &t.protocol,
+                    &QueryMsg::RewardRate {
+                        staking_denom: t.denom.clone(),
+                    },
+                )?;
+                Ok(rate
+                    .map(|rate| vec![AssetInfo::Native(rate.denom)])
+                    .unwrap_or_default())
+            })
+            .collect::<Result<_, CwStakingError>>()?;
+
+        Ok(RewardTokensResponse { tokens })
+    }
+}
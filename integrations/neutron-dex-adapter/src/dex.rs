@@ -19,12 +19,18 @@ impl Identify for Neutron {
 
 #[cfg(feature = "full_integration")]
 use ::{
-    abstract_dex_standard::{DexCommand, DexError, Fee, FeeOnInput, Return, Spread, SwapNode},
+    abstract_dex_standard::{
+        msg::Order, DexCommand, DexError, Fee, FeeOnInput, Return, Spread, SwapNode,
+    },
     abstract_sdk::feature_objects::{AnsHost, RegistryContract},
     abstract_sdk::std::objects::PoolAddress,
-    cosmwasm_std::{CosmosMsg, Decimal, Deps},
-    cw_asset::{Asset, AssetInfo},
-    neutron_std::types::neutron::dex::{MsgMultiHopSwap, MultiHopRoute},
+    cosmwasm_std::{CosmosMsg, Decimal, Deps, Empty, StdError, Uint128},
+    cw_asset::{Asset, AssetBase, AssetInfo, AssetInfoBase},
+    neutron_std::types::neutron::dex::{
+        DexQuerier, LimitOrderType, MsgCancelLimitOrder, MsgMultiHopSwap, MsgPlaceLimitOrder,
+        MsgWithdrawFilledLimitOrder, MultiHopRoute,
+    },
+    std::str::FromStr,
 };
 
 #[cfg(feature = "full_integration")]
@@ -115,7 +121,7 @@ impl DexCommand for Neutron {
         _offer_assets: Vec<Asset>,
         _max_spread: Option<Decimal>,
     ) -> Result<Vec<CosmosMsg>, DexError> {
-        unimplemented!();
+        Err(DexError::NotImplemented(self.name().to_owned()))
     }
 
     fn withdraw_liquidity(
@@ -124,7 +130,7 @@ impl DexCommand for Neutron {
         _pool_id: PoolAddress,
         _lp_token: Asset,
     ) -> Result<Vec<CosmosMsg>, DexError> {
-        unimplemented!();
+        Err(DexError::NotImplemented(self.name().to_owned()))
     }
 
     fn simulate_swap(
@@ -134,6 +140,149 @@ impl DexCommand for Neutron {
         _offer_asset: Asset,
         _ask_asset: AssetInfo,
     ) -> Result<(Return, Spread, Fee, FeeOnInput), DexError> {
-        unimplemented!();
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    fn place_limit_order(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let pool_denoms = pool_denoms(&DexQuerier::new(&deps.querier), pool_id.expect_id()?)?;
+        let (offer_denom, ask_denom) = (offer_asset.info.inner(), ask_asset.inner());
+        if offer_denom == ask_denom
+            || !pool_denoms.contains(&offer_denom)
+            || !pool_denoms.contains(&ask_denom)
+        {
+            let mismatched = if pool_denoms.contains(&offer_denom) {
+                ask_denom
+            } else {
+                offer_denom
+            };
+            return Err(DexError::ArgumentMismatch(mismatched, pool_denoms.to_vec()));
+        }
+        let sender = self
+            .addr_as_sender
+            .as_ref()
+            .expect("no local account")
+            .to_string();
+        let order_msg = MsgPlaceLimitOrder {
+            creator: sender.clone(),
+            receiver: sender,
+            token_in: offer_denom,
+            token_out: ask_denom,
+            amount_in: offer_asset.amount.to_string(),
+            order_type: LimitOrderType::GoodTilCancelled as i32,
+            limit_sell_price: price.to_string(),
+            ..Default::default()
+        };
+        Ok(vec![order_msg.into()])
+    }
+
+    fn cancel_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let cancel_msg = MsgCancelLimitOrder {
+            creator: self
+                .addr_as_sender
+                .as_ref()
+                .expect("no local account")
+                .to_string(),
+            tranche_key: order_id,
+        };
+        Ok(vec![cancel_msg.into()])
+    }
+
+    fn withdraw_filled_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let withdraw_msg = MsgWithdrawFilledLimitOrder {
+            creator: self
+                .addr_as_sender
+                .as_ref()
+                .expect("no local account")
+                .to_string(),
+            tranche_key: order_id,
+        };
+        Ok(vec![withdraw_msg.into()])
+    }
+
+    fn orders(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        account: Addr,
+    ) -> Result<Vec<Order>, DexError> {
+        let querier = DexQuerier::new(&deps.querier);
+        let pool_denoms = pool_denoms(&querier, pool_id.expect_id()?)?;
+        let response =
+            querier.limit_order_tranche_user_all_by_address(account.to_string(), None)?;
+
+        response
+            .limit_orders
+            .into_iter()
+            .filter_map(|order| Some((order.trade_pair_id.clone()?, order)))
+            .filter(|(trade_pair, _)| {
+                pool_denoms.contains(&trade_pair.maker_denom)
+                    && pool_denoms.contains(&trade_pair.taker_denom)
+            })
+            .map(|(trade_pair, order)| {
+                let tranche = querier
+                    .limit_order_tranche(
+                        pair_id(&trade_pair.maker_denom, &trade_pair.taker_denom),
+                        order.tick_index_taker_to_maker,
+                        trade_pair.maker_denom.clone(),
+                        order.tranche_key.clone(),
+                    )?
+                    .limit_order_tranche
+                    .ok_or_else(|| {
+                        StdError::not_found(format!("limit order tranche {}", order.tranche_key))
+                    })?;
+                // Share of the tranche's unfilled reserves owned by the account
+                let shares = Uint128::from_str(&order.shares_owned)?
+                    .checked_sub(Uint128::from_str(&order.shares_cancelled)?)
+                    .map_err(StdError::from)?;
+                let offer_amount = Uint128::from_str(&tranche.reserves_maker_denom)?
+                    .checked_multiply_ratio(shares, Uint128::from_str(&tranche.total_maker_denom)?)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                Ok(Order {
+                    id: order.tranche_key,
+                    offer_asset: AssetBase::native(trade_pair.maker_denom, offer_amount),
+                    ask_asset: AssetInfoBase::native(trade_pair.taker_denom),
+                    filled_amount: None,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Denoms of the pair traded in a pool
+#[cfg(feature = "full_integration")]
+fn pool_denoms(querier: &DexQuerier<Empty>, pool_id: u64) -> Result<[String; 2], DexError> {
+    let trade_pair = querier
+        .pool_by_id(pool_id)?
+        .pool
+        .and_then(|pool| pool.lower_tick0.or(pool.upper_tick1))
+        .and_then(|reserves| reserves.key?.trade_pair_id)
+        .ok_or_else(|| StdError::not_found(format!("pool {pool_id}")))?;
+    Ok([trade_pair.maker_denom, trade_pair.taker_denom])
+}
+
+/// Id of the pair of two denoms, in the format "token0<>token1" with sorted denoms
+#[cfg(feature = "full_integration")]
+fn pair_id(denom_a: &str, denom_b: &str) -> String {
+    if denom_a < denom_b {
+        format!("{denom_a}<>{denom_b}")
+    } else {
+        format!("{denom_b}<>{denom_a}")
     }
 }
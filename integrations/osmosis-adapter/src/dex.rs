@@ -19,22 +19,32 @@ impl Identify for Osmosis {
 
 #[cfg(feature = "full_integration")]
 use {
-    abstract_dex_standard::{DexCommand, DexError, Fee, FeeOnInput, Return, Spread, SwapNode},
+    abstract_dex_standard::{
+        msg::{LiquidityPosition, PriceKind},
+        DexCommand, DexError, Fee, FeeOnInput, Return, Spread, SwapNode,
+    },
     abstract_sdk::{
         feature_objects::{AnsHost, RegistryContract},
         std::objects::PoolAddress,
     },
     cosmwasm_std::{
-        Coin, CosmosMsg, Decimal, Decimal256, Deps, StdError, StdResult, Uint128, Uint256,
+        Coin, CosmosMsg, Decimal, Decimal256, Deps, Env, StdError, StdResult, Uint128, Uint256,
     },
-    cw_asset::{Asset, AssetInfo},
+    cw_asset::{Asset, AssetBase, AssetInfo},
     osmosis_std::{
+        types::osmosis::concentratedliquidity::v1beta1::{
+            FullPositionBreakdown, MsgAddToPosition, MsgCollectIncentives, MsgCollectSpreadRewards,
+            MsgCreatePosition, MsgWithdrawPosition, Pool as ClPool, PositionByIdRequest,
+            UserPositionsRequest,
+        },
         types::osmosis::gamm::v1beta1::{MsgExitPool, MsgJoinPool, MsgSwapExactAmountIn},
         types::osmosis::poolmanager::v1beta1::{
-            EstimateSwapExactAmountInRequest, PoolRequest, SwapAmountInRoute,
+            EstimateSwapExactAmountInRequest, PoolRequest, SpotPriceRequest, SwapAmountInRoute,
         },
+        types::osmosis::twap::v1beta1::ArithmeticTwapToNowRequest,
         types::{cosmos::base::v1beta1::Coin as OsmoCoin, osmosis::gamm::v1beta1::Pool},
     },
+    std::str::FromStr,
 };
 
 #[cfg(feature = "full_integration")]
@@ -308,6 +318,290 @@ impl DexCommand for Osmosis {
             false,
         ))
     }
+
+    fn create_position(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        lower_tick: i64,
+        upper_tick: i64,
+        assets: Vec<Asset>,
+        min_assets: Vec<Asset>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let pool_id = pool_id.expect_id()?;
+        let pool = query_cl_pool(deps, pool_id)?;
+        let [token_min_amount0, token_min_amount1] =
+            min_amounts(&min_assets, [&pool.token0, &pool.token1])?;
+        let mut tokens_provided = assets
+            .into_iter()
+            .map(Coin::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        // Coins must be sorted by denom
+        tokens_provided.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        let create_msg: CosmosMsg = MsgCreatePosition {
+            pool_id,
+            sender: self.addr_as_sender.as_ref().unwrap().to_string(),
+            lower_tick,
+            upper_tick,
+            tokens_provided: tokens_provided.into_iter().map(Into::into).collect(),
+            token_min_amount0: token_min_amount0.to_string(),
+            token_min_amount1: token_min_amount1.to_string(),
+        }
+        .into();
+
+        Ok(vec![create_msg])
+    }
+
+    fn add_to_position(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        position_id: u64,
+        assets: Vec<Asset>,
+        min_assets: Vec<Asset>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let pool_id = pool_id.expect_id()?;
+        query_pool_position(deps, pool_id, position_id)?;
+        let pool = query_cl_pool(deps, pool_id)?;
+        // Amounts are provided in the order of the pool assets
+        let [amount0, amount1] = [&pool.token0, &pool.token1].map(|denom| {
+            let info = AssetInfo::native(denom);
+            assets
+                .iter()
+                .find(|asset| asset.info == info)
+                .map(|asset| asset.amount)
+                .unwrap_or_default()
+        });
+        let [token_min_amount0, token_min_amount1] =
+            min_amounts(&min_assets, [&pool.token0, &pool.token1])?;
+
+        let add_msg: CosmosMsg = MsgAddToPosition {
+            position_id,
+            sender: self.addr_as_sender.as_ref().unwrap().to_string(),
+            amount0: amount0.to_string(),
+            amount1: amount1.to_string(),
+            token_min_amount0: token_min_amount0.to_string(),
+            token_min_amount1: token_min_amount1.to_string(),
+        }
+        .into();
+
+        Ok(vec![add_msg])
+    }
+
+    fn withdraw_position(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        position_id: u64,
+        share: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let position = query_pool_position(deps, pool_id.expect_id()?, position_id)?;
+        let liquidity = position_liquidity(&position)?;
+
+        let withdraw_msg: CosmosMsg = MsgWithdrawPosition {
+            position_id,
+            sender: self.addr_as_sender.as_ref().unwrap().to_string(),
+            liquidity_amount: (liquidity * Decimal256::from(share)).to_string(),
+        }
+        .into();
+
+        Ok(vec![withdraw_msg])
+    }
+
+    fn collect_position_rewards(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        position_ids: Vec<u64>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let sender = self.addr_as_sender.as_ref().unwrap().to_string();
+        let spread_rewards_msg: CosmosMsg = MsgCollectSpreadRewards {
+            position_ids: position_ids.clone(),
+            sender: sender.clone(),
+        }
+        .into();
+        let incentives_msg: CosmosMsg = MsgCollectIncentives {
+            position_ids,
+            sender,
+        }
+        .into();
+
+        Ok(vec![spread_rewards_msg, incentives_msg])
+    }
+
+    fn positions(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        account: Addr,
+    ) -> Result<Vec<LiquidityPosition>, DexError> {
+        let response = UserPositionsRequest {
+            address: account.to_string(),
+            pool_id: pool_id.expect_id()?,
+            pagination: None,
+        }
+        .query(&deps.querier)?;
+
+        response
+            .positions
+            .into_iter()
+            .map(|breakdown| {
+                let liquidity = position_liquidity(&breakdown)?;
+                let position = breakdown.position.unwrap_or_default();
+                Ok(LiquidityPosition {
+                    id: position.position_id,
+                    lower_tick: position.lower_tick,
+                    upper_tick: position.upper_tick,
+                    liquidity,
+                    assets: osmo_coins_to_assets(
+                        breakdown.asset0.into_iter().chain(breakdown.asset1),
+                    )?,
+                    claimable_fees: osmo_coins_to_assets(breakdown.claimable_spread_rewards)?,
+                    claimable_incentives: osmo_coins_to_assets(breakdown.claimable_incentives)?,
+                })
+            })
+            .collect()
+    }
+
+    fn price(
+        &self,
+        deps: Deps,
+        env: &Env,
+        pool_id: PoolAddress,
+        base: AssetInfo,
+        quote: AssetInfo,
+        kind: &PriceKind,
+    ) -> Result<Decimal, DexError> {
+        let pool_id = pool_id.expect_id()?;
+        let (AssetInfo::Native(base_denom), AssetInfo::Native(quote_denom)) = (&base, &quote)
+        else {
+            return Err(DexError::UnsupportedAssetType(format!("{base} or {quote}")));
+        };
+
+        let price = match kind {
+            PriceKind::Spot => {
+                SpotPriceRequest {
+                    pool_id,
+                    base_asset_denom: base_denom.clone(),
+                    quote_asset_denom: quote_denom.clone(),
+                }
+                .query(&deps.querier)?
+                .spot_price
+            }
+            PriceKind::Twap { window_seconds } => {
+                let start_seconds = env
+                    .block
+                    .time
+                    .seconds()
+                    .checked_sub(*window_seconds)
+                    .filter(|_| *window_seconds > 0)
+                    .ok_or(DexError::InvalidPriceWindow(*window_seconds))?;
+                ArithmeticTwapToNowRequest {
+                    pool_id,
+                    base_asset: base_denom.clone(),
+                    quote_asset: quote_denom.clone(),
+                    start_time: Some(osmosis_std::shim::Timestamp {
+                        seconds: start_seconds as i64,
+                        nanos: env.block.time.subsec_nanos() as i32,
+                    }),
+                }
+                .query(&deps.querier)?
+                .arithmetic_twap
+            }
+        };
+        parse_osmosis_dec(&price).map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "full_integration")]
+/// Parse an Osmosis decimal, which can have more fractional digits than [`Decimal`] supports
+fn parse_osmosis_dec(value: &str) -> StdResult<Decimal> {
+    let truncated = match value.split_once('.') {
+        Some((whole, fraction)) if fraction.len() > Decimal::DECIMAL_PLACES as usize => {
+            format!("{whole}.{}", &fraction[..Decimal::DECIMAL_PLACES as usize])
+        }
+        _ => value.to_owned(),
+    };
+    Decimal::from_str(&truncated)
+}
+
+#[cfg(feature = "full_integration")]
+fn query_position(deps: Deps, position_id: u64) -> StdResult<FullPositionBreakdown> {
+    PositionByIdRequest { position_id }
+        .query(&deps.querier)?
+        .position
+        .ok_or_else(|| StdError::not_found(format!("position {position_id}")))
+}
+
+/// Position `position_id`, which must be in the pool `pool_id`
+#[cfg(feature = "full_integration")]
+fn query_pool_position(
+    deps: Deps,
+    pool_id: u64,
+    position_id: u64,
+) -> Result<FullPositionBreakdown, DexError> {
+    let position = query_position(deps, position_id)?;
+    if position.position.as_ref().map(|p| p.pool_id) != Some(pool_id) {
+        return Err(DexError::PositionNotInPool {
+            position_id,
+            pool: pool_id.to_string(),
+        });
+    }
+    Ok(position)
+}
+
+#[cfg(feature = "full_integration")]
+fn query_cl_pool(deps: Deps, pool_id: u64) -> StdResult<ClPool> {
+    let pool = PoolRequest { pool_id }
+        .query(&deps.querier)?
+        .pool
+        .ok_or_else(|| StdError::not_found(format!("pool {pool_id}")))?;
+    ClPool::try_from(pool).map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+/// Minimum amounts of the pool assets `denoms`, zero for the assets that aren't listed
+#[cfg(feature = "full_integration")]
+fn min_amounts(min_assets: &[Asset], denoms: [&String; 2]) -> Result<[Uint128; 2], DexError> {
+    let mut amounts = [Uint128::zero(); 2];
+    for min_asset in min_assets {
+        let i = denoms
+            .iter()
+            .position(|denom| min_asset.info == AssetInfo::native(*denom))
+            .ok_or_else(|| {
+                DexError::ArgumentMismatch(
+                    min_asset.info.to_string(),
+                    denoms.iter().map(|denom| denom.to_string()).collect(),
+                )
+            })?;
+        amounts[i] = min_asset.amount;
+    }
+    Ok(amounts)
+}
+
+#[cfg(feature = "full_integration")]
+fn position_liquidity(position: &FullPositionBreakdown) -> StdResult<Decimal256> {
+    let liquidity = position
+        .position
+        .as_ref()
+        .map(|p| p.liquidity.as_str())
+        .unwrap_or("0");
+    Decimal256::from_str(liquidity)
+}
+
+#[cfg(feature = "full_integration")]
+fn osmo_coins_to_assets(
+    coins: impl IntoIterator<Item = OsmoCoin>,
+) -> StdResult<Vec<AssetBase<String>>> {
+    coins
+        .into_iter()
+        .map(|coin| {
+            Ok(AssetBase::native(
+                coin.denom,
+                Uint128::from_str(&coin.amount)?,
+            ))
+        })
+        .collect()
 }
 
 #[cfg(feature = "full_integration")]
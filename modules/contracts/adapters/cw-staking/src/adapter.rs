@@ -6,7 +6,9 @@ use abstract_adapter::sdk::{
     Execution,
 };
 use abstract_staking_standard::{msg::StakingAction, CwStakingCommand, CwStakingError};
-use cosmwasm_std::{DepsMut, Env, SubMsg};
+use cosmwasm_std::{DepsMut, Env, ReplyOn, SubMsg};
+
+use crate::handlers::StakingReplyPayload;
 
 impl<T> CwStakingAdapter for T where T: AbstractNameService + AbstractRegistryAccess + Execution {}
 
@@ -24,11 +26,12 @@ pub trait CwStakingAdapter: AbstractNameService + AbstractRegistryAccess + Execu
         mut provider: Box<dyn CwStakingCommand>,
     ) -> Result<SubMsg, CwStakingError> {
         let staking_asset = staking_assets_from_action(&action);
+        let account_addr = target_account.into_addr();
 
         provider.fetch_data(
             deps.as_ref(),
             env.clone(),
-            Some(target_account.into_addr()),
+            Some(account_addr.clone()),
             &self.ans_host(deps.as_ref())?,
             self.abstract_registry(deps.as_ref())?,
             staking_asset,
@@ -45,6 +48,34 @@ pub trait CwStakingAdapter: AbstractNameService + AbstractRegistryAccess + Execu
             } => provider.unstake(deps.as_ref(), assets, unbonding_period)?,
             StakingAction::ClaimRewards { assets: _ } => provider.claim_rewards(deps.as_ref())?,
             StakingAction::Claim { assets: _ } => provider.claim(deps.as_ref())?,
+            StakingAction::Compound {
+                assets,
+                dex,
+                min_receive,
+                unbonding_period,
+                distributed_rewards: _,
+            } => {
+                // Rewards that are distributed without a claim can't be told apart from the
+                // balance, they have to be compounded as `distributed_rewards`
+                let claim_msgs = provider.claim_rewards(deps.as_ref())?;
+                if claim_msgs.is_empty() {
+                    return Err(CwStakingError::NoRewardsToClaim(provider.name().to_owned()));
+                }
+                // The claimed rewards are compounded once the claim succeeded
+                let payload = StakingReplyPayload::rewards_claimed(
+                    deps.as_ref(),
+                    provider.as_ref(),
+                    account_addr,
+                    assets,
+                    dex,
+                    min_receive,
+                    unbonding_period,
+                )?;
+                return self
+                    .executor(deps.as_ref())
+                    .execute_with_reply_and_payload(claim_msgs, ReplyOn::Success, &payload)
+                    .map_err(Into::into);
+            }
         };
 
         self.executor(deps.as_ref())
@@ -71,5 +102,9 @@ fn staking_assets_from_action(action: &StakingAction) -> Vec<AssetEntry> {
         StakingAction::Claim {
             assets: staking_token,
         } => staking_token.clone(),
+        StakingAction::Compound {
+            assets: staking_tokens,
+            ..
+        } => staking_tokens.clone(),
     }
 }
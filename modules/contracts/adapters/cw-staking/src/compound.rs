@@ -0,0 +1,578 @@
+//! Compounding of staking rewards.
+//!
+//! Claimed rewards are compounded one staking token at a time, each step continues in the reply
+//! of the account execution that precedes it:
+//! 1. Swap the rewards to the staking token, or to the pool assets of an LP staking token.
+//! 2. Provide the pool assets as liquidity for an LP staking token.
+//! 3. Stake the received staking tokens and continue with the next staking token.
+
+use abstract_adapter::sdk::{
+    features::{AbstractNameService, AbstractRegistryAccess},
+    AbstractSdkError, Execution, ModuleInterface, Resolve,
+};
+use abstract_adapter::std::{
+    adapter::AdapterRequestMsg,
+    objects::{AnsAsset, AnsEntryConvertor, AssetEntry, DexAssetPairing},
+};
+use abstract_dex_adapter::{
+    msg::{DexQueryMsg, ExecuteMsg, PriceKind, PriceResponse, QueryMsg, SimulateSwapResponse},
+    DEX_ADAPTER_ID,
+};
+use abstract_dex_standard::ans_action::{DexAnsAction, WholeDexAction};
+use abstract_staking_standard::CwStakingError;
+use cosmwasm_std::{
+    wasm_execute, Addr, CosmosMsg, Decimal, Decimal256, Deps, Env, ReplyOn, Response, Uint128,
+};
+use cw_asset::{Asset, AssetInfo};
+use cw_utils::Duration;
+
+use crate::{
+    contract::{CwStakingAdapter, StakingResult},
+    handlers::StakingReplyPayload,
+    resolver,
+};
+
+/// Maximum spread of the reward swaps, below their simulated return.
+pub(crate) const COMPOUND_MAX_SPREAD: Decimal = Decimal::percent(1);
+
+/// Rewards compounding of an account.
+#[cosmwasm_schema::cw_serde]
+pub struct Compound {
+    pub account: Addr,
+    pub provider: String,
+    /// Dex to swap the rewards on
+    pub dex: String,
+    pub unbonding_period: Option<Duration>,
+    /// Staking tokens left to compound
+    pub targets: Vec<CompoundTarget>,
+}
+
+impl Compound {
+    /// Compounding of the rewards of the staked `assets` of the `account`.
+    pub(crate) fn new(
+        account: Addr,
+        provider: String,
+        assets: Vec<AssetEntry>,
+        dex: String,
+        min_receive: &[AnsAsset],
+        unbonding_period: Option<Duration>,
+    ) -> Self {
+        let targets = assets
+            .into_iter()
+            .map(|staking_token| CompoundTarget {
+                min_receive: min_receive
+                    .iter()
+                    .find(|min| min.name == staking_token)
+                    .map(|min| min.amount)
+                    .unwrap_or_default(),
+                staking_token,
+                rewards: vec![],
+            })
+            .collect();
+        Self {
+            account,
+            provider,
+            dex,
+            unbonding_period,
+            targets,
+        }
+    }
+}
+
+/// Rewards to compound into a staking token.
+#[cosmwasm_schema::cw_serde]
+pub struct CompoundTarget {
+    pub staking_token: AssetEntry,
+    /// Minimum amount of the staking token to stake
+    pub min_receive: Uint128,
+    pub rewards: Vec<Asset>,
+}
+
+/// Allocate the claimed rewards to the staking tokens that earn them.
+/// Rewards earned by several staking tokens are split evenly between them.
+pub(crate) fn allocate_rewards(
+    targets: &mut [CompoundTarget],
+    reward_tokens: &[Vec<AssetInfo>],
+    claimed: Vec<Asset>,
+) {
+    for reward in claimed {
+        let earners: Vec<usize> = reward_tokens
+            .iter()
+            .take(targets.len())
+            .enumerate()
+            .filter(|(_, tokens)| tokens.contains(&reward.info))
+            .map(|(i, _)| i)
+            .collect();
+        let parts = split(reward.amount, earners.len());
+        for (i, amount) in earners.into_iter().zip(parts) {
+            if !amount.is_zero() {
+                targets[i]
+                    .rewards
+                    .push(Asset::new(reward.info.clone(), amount));
+            }
+        }
+    }
+}
+
+/// Split `amount` evenly in `parts`, the last part gets the remainder.
+pub(crate) fn split(amount: Uint128, parts: usize) -> Vec<Uint128> {
+    if parts == 0 {
+        return vec![];
+    }
+    let part = amount / Uint128::from(parts as u128);
+    let mut splits = vec![part; parts];
+    splits[parts - 1] = amount - part * Uint128::from(parts as u128 - 1);
+    splits
+}
+
+/// Split `amount` of a reward into the parts to swap to each pool asset, so that the swaps return
+/// assets of equal value in the pool. `values` are the pool values returned per reward token by
+/// the swap to each asset, the split is even if one of them is unknown or zero.
+pub(crate) fn size_swaps(amount: Uint128, values: &[Option<Decimal256>]) -> Vec<Uint128> {
+    weighted_split(amount, values).unwrap_or_else(|| split(amount, values.len()))
+}
+
+/// Split `amount` inversely proportional to the `values`, the last part gets the remainder.
+fn weighted_split(amount: Uint128, values: &[Option<Decimal256>]) -> Option<Vec<Uint128>> {
+    let weights = values
+        .iter()
+        .map(|value| Decimal256::one().checked_div((*value)?).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let total = weights
+        .iter()
+        .try_fold(Decimal256::zero(), |total, weight| {
+            total.checked_add(*weight).ok()
+        })?;
+    let amount_256 = Decimal256::from_ratio(amount, 1u128);
+    let mut remaining = amount;
+    let mut parts = Vec::with_capacity(weights.len());
+    for weight in &weights[..weights.len().saturating_sub(1)] {
+        let part: Uint128 = amount_256
+            .checked_mul(*weight)
+            .ok()?
+            .checked_div(total)
+            .ok()?
+            .to_uint_floor()
+            .try_into()
+            .ok()?;
+        remaining = remaining.checked_sub(part).ok()?;
+        parts.push(part);
+    }
+    if !weights.is_empty() {
+        parts.push(remaining);
+    }
+    Some(parts)
+}
+
+/// Assets to swap the rewards to: the pool assets of an LP staking token or the staking token.
+pub(crate) fn staking_token_assets(staking_token: &AssetEntry) -> Vec<AssetEntry> {
+    match AnsEntryConvertor::new(staking_token.clone()).lp_token() {
+        Ok(lp_token) => lp_token.assets,
+        Err(_) => vec![staking_token.clone()],
+    }
+}
+
+/// Address of the dex adapter installed on the account, rewards are swapped through it.
+pub(crate) fn dex_adapter(deps: Deps, module: &CwStakingAdapter) -> StakingResult<Addr> {
+    module
+        .modules(deps)
+        .module_address(DEX_ADAPTER_ID)
+        .map_err(|err| match err {
+            AbstractSdkError::MissingModule { .. } => CwStakingError::DexAdapterNotInstalled {},
+            err => err.into(),
+        })
+}
+
+/// Execute a dex action through the dex adapter, executed by the account.
+fn dex_msg(
+    deps: Deps,
+    module: &CwStakingAdapter,
+    dex_adapter: &Addr,
+    dex: String,
+    action: DexAnsAction,
+) -> StakingResult<CosmosMsg> {
+    let request = WholeDexAction(dex, action).resolve(&deps.querier, &module.ans_host(deps)?)?;
+    // The account is the sender, no need to specify it
+    let msg: ExecuteMsg = AdapterRequestMsg::new(None, request).into();
+    Ok(wasm_execute(dex_adapter, &msg, vec![])?.into())
+}
+
+/// Spot prices of the `assets` of an LP staking token in its first asset, on the dex of the pool.
+/// `None` if the staking token isn't an LP token or the dex has no spot price.
+fn pool_prices(
+    deps: Deps,
+    dex_adapter: &Addr,
+    staking_token: &AssetEntry,
+    assets: &[AssetEntry],
+) -> Option<Vec<Decimal256>> {
+    let lp_token = AnsEntryConvertor::new(staking_token.clone())
+        .lp_token()
+        .ok()?;
+    assets
+        .iter()
+        .map(|asset| {
+            if asset == &assets[0] {
+                return Some(Decimal256::one());
+            }
+            let pair = DexAssetPairing::new(asset.clone(), assets[0].clone(), &lp_token.dex);
+            let response: PriceResponse = deps
+                .querier
+                .query_wasm_smart(
+                    dex_adapter,
+                    &QueryMsg::Module(DexQueryMsg::Price {
+                        pair,
+                        kind: PriceKind::Spot,
+                    }),
+                )
+                .ok()?;
+            Some(response.price.into())
+        })
+        .collect()
+}
+
+/// Return of swapping `offer_asset` on the `dex`, simulated by the dex adapter.
+fn simulate_swap(
+    deps: Deps,
+    dex_adapter: &Addr,
+    dex: &str,
+    offer_asset: AnsAsset,
+    ask_asset: &AssetEntry,
+) -> StakingResult<Uint128> {
+    let response: SimulateSwapResponse = deps.querier.query_wasm_smart(
+        dex_adapter,
+        &QueryMsg::Module(DexQueryMsg::SimulateSwap {
+            offer_asset,
+            ask_asset: ask_asset.clone(),
+            dex: dex.to_owned(),
+        }),
+    )?;
+    Ok(response.return_amount)
+}
+
+/// Amount of `ask_asset` returned per offered token by swapping `offer_asset` on the `dex`.
+fn swap_return(
+    deps: Deps,
+    dex_adapter: &Addr,
+    dex: &str,
+    offer_asset: AnsAsset,
+    ask_asset: &AssetEntry,
+) -> Option<Decimal256> {
+    if offer_asset.amount.is_zero() {
+        return None;
+    }
+    let offer_amount = offer_asset.amount;
+    let return_amount = simulate_swap(deps, dex_adapter, dex, offer_asset, ask_asset).ok()?;
+    Some(Decimal256::from_ratio(return_amount, offer_amount))
+}
+
+/// Compound rewards the provider already distributed to the account, without claiming them.
+/// They're split evenly between the staking tokens.
+pub(crate) fn compound_distributed(
+    deps: Deps,
+    env: &Env,
+    module: &CwStakingAdapter,
+    mut compound: Compound,
+    distributed_rewards: Vec<AnsAsset>,
+    response: Response,
+) -> StakingResult {
+    let rewards = distributed_rewards.resolve(&deps.querier, &module.ans_host(deps)?)?;
+    let reward_tokens: Vec<AssetInfo> = rewards.iter().map(|reward| reward.info.clone()).collect();
+    let reward_tokens = vec![reward_tokens; compound.targets.len()];
+    allocate_rewards(&mut compound.targets, &reward_tokens, rewards);
+    compound_next(deps, env, module, compound, response)
+}
+
+/// Compound the rewards of the next staking token, extending the `response`.
+pub(crate) fn compound_next(
+    deps: Deps,
+    env: &Env,
+    module: &CwStakingAdapter,
+    mut compound: Compound,
+    response: Response,
+) -> StakingResult {
+    if compound.targets.is_empty() {
+        return Ok(response);
+    }
+    let target = compound.targets.remove(0);
+    let dex_adapter = dex_adapter(deps, module)?;
+    let ans = module.name_service(deps);
+    let assets = staking_token_assets(&target.staking_token);
+    let asset_infos = ans.query(&assets)?;
+    let prices = match assets.len() {
+        1 => None,
+        _ => pool_prices(deps, &dex_adapter, &target.staking_token, &assets),
+    };
+
+    // Rewards that are already one of the assets and the minimum returns of the swaps, per asset
+    let mut received: Vec<AnsAsset> = assets
+        .iter()
+        .map(|asset| AnsAsset::new(asset.clone(), 0u128))
+        .collect();
+    let mut swaps = vec![];
+    for reward in &target.rewards {
+        let reward_entry = ans
+            .host()
+            .query_asset_reverse(&deps.querier, &reward.info)?;
+        // Pool value returned per reward token by the swap to each asset
+        let values: Vec<Option<Decimal256>> = match &prices {
+            Some(prices) => {
+                let probe = reward.amount / Uint128::from(assets.len() as u128);
+                assets
+                    .iter()
+                    .zip(&asset_infos)
+                    .zip(prices)
+                    .map(|((asset, info), price)| {
+                        let returned = if info == &reward.info {
+                            Decimal256::one()
+                        } else {
+                            swap_return(
+                                deps,
+                                &dex_adapter,
+                                &compound.dex,
+                                AnsAsset::new(reward_entry.clone(), probe),
+                                asset,
+                            )?
+                        };
+                        returned.checked_mul(*price).ok()
+                    })
+                    .collect()
+            }
+            None => vec![None; assets.len()],
+        };
+        let parts = size_swaps(reward.amount, &values);
+        for (i, amount) in parts.into_iter().enumerate() {
+            if amount.is_zero() {
+                continue;
+            }
+            if asset_infos[i] == reward.info {
+                received[i].amount += amount;
+                continue;
+            }
+            let offer_asset = AnsAsset::new(reward_entry.clone(), amount);
+            // The dex adapter enforces the minimum return, so it's available once the swaps succeed
+            let min_receive = simulate_swap(
+                deps,
+                &dex_adapter,
+                &compound.dex,
+                offer_asset.clone(),
+                &assets[i],
+            )?
+            .mul_floor(Decimal::one() - COMPOUND_MAX_SPREAD);
+            received[i].amount += min_receive;
+            swaps.push(dex_msg(
+                deps,
+                module,
+                &dex_adapter,
+                compound.dex.clone(),
+                DexAnsAction::Swap {
+                    offer_asset,
+                    ask_asset: assets[i].clone(),
+                    max_spread: Some(COMPOUND_MAX_SPREAD),
+                    belief_price: None,
+                    min_receive: Some(min_receive),
+                },
+            )?);
+        }
+    }
+    if swaps.is_empty() {
+        return rewards_swapped(deps, env, module, compound, target, received, response);
+    }
+
+    let payload = StakingReplyPayload::RewardsSwapped {
+        compound,
+        target,
+        received,
+    };
+    let account_msg =
+        module
+            .executor(deps)
+            .execute_with_reply_and_payload(swaps, ReplyOn::Success, &payload)?;
+    Ok(response.add_submessage(account_msg))
+}
+
+/// Stake the assets the rewards are swapped to, providing liquidity first for an LP staking token.
+pub(crate) fn rewards_swapped(
+    deps: Deps,
+    env: &Env,
+    module: &CwStakingAdapter,
+    compound: Compound,
+    target: CompoundTarget,
+    received: Vec<AnsAsset>,
+    response: Response,
+) -> StakingResult {
+    let received: Vec<AnsAsset> = received
+        .into_iter()
+        .filter(|asset| !asset.amount.is_zero())
+        .collect();
+    let lp_token = match AnsEntryConvertor::new(target.staking_token.clone()).lp_token() {
+        Ok(lp_token) => lp_token,
+        Err(_) => {
+            let amount = received.iter().map(|asset| asset.amount).sum();
+            return stake(deps, env, module, compound, target, amount, response);
+        }
+    };
+    if received.is_empty() {
+        return stake(
+            deps,
+            env,
+            module,
+            compound,
+            target,
+            Uint128::zero(),
+            response,
+        );
+    }
+
+    // Liquidity is provided on the dex of the LP token, which can differ from the swap dex
+    let staking_token = module.name_service(deps).query(&target.staking_token)?;
+    let balance_before = staking_token.query_balance(&deps.querier, &compound.account)?;
+    let provide_liquidity = dex_msg(
+        deps,
+        module,
+        &dex_adapter(deps, module)?,
+        lp_token.dex,
+        DexAnsAction::ProvideLiquidity {
+            assets: received,
+            max_spread: None,
+        },
+    )?;
+    let payload = StakingReplyPayload::LiquidityProvided {
+        compound,
+        target,
+        balance_before,
+    };
+    let account_msg = module.executor(deps).execute_with_reply_and_payload(
+        vec![provide_liquidity],
+        ReplyOn::Success,
+        &payload,
+    )?;
+    Ok(response.add_submessage(account_msg))
+}
+
+/// Stake the compounded `amount` of the staking token and continue with the next staking token.
+pub(crate) fn stake(
+    deps: Deps,
+    env: &Env,
+    module: &CwStakingAdapter,
+    compound: Compound,
+    target: CompoundTarget,
+    amount: Uint128,
+    mut response: Response,
+) -> StakingResult {
+    if amount < target.min_receive {
+        return Err(CwStakingError::CompoundMinReceiveNotMet {
+            asset: target.staking_token.to_string(),
+            min_receive: target.min_receive,
+            restaked: amount,
+        });
+    }
+    if !amount.is_zero() {
+        let mut provider = resolver::resolve_local_provider(&compound.provider)?;
+        provider.fetch_data(
+            deps,
+            env.clone(),
+            Some(compound.account.clone()),
+            &module.ans_host(deps)?,
+            module.abstract_registry(deps)?,
+            vec![target.staking_token.clone()],
+        )?;
+        let compounded = AnsAsset::new(target.staking_token, amount);
+        let msgs = provider.stake(deps, vec![compounded.clone()], compound.unbonding_period)?;
+        response = response
+            .add_message(module.executor(deps).execute(msgs)?)
+            .add_attribute("compounded", compounded.to_string());
+    }
+    compound_next(deps, env, module, compound, response)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn target(staking_token: &str) -> CompoundTarget {
+        CompoundTarget {
+            staking_token: AssetEntry::new(staking_token),
+            min_receive: Uint128::zero(),
+            rewards: vec![],
+        }
+    }
+
+    #[test]
+    fn split_gives_remainder_to_last_part() {
+        assert_eq!(
+            split(Uint128::new(10), 3),
+            vec![Uint128::new(3), Uint128::new(3), Uint128::new(4)]
+        );
+        assert_eq!(split(Uint128::new(10), 1), vec![Uint128::new(10)]);
+        assert!(split(Uint128::new(10), 0).is_empty());
+    }
+
+    fn value(value: u128) -> Option<Decimal256> {
+        Some(Decimal256::from_ratio(value, 1u128))
+    }
+
+    #[test]
+    fn swaps_are_sized_to_equal_pool_values() {
+        // Swapping to the second asset returns 3 times the value of the first one
+        assert_eq!(
+            size_swaps(Uint128::new(100), &[value(1), value(3)]),
+            vec![Uint128::new(75), Uint128::new(25)]
+        );
+        assert_eq!(
+            size_swaps(Uint128::new(100), &[value(2), value(2)]),
+            vec![Uint128::new(50), Uint128::new(50)]
+        );
+        assert_eq!(
+            size_swaps(Uint128::new(10), &[value(1), value(1), value(1)]),
+            vec![Uint128::new(3), Uint128::new(3), Uint128::new(4)]
+        );
+    }
+
+    #[test]
+    fn swaps_are_split_evenly_without_values() {
+        assert_eq!(
+            size_swaps(Uint128::new(100), &[value(1), None]),
+            vec![Uint128::new(50), Uint128::new(50)]
+        );
+        assert_eq!(
+            size_swaps(Uint128::new(100), &[value(1), value(0)]),
+            vec![Uint128::new(50), Uint128::new(50)]
+        );
+        assert_eq!(
+            size_swaps(Uint128::new(100), &[None]),
+            vec![Uint128::new(100)]
+        );
+    }
+
+    #[test]
+    fn shared_rewards_are_split_between_earners() {
+        let wynd = AssetInfo::native("wynd");
+        let juno = AssetInfo::native("juno");
+        let mut targets = vec![target("wyndex/eur,usd"), target("wyndex/juno,wynd")];
+        allocate_rewards(
+            &mut targets,
+            &[vec![wynd.clone()], vec![wynd.clone(), juno.clone()]],
+            vec![
+                Asset::new(wynd.clone(), 101u128),
+                Asset::new(juno.clone(), 40u128),
+            ],
+        );
+        assert_eq!(targets[0].rewards, vec![Asset::new(wynd.clone(), 50u128)]);
+        assert_eq!(
+            targets[1].rewards,
+            vec![Asset::new(wynd, 51u128), Asset::new(juno, 40u128)]
+        );
+    }
+
+    #[test]
+    fn lp_staking_token_swaps_to_pool_assets() {
+        assert_eq!(
+            staking_token_assets(&AssetEntry::new("wyndex/eur,usd")),
+            vec![AssetEntry::new("eur"), AssetEntry::new("usd")]
+        );
+        assert_eq!(
+            staking_token_assets(&AssetEntry::new("juno>wynd")),
+            vec![AssetEntry::new("juno>wynd")]
+        );
+    }
+}
@@ -20,7 +20,8 @@ pub type StakingResult<T = Response> = Result<T, CwStakingError>;
 pub const CW_STAKING_ADAPTER: CwStakingAdapter =
     CwStakingAdapter::new(CW_STAKING_ADAPTER_ID, CONTRACT_VERSION, None)
         .with_execute(handlers::execute_handler)
-        .with_query(handlers::query_handler);
+        .with_query(handlers::query_handler)
+        .with_payload_replies::<handlers::StakingReplyPayload>();
 
 // Export the endpoints for this contract
 #[cfg(feature = "export")]
@@ -10,6 +10,7 @@ use cosmwasm_std::{to_json_binary, Coin, Deps, DepsMut, Env, MessageInfo};
 
 use crate::{
     adapter::CwStakingAdapter,
+    compound::{self, Compound},
     contract::{CwStakingAdapter as CwStakingContract, StakingResult},
     resolver::{self, is_over_ibc},
     CW_STAKING_ADAPTER_ID,
@@ -48,6 +49,37 @@ fn handle_local_request(
 ) -> StakingResult {
     let provider = resolver::resolve_local_provider(&provider_name)?;
     let target_account = module.account(deps.as_ref())?;
+    if let StakingAction::Compound {
+        assets,
+        dex,
+        min_receive,
+        unbonding_period,
+        distributed_rewards,
+    } = &action
+    {
+        // Fail before claiming the rewards if they can't be swapped
+        compound::dex_adapter(deps.as_ref(), &module)?;
+        if !distributed_rewards.is_empty() {
+            let compound = Compound::new(
+                target_account.into_addr(),
+                provider_name.clone(),
+                assets.clone(),
+                dex.clone(),
+                min_receive,
+                *unbonding_period,
+            );
+            let response =
+                module.custom_response("handle_local_request", vec![("provider", provider_name)]);
+            return compound::compound_distributed(
+                deps.as_ref(),
+                &env,
+                &module,
+                compound,
+                distributed_rewards.clone(),
+                response,
+            );
+        }
+    }
     Ok(module
         .custom_response("handle_local_request", vec![("provider", provider_name)])
         .add_submessage(module.resolve_staking_action(
@@ -1,4 +1,7 @@
 mod execute;
 mod query;
+mod reply;
 
-pub use crate::handlers::{execute::execute_handler, query::query_handler};
+pub use crate::handlers::{
+    execute::execute_handler, query::query_handler, reply::StakingReplyPayload,
+};
@@ -0,0 +1,150 @@
+use abstract_adapter::sdk::{base::ReplyPayload, features::AbstractNameService};
+use abstract_adapter::std::{
+    objects::{AnsAsset, AssetEntry},
+    registry::Account,
+};
+use abstract_staking_standard::{CwStakingCommand, CwStakingError};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Reply, Response, Uint128};
+use cw_asset::{Asset, AssetInfo};
+use cw_utils::Duration;
+
+use crate::{
+    compound::{self, Compound, CompoundTarget},
+    contract::{CwStakingAdapter, StakingResult},
+};
+
+/// Payload of the staking actions executed on the account, handled after they succeed.
+#[cosmwasm_schema::cw_serde]
+pub enum StakingReplyPayload {
+    /// Rewards are claimed, compound them into the staking tokens
+    RewardsClaimed {
+        compound: Compound,
+        /// Reward tokens of each staking token
+        reward_tokens: Vec<Vec<AssetInfo>>,
+        /// Balances of the reward tokens before the claim
+        balances_before: Vec<Asset>,
+    },
+    /// Rewards are swapped to the assets of the staking token
+    RewardsSwapped {
+        compound: Compound,
+        target: CompoundTarget,
+        /// Rewards that are already one of the assets and minimum returns of the swaps, per asset
+        received: Vec<AnsAsset>,
+    },
+    /// Liquidity is provided to the pool of the LP staking token
+    LiquidityProvided {
+        compound: Compound,
+        target: CompoundTarget,
+        balance_before: Uint128,
+    },
+}
+
+impl StakingReplyPayload {
+    /// Compound the rewards of the staked `assets` once they're claimed to the `account`
+    pub fn rewards_claimed(
+        deps: Deps,
+        provider: &dyn CwStakingCommand,
+        account: Addr,
+        assets: Vec<AssetEntry>,
+        dex: String,
+        min_receive: Vec<AnsAsset>,
+        unbonding_period: Option<Duration>,
+    ) -> StakingResult<Self> {
+        let reward_tokens = provider.query_rewards(&deps.querier)?.tokens;
+        let mut balances_before: Vec<Asset> = vec![];
+        for info in reward_tokens.iter().flatten() {
+            if balances_before.iter().any(|balance| &balance.info == info) {
+                continue;
+            }
+            let balance = info.query_balance(&deps.querier, &account)?;
+            balances_before.push(Asset::new(info.clone(), balance));
+        }
+        Ok(Self::RewardsClaimed {
+            compound: Compound::new(
+                account,
+                provider.name().to_owned(),
+                assets,
+                dex,
+                &min_receive,
+                unbonding_period,
+            ),
+            reward_tokens,
+            balances_before,
+        })
+    }
+
+    fn account(&self) -> &Addr {
+        match self {
+            Self::RewardsClaimed { compound, .. }
+            | Self::RewardsSwapped { compound, .. }
+            | Self::LiquidityProvided { compound, .. } => &compound.account,
+        }
+    }
+}
+
+impl ReplyPayload<CwStakingAdapter, CwStakingError> for StakingReplyPayload {
+    fn handle(
+        self,
+        deps: DepsMut,
+        env: Env,
+        mut module: CwStakingAdapter,
+        _reply: Reply,
+    ) -> StakingResult {
+        // Continue executing on the account of the compounded stakes
+        module.target_account = Some(Account::new(self.account().clone()));
+        let deps = deps.as_ref();
+        match self {
+            StakingReplyPayload::RewardsClaimed {
+                mut compound,
+                reward_tokens,
+                balances_before,
+            } => {
+                let claimed = balances_before
+                    .into_iter()
+                    .map(|before| {
+                        let balance = before
+                            .info
+                            .query_balance(&deps.querier, &compound.account)?;
+                        Ok(Asset::new(
+                            before.info,
+                            balance.saturating_sub(before.amount),
+                        ))
+                    })
+                    .collect::<StakingResult<Vec<_>>>()?;
+                compound::allocate_rewards(&mut compound.targets, &reward_tokens, claimed);
+                compound::compound_next(deps, &env, &module, compound, Response::new())
+            }
+            StakingReplyPayload::RewardsSwapped {
+                compound,
+                target,
+                received,
+            } => compound::rewards_swapped(
+                deps,
+                &env,
+                &module,
+                compound,
+                target,
+                received,
+                Response::new(),
+            ),
+            StakingReplyPayload::LiquidityProvided {
+                compound,
+                target,
+                balance_before,
+            } => {
+                let staking_token = module.name_service(deps).query(&target.staking_token)?;
+                let balance = staking_token.query_balance(&deps.querier, &compound.account)?;
+                let amount = balance.saturating_sub(balance_before);
+                compound::stake(
+                    deps,
+                    &env,
+                    &module,
+                    compound,
+                    target,
+                    amount,
+                    Response::new(),
+                )
+            }
+        }
+    }
+}
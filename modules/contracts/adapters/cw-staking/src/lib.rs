@@ -1,4 +1,5 @@
 mod adapter;
+mod compound;
 pub mod contract;
 mod handlers;
 
@@ -162,5 +163,44 @@ pub mod interface {
             self.staking_action(provider, action, account)?;
             Ok(())
         }
+
+        pub fn compound(
+            &self,
+            stake_asset: AssetEntry,
+            provider: String,
+            dex: String,
+            duration: Option<cw_utils::Duration>,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let action = StakingAction::Compound {
+                assets: vec![stake_asset],
+                dex,
+                min_receive: vec![],
+                unbonding_period: duration,
+                distributed_rewards: vec![],
+            };
+            self.staking_action(provider, action, account)?;
+            Ok(())
+        }
+
+        pub fn compound_distributed_rewards(
+            &self,
+            stake_asset: AssetEntry,
+            provider: String,
+            dex: String,
+            distributed_rewards: Vec<AnsAsset>,
+            duration: Option<cw_utils::Duration>,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let action = StakingAction::Compound {
+                assets: vec![stake_asset],
+                dex,
+                min_receive: vec![],
+                unbonding_period: duration,
+                distributed_rewards,
+            };
+            self.staking_action(provider, action, account)?;
+            Ok(())
+        }
     }
 }
@@ -27,6 +27,10 @@ pub(crate) fn identify_provider(value: &str) -> Result<Box<dyn Identify>, CwStak
         abstract_astrovault_adapter::ASTROVAULT => {
             Ok(Box::<abstract_astrovault_adapter::staking::Astrovault>::default())
         }
+        #[cfg(feature = "mock")]
+        abstract_mock_adapter::MOCK => {
+            Ok(Box::<abstract_mock_adapter::staking::MockStaking>::default())
+        }
         _ => Err(CwStakingError::UnknownStaking(value.to_string())),
     }
 }
@@ -56,6 +60,10 @@ pub(crate) fn resolve_local_provider(
         abstract_astrovault_adapter::ASTROVAULT => {
             Ok(Box::<abstract_astrovault_adapter::staking::Astrovault>::default())
         }
+        #[cfg(feature = "mock")]
+        abstract_mock_adapter::MOCK => {
+            Ok(Box::<abstract_mock_adapter::staking::MockStaking>::default())
+        }
         _ => Err(CwStakingError::ForeignStaking(name.to_owned())),
     }
 }
@@ -0,0 +1,213 @@
+#![cfg(feature = "mock")]
+
+use abstract_adapter::abstract_interface::{Abstract, AccountI, AdapterDeployer, DeployStrategy};
+use abstract_adapter::std::objects::{gov_type::GovernanceDetails, AnsAsset, AssetEntry};
+use abstract_cw_staking::{
+    contract::CONTRACT_VERSION, interface::CwStakingAdapter, CW_STAKING_ADAPTER_ID,
+};
+use abstract_dex_adapter::{interface::DexAdapter, msg::DexInstantiateMsg, DEX_ADAPTER_ID};
+use abstract_mock_adapter::{
+    interface::MockProtocol,
+    lp_denom,
+    msg::{InstantiateMsg, QueryMsg, RewardRate},
+    MOCK,
+};
+use cosmwasm_std::{coin, coins, Decimal, Uint128};
+use cw_orch::prelude::*;
+
+const JUNO: &str = "ujuno";
+const USD: &str = "uusd";
+
+/// Account that staked 1_000 juno on the mock protocol, which pays 1% of the stake in usd per
+/// second, for juno and juno/usd LP stakes. Juno and usd trade at the same price.
+fn setup() -> anyhow::Result<(
+    MockBech32,
+    AccountI<MockBech32>,
+    CwStakingAdapter<MockBech32>,
+    DexAdapter<MockBech32>,
+    MockProtocol<MockBech32>,
+)> {
+    let chain = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(chain.clone(), ())?;
+    let governance = GovernanceDetails::Monarchy {
+        monarch: chain.sender_addr().to_string(),
+    };
+    let _root_account = AccountI::create_default_account(&abstr, governance.clone())?;
+
+    let protocol = MockProtocol::deploy(
+        chain.clone(),
+        &InstantiateMsg {
+            swap_fee: Decimal::zero(),
+            prices: vec![
+                (JUNO.to_owned(), Decimal::one()),
+                (USD.to_owned(), Decimal::one()),
+            ],
+            markets: vec![],
+            reward_rates: [JUNO.to_owned(), lp_denom(&[JUNO, USD])]
+                .into_iter()
+                .map(|staking_denom| {
+                    let rate = RewardRate {
+                        denom: USD.to_owned(),
+                        per_second: Decimal::percent(1),
+                    };
+                    (staking_denom, rate)
+                })
+                .collect(),
+        },
+    )?;
+    chain.set_balance(
+        &protocol.address()?,
+        vec![
+            coin(1_000_000, JUNO),
+            coin(1_000_000, USD),
+            coin(1_000_000, lp_denom(&[JUNO, USD])),
+        ],
+    )?;
+    protocol.register_on_ans(&abstr, &[("juno", JUNO), ("usd", USD)])?;
+
+    let dex_adapter = DexAdapter::new(DEX_ADAPTER_ID, chain.clone());
+    dex_adapter.deploy(
+        abstract_dex_adapter::contract::CONTRACT_VERSION.parse()?,
+        DexInstantiateMsg {
+            swap_fee: Decimal::zero(),
+            recipient_account: 0,
+        },
+        DeployStrategy::Try,
+    )?;
+    let staking = CwStakingAdapter::new(CW_STAKING_ADAPTER_ID, chain.clone());
+    staking.deploy(CONTRACT_VERSION.parse()?, Empty {}, DeployStrategy::Try)?;
+
+    let account = AccountI::create_default_account(&abstr, governance)?;
+    account.install_adapter(&staking, &[])?;
+    chain.set_balance(&account.address()?, coins(1_000, JUNO))?;
+    staking.stake(
+        AnsAsset::new("juno", 1_000u128),
+        MOCK.to_owned(),
+        None,
+        &account,
+    )?;
+    Ok((chain, account, staking, dex_adapter, protocol))
+}
+
+fn staked(
+    protocol: &MockProtocol<MockBech32>,
+    account: &AccountI<MockBech32>,
+    denom: &str,
+) -> anyhow::Result<Uint128> {
+    let staked = protocol.query(&QueryMsg::Staked {
+        user: account.addr_str()?,
+        denom: denom.to_owned(),
+    })?;
+    Ok(staked)
+}
+
+#[test]
+fn stake_and_unstake() -> anyhow::Result<()> {
+    let (chain, account, staking, _, protocol) = setup()?;
+    assert_eq!(staked(&protocol, &account, JUNO)?.u128(), 1_000);
+    assert_eq!(chain.query_balance(&account.address()?, JUNO)?.u128(), 0);
+
+    staking.unstake(
+        AnsAsset::new("juno", 400u128),
+        MOCK.to_owned(),
+        None,
+        &account,
+    )?;
+    assert_eq!(staked(&protocol, &account, JUNO)?.u128(), 600);
+    assert_eq!(chain.query_balance(&account.address()?, JUNO)?.u128(), 400);
+    Ok(())
+}
+
+#[test]
+fn compound_requires_dex_adapter() -> anyhow::Result<()> {
+    let (chain, account, staking, _, protocol) = setup()?;
+    chain.wait_seconds(100)?;
+
+    let err: anyhow::Error = staking
+        .compound(
+            AssetEntry::new("juno"),
+            MOCK.to_owned(),
+            MOCK.to_owned(),
+            None,
+            &account,
+        )
+        .unwrap_err()
+        .into();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("install it on the account first"));
+    // Rewards are left unclaimed
+    assert_eq!(chain.query_balance(&account.address()?, USD)?.u128(), 0);
+    assert_eq!(staked(&protocol, &account, JUNO)?.u128(), 1_000);
+    Ok(())
+}
+
+#[test]
+fn compound_restakes_rewards() -> anyhow::Result<()> {
+    let (chain, account, staking, dex_adapter, protocol) = setup()?;
+    account.install_adapter(&dex_adapter, &[])?;
+    chain.wait_seconds(100)?;
+
+    staking.compound(
+        AssetEntry::new("juno"),
+        MOCK.to_owned(),
+        MOCK.to_owned(),
+        None,
+        &account,
+    )?;
+    // 1_000 usd of rewards are swapped to juno and staked
+    assert_eq!(staked(&protocol, &account, JUNO)?.u128(), 2_000);
+    assert_eq!(chain.query_balance(&account.address()?, USD)?.u128(), 0);
+    assert_eq!(chain.query_balance(&account.address()?, JUNO)?.u128(), 0);
+    Ok(())
+}
+
+#[test]
+fn compound_provides_liquidity_for_lp_stakes() -> anyhow::Result<()> {
+    let (chain, account, staking, dex_adapter, protocol) = setup()?;
+    account.install_adapter(&dex_adapter, &[])?;
+    let abstr = Abstract::load_from(chain.clone())?;
+    let lp_denom = lp_denom(&[JUNO, USD]);
+
+    // Move the juno stake to 2_000 juno/usd LP tokens, one per unit of value
+    staking.unstake(
+        AnsAsset::new("juno", 1_000u128),
+        MOCK.to_owned(),
+        None,
+        &account,
+    )?;
+    chain.add_balance(&account.address()?, coins(1_000, USD))?;
+    dex_adapter.ans_provide_liquidity(
+        vec![("juno", 1_000), ("usd", 1_000)],
+        MOCK.to_owned(),
+        &account,
+        &abstr.ans_host,
+    )?;
+    staking.stake(
+        AnsAsset::new("mock/juno,usd", 2_000u128),
+        MOCK.to_owned(),
+        None,
+        &account,
+    )?;
+    chain.wait_seconds(50)?;
+
+    staking.compound(
+        AssetEntry::new("mock/juno,usd"),
+        MOCK.to_owned(),
+        MOCK.to_owned(),
+        None,
+        &account,
+    )?;
+    // Half of the 1_000 usd of rewards is swapped to juno, for a minimum of 495 juno.
+    // That minimum and the other half are provided as liquidity and their LP tokens staked.
+    assert_eq!(staked(&protocol, &account, &lp_denom)?.u128(), 2_995);
+    assert_eq!(chain.query_balance(&account.address()?, USD)?.u128(), 0);
+    assert_eq!(
+        chain.query_balance(&account.address()?, &lp_denom)?.u128(),
+        0
+    );
+    // The swap returned more than its minimum
+    assert_eq!(chain.query_balance(&account.address()?, JUNO)?.u128(), 5);
+    Ok(())
+}
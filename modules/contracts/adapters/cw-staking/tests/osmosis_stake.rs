@@ -12,11 +12,12 @@ mod osmosis_test {
     use abstract_client::GovernanceDetails;
     use abstract_cw_staking::interface::CwStakingAdapter;
     use abstract_cw_staking::{contract::CONTRACT_VERSION, msg::StakingQueryMsgFns};
+    use abstract_dex_adapter::{interface::DexAdapter, msg::DexInstantiateMsg, DEX_ADAPTER_ID};
     use abstract_staking_standard::{
         msg::{StakingInfo, StakingInfoResponse},
         CwStakingError,
     };
-    use cosmwasm_std::{coin, coins, from_json, to_json_binary, Empty, Uint128};
+    use cosmwasm_std::{coin, coins, from_json, to_json_binary, Decimal, Empty, Uint128};
     use cw_asset::AssetInfoBase;
     use cw_orch_osmosis_test_tube::osmosis_test_tube::{
         osmosis_std::{
@@ -128,6 +129,19 @@ mod osmosis_test {
         Ok((tube, pool_id, staking, os))
     }
 
+    fn deploy_dex_adapter(tube: &OsmosisTestTube) -> anyhow::Result<DexAdapter<OsmosisTestTube>> {
+        let dex_adapter = DexAdapter::new(DEX_ADAPTER_ID, tube.clone());
+        dex_adapter.deploy(
+            abstract_dex_adapter::contract::CONTRACT_VERSION.parse()?,
+            DexInstantiateMsg {
+                swap_fee: Decimal::zero(),
+                recipient_account: 0,
+            },
+            DeployStrategy::Error,
+        )?;
+        Ok(dex_adapter)
+    }
+
     #[test]
     fn staking_inited() -> anyhow::Result<()> {
         let (_, pool_id, staking, _) = setup_osmosis()?;
@@ -278,6 +292,67 @@ mod osmosis_test {
         Ok(())
     }
 
+    #[test]
+    fn compound_requires_distributed_rewards() -> anyhow::Result<()> {
+        let (tube, _, staking, os) = setup_osmosis()?;
+        let dex_adapter = deploy_dex_adapter(&tube)?;
+        os.install_adapter(&dex_adapter, &[])?;
+
+        let dur = Some(cw_utils::Duration::Time(2));
+        staking.stake(AnsAsset::new(LP, 100u128), OSMOSIS.into(), dur, &os)?;
+
+        // Rewards are distributed at epoch, there is nothing to claim
+        let err = staking
+            .compound(
+                AssetEntry::new(LP),
+                OSMOSIS.into(),
+                OSMOSIS.into(),
+                dur,
+                &os,
+            )
+            .unwrap_err();
+        if let AbstractInterfaceError::Orch(CwOrchError::StdErr(e)) = err {
+            let expected_err = CwStakingError::NoRewardsToClaim("osmosis".to_owned());
+            assert!(e.contains(&expected_err.to_string()));
+        } else {
+            panic!("Expected stderror");
+        };
+        Ok(())
+    }
+
+    #[test]
+    fn compound_distributed_rewards() -> anyhow::Result<()> {
+        let (tube, _, staking, os) = setup_osmosis()?;
+        let dex_adapter = deploy_dex_adapter(&tube)?;
+        os.install_adapter(&dex_adapter, &[])?;
+        let account_addr = os.address()?;
+
+        let dur = Some(cw_utils::Duration::Time(2));
+        staking.stake(AnsAsset::new(LP, 100u128), OSMOSIS.into(), dur, &os)?;
+
+        // Rewards distributed at epoch
+        tube.bank_send(account_addr.to_string(), coins(100u128, ASSET_1))?;
+        staking.compound_distributed_rewards(
+            AssetEntry::new(LP),
+            OSMOSIS.into(),
+            OSMOSIS.into(),
+            vec![AnsAsset::new("osmo", 100u128)],
+            dur,
+            &os,
+        )?;
+
+        // The rewards are provided as liquidity and the LP tokens locked
+        let staked_balance: AccountLockedCoinsResponse = tube.app.borrow().query(
+            "/osmosis.lockup.Query/AccountLockedCoins",
+            &AccountLockedCoinsRequest {
+                owner: account_addr.to_string(),
+            },
+        )?;
+        assert!(staked_balance.coins[0].amount.parse::<u128>()? > 100);
+        assert!(tube.query_balance(&account_addr, ASSET_1)?.is_zero());
+        Ok(())
+    }
+
     // Currently not supported for provide/withdraw
     #[test]
     fn concentrated_liquidity() -> anyhow::Result<()> {
@@ -154,6 +154,7 @@ fn main() -> anyhow::Result<()> {
         ask_asset,
         max_spread: Some(Decimal::percent(30)),
         belief_price: Some(Decimal::percent(1)),
+        min_receive: None,
     };
 
     dex_adapter.module::<DexAdapter<_>>()?.ans_action(
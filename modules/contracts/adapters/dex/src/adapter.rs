@@ -6,7 +6,7 @@ use abstract_adapter::sdk::{
 use abstract_adapter::std::objects::pool_id::PoolAddressBase;
 use abstract_dex_standard::{action::DexAction, msg::SwapNode, DexCommand, DexError};
 use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps};
-use cw_asset::{AssetBase, AssetInfoBase};
+use cw_asset::{Asset, AssetBase, AssetInfoBase};
 
 use crate::state::DEX_FEES;
 
@@ -15,6 +15,13 @@ pub const PROVIDE_LIQUIDITY_SYM: u64 = 7543;
 pub const WITHDRAW_LIQUIDITY: u64 = 7546;
 pub const SWAP: u64 = 7544;
 pub const SWAP_ROUTE: u64 = 7545;
+pub const PLACE_LIMIT_ORDER: u64 = 7547;
+pub const CANCEL_ORDER: u64 = 7548;
+pub const WITHDRAW_FILLED_ORDER: u64 = 7549;
+pub const CREATE_POSITION: u64 = 7550;
+pub const ADD_TO_POSITION: u64 = 7551;
+pub const WITHDRAW_POSITION: u64 = 7552;
+pub const COLLECT_POSITION_REWARDS: u64 = 7553;
 
 impl<T> DexAdapter for T where T: AbstractNameService + Execution + AbstractRegistryAccess {}
 
@@ -60,6 +67,7 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
                 ask_asset,
                 max_spread,
                 belief_price,
+                ..
             } => (
                 self.resolve_swap(
                     deps,
@@ -78,6 +86,7 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
                 offer_asset,
                 max_spread,
                 belief_price,
+                ..
             } => (
                 self.resolve_route_swap(
                     deps,
@@ -90,9 +99,155 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
                 )?,
                 SWAP_ROUTE,
             ),
+            DexAction::PlaceLimitOrder {
+                pool,
+                offer_asset,
+                ask_asset,
+                price,
+            } => (
+                self.resolve_place_limit_order(
+                    deps,
+                    sender,
+                    offer_asset,
+                    ask_asset,
+                    pool,
+                    exchange.as_mut(),
+                    price,
+                )?,
+                PLACE_LIMIT_ORDER,
+            ),
+            DexAction::CancelOrder { pool, order_id } => {
+                let pool_address = pool.check(deps.api)?;
+                self.fetch_exchange_data(deps, sender, exchange.as_mut())?;
+                (
+                    exchange.cancel_order(deps, pool_address, order_id)?,
+                    CANCEL_ORDER,
+                )
+            }
+            DexAction::WithdrawFilledOrder { pool, order_id } => {
+                let pool_address = pool.check(deps.api)?;
+                self.fetch_exchange_data(deps, sender, exchange.as_mut())?;
+                (
+                    exchange.withdraw_filled_order(deps, pool_address, order_id)?,
+                    WITHDRAW_FILLED_ORDER,
+                )
+            }
+            DexAction::CreatePosition {
+                pool,
+                lower_tick,
+                upper_tick,
+                assets,
+                min_assets,
+            } => {
+                let pool_address = pool.check(deps.api)?;
+                let assets = check_assets(deps, assets)?;
+                let min_assets = check_assets(deps, min_assets)?;
+                self.fetch_exchange_data(deps, sender, exchange.as_mut())?;
+                (
+                    exchange.create_position(
+                        deps,
+                        pool_address,
+                        lower_tick,
+                        upper_tick,
+                        assets,
+                        min_assets,
+                    )?,
+                    CREATE_POSITION,
+                )
+            }
+            DexAction::AddToPosition {
+                pool,
+                position_id,
+                assets,
+                min_assets,
+            } => {
+                let pool_address = pool.check(deps.api)?;
+                let assets = check_assets(deps, assets)?;
+                let min_assets = check_assets(deps, min_assets)?;
+                self.fetch_exchange_data(deps, sender, exchange.as_mut())?;
+                (
+                    exchange.add_to_position(
+                        deps,
+                        pool_address,
+                        position_id,
+                        assets,
+                        min_assets,
+                    )?,
+                    ADD_TO_POSITION,
+                )
+            }
+            DexAction::WithdrawPosition {
+                pool,
+                position_id,
+                share,
+            } => {
+                if share.is_zero() || share > Decimal::one() {
+                    return Err(DexError::InvalidPositionShare(share));
+                }
+                let pool_address = pool.check(deps.api)?;
+                self.fetch_exchange_data(deps, sender, exchange.as_mut())?;
+                (
+                    exchange.withdraw_position(deps, pool_address, position_id, share)?,
+                    WITHDRAW_POSITION,
+                )
+            }
+            DexAction::CollectPositionRewards { pool, position_ids } => {
+                let pool_address = pool.check(deps.api)?;
+                self.fetch_exchange_data(deps, sender, exchange.as_mut())?;
+                (
+                    exchange.collect_position_rewards(deps, pool_address, position_ids)?,
+                    COLLECT_POSITION_REWARDS,
+                )
+            }
         })
     }
 
+    /// Provide the sender and Abstract contracts to the exchange
+    fn fetch_exchange_data(
+        &self,
+        deps: Deps,
+        sender: Addr,
+        exchange: &mut dyn DexCommand,
+    ) -> Result<(), DexError> {
+        exchange.fetch_data(
+            deps,
+            sender,
+            self.abstract_registry(deps)?,
+            self.ans_host(deps)?,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_place_limit_order(
+        &self,
+        deps: Deps,
+        sender: Addr,
+        offer_asset: AssetBase<String>,
+        ask_asset: AssetInfoBase<String>,
+        pool: PoolAddressBase<String>,
+        exchange: &mut dyn DexCommand,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let pool_address = pool.check(deps.api)?;
+        let mut offer_asset = offer_asset.check(deps.api, None)?;
+        let ask_asset = ask_asset.check(deps.api, None)?;
+
+        // account for fee
+        let dex_fees = DEX_FEES.load(deps.storage)?;
+        let usage_fee = dex_fees.swap_usage_fee()?;
+        let fee_msg = offer_asset.charge_usage_fee(usage_fee)?;
+
+        self.fetch_exchange_data(deps, sender, exchange)?;
+        let mut order_msgs =
+            exchange.place_limit_order(deps, pool_address, offer_asset, ask_asset, price)?;
+        // insert fee msg
+        if let Some(f) = fee_msg {
+            order_msgs.push(f)
+        }
+
+        Ok(order_msgs)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn resolve_swap(
         &self,
@@ -218,3 +373,10 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
         exchange.withdraw_liquidity(deps, pool_address, lp_token)
     }
 }
+
+fn check_assets(deps: Deps, assets: Vec<AssetBase<String>>) -> Result<Vec<Asset>, DexError> {
+    assets
+        .into_iter()
+        .map(|a| a.check(deps.api, None).map_err(Into::into))
+        .collect()
+}
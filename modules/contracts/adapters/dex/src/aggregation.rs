@@ -0,0 +1,280 @@
+//! Best-execution routing of a swap over the local dexes.
+
+use abstract_adapter::sdk::features::AbstractNameService;
+use abstract_adapter::std::objects::{AnsAsset, AssetEntry, PoolAddress};
+use abstract_dex_standard::{
+    ans_action::pool_address,
+    msg::{DexName, SwapLeg},
+    DexCommand, DexError,
+};
+use cosmwasm_std::{Deps, Env, Uint128};
+use cw_asset::Asset;
+
+use crate::{
+    contract::{DexAdapter, DexResult},
+    exchanges::exchange_resolver::{is_over_ibc, resolve_exchange},
+    state::DEX_FEES,
+};
+
+/// Number of parts the offer asset is divided in per dex the swap can be split over.
+pub(crate) const SPLIT_STEPS_PER_DEX: u128 = 4;
+
+/// Maximum number of dexes a swap can be split over, bounds the number of simulations per swap.
+pub(crate) const MAX_SPLITS: u8 = 4;
+
+/// Local dex with a pool for the swapped pair.
+pub(crate) struct SwapVenue {
+    pub dex: DexName,
+    pub exchange: Box<dyn DexCommand>,
+    pub pool: PoolAddress,
+}
+
+/// Simulate the swap on the candidate dexes and route it to the ones that return the most.
+pub(crate) fn route_swap(
+    deps: Deps,
+    env: &Env,
+    module: &DexAdapter,
+    dexes: Option<Vec<DexName>>,
+    offer_asset: &AnsAsset,
+    ask_asset: &AssetEntry,
+    max_splits: Option<u8>,
+) -> DexResult<Vec<(SwapVenue, SwapLeg)>> {
+    let ans = module.name_service(deps);
+    let offer_info = ans.query(&offer_asset.name)?;
+    let ask_info = ans.query(ask_asset)?;
+    let venues = swap_venues(deps, env, module, dexes, offer_asset, ask_asset)?;
+
+    let fee = DEX_FEES.load(deps.storage)?.swap_fee();
+    let dex_names: Vec<DexName> = venues.iter().map(|v| v.dex.clone()).collect();
+    let legs = plan_swap(
+        &dex_names,
+        offer_asset.amount,
+        max_splits.unwrap_or(1).min(MAX_SPLITS),
+        |venue, amount| {
+            // The adapter fee is charged on the offer asset before swapping
+            let SwapVenue { exchange, pool, .. } = &venues[venue];
+            let offer = Asset::new(offer_info.clone(), amount - fee.compute(amount));
+            let (return_amount, ..) =
+                exchange.simulate_swap(deps, pool.clone(), offer, ask_info.clone())?;
+            Ok(return_amount)
+        },
+    )?;
+    if legs.is_empty() {
+        return Err(DexError::NoSwapVenue {
+            offer_asset: offer_asset.name.to_string(),
+            ask_asset: ask_asset.to_string(),
+        });
+    }
+
+    let mut venues: Vec<Option<SwapVenue>> = venues.into_iter().map(Some).collect();
+    Ok(legs
+        .into_iter()
+        .map(|leg| {
+            let venue = dex_names.iter().position(|dex| dex == &leg.dex).unwrap();
+            (venues[venue].take().unwrap(), leg)
+        })
+        .collect())
+}
+
+/// Resolve the candidate dexes that are available on this chain and have a pool for the pair.
+/// Explicitly requested dexes must be local, registered dexes that aren't supported by this adapter are skipped.
+fn swap_venues(
+    deps: Deps,
+    env: &Env,
+    module: &DexAdapter,
+    dexes: Option<Vec<DexName>>,
+    offer_asset: &AnsAsset,
+    ask_asset: &AssetEntry,
+) -> DexResult<Vec<SwapVenue>> {
+    let ans_host = module.ans_host(deps)?;
+    let candidates: Vec<(DexName, Box<dyn DexCommand>)> = match dexes {
+        Some(dexes) => dexes
+            .into_iter()
+            .map(|dex| {
+                let (local_dex_name, is_over_ibc) = is_over_ibc(env, &dex)?;
+                if is_over_ibc {
+                    return Err(DexError::ForeignDex(dex));
+                }
+                let exchange = resolve_exchange(&local_dex_name)?;
+                Ok((local_dex_name, exchange))
+            })
+            .collect::<DexResult<_>>()?,
+        None => ans_host
+            .query_registered_dexes(&deps.querier)?
+            .dexes
+            .into_iter()
+            .filter_map(|dex| resolve_exchange(&dex).ok().map(|exchange| (dex, exchange)))
+            .collect(),
+    };
+
+    let mut venues: Vec<SwapVenue> = vec![];
+    for (dex, exchange) in candidates {
+        if venues.iter().any(|venue| venue.dex == dex) {
+            continue;
+        }
+        let Ok(pool) = pool_address(
+            &dex,
+            (offer_asset.name.clone(), ask_asset.clone()),
+            &deps.querier,
+            &ans_host,
+        ) else {
+            continue;
+        };
+        venues.push(SwapVenue {
+            dex,
+            exchange,
+            pool,
+        });
+    }
+    Ok(venues)
+}
+
+/// Split `offer_amount` over at most `max_splits` of the `dexes`, maximizing the simulated return.
+///
+/// Dexes on which the full amount can't be simulated are ignored, as are dexes that fail to simulate a part.
+/// The offer amount is divided in equal parts that are each routed to the dex with the highest marginal return.
+/// The result falls back to the single best dex if that returns more.
+pub(crate) fn plan_swap(
+    dexes: &[DexName],
+    offer_amount: Uint128,
+    max_splits: u8,
+    mut simulate: impl FnMut(usize, Uint128) -> DexResult<Uint128>,
+) -> DexResult<Vec<SwapLeg>> {
+    let viable: Vec<(usize, Uint128)> = (0..dexes.len())
+        .filter_map(|venue| Some((venue, simulate(venue, offer_amount).ok()?)))
+        .collect();
+    // Max by return amount, first venue on ties
+    let Some(&(best_venue, best_return)) = viable
+        .iter()
+        .rev()
+        .max_by_key(|(_, return_amount)| *return_amount)
+    else {
+        return Ok(vec![]);
+    };
+    let single_leg = vec![SwapLeg {
+        dex: dexes[best_venue].clone(),
+        offer_amount,
+        return_amount: best_return,
+    }];
+    let max_splits = max_splits as usize;
+    if max_splits <= 1 || viable.len() == 1 {
+        return Ok(single_leg);
+    }
+
+    let steps = (max_splits as u128 * SPLIT_STEPS_PER_DEX).min(offer_amount.u128());
+    // (offer amount, return amount) allocated to every viable venue
+    let mut allocation = vec![(Uint128::zero(), Uint128::zero()); viable.len()];
+    for step in 0..steps {
+        let chunk =
+            offer_amount.multiply_ratio(step + 1, steps) - offer_amount.multiply_ratio(step, steps);
+        let used = allocation.iter().filter(|(o, _)| !o.is_zero()).count();
+        let mut best: Option<(usize, Uint128, Uint128)> = None;
+        for (i, &(venue, _)) in viable.iter().enumerate() {
+            let (allocated_offer, allocated_return) = allocation[i];
+            if used >= max_splits && allocated_offer.is_zero() {
+                continue;
+            }
+            let Ok(return_amount) = simulate(venue, allocated_offer + chunk) else {
+                continue;
+            };
+            let gain = return_amount.saturating_sub(allocated_return);
+            if best.map_or(true, |(_, best_gain, _)| gain > best_gain) {
+                best = Some((i, gain, return_amount));
+            }
+        }
+        let Some((i, _, return_amount)) = best else {
+            return Ok(single_leg);
+        };
+        allocation[i] = (allocation[i].0 + chunk, return_amount);
+    }
+
+    let split_return: Uint128 = allocation.iter().map(|(_, r)| r).sum();
+    if split_return <= best_return {
+        return Ok(single_leg);
+    }
+    Ok(viable
+        .iter()
+        .zip(allocation)
+        .filter(|(_, (offer, _))| !offer.is_zero())
+        .map(|(&(venue, _), (offer_amount, return_amount))| SwapLeg {
+            dex: dexes[venue].clone(),
+            offer_amount,
+            return_amount,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dexes() -> Vec<DexName> {
+        vec!["deep".to_owned(), "shallow".to_owned(), "broken".to_owned()]
+    }
+
+    /// Constant product pools with different depths, "broken" can't simulate
+    fn simulate(venue: usize, amount: Uint128) -> DexResult<Uint128> {
+        let reserves: u128 = match venue {
+            0 => 1_000_000,
+            1 => 500_000,
+            _ => return Err(DexError::NotImplemented("broken".to_owned())),
+        };
+        Ok(Uint128::new(reserves).multiply_ratio(amount, Uint128::new(reserves) + amount))
+    }
+
+    #[test]
+    fn routes_to_best_dex() {
+        let legs = plan_swap(&dexes(), Uint128::new(1_000), 1, simulate).unwrap();
+        assert_eq!(
+            legs,
+            vec![SwapLeg {
+                dex: "deep".to_owned(),
+                offer_amount: Uint128::new(1_000),
+                return_amount: simulate(0, Uint128::new(1_000)).unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn splits_large_swaps() {
+        let offer_amount = Uint128::new(600_000);
+        let legs = plan_swap(&dexes(), offer_amount, 3, simulate).unwrap();
+
+        assert_eq!(legs.len(), 2);
+        assert_eq!(legs[0].dex, "deep");
+        assert_eq!(legs[1].dex, "shallow");
+        assert!(legs[0].offer_amount > legs[1].offer_amount);
+        assert_eq!(legs[0].offer_amount + legs[1].offer_amount, offer_amount);
+
+        let split_return = legs[0].return_amount + legs[1].return_amount;
+        assert!(split_return > simulate(0, offer_amount).unwrap());
+    }
+
+    #[test]
+    fn skips_dex_failing_partial_simulation() {
+        let offer_amount = Uint128::new(600_000);
+        // "shallow" can only simulate the full amount
+        let legs = plan_swap(&dexes(), offer_amount, 3, |venue, amount| match venue {
+            1 if amount != offer_amount => Err(DexError::NotImplemented("shallow".to_owned())),
+            _ => simulate(venue, amount),
+        })
+        .unwrap();
+        assert_eq!(
+            legs,
+            vec![SwapLeg {
+                dex: "deep".to_owned(),
+                offer_amount,
+                return_amount: simulate(0, offer_amount).unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn no_viable_dex() {
+        let legs = plan_swap(&dexes()[2..], Uint128::new(1_000), 2, |_, _| {
+            Err(DexError::NotImplemented("broken".to_owned()))
+        })
+        .unwrap();
+        assert!(legs.is_empty());
+    }
+}
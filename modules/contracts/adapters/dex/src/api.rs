@@ -13,7 +13,7 @@ use abstract_dex_standard::{
     msg::{DexExecuteMsg, DexName, DexQueryMsg, SimulateSwapResponse},
 };
 use cosmwasm_schema::serde::de::DeserializeOwned;
-use cosmwasm_std::{CosmosMsg, Decimal, Deps, Env};
+use cosmwasm_std::{CosmosMsg, Decimal, Deps, Env, Uint128};
 use cw_asset::{Asset, AssetInfo, AssetInfoBase};
 
 use self::{ans::AnsDex, raw::Dex};
@@ -110,11 +110,25 @@ pub mod raw {
             max_spread: Option<Decimal>,
             belief_price: Option<Decimal>,
             pool: PoolAddress,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.swap_with_min_receive(offer_asset, ask_asset, max_spread, belief_price, None, pool)
+        }
+
+        /// Swap assets without ANS, the adapter fails the swap if the account receives less than `min_receive`
+        pub fn swap_with_min_receive(
+            &self,
+            offer_asset: Asset,
+            ask_asset: AssetInfo,
+            max_spread: Option<Decimal>,
+            belief_price: Option<Decimal>,
+            min_receive: Option<Uint128>,
+            pool: PoolAddress,
         ) -> AbstractSdkResult<CosmosMsg> {
             self.execute(DexAction::Swap {
                 offer_asset: offer_asset.into(),
                 ask_asset: ask_asset.into(),
                 belief_price,
+                min_receive,
                 max_spread,
                 pool: pool.into(),
             })
@@ -189,6 +203,7 @@ pub mod raw {
                         ask_asset: ask_asset.into(),
                         max_spread,
                         belief_price,
+                        min_receive: None,
                         pool: pool.into(),
                     },
                 },
@@ -259,12 +274,25 @@ pub mod ans {
             ask_asset: AssetEntry,
             max_spread: Option<Decimal>,
             belief_price: Option<Decimal>,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.swap_with_min_receive(offer_asset, ask_asset, max_spread, belief_price, None)
+        }
+
+        /// Swap assets in the DEX, the adapter fails the swap if the account receives less than `min_receive`
+        pub fn swap_with_min_receive(
+            &self,
+            offer_asset: AnsAsset,
+            ask_asset: AssetEntry,
+            max_spread: Option<Decimal>,
+            belief_price: Option<Decimal>,
+            min_receive: Option<Uint128>,
         ) -> AbstractSdkResult<CosmosMsg> {
             self.execute(DexAnsAction::Swap {
                 offer_asset,
                 ask_asset,
                 belief_price,
                 max_spread,
+                min_receive,
             })
         }
 
@@ -321,6 +349,7 @@ pub mod ans {
                     ask_asset,
                     max_spread,
                     belief_price,
+                    min_receive: None,
                 },
             )
             .resolve(&self.deps.querier, &ans_host)
@@ -395,6 +424,7 @@ mod test {
                     ask_asset: ask_asset.clone().into(),
                     max_spread,
                     belief_price,
+                    min_receive: None,
                     pool: pool.clone().into(),
                 },
             },
@@ -414,6 +444,63 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn swap_msg_with_min_receive() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+        let account = test_account(deps.api);
+        deps.querier = abstract_adapter::abstract_testing::abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .build();
+        let stub = MockModule::new(deps.api, account.clone());
+        let dex = stub
+            .dex(deps.as_ref(), &env, "junoswap".into())
+            .with_module_id(abstract_adapter::abstract_testing::prelude::TEST_MODULE_ID);
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        let dex_name = "junoswap".to_string();
+        let offer_asset = Asset::native("ujuno", 100_000u128);
+        let ask_asset = AssetInfo::native("uusd");
+        let max_spread = Some(Decimal::percent(1));
+        let belief_price = Some(Decimal::percent(2));
+        let min_receive = Some(Uint128::new(90_000));
+        let pool = PoolAddressBase::Id(POOL);
+
+        let expected = expected_request_with_test_account(
+            DexExecuteMsg::Action {
+                dex: dex_name,
+                action: DexAction::Swap {
+                    offer_asset: offer_asset.clone().into(),
+                    ask_asset: ask_asset.clone().into(),
+                    max_spread,
+                    belief_price,
+                    min_receive,
+                    pool: pool.clone().into(),
+                },
+            },
+            account.addr(),
+        );
+
+        let actual = dex.swap_with_min_receive(
+            offer_asset,
+            ask_asset,
+            max_spread,
+            belief_price,
+            min_receive,
+            pool,
+        );
+
+        assert!(actual.is_ok());
+
+        let actual = match actual.unwrap() {
+            CosmosMsg::Wasm(msg) => msg,
+            _ => panic!("expected wasm msg"),
+        };
+        let expected = wasm_execute(&abstr.module_address, &expected, vec![]).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn provide_liquidity_msg() {
         let mut deps = mock_dependencies();
@@ -15,7 +15,9 @@ pub type DexResult<T = Response> = Result<T, DexError>;
 pub const DEX_ADAPTER: DexAdapter = DexAdapter::new(DEX_ADAPTER_ID, CONTRACT_VERSION, None)
     .with_instantiate(handlers::instantiate_handler)
     .with_execute(handlers::execute_handler)
-    .with_query(handlers::query_handler);
+    .with_query(handlers::query_handler)
+    .with_payload_replies::<handlers::DexReplyPayload>()
+    .with_request_actions(DexExecuteMsg::ACTIONS, DexExecuteMsg::action_name);
 
 #[cfg(feature = "export")]
 use abstract_adapter::export_endpoints;
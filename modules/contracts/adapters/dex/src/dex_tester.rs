@@ -115,6 +115,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                 ask_asset: AssetEntry::new(&ans_asset_b),
                 max_spread: None,
                 belief_price: None,
+                min_receive: None,
             },
             &new_account,
             self.abstr_deployment.name_service(),
@@ -134,6 +135,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                 ask_asset: AssetEntry::new(&ans_asset_a),
                 max_spread: None,
                 belief_price: None,
+                min_receive: None,
             },
             &new_account,
             self.abstr_deployment.name_service(),
@@ -175,6 +177,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                 ask_asset: AssetEntry::new(&ans_asset_b),
                 max_spread: Some(Decimal::percent(10)),
                 belief_price: Some(belief_price_a_to_b),
+                min_receive: None,
             },
             &new_account,
             self.abstr_deployment.name_service(),
@@ -194,6 +197,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                 ask_asset: AssetEntry::new(&ans_asset_a),
                 max_spread: Some(Decimal::percent(10)),
                 belief_price: Some(belief_price_b_to_a),
+                min_receive: None,
             },
             &new_account,
             self.abstr_deployment.name_service(),
@@ -215,6 +219,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                 ask_asset: AssetEntry::new(&ans_asset_b),
                 max_spread: Some(Decimal::percent(10)),
                 belief_price: Some(Decimal::from_ratio(1u128, 4242u128)),
+                min_receive: None,
             },
             &new_account,
             self.abstr_deployment.name_service(),
@@ -230,6 +235,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                 ask_asset: AssetEntry::new(&ans_asset_a),
                 max_spread: Some(Decimal::percent(10)),
                 belief_price: Some(Decimal::from_ratio(1u128, 424242u128)),
+                min_receive: None,
             },
             &new_account,
             self.abstr_deployment.name_service(),
@@ -579,6 +585,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset,
                         max_spread: None,
                         belief_price: None,
+                        min_receive: None,
                     },
                 )
                 .resolve(self.abstr_deployment.name_service())?,
@@ -27,6 +27,8 @@ pub(crate) fn identify_exchange(value: &str) -> Result<Box<dyn Identify>, DexErr
         abstract_astrovault_adapter::ASTROVAULT => {
             Ok(Box::<abstract_astrovault_adapter::dex::Astrovault>::default())
         }
+        #[cfg(feature = "mock")]
+        abstract_mock_adapter::MOCK => Ok(Box::<abstract_mock_adapter::dex::MockDex>::default()),
         _ => Err(DexError::UnknownDex(value.to_owned())),
     }
 }
@@ -57,6 +59,8 @@ pub(crate) fn resolve_exchange(value: &str) -> Result<Box<dyn DexCommand>, DexEr
         abstract_astrovault_adapter::ASTROVAULT => {
             Ok(Box::<abstract_astrovault_adapter::dex::Astrovault>::default())
         }
+        #[cfg(feature = "mock")]
+        abstract_mock_adapter::MOCK => Ok(Box::<abstract_mock_adapter::dex::MockDex>::default()),
         _ => Err(DexError::ForeignDex(value.to_owned())),
     }
 }
@@ -12,15 +12,20 @@ use abstract_adapter::std::{
     },
 };
 use abstract_dex_standard::{action::DexAction, msg::ExecuteMsg, DexError, DEX_ADAPTER_ID};
-use cosmwasm_std::{ensure_eq, to_json_binary, Coin, Deps, DepsMut, Env, MessageInfo, Response};
-use cw_asset::AssetBase;
+use cosmwasm_std::{
+    ensure_eq, to_json_binary, Addr, Coin, Deps, DepsMut, Env, MessageInfo, ReplyOn, Response,
+    Uint128,
+};
+use cw_asset::{Asset, AssetBase};
 
 use crate::{
+    aggregation,
     contract::{DexAdapter, DexResult},
     exchanges::exchange_resolver,
-    handlers::execute::exchange_resolver::is_over_ibc,
+    handlers::{execute::exchange_resolver::is_over_ibc, reply::DexReplyPayload},
     msg::{DexExecuteMsg, DexName},
     state::DEX_FEES,
+    twap::PairPool,
 };
 
 use abstract_adapter::sdk::features::AccountIdentification;
@@ -47,6 +52,61 @@ pub fn execute_handler(
                 handle_local_request(deps, info, &module, local_dex_name, action)
             }
         }
+        DexExecuteMsg::AggregatedSwap {
+            dexes,
+            offer_asset,
+            ask_asset,
+            min_receive,
+            max_splits,
+            max_spread,
+        } => {
+            let legs = aggregation::route_swap(
+                deps.as_ref(),
+                &env,
+                &module,
+                dexes,
+                &offer_asset,
+                &ask_asset,
+                max_splits,
+            )?;
+            // Fail early if the simulated swaps don't return enough, the received amount is checked in the reply
+            let return_amount: Uint128 = legs.iter().map(|(_, leg)| leg.return_amount).sum();
+            if return_amount < min_receive {
+                return Err(DexError::MinReceiveNotMet {
+                    min_receive,
+                    return_amount,
+                });
+            }
+
+            let ans = module.name_service(deps.as_ref());
+            let offer_info = ans.query(&offer_asset.name)?;
+            let ask_info = ans.query(&ask_asset)?;
+            let target_account = module.account(deps.as_ref())?.into_addr();
+            let mut msgs = vec![];
+            for (mut venue, leg) in legs {
+                msgs.extend(crate::adapter::DexAdapter::resolve_swap(
+                    &module,
+                    deps.as_ref(),
+                    target_account.clone(),
+                    Asset::new(offer_info.clone(), leg.offer_amount).into(),
+                    ask_info.clone().into(),
+                    venue.pool.into(),
+                    venue.exchange.as_mut(),
+                    max_spread,
+                    None,
+                )?);
+            }
+            let guard =
+                DexReplyPayload::min_receive(deps.as_ref(), target_account, ask_info, min_receive)?;
+            let account_msg = module
+                .executor(deps.as_ref())
+                .execute_with_reply_and_payload(msgs, ReplyOn::Success, &guard)?;
+            Ok(Response::new().add_submessage(account_msg))
+        }
+        DexExecuteMsg::ObservePrice { pair } => {
+            PairPool::resolve(deps.as_ref(), &env, &module, &pair)?.observe(deps, &env)?;
+            Ok(Response::default())
+        }
         DexExecuteMsg::UpdateFee {
             swap_fee,
             recipient_account: recipient_account_id,
@@ -93,16 +153,50 @@ fn handle_local_request(
     action: DexAction,
 ) -> DexResult {
     let exchange = exchange_resolver::resolve_exchange(&exchange)?;
-    let target_account = module.account(deps.as_ref())?;
+    let target_account = module.account(deps.as_ref())?.into_addr();
+    let guard = min_receive_guard(deps.as_ref(), &target_account, &action)?;
     let (msgs, _) = crate::adapter::DexAdapter::resolve_dex_action(
         module,
         deps.as_ref(),
-        target_account.into_addr(),
+        target_account,
         action,
         exchange,
     )?;
-    let account_msg = module.executor(deps.as_ref()).execute(msgs)?;
-    Ok(Response::new().add_message(account_msg))
+    let executor = module.executor(deps.as_ref());
+    match guard {
+        Some(guard) => {
+            let account_msg =
+                executor.execute_with_reply_and_payload(msgs, ReplyOn::Success, &guard)?;
+            Ok(Response::new().add_submessage(account_msg))
+        }
+        None => Ok(Response::new().add_message(executor.execute(msgs)?)),
+    }
+}
+
+/// Check the received ask asset of swaps with a `min_receive` after they're executed
+fn min_receive_guard(
+    deps: Deps,
+    account: &Addr,
+    action: &DexAction,
+) -> DexResult<Option<DexReplyPayload>> {
+    let (ask_asset, min_receive) = match action {
+        DexAction::Swap {
+            ask_asset,
+            min_receive: Some(min_receive),
+            ..
+        } => (ask_asset, *min_receive),
+        DexAction::RouteSwap {
+            route,
+            min_receive: Some(min_receive),
+            ..
+        } => match route.last() {
+            Some(node) => (&node.ask_asset, *min_receive),
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let ask_asset = ask_asset.check(deps.api, None)?;
+    DexReplyPayload::min_receive(deps, account.clone(), ask_asset, min_receive).map(Some)
 }
 
 /// Handle an adapter request that can be executed on an IBC chain
@@ -177,6 +271,14 @@ pub(crate) fn resolve_assets_to_transfer(
             coins
         }
         DexAction::WithdrawLiquidity { lp_token, .. } => Ok(vec![offer_to_coin(lp_token)?]),
+        DexAction::PlaceLimitOrder { offer_asset, .. } => Ok(vec![offer_to_coin(offer_asset)?]),
+        DexAction::CreatePosition { assets, .. } | DexAction::AddToPosition { assets, .. } => {
+            assets.iter().map(offer_to_coin).collect()
+        }
+        DexAction::CancelOrder { .. }
+        | DexAction::WithdrawFilledOrder { .. }
+        | DexAction::WithdrawPosition { .. }
+        | DexAction::CollectPositionRewards { .. } => Ok(vec![]),
     }
     .map_err(Into::into)
 }
@@ -3,17 +3,21 @@ use abstract_adapter::std::objects::{AssetEntry, DexAssetPairing, PoolAddress};
 use abstract_dex_standard::{
     ans_action::pool_address,
     msg::{
-        DexExecuteMsg, DexFeesResponse, DexQueryMsg, GenerateMessagesResponse, SimulateSwapResponse,
+        AggregatedSwapResponse, DexExecuteMsg, DexFeesResponse, DexQueryMsg,
+        GenerateMessagesResponse, OrdersResponse, PositionsResponse, PriceResponse,
+        SimulateSwapResponse, SwapLeg,
     },
     DexError,
 };
 use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
 
 use crate::{
+    aggregation,
     contract::{DexAdapter, DexResult},
     exchanges::exchange_resolver::{self, resolve_exchange},
     handlers::query::exchange_resolver::is_over_ibc,
     state::DEX_FEES,
+    twap::PairPool,
 };
 use cw_asset::{Asset, AssetInfo, AssetInfoBase};
 
@@ -67,6 +71,60 @@ pub fn query_handler(
             }
         }
         DexQueryMsg::Fees {} => fees(deps),
+        DexQueryMsg::Orders { account, pool, dex } => {
+            let (local_dex_name, is_over_ibc) = is_over_ibc(&env, &dex)?;
+            if is_over_ibc {
+                return Err(DexError::ForeignDex(dex));
+            }
+            let exchange = exchange_resolver::resolve_exchange(&local_dex_name)?;
+            let orders = exchange.orders(
+                deps,
+                pool.check(deps.api)?,
+                deps.api.addr_validate(&account)?,
+            )?;
+            to_json_binary(&OrdersResponse { orders }).map_err(Into::into)
+        }
+        DexQueryMsg::Price { pair, kind } => {
+            let price = PairPool::resolve(deps, &env, module, &pair)?.price(deps, &env, &kind)?;
+            to_json_binary(&PriceResponse { pair, kind, price }).map_err(Into::into)
+        }
+        DexQueryMsg::Positions { account, pool, dex } => {
+            let (local_dex_name, is_over_ibc) = is_over_ibc(&env, &dex)?;
+            if is_over_ibc {
+                return Err(DexError::ForeignDex(dex));
+            }
+            let exchange = exchange_resolver::resolve_exchange(&local_dex_name)?;
+            let positions = exchange.positions(
+                deps,
+                pool.check(deps.api)?,
+                deps.api.addr_validate(&account)?,
+            )?;
+            to_json_binary(&PositionsResponse { positions }).map_err(Into::into)
+        }
+        DexQueryMsg::SimulateAggregatedSwap {
+            offer_asset,
+            ask_asset,
+            dexes,
+            max_splits,
+        } => {
+            let legs: Vec<SwapLeg> = aggregation::route_swap(
+                deps,
+                &env,
+                module,
+                dexes,
+                &offer_asset,
+                &ask_asset,
+                max_splits,
+            )?
+            .into_iter()
+            .map(|(_, leg)| leg)
+            .collect();
+            let resp = AggregatedSwapResponse {
+                return_amount: legs.iter().map(|leg| leg.return_amount).sum(),
+                legs,
+            };
+            to_json_binary(&resp).map_err(Into::into)
+        }
         DexQueryMsg::SimulateSwap {
             offer_asset,
             ask_asset,
@@ -0,0 +1,59 @@
+use abstract_adapter::sdk::base::ReplyPayload;
+use abstract_dex_standard::DexError;
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Reply, Response, Uint128};
+use cw_asset::AssetInfo;
+
+use crate::contract::{DexAdapter, DexResult};
+
+/// Payload of the swaps executed on the account, handled after they succeed.
+#[cosmwasm_schema::cw_serde]
+pub enum DexReplyPayload {
+    /// Assert the account received at least `min_receive` of the ask asset
+    MinReceive {
+        account: Addr,
+        ask_asset: AssetInfo,
+        balance_before: Uint128,
+        min_receive: Uint128,
+    },
+}
+
+impl DexReplyPayload {
+    /// Guard a swap that pays out `ask_asset` to the `account`
+    pub fn min_receive(
+        deps: Deps,
+        account: Addr,
+        ask_asset: AssetInfo,
+        min_receive: Uint128,
+    ) -> DexResult<Self> {
+        let balance_before = ask_asset.query_balance(&deps.querier, &account)?;
+        Ok(Self::MinReceive {
+            account,
+            ask_asset,
+            balance_before,
+            min_receive,
+        })
+    }
+}
+
+impl ReplyPayload<DexAdapter, DexError> for DexReplyPayload {
+    fn handle(self, deps: DepsMut, _env: Env, _module: DexAdapter, _reply: Reply) -> DexResult {
+        match self {
+            DexReplyPayload::MinReceive {
+                account,
+                ask_asset,
+                balance_before,
+                min_receive,
+            } => {
+                let balance = ask_asset.query_balance(&deps.querier, &account)?;
+                let received = balance.saturating_sub(balance_before);
+                if received < min_receive {
+                    return Err(DexError::MinReceiveNotMet {
+                        min_receive,
+                        return_amount: received,
+                    });
+                }
+                Ok(Response::new().add_attribute("received", received))
+            }
+        }
+    }
+}
@@ -1,12 +1,17 @@
 pub mod adapter;
+mod aggregation;
 pub mod api;
 pub mod contract;
 mod exchanges;
 pub(crate) mod handlers;
 pub mod state;
+mod twap;
 pub mod msg {
     pub use abstract_dex_standard::msg::*;
 }
+pub mod client {
+    pub use abstract_dex_standard::client::*;
+}
 pub use abstract_dex_standard::DEX_ADAPTER_ID;
 
 // Export interface for use in SDK modules
@@ -35,7 +40,7 @@ pub mod interface {
 
     use abstract_adapter::traits::Dependencies;
     use abstract_dex_standard::ans_action::{DexAnsAction, WholeDexAction};
-    use cosmwasm_std::Decimal;
+    use cosmwasm_std::{Decimal, Uint128};
     use cw_asset::{AssetBase, AssetInfoBase};
     use cw_orch::{build::BuildPostfix, interface};
     use cw_orch::{contract::Contract, prelude::*};
@@ -106,6 +111,19 @@ pub mod interface {
             dex: String,
             account: impl AsRef<AccountI<Chain>>,
             ans_host: &AnsHost<Chain>,
+        ) -> Result<(), AbstractInterfaceError> {
+            self.ans_swap_with_min_receive(offer_asset, ask_asset, None, dex, account, ans_host)
+        }
+
+        /// Swap using ans resolved assets, failing if less than `min_receive` is received
+        pub fn ans_swap_with_min_receive(
+            &self,
+            offer_asset: (&str, u128),
+            ask_asset: &str,
+            min_receive: Option<Uint128>,
+            dex: String,
+            account: impl AsRef<AccountI<Chain>>,
+            ans_host: &AnsHost<Chain>,
         ) -> Result<(), AbstractInterfaceError> {
             let asset = AssetEntry::new(offer_asset.0);
             let ask_asset = AssetEntry::new(ask_asset);
@@ -115,6 +133,7 @@ pub mod interface {
                 ask_asset,
                 max_spread: Some(Decimal::percent(30)),
                 belief_price: None,
+                min_receive,
             };
             self.ans_action(dex, action, account, ans_host)?;
             Ok(())
@@ -128,6 +147,19 @@ pub mod interface {
             dex: String,
             account: impl AsRef<AccountI<Chain>>,
             pool: PoolAddressBase<String>,
+        ) -> Result<(), AbstractInterfaceError> {
+            self.raw_swap_native_with_min_receive(offer_asset, ask_asset, None, dex, account, pool)
+        }
+
+        /// Swap using raw native assets denoms, failing if less than `min_receive` is received
+        pub fn raw_swap_native_with_min_receive(
+            &self,
+            offer_asset: (&str, u128),
+            ask_asset: &str,
+            min_receive: Option<Uint128>,
+            dex: String,
+            account: impl AsRef<AccountI<Chain>>,
+            pool: PoolAddressBase<String>,
         ) -> Result<(), AbstractInterfaceError> {
             let action = DexAction::Swap {
                 offer_asset: AssetBase::native(offer_asset.0, offer_asset.1),
@@ -135,6 +167,7 @@ pub mod interface {
                 pool,
                 max_spread: Some(Decimal::percent(30)),
                 belief_price: None,
+                min_receive,
             };
             self.raw_action(dex, action, account)?;
             Ok(())
@@ -1,4 +1,21 @@
+use abstract_adapter::std::objects::DexAssetPairing;
 use abstract_dex_standard::msg::DexFees;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Decimal, Decimal256, Timestamp};
+use cw_storage_plus::{Item, Map};
 
 pub const DEX_FEES: Item<DexFees> = Item::new("dex_fees");
+
+/// Cumulative price of a pair at a point in time
+#[cosmwasm_schema::cw_serde]
+pub struct PriceObservation {
+    pub timestamp: Timestamp,
+    /// Price accumulated over time (price × seconds) up to `timestamp`
+    pub cumulative_price: Decimal256,
+    /// Spot price at `timestamp` if the dex doesn't track a cumulative price. The median of the
+    /// latest spot prices is accumulated until the next observation
+    pub spot_price: Option<Decimal>,
+}
+
+/// Price observations of pairs on local dexes, by the seconds of their timestamp
+pub const PRICE_OBSERVATIONS: Map<(&DexAssetPairing, u64), PriceObservation> =
+    Map::new("price_observations");
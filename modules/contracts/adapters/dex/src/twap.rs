@@ -0,0 +1,285 @@
+//! Time weighted average prices of dexes without a native TWAP, from observed cumulative prices.
+//!
+//! Dexes that only provide a spot price, like FIN, get their spot prices observed and accumulated here.
+//! abstract-std's `TimeWeightedAverage` doesn't fit this: it's a single `Item` under a static key while
+//! pairs are only known at runtime, it accumulates into a `u128` that truncates prices below one, and
+//! it only averages over one fixed period while `Price` takes any window up to [`MAX_OBSERVED_WINDOW`].
+
+use abstract_adapter::sdk::features::AbstractNameService;
+use abstract_adapter::std::objects::{DexAssetPairing, PoolAddress};
+use abstract_dex_standard::{ans_action::pool_address, msg::PriceKind, DexCommand, DexError};
+use cosmwasm_std::{
+    Decimal, Decimal256, Deps, DepsMut, Env, Order, StdError, StdResult, Storage, Timestamp,
+};
+use cw_asset::AssetInfo;
+use cw_storage_plus::Bound;
+
+use crate::{
+    contract::{DexAdapter, DexResult},
+    exchanges::exchange_resolver::{is_over_ibc, resolve_exchange},
+    state::{PriceObservation, PRICE_OBSERVATIONS},
+};
+
+/// Minimum time between two observations of a pair. Observing more often is a no-op, so callers
+/// can't crowd out older observations.
+pub(crate) const OBSERVATION_PERIOD: u64 = 5 * 60;
+/// Longest TWAP window served from observations, older observations are dropped.
+pub(crate) const MAX_OBSERVED_WINDOW: u64 = 24 * 60 * 60;
+/// Number of latest spot prices whose median is accumulated, so that a single spot price
+/// manipulated in the observing transaction doesn't move the average.
+const SPOT_PRICE_SAMPLES: usize = 3;
+
+/// Local pool of a pair with its resolved assets.
+pub(crate) struct PairPool {
+    /// Pairing on the local dex, key of the price observations
+    pub pair: DexAssetPairing,
+    pub exchange: Box<dyn DexCommand>,
+    pub pool: PoolAddress,
+    pub base: AssetInfo,
+    pub quote: AssetInfo,
+}
+
+impl PairPool {
+    /// Resolve the pool of the pair, the dex must be local.
+    pub(crate) fn resolve(
+        deps: Deps,
+        env: &Env,
+        module: &DexAdapter,
+        pair: &DexAssetPairing,
+    ) -> DexResult<Self> {
+        let (local_dex_name, is_over_ibc) = is_over_ibc(env, pair.dex())?;
+        if is_over_ibc {
+            return Err(DexError::ForeignDex(pair.dex().to_owned()));
+        }
+        let ans = module.name_service(deps);
+        let base = ans.query(pair.asset_x())?;
+        let quote = ans.query(pair.asset_y())?;
+        let pool = pool_address(
+            &local_dex_name,
+            (pair.asset_x().clone(), pair.asset_y().clone()),
+            &deps.querier,
+            ans.host(),
+        )?;
+        Ok(Self {
+            pair: DexAssetPairing::new(
+                pair.asset_x().clone(),
+                pair.asset_y().clone(),
+                &local_dex_name,
+            ),
+            exchange: resolve_exchange(&local_dex_name)?,
+            pool,
+            base,
+            quote,
+        })
+    }
+
+    /// Price of the pair, TWAPs are averaged from the observations if the dex doesn't provide them.
+    pub(crate) fn price(&self, deps: Deps, env: &Env, kind: &PriceKind) -> DexResult<Decimal> {
+        if let PriceKind::Twap { window_seconds } = kind {
+            if *window_seconds == 0 || *window_seconds > env.block.time.seconds() {
+                return Err(DexError::InvalidPriceWindow(*window_seconds));
+            }
+        }
+        let price = self.exchange.price(
+            deps,
+            env,
+            self.pool.clone(),
+            self.base.clone(),
+            self.quote.clone(),
+            kind,
+        );
+        match (price, kind) {
+            (Err(DexError::NotImplemented(_)), PriceKind::Twap { window_seconds }) => {
+                let window_seconds = *window_seconds;
+                if window_seconds > MAX_OBSERVED_WINDOW {
+                    return Err(DexError::InvalidPriceWindow(window_seconds));
+                }
+                let cumulative_price = match self.cumulative_price(deps, env)? {
+                    Some(cumulative_price) => cumulative_price,
+                    None => accumulate(
+                        &latest_observations(deps.storage, &self.pair, SPOT_PRICE_SAMPLES)?,
+                        env.block.time,
+                    )?,
+                };
+                let start = env.block.time.seconds() - window_seconds;
+                let observation = PRICE_OBSERVATIONS
+                    .prefix(&self.pair)
+                    .range(
+                        deps.storage,
+                        None,
+                        Some(Bound::inclusive(start)),
+                        Order::Descending,
+                    )
+                    .next()
+                    .transpose()?
+                    .map(|(_, observation)| observation)
+                    .ok_or_else(|| DexError::NoPriceObservation {
+                        pair: self.pair.clone(),
+                        window_seconds,
+                    })?;
+                Ok(average(&observation, env.block.time, cumulative_price)?)
+            }
+            (price, _) => price,
+        }
+    }
+
+    /// Record the cumulative price of the pair, at most once per [`OBSERVATION_PERIOD`], and drop
+    /// the observations that are too old to start a TWAP window.
+    pub(crate) fn observe(&self, deps: DepsMut, env: &Env) -> DexResult<()> {
+        let now = env.block.time;
+        let latest = latest_observations(deps.storage, &self.pair, SPOT_PRICE_SAMPLES)?;
+        if latest.first().is_some_and(|last| {
+            now.seconds() < last.timestamp.seconds().saturating_add(OBSERVATION_PERIOD)
+        }) {
+            return Ok(());
+        }
+
+        let observation = match self.cumulative_price(deps.as_ref(), env)? {
+            Some(cumulative_price) => PriceObservation {
+                timestamp: now,
+                cumulative_price,
+                spot_price: None,
+            },
+            None => PriceObservation {
+                timestamp: now,
+                cumulative_price: accumulate(&latest, now)?,
+                spot_price: Some(self.exchange.price(
+                    deps.as_ref(),
+                    env,
+                    self.pool.clone(),
+                    self.base.clone(),
+                    self.quote.clone(),
+                    &PriceKind::Spot,
+                )?),
+            },
+        };
+        PRICE_OBSERVATIONS.save(deps.storage, (&self.pair, now.seconds()), &observation)?;
+
+        // Keep the latest observation before the longest window so it can still start one
+        let oldest_start = now
+            .seconds()
+            .saturating_sub(MAX_OBSERVED_WINDOW + OBSERVATION_PERIOD);
+        let expired = PRICE_OBSERVATIONS
+            .prefix(&self.pair)
+            .keys(
+                deps.storage,
+                None,
+                Some(Bound::exclusive(oldest_start)),
+                Order::Ascending,
+            )
+            .collect::<StdResult<Vec<u64>>>()?;
+        for timestamp in expired {
+            PRICE_OBSERVATIONS.remove(deps.storage, (&self.pair, timestamp));
+        }
+        Ok(())
+    }
+
+    fn cumulative_price(&self, deps: Deps, env: &Env) -> DexResult<Option<Decimal256>> {
+        self.exchange.cumulative_price(
+            deps,
+            env,
+            self.pool.clone(),
+            self.base.clone(),
+            self.quote.clone(),
+        )
+    }
+}
+
+/// Latest observations of a pair, newest first.
+fn latest_observations(
+    storage: &dyn Storage,
+    pair: &DexAssetPairing,
+    limit: usize,
+) -> StdResult<Vec<PriceObservation>> {
+    PRICE_OBSERVATIONS
+        .prefix(pair)
+        .range(storage, None, None, Order::Descending)
+        .take(limit)
+        .map(|item| item.map(|(_, observation)| observation))
+        .collect()
+}
+
+/// Cumulative price at `now`, accumulating the median of the spot prices of the `latest`
+/// observations, newest first, over the time since the last one was made.
+fn accumulate(latest: &[PriceObservation], now: Timestamp) -> StdResult<Decimal256> {
+    let Some(last) = latest.first() else {
+        return Ok(Decimal256::zero());
+    };
+    let mut spot_prices: Vec<Decimal> = latest.iter().filter_map(|o| o.spot_price).collect();
+    spot_prices.sort();
+    let spot_price = spot_prices
+        .get(spot_prices.len().saturating_sub(1) / 2)
+        .copied()
+        .unwrap_or_default();
+    let elapsed = now.seconds().saturating_sub(last.timestamp.seconds());
+    Ok(last.cumulative_price.checked_add(
+        Decimal256::from(spot_price).checked_mul(Decimal256::from_ratio(elapsed, 1u128))?,
+    )?)
+}
+
+/// Average price between the `start` observation and `now`.
+fn average(
+    start: &PriceObservation,
+    now: Timestamp,
+    cumulative_price: Decimal256,
+) -> StdResult<Decimal> {
+    let elapsed = now.seconds() - start.timestamp.seconds();
+    let average = cumulative_price
+        .checked_sub(start.cumulative_price)?
+        .checked_div(Decimal256::from_ratio(elapsed, 1u128))
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    Decimal::try_from(average).map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn observation(seconds: u64, cumulative_price: u128, spot_price: u128) -> PriceObservation {
+        PriceObservation {
+            timestamp: Timestamp::from_seconds(seconds),
+            cumulative_price: Decimal256::from_ratio(cumulative_price, 1u128),
+            spot_price: Some(Decimal::from_ratio(spot_price, 1u128)),
+        }
+    }
+
+    #[test]
+    fn accumulates_median_spot_price() {
+        let last = observation(100, 50, 2);
+        assert_eq!(
+            accumulate(&[last.clone()], Timestamp::from_seconds(130)).unwrap(),
+            Decimal256::from_ratio(110u128, 1u128)
+        );
+        assert_eq!(
+            accumulate(&[], Timestamp::from_seconds(130)).unwrap(),
+            Decimal256::zero()
+        );
+
+        // A manipulated latest spot price of 100 is ignored for the median of 2
+        let latest = [
+            observation(100, 50, 100),
+            observation(50, 0, 2),
+            observation(0, 0, 1),
+        ];
+        assert_eq!(
+            accumulate(&latest, Timestamp::from_seconds(130)).unwrap(),
+            Decimal256::from_ratio(110u128, 1u128)
+        );
+    }
+
+    #[test]
+    fn averages_since_start_observation() {
+        // Price of 2 for 100 seconds, then 4 for 100 seconds
+        let now = Timestamp::from_seconds(200);
+        let cumulative_price = Decimal256::from_ratio(600u128, 1u128);
+
+        assert_eq!(
+            average(&observation(100, 200, 4), now, cumulative_price).unwrap(),
+            Decimal::from_ratio(4u128, 1u128)
+        );
+        assert_eq!(
+            average(&observation(0, 0, 2), now, cumulative_price).unwrap(),
+            Decimal::from_ratio(3u128, 1u128)
+        );
+    }
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "mock")]
+
+use abstract_adapter::std::objects::{gov_type::GovernanceDetails, AnsAsset};
+use abstract_dex_adapter::{
+    contract::CONTRACT_VERSION, interface::DexAdapter, msg::DexInstantiateMsg, DEX_ADAPTER_ID,
+};
+use abstract_dex_standard::ans_action::DexAnsAction;
+use abstract_interface::{Abstract, AccountI, AdapterDeployer, DeployStrategy};
+use abstract_mock_adapter::{
+    interface::MockProtocol,
+    lp_denom,
+    msg::{InstantiateMsg, Pool, QueryMsg},
+    MOCK,
+};
+use cosmwasm_std::{coin, Decimal};
+use cw_orch::prelude::*;
+
+const JUNO: &str = "ujuno";
+const USD: &str = "uusd";
+
+/// Account with 1_000 juno and 1_000 usd and the dex adapter.
+/// Juno is worth 2 usd, the protocol takes a 1% fee.
+fn setup() -> anyhow::Result<(
+    MockBech32,
+    Abstract<MockBech32>,
+    AccountI<MockBech32>,
+    DexAdapter<MockBech32>,
+    MockProtocol<MockBech32>,
+)> {
+    let chain = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(chain.clone(), ())?;
+    let governance = GovernanceDetails::Monarchy {
+        monarch: chain.sender_addr().to_string(),
+    };
+    let _root_account = AccountI::create_default_account(&abstr, governance.clone())?;
+
+    let protocol = MockProtocol::deploy(
+        chain.clone(),
+        &InstantiateMsg {
+            swap_fee: Decimal::percent(1),
+            prices: vec![
+                (JUNO.to_owned(), Decimal::percent(200)),
+                (USD.to_owned(), Decimal::one()),
+            ],
+            markets: vec![],
+            reward_rates: vec![],
+        },
+    )?;
+    chain.set_balance(
+        &protocol.address()?,
+        vec![
+            coin(1_000_000, USD),
+            coin(1_000_000, lp_denom(&[JUNO, USD])),
+        ],
+    )?;
+    protocol.register_on_ans(&abstr, &[("juno", JUNO), ("usd", USD)])?;
+
+    let dex_adapter = DexAdapter::new(DEX_ADAPTER_ID, chain.clone());
+    dex_adapter.deploy(
+        CONTRACT_VERSION.parse()?,
+        DexInstantiateMsg {
+            swap_fee: Decimal::zero(),
+            recipient_account: 0,
+        },
+        DeployStrategy::Try,
+    )?;
+    let account = AccountI::create_default_account(&abstr, governance)?;
+    account.install_adapter(&dex_adapter, &[])?;
+    chain.set_balance(
+        &account.address()?,
+        vec![coin(1_000, JUNO), coin(1_000, USD)],
+    )?;
+    Ok((chain, abstr, account, dex_adapter, protocol))
+}
+
+#[test]
+fn swap_on_mock_protocol() -> anyhow::Result<()> {
+    let (chain, abstr, account, dex_adapter, _) = setup()?;
+
+    dex_adapter.ans_swap(("juno", 100), "usd", MOCK.into(), &account, &abstr.ans_host)?;
+
+    let account_addr = account.address()?;
+    assert_eq!(chain.query_balance(&account_addr, JUNO)?.u128(), 900);
+    assert_eq!(chain.query_balance(&account_addr, USD)?.u128(), 1_198);
+    Ok(())
+}
+
+#[test]
+fn provide_and_withdraw_liquidity() -> anyhow::Result<()> {
+    let (chain, abstr, account, dex_adapter, protocol) = setup()?;
+    let account_addr = account.address()?;
+    let lp_denom = lp_denom(&[JUNO, USD]);
+
+    // 100 juno and 200 usd are worth 400 usd, an LP token per usd
+    dex_adapter.ans_provide_liquidity(
+        vec![("juno", 100), ("usd", 200)],
+        MOCK.into(),
+        &account,
+        &abstr.ans_host,
+    )?;
+    assert_eq!(chain.query_balance(&account_addr, &lp_denom)?.u128(), 400);
+    assert_eq!(chain.query_balance(&account_addr, JUNO)?.u128(), 900);
+    assert_eq!(chain.query_balance(&account_addr, USD)?.u128(), 800);
+
+    dex_adapter.ans_action(
+        MOCK.into(),
+        DexAnsAction::WithdrawLiquidity {
+            lp_token: AnsAsset::new("mock/juno,usd", 100u128),
+        },
+        &account,
+        &abstr.ans_host,
+    )?;
+    assert_eq!(chain.query_balance(&account_addr, &lp_denom)?.u128(), 300);
+    assert_eq!(chain.query_balance(&account_addr, JUNO)?.u128(), 925);
+    assert_eq!(chain.query_balance(&account_addr, USD)?.u128(), 850);
+
+    let pool: Pool = protocol.query(&QueryMsg::Pool { lp_denom })?;
+    assert_eq!(pool.supply.u128(), 300);
+    assert_eq!(pool.reserves, vec![coin(75, JUNO), coin(150, USD)]);
+    Ok(())
+}
@@ -9,13 +9,18 @@ use abstract_adapter::std::{
 use abstract_dex_adapter::{
     contract::CONTRACT_VERSION, interface::DexAdapter, msg::DexInstantiateMsg, DEX_ADAPTER_ID,
 };
-use abstract_dex_standard::ans_action::DexAnsAction;
+use abstract_dex_standard::{
+    action::DexAction,
+    ans_action::DexAnsAction,
+    msg::{DexQueryMsg, OrdersResponse},
+};
 use abstract_interface::{
     Abstract, AbstractInterfaceError, AccountI, AdapterDeployer, AnsHost, DeployStrategy,
 };
 use abstract_neutron_dex_adapter::NEUTRON;
 use anyhow::Result as AnyResult;
 use cosmwasm_std::{coin, coins, Decimal, Uint128};
+use cw_asset::{AssetBase, AssetInfoBase};
 use cw_orch::prelude::*;
 use cw_orch_neutron_test_tube::{
     neutron_test_tube::{
@@ -56,6 +61,29 @@ pub const NTRN: &str = "untrn";
 pub const ATOM: &str = "uatom";
 pub const STARS: &str = "ustars";
 
+/// Deposit initial liquidity of a pair, which creates its pool with the next pool id
+fn create_pool(chain: &NeutronTestTube, token_a: &str, token_b: &str) -> AnyResult<()> {
+    let pool_create_msg = MsgDeposit {
+        token_a: token_a.to_string(),
+        token_b: token_b.to_string(),
+        amounts_a: vec!["100000000000".to_string()],
+        amounts_b: vec!["100000000000".to_string()],
+        creator: chain.sender_addr().to_string(),
+        receiver: chain.sender_addr().to_string(),
+        fees: vec![0],
+        options: vec![DepositOptions {
+            disable_autoswap: false,
+            fail_tx_on_bel: false,
+        }],
+        tick_indexes_a_to_b: vec![0],
+    };
+
+    let app = chain.app.borrow_mut();
+    let dex = Dex::new(&*app);
+    dex.deposit(pool_create_msg, chain.sender())?;
+    Ok(())
+}
+
 #[allow(clippy::type_complexity)]
 fn setup_mock() -> anyhow::Result<(
     NeutronTestTube,
@@ -90,25 +118,7 @@ fn setup_mock() -> anyhow::Result<(
     )?;
 
     // Deposit some inital liquidity
-    let pool_create_msg = MsgDeposit {
-        token_a: NTRN.to_string(),
-        token_b: ATOM.to_string(),
-        amounts_a: vec!["100000000000".to_string()],
-        amounts_b: vec!["100000000000".to_string()],
-        creator: chain.sender_addr().to_string(),
-        receiver: chain.sender_addr().to_string(),
-        fees: vec![0],
-        options: vec![DepositOptions {
-            disable_autoswap: false,
-            fail_tx_on_bel: false,
-        }],
-        tick_indexes_a_to_b: vec![0],
-    };
-
-    let app = chain.app.borrow_mut();
-    let dex = Dex::new(&*app);
-    dex.deposit(pool_create_msg, chain.sender())?;
-    drop(app);
+    create_pool(&chain, NTRN, ATOM)?;
 
     // We need to register some pairs and assets on the ans host contract
     // Register NTRN and ATOM assets
@@ -177,6 +187,7 @@ fn swap() -> AnyResult<()> {
         ask_asset,
         max_spread: Some(Decimal::percent(30)),
         belief_price: Some(Decimal::percent(1)),
+        min_receive: None,
     };
     dex_adapter.ans_action(NEUTRON.into(), action, &os, &abstr.ans_host)?;
 
@@ -188,3 +199,115 @@ fn swap() -> AnyResult<()> {
 
     Ok(())
 }
+
+fn query_orders(
+    dex_adapter: &DexAdapter<NeutronTestTube>,
+    account: &AccountI<NeutronTestTube>,
+    pool_id: u64,
+) -> AnyResult<OrdersResponse> {
+    let orders = dex_adapter.query(&abstract_dex_adapter::msg::QueryMsg::Module(
+        DexQueryMsg::Orders {
+            account: account.addr_str()?,
+            pool: PoolAddressBase::id(pool_id),
+            dex: NEUTRON.into(),
+        },
+    ))?;
+    Ok(orders)
+}
+
+#[test]
+fn limit_order() -> AnyResult<()> {
+    let (chain, dex_adapter, os, _abstr, pool_id) = setup_mock()?;
+    let account_addr = os.address()?;
+
+    let offer_value = 1_000_000u128;
+    chain.bank_send(&account_addr, coins(offer_value, NTRN))?;
+
+    // Sell NTRN above the pool price so the order stays open
+    dex_adapter.raw_action(
+        NEUTRON.into(),
+        DexAction::PlaceLimitOrder {
+            pool: PoolAddressBase::id(pool_id),
+            offer_asset: AssetBase::native(NTRN, offer_value),
+            ask_asset: AssetInfoBase::native(ATOM),
+            price: Decimal::percent(200),
+        },
+        &os,
+    )?;
+
+    // The adapter fee is charged on the offer asset
+    let OrdersResponse { orders } = query_orders(&dex_adapter, &os, pool_id)?;
+    assert_eq!(orders.len(), 1);
+    let order = &orders[0];
+    assert_eq!(order.offer_asset, AssetBase::native(NTRN, 990_000u128));
+    assert_eq!(order.ask_asset, AssetInfoBase::native(ATOM));
+
+    dex_adapter.raw_action(
+        NEUTRON.into(),
+        DexAction::CancelOrder {
+            pool: PoolAddressBase::id(pool_id),
+            order_id: order.id.clone(),
+        },
+        &os,
+    )?;
+
+    let OrdersResponse { orders } = query_orders(&dex_adapter, &os, pool_id)?;
+    assert!(orders.is_empty());
+    let balance = chain.query_balance(&account_addr, NTRN)?;
+    assert_eq!(balance.u128(), 990_000);
+
+    Ok(())
+}
+
+#[test]
+fn limit_order_outside_of_pool_is_rejected() -> AnyResult<()> {
+    let (chain, dex_adapter, os, _abstr, pool_id) = setup_mock()?;
+    chain.bank_send(&os.address()?, coins(1_000_000, STARS))?;
+
+    // STARS isn't traded in the NTRN/ATOM pool
+    let err: anyhow::Error = dex_adapter
+        .raw_action(
+            NEUTRON.into(),
+            DexAction::PlaceLimitOrder {
+                pool: PoolAddressBase::id(pool_id),
+                offer_asset: AssetBase::native(STARS, 1_000_000u128),
+                ask_asset: AssetInfoBase::native(ATOM),
+                price: Decimal::percent(200),
+            },
+            &os,
+        )
+        .unwrap_err()
+        .into();
+    assert!(err.root_cause().to_string().contains(STARS));
+
+    Ok(())
+}
+
+#[test]
+fn orders_of_other_pool_are_excluded() -> AnyResult<()> {
+    let (chain, dex_adapter, os, _abstr, pool_id) = setup_mock()?;
+    let account_addr = os.address()?;
+
+    create_pool(&chain, STARS, ATOM)?;
+    let stars_pool_id = pool_id + 1;
+    chain.bank_send(&account_addr, coins(1_000_000, STARS))?;
+
+    // Order on the STARS/ATOM pool, not on the NTRN/ATOM pool
+    dex_adapter.raw_action(
+        NEUTRON.into(),
+        DexAction::PlaceLimitOrder {
+            pool: PoolAddressBase::id(stars_pool_id),
+            offer_asset: AssetBase::native(STARS, 1_000_000u128),
+            ask_asset: AssetInfoBase::native(ATOM),
+            price: Decimal::percent(200),
+        },
+        &os,
+    )?;
+
+    let OrdersResponse { orders } = query_orders(&dex_adapter, &os, pool_id)?;
+    assert!(orders.is_empty());
+    let OrdersResponse { orders } = query_orders(&dex_adapter, &os, stars_pool_id)?;
+    assert_eq!(orders.len(), 1);
+
+    Ok(())
+}
@@ -5,13 +5,17 @@ use std::format;
 use abstract_adapter::std::{
     ans_host::ExecuteMsgFns,
     objects::{
-        gov_type::GovernanceDetails, pool_id::PoolAddressBase, AnsAsset, AssetEntry, PoolMetadata,
+        gov_type::GovernanceDetails, pool_id::PoolAddressBase, AnsAsset, AssetEntry,
+        DexAssetPairing, PoolMetadata,
     },
 };
 use abstract_dex_adapter::{
     contract::CONTRACT_VERSION,
     interface::DexAdapter,
-    msg::{DexInstantiateMsg, SwapNode},
+    msg::{
+        DexAction, DexInstantiateMsg, DexQueryMsg, PositionsResponse, PriceKind, PriceResponse,
+        SwapNode,
+    },
     DEX_ADAPTER_ID,
 };
 use abstract_dex_standard::ans_action::DexAnsAction;
@@ -23,7 +27,15 @@ use anyhow::Result as AnyResult;
 use cosmwasm_std::{coin, coins, Decimal, Uint128};
 use cw_asset::AssetBase;
 use cw_orch::prelude::*;
-use cw_orch_osmosis_test_tube::OsmosisTestTube;
+use cw_orch_osmosis_test_tube::{
+    osmosis_test_tube::{
+        osmosis_std::types::osmosis::concentratedliquidity::v1beta1::{
+            CreateConcentratedLiquidityPoolsProposal, PoolRecord,
+        },
+        GovWithAppAccess,
+    },
+    OsmosisTestTube,
+};
 
 /// Provide liquidity using Abstract's OS (registered in daemon_state).
 pub fn provide<Chain: CwEnv>(
@@ -472,6 +484,7 @@ fn swap_route() -> AnyResult<()> {
             offer_asset: AssetBase::native("uatom", swap_value),
             max_spread: None,
             belief_price: None,
+            min_receive: None,
         },
         &os,
     )?;
@@ -484,3 +497,247 @@ fn swap_route() -> AnyResult<()> {
 
     Ok(())
 }
+
+/// Full range ticks of a pool with a tick spacing of 100
+const MIN_TICK: i64 = -108_000_000;
+const MAX_TICK: i64 = 342_000_000;
+
+/// Create a concentrated liquidity atom/osmo pool, returns its id
+fn create_cl_pool(chain: &OsmosisTestTube, last_pool_id: u64) -> AnyResult<u64> {
+    let app = chain.app.borrow();
+    GovWithAppAccess::new(&app).propose_and_execute(
+        CreateConcentratedLiquidityPoolsProposal::TYPE_URL.to_string(),
+        CreateConcentratedLiquidityPoolsProposal {
+            title: "Create concentrated uatom:uosmo pool".to_string(),
+            description: "Create concentrated uatom:uosmo pool".to_string(),
+            pool_records: vec![PoolRecord {
+                denom0: "uatom".to_string(),
+                denom1: "uosmo".to_string(),
+                tick_spacing: 100,
+                spread_factor: "0".to_string(),
+            }],
+        },
+        chain.sender_addr().to_string(),
+        chain.sender(),
+    )?;
+    // Pool ids are sequential
+    Ok(last_pool_id + 1)
+}
+
+fn positions(
+    dex_adapter: &DexAdapter<OsmosisTestTube>,
+    account: &AccountI<OsmosisTestTube>,
+    pool_id: u64,
+) -> AnyResult<PositionsResponse> {
+    let positions = dex_adapter.query(&abstract_dex_adapter::msg::QueryMsg::Module(
+        DexQueryMsg::Positions {
+            account: account.addr_str()?,
+            pool: PoolAddressBase::id(pool_id),
+            dex: OSMOSIS.into(),
+        },
+    ))?;
+    Ok(positions)
+}
+
+#[test]
+fn concentrated_liquidity_position() -> AnyResult<()> {
+    let (chain, dex_adapter, os, _abstr, pool_id) = setup_mock()?;
+    let pool_id = create_cl_pool(&chain, pool_id)?;
+    let account_addr = os.address()?;
+
+    let provide_value = 1_000_000u128;
+    chain.bank_send(account_addr.to_string(), coins(provide_value * 2, "uatom"))?;
+    chain.bank_send(account_addr.to_string(), coins(provide_value * 2, "uosmo"))?;
+    let assets = vec![
+        AssetBase::native("uatom", provide_value),
+        AssetBase::native("uosmo", provide_value),
+    ];
+
+    dex_adapter.raw_action(
+        OSMOSIS.into(),
+        DexAction::CreatePosition {
+            pool: PoolAddressBase::id(pool_id),
+            lower_tick: MIN_TICK,
+            upper_tick: MAX_TICK,
+            assets: assets.clone(),
+            min_assets: vec![AssetBase::native("uatom", provide_value - 1)],
+        },
+        &os,
+    )?;
+
+    let PositionsResponse { positions: created } = positions(&dex_adapter, &os, pool_id)?;
+    assert_eq!(created.len(), 1);
+    let position = &created[0];
+    assert_eq!(position.lower_tick, MIN_TICK);
+    assert_eq!(position.upper_tick, MAX_TICK);
+    assert!(!position.liquidity.is_zero());
+    assert_eq!(position.assets.len(), 2);
+
+    // Can't add less than the minimum
+    let res = dex_adapter.raw_action(
+        OSMOSIS.into(),
+        DexAction::AddToPosition {
+            pool: PoolAddressBase::id(pool_id),
+            position_id: position.id,
+            assets: assets.clone(),
+            min_assets: vec![AssetBase::native("uosmo", provide_value * 2)],
+        },
+        &os,
+    );
+    assert!(res.is_err());
+
+    // Adding replaces the position with a new one
+    dex_adapter.raw_action(
+        OSMOSIS.into(),
+        DexAction::AddToPosition {
+            pool: PoolAddressBase::id(pool_id),
+            position_id: position.id,
+            assets,
+            min_assets: vec![],
+        },
+        &os,
+    )?;
+    let PositionsResponse { positions: added } = positions(&dex_adapter, &os, pool_id)?;
+    assert_eq!(added.len(), 1);
+    let position = &added[0];
+    assert!(position.liquidity > created[0].liquidity);
+
+    dex_adapter.raw_action(
+        OSMOSIS.into(),
+        DexAction::CollectPositionRewards {
+            pool: PoolAddressBase::id(pool_id),
+            position_ids: vec![position.id],
+        },
+        &os,
+    )?;
+
+    let balance_before = chain.query_balance(&account_addr, "uatom")?;
+    dex_adapter.raw_action(
+        OSMOSIS.into(),
+        DexAction::WithdrawPosition {
+            pool: PoolAddressBase::id(pool_id),
+            position_id: position.id,
+            share: Decimal::percent(50),
+        },
+        &os,
+    )?;
+    let PositionsResponse {
+        positions: withdrawn,
+    } = positions(&dex_adapter, &os, pool_id)?;
+    assert_eq!(withdrawn.len(), 1);
+    assert!(withdrawn[0].liquidity < position.liquidity);
+    let balance = chain.query_balance(&account_addr, "uatom")?;
+    assert!(balance > balance_before);
+
+    Ok(())
+}
+
+#[test]
+fn withdraw_position_of_other_pool() -> AnyResult<()> {
+    let (chain, dex_adapter, os, _abstr, other_pool_id) = setup_mock()?;
+    let pool_id = create_cl_pool(&chain, other_pool_id)?;
+    let account_addr = os.address()?;
+
+    chain.bank_send(account_addr.to_string(), coins(1_000_000, "uatom"))?;
+    chain.bank_send(account_addr.to_string(), coins(1_000_000, "uosmo"))?;
+    dex_adapter.raw_action(
+        OSMOSIS.into(),
+        DexAction::CreatePosition {
+            pool: PoolAddressBase::id(pool_id),
+            lower_tick: MIN_TICK,
+            upper_tick: MAX_TICK,
+            assets: vec![
+                AssetBase::native("uatom", 1_000_000u128),
+                AssetBase::native("uosmo", 1_000_000u128),
+            ],
+            min_assets: vec![],
+        },
+        &os,
+    )?;
+    let position = positions(&dex_adapter, &os, pool_id)?.positions[0].clone();
+
+    let err: anyhow::Error = dex_adapter
+        .raw_action(
+            OSMOSIS.into(),
+            DexAction::WithdrawPosition {
+                pool: PoolAddressBase::id(other_pool_id),
+                position_id: position.id,
+                share: Decimal::one(),
+            },
+            &os,
+        )
+        .unwrap_err()
+        .into();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains(&format!("is not in pool {other_pool_id}")));
+    assert_eq!(
+        positions(&dex_adapter, &os, pool_id)?.positions,
+        vec![position]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_position_min_asset_not_in_pool() -> AnyResult<()> {
+    let (chain, dex_adapter, os, _abstr, pool_id) = setup_mock()?;
+    let pool_id = create_cl_pool(&chain, pool_id)?;
+    let account_addr = os.address()?;
+
+    chain.bank_send(account_addr.to_string(), coins(1_000_000, "uatom"))?;
+    chain.bank_send(account_addr.to_string(), coins(1_000_000, "uosmo"))?;
+
+    let res = dex_adapter.raw_action(
+        OSMOSIS.into(),
+        DexAction::CreatePosition {
+            pool: PoolAddressBase::id(pool_id),
+            lower_tick: MIN_TICK,
+            upper_tick: MAX_TICK,
+            assets: vec![
+                AssetBase::native("uatom", 1_000_000u128),
+                AssetBase::native("uosmo", 1_000_000u128),
+            ],
+            min_assets: vec![AssetBase::native("ujunox", 1u128)],
+        },
+        &os,
+    );
+    assert!(res.is_err());
+    assert!(positions(&dex_adapter, &os, pool_id)?.positions.is_empty());
+
+    Ok(())
+}
+
+fn price(dex_adapter: &DexAdapter<OsmosisTestTube>, kind: PriceKind) -> AnyResult<PriceResponse> {
+    let price = dex_adapter.query(&abstract_dex_adapter::msg::QueryMsg::Module(
+        DexQueryMsg::Price {
+            pair: DexAssetPairing::new("osmo".into(), "atom".into(), OSMOSIS),
+            kind,
+        },
+    ))?;
+    Ok(price)
+}
+
+#[test]
+fn twap_price() -> AnyResult<()> {
+    let (chain, dex_adapter, _os, _abstr, _pool_id) = setup_mock()?;
+
+    // The window can't start before the genesis
+    let err = price(
+        &dex_adapter,
+        PriceKind::Twap {
+            window_seconds: u64::MAX,
+        },
+    )
+    .unwrap_err();
+    assert!(err.root_cause().to_string().contains("Price window"));
+
+    chain.app.borrow().increase_time(60);
+    let twap = price(&dex_adapter, PriceKind::Twap { window_seconds: 30 })?;
+    let spot = price(&dex_adapter, PriceKind::Spot)?;
+    // Nothing was swapped, the average is the spot price
+    assert_eq!(twap.price, spot.price);
+    assert_eq!(spot.price, Decimal::one());
+    Ok(())
+}
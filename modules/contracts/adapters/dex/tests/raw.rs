@@ -142,6 +142,7 @@ fn raw_swap_raw() -> anyhow::Result<()> {
                 pool: PoolAddress::contract(wyndex.raw_eur_pair).into(),
                 max_spread: Some(Decimal::percent(30)),
                 belief_price: None,
+                min_receive: None,
             },
         },
     });
@@ -161,3 +162,38 @@ fn raw_swap_raw() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn raw_swap_min_receive() -> anyhow::Result<()> {
+    let (chain, wyndex, _, account, _) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    let swap_msg = |min_receive: u128| {
+        abstract_dex_adapter::msg::ExecuteMsg::Module(AdapterRequestMsg {
+            account_address: None,
+            request: DexExecuteMsg::Action {
+                dex: WYNDEX.to_owned(),
+                action: DexAction::Swap {
+                    offer_asset: AssetBase::native(EUR, 100u128),
+                    ask_asset: AssetInfoBase::native(USD),
+                    pool: PoolAddress::contract(wyndex.eur_usd_pair.clone()).into(),
+                    max_spread: Some(Decimal::percent(30)),
+                    belief_price: None,
+                    min_receive: Some(min_receive.into()),
+                },
+            },
+        })
+    };
+
+    // swap returns 98 USD, less than the minimum
+    let res = account.execute_on_module(DEX_ADAPTER_ID, swap_msg(99), vec![]);
+    assert!(res.is_err());
+    let eur_balance = chain.query_balance(&account_addr, EUR)?;
+    assert_eq!(eur_balance.u128(), 10_000);
+
+    account.execute_on_module(DEX_ADAPTER_ID, swap_msg(98), vec![])?;
+    let usd_balance = chain.query_balance(&account_addr, USD)?;
+    assert_eq!(usd_balance.u128(), 98);
+
+    Ok(())
+}
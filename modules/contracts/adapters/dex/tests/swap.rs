@@ -105,6 +105,38 @@ fn swap_native_without_chain() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn swap_native_min_receive() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, account, abstr) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    // swap returns 98 USD, less than the minimum
+    let res = dex_adapter.ans_swap_with_min_receive(
+        (EUR, 100),
+        USD,
+        Some(99u128.into()),
+        WYNDEX.into(),
+        &account,
+        &abstr.ans_host,
+    );
+    assert!(res.is_err());
+    let eur_balance = chain.query_balance(&account_addr, EUR)?;
+    assert_eq!(eur_balance.u128(), 10_000);
+
+    dex_adapter.ans_swap_with_min_receive(
+        (EUR, 100),
+        USD,
+        Some(98u128.into()),
+        WYNDEX.into(),
+        &account,
+        &abstr.ans_host,
+    )?;
+    let usd_balance = chain.query_balance(&account_addr, USD)?;
+    assert_eq!(usd_balance.u128(), 98);
+
+    Ok(())
+}
+
 #[test]
 fn swap_raw() -> anyhow::Result<()> {
     let (chain, wyndex, dex_adapter, account, abstr) = setup_mock()?;
@@ -17,6 +17,7 @@ pub const PROVIDE_COLLATERAL: u64 = 8144;
 pub const WITHDRAW_COLLATERAL: u64 = 8145;
 pub const BORROW: u64 = 8146;
 pub const REPAY: u64 = 8147;
+pub const LIQUIDATE: u64 = 8148;
 
 impl<T> MoneyMarketAdapter for T where T: AbstractNameService + Execution + AbstractRegistryAccess {}
 
@@ -50,6 +51,9 @@ pub trait MoneyMarketAdapter: AbstractNameService + AbstractRegistryAccess + Exe
             abstract_money_market_standard::raw_action::MoneyMarketRawRequest::Repay { borrowed_asset, collateral_asset } => {
                 (self.resolve_repay(deps, sender, borrowed_asset, collateral_asset, action.contract_addr, money_market.as_mut())?, REPAY)
             }
+            abstract_money_market_standard::raw_action::MoneyMarketRawRequest::Liquidate { user, debt_asset, collateral_asset } => {
+                (self.resolve_liquidate(deps, sender, user, debt_asset, collateral_asset, action.contract_addr, money_market.as_mut())?, LIQUIDATE)
+            }
         })
     }
 
@@ -153,4 +157,24 @@ pub trait MoneyMarketAdapter: AbstractNameService + AbstractRegistryAccess + Exe
         money_market.fetch_data(sender, &deps.querier, &self.ans_host(deps)?)?;
         money_market.repay(deps, contract_addr, borrowed_asset)
     }
+
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_liquidate(
+        &self,
+        deps: Deps,
+        sender: Addr,
+        user: String,
+        debt_asset: AssetBase<String>,
+        collateral_asset: AssetInfoBase<String>,
+        contract_addr: String,
+        money_market: &mut dyn MoneyMarketCommand,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let contract_addr = deps.api.addr_validate(&contract_addr)?;
+        let user = deps.api.addr_validate(&user)?;
+        let debt_asset = debt_asset.check(deps.api, None)?;
+        let collateral_asset = collateral_asset.check(deps.api, None)?;
+
+        money_market.fetch_data(sender, &deps.querier, &self.ans_host(deps)?)?;
+        money_market.liquidate(deps, contract_addr, user, debt_asset, collateral_asset)
+    }
 }
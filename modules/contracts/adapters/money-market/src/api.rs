@@ -6,7 +6,10 @@ use abstract_adapter::sdk::{
 use abstract_adapter::std::objects::{module::ModuleId, AnsAsset, AssetEntry};
 use abstract_money_market_standard::{
     ans_action::MoneyMarketAnsAction,
-    msg::{MoneyMarketExecuteMsg, MoneyMarketName, MoneyMarketQueryMsg},
+    msg::{
+        HealthResponse, MoneyMarketExecuteMsg, MoneyMarketName, MoneyMarketQueryMsg,
+        UserLiquidationThresholdResponse,
+    },
     raw_action::{MoneyMarketRawAction, MoneyMarketRawRequest},
 };
 use cosmwasm_schema::serde::de::DeserializeOwned;
@@ -189,6 +192,24 @@ pub mod raw {
                 },
             })
         }
+
+        /// Liquidate the position of another user on MoneyMarket
+        pub fn liquidate(
+            &self,
+            contract_addr: Addr,
+            user: String,
+            collateral_asset: AssetInfo,
+            debt_asset: Asset,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(MoneyMarketRawAction {
+                contract_addr: contract_addr.to_string(),
+                request: MoneyMarketRawRequest::Liquidate {
+                    user,
+                    collateral_asset: collateral_asset.into(),
+                    debt_asset: debt_asset.into(),
+                },
+            })
+        }
     }
 
     impl<T: MoneyMarketInterface> MoneyMarket<'_, T> {
@@ -266,6 +287,24 @@ pub mod raw {
                 money_market: self.money_market_name(),
             })
         }
+        pub fn liquidation_threshold(
+            &self,
+            user: String,
+            collateral_asset: AssetInfo,
+            borrowed_asset: AssetInfo,
+            contract_addr: String,
+        ) -> AbstractSdkResult<Decimal> {
+            self.query::<UserLiquidationThresholdResponse>(
+                MoneyMarketQueryMsg::RawLiquidationThreshold {
+                    user,
+                    borrowed_asset: borrowed_asset.into(),
+                    collateral_asset: collateral_asset.into(),
+                    contract_addr,
+                    money_market: self.money_market_name(),
+                },
+            )
+            .map(|response| response.liquidation_threshold)
+        }
         pub fn price(&self, quote: AssetInfo, base: AssetInfo) -> AbstractSdkResult<Decimal> {
             self.query(MoneyMarketQueryMsg::RawPrice {
                 quote: quote.into(),
@@ -394,6 +433,68 @@ pub mod ans {
                 borrowed_asset,
             })
         }
+
+        /// Liquidate the position of another user on MoneyMarket
+        pub fn liquidate(
+            &self,
+            user: String,
+            collateral_asset: AssetEntry,
+            debt_asset: AnsAsset,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(MoneyMarketAnsAction::Liquidate {
+                user,
+                collateral_asset,
+                debt_asset,
+            })
+        }
+
+        /// Leverage the position up to `target_ltv`, swapping the borrowed asset on `dex`
+        pub fn leverage(
+            &self,
+            target_ltv: Decimal,
+            collateral: AnsAsset,
+            borrow_asset: AssetEntry,
+            dex: String,
+            max_spread: Option<Decimal>,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            let adapters = self.base.adapters(self.deps);
+
+            adapters.execute(
+                self.money_market_module_id(),
+                MoneyMarketExecuteMsg::Leverage {
+                    money_market: self.money_market_name(),
+                    target_ltv,
+                    collateral,
+                    borrow_asset,
+                    dex,
+                    max_spread,
+                },
+            )
+        }
+
+        /// Deleverage the position down to `target_ltv`, swapping the collateral on `dex`
+        pub fn deleverage(
+            &self,
+            target_ltv: Decimal,
+            collateral_asset: AssetEntry,
+            borrowed_asset: AssetEntry,
+            dex: String,
+            max_spread: Option<Decimal>,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            let adapters = self.base.adapters(self.deps);
+
+            adapters.execute(
+                self.money_market_module_id(),
+                MoneyMarketExecuteMsg::Deleverage {
+                    money_market: self.money_market_name(),
+                    target_ltv,
+                    collateral_asset,
+                    borrowed_asset,
+                    dex,
+                    max_spread,
+                },
+            )
+        }
     }
 
     impl<T: MoneyMarketInterface> AnsMoneyMarket<'_, T> {
@@ -457,6 +558,38 @@ pub mod ans {
                 money_market: self.money_market_name(),
             })
         }
+        pub fn liquidation_threshold(
+            &self,
+            user: String,
+            collateral_asset: AssetEntry,
+            borrowed_asset: AssetEntry,
+        ) -> AbstractSdkResult<Decimal> {
+            self.query::<UserLiquidationThresholdResponse>(
+                MoneyMarketQueryMsg::AnsLiquidationThreshold {
+                    user,
+                    borrowed_asset,
+                    collateral_asset,
+                    money_market: self.money_market_name(),
+                },
+            )
+            .map(|response| response.liquidation_threshold)
+        }
+        /// Health of the account position after the actions, without executing them
+        pub fn simulate_health(
+            &self,
+            account: String,
+            after: Vec<MoneyMarketAnsAction>,
+            collateral_asset: AssetEntry,
+            borrowed_asset: AssetEntry,
+        ) -> AbstractSdkResult<HealthResponse> {
+            self.query(MoneyMarketQueryMsg::SimulateHealth {
+                account,
+                after,
+                collateral_asset,
+                borrowed_asset,
+                money_market: self.money_market_name(),
+            })
+        }
         pub fn price(&self, quote: AssetEntry, base: AssetEntry) -> AbstractSdkResult<Decimal> {
             self.query(MoneyMarketQueryMsg::AnsPrice {
                 quote,
@@ -744,6 +877,49 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn liquidate_msg() {
+        let mut deps = mock_dependencies();
+        let account = test_account(deps.api);
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .build();
+        let stub = MockModule::new(deps.api, account.clone());
+        let money_market = stub
+            .ans_money_market(deps.as_ref(), "mars".into())
+            .with_module_id(TEST_MODULE_ID);
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        let money_market_name = "mars".to_string();
+        let user = deps.api.addr_make("liquidated").to_string();
+        let collateral_asset = AssetEntry::new("juno");
+        let debt_asset = AnsAsset::new("usdc", 1000u128);
+
+        let expected = expected_request_with_test_account(
+            MoneyMarketExecuteMsg::AnsAction {
+                money_market: money_market_name,
+                action: MoneyMarketAnsAction::Liquidate {
+                    user: user.clone(),
+                    debt_asset: debt_asset.clone(),
+                    collateral_asset: collateral_asset.clone(),
+                },
+            },
+            account.addr(),
+        );
+
+        let actual = money_market.liquidate(user, collateral_asset, debt_asset);
+
+        assert!(actual.is_ok());
+
+        let actual = match actual.unwrap() {
+            CosmosMsg::Wasm(msg) => msg,
+            _ => panic!("expected wasm msg"),
+        };
+        let expected = wasm_execute(&abstr.module_address, &expected, vec![]).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
     mod raw {
         use super::*;
 
@@ -21,7 +21,12 @@ pub const MONEY_MARKET_ADAPTER: MoneyMarketAdapter =
     MoneyMarketAdapter::new(MONEY_MARKET_ADAPTER_ID, CONTRACT_VERSION, None)
         .with_instantiate(handlers::instantiate_handler)
         .with_execute(handlers::execute_handler)
-        .with_query(handlers::query_handler);
+        .with_query(handlers::query_handler)
+        .with_request_actions(
+            MoneyMarketExecuteMsg::ACTIONS,
+            MoneyMarketExecuteMsg::action_name,
+        )
+        .with_payload_replies::<handlers::MoneyMarketReplyPayload>();
 
 #[cfg(feature = "export")]
 export_endpoints!(MONEY_MARKET_ADAPTER, MoneyMarketAdapter);
@@ -8,13 +8,15 @@ use abstract_adapter::std::objects::{
     AccountId,
 };
 use abstract_money_market_standard::{
-    ans_action::MoneyMarketActionResolveWrapper, raw_action::MoneyMarketRawAction, MoneyMarketError,
+    ans_action::MoneyMarketActionResolveWrapper, msg::DEFAULT_LEVERAGE_MAX_SPREAD,
+    raw_action::MoneyMarketRawAction, MoneyMarketCommand, MoneyMarketError,
 };
-use cosmwasm_std::{ensure_eq, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{ensure_eq, Addr, Deps, DepsMut, Env, MessageInfo, ReplyOn, Response};
 
 use crate::{
     contract::{MoneyMarketAdapter, MoneyMarketResult},
-    handlers::execute::platform_resolver::is_over_ibc,
+    handlers::{execute::platform_resolver::is_over_ibc, MoneyMarketReplyPayload},
+    leverage,
     msg::MoneyMarketExecuteMsg,
     platform_resolver,
     state::MONEY_MARKET_FEES,
@@ -65,6 +67,66 @@ pub fn execute_handler(
                 handle_local_request(deps, info, &module, local_money_market_name, action)
             }
         }
+        MoneyMarketExecuteMsg::Leverage {
+            money_market: money_market_name,
+            target_ltv,
+            collateral,
+            borrow_asset,
+            dex,
+            max_spread,
+        } => {
+            let (money_market, account) =
+                resolve_local_money_market(deps.as_ref(), &env, &module, money_market_name)?;
+            let (msgs, leftovers) = leverage::leverage(
+                deps.as_ref(),
+                &module,
+                money_market.as_ref(),
+                account,
+                target_ltv,
+                collateral,
+                borrow_asset,
+                dex,
+                max_spread.unwrap_or(DEFAULT_LEVERAGE_MAX_SPREAD),
+            )?;
+            let account_msg = module
+                .executor(deps.as_ref())
+                .execute_with_reply_and_payload(
+                    msgs,
+                    ReplyOn::Success,
+                    &MoneyMarketReplyPayload::LoopExecuted(leftovers),
+                )?;
+            Ok(Response::new().add_submessage(account_msg))
+        }
+        MoneyMarketExecuteMsg::Deleverage {
+            money_market: money_market_name,
+            target_ltv,
+            collateral_asset,
+            borrowed_asset,
+            dex,
+            max_spread,
+        } => {
+            let (money_market, account) =
+                resolve_local_money_market(deps.as_ref(), &env, &module, money_market_name)?;
+            let (msgs, leftovers) = leverage::deleverage(
+                deps.as_ref(),
+                &module,
+                money_market.as_ref(),
+                account,
+                target_ltv,
+                collateral_asset,
+                borrowed_asset,
+                dex,
+                max_spread.unwrap_or(DEFAULT_LEVERAGE_MAX_SPREAD),
+            )?;
+            let account_msg = module
+                .executor(deps.as_ref())
+                .execute_with_reply_and_payload(
+                    msgs,
+                    ReplyOn::Success,
+                    &MoneyMarketReplyPayload::LoopExecuted(leftovers),
+                )?;
+            Ok(Response::new().add_submessage(account_msg))
+        }
         MoneyMarketExecuteMsg::UpdateFee {
             money_market_fee,
             recipient_account: recipient_account_id,
@@ -123,3 +185,20 @@ fn handle_local_request(
     let account_msg = module.executor(deps.as_ref()).execute(msgs)?;
     Ok(Response::new().add_message(account_msg))
 }
+
+/// Resolve a money market of this chain, with its data fetched for the target account
+pub(crate) fn resolve_local_money_market(
+    deps: Deps,
+    env: &Env,
+    module: &MoneyMarketAdapter,
+    money_market_name: String,
+) -> MoneyMarketResult<(Box<dyn MoneyMarketCommand>, Addr)> {
+    let (local_money_market_name, is_over_ibc) = is_over_ibc(env, &money_market_name)?;
+    if is_over_ibc {
+        return Err(MoneyMarketError::ForeignMoneyMarket(money_market_name));
+    }
+    let mut money_market = platform_resolver::resolve_money_market(&local_money_market_name)?;
+    let account = module.account(deps)?.into_addr();
+    money_market.fetch_data(account.clone(), &deps.querier, &module.ans_host(deps)?)?;
+    Ok((money_market, account))
+}
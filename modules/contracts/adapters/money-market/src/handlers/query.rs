@@ -4,7 +4,7 @@ use abstract_money_market_standard::{
     msg::{
         GenerateMessagesResponse, MoneyMarketExecuteMsg, MoneyMarketQueryMsg, PriceResponse,
         UserBorrowResponse, UserCollateralResponse, UserCurrentLTVResponse, UserDepositResponse,
-        UserMaxLTVResponse,
+        UserLiquidationThresholdResponse, UserMaxLTVResponse,
     },
     query::MoneyMarketQueryResolveWrapper,
     MoneyMarketError,
@@ -13,6 +13,7 @@ use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
 
 use crate::{
     contract::{MoneyMarketAdapter, MoneyMarketResult},
+    health::simulate_health,
     platform_resolver::{self, is_over_ibc},
     state::MONEY_MARKET_FEES,
 };
@@ -217,6 +218,49 @@ fn handle_local_query(
                 )?,
             })?
         }
+        MoneyMarketQueryMsg::RawLiquidationThreshold {
+            user,
+            collateral_asset,
+            borrowed_asset,
+            contract_addr,
+            money_market: _,
+        } => {
+            let user = deps.api.addr_validate(&user)?;
+            let contract_addr = deps.api.addr_validate(&contract_addr)?;
+            let collateral_asset = collateral_asset.check(deps.api, None)?;
+            let borrowed_asset = borrowed_asset.check(deps.api, None)?;
+
+            money_market.fetch_data(user.clone(), &deps.querier, &ans_host)?;
+            to_json_binary(&UserLiquidationThresholdResponse {
+                liquidation_threshold: money_market.liquidation_threshold(
+                    deps,
+                    contract_addr,
+                    user,
+                    borrowed_asset,
+                    collateral_asset,
+                )?,
+            })?
+        }
+        MoneyMarketQueryMsg::SimulateHealth {
+            account,
+            after,
+            collateral_asset,
+            borrowed_asset,
+            money_market: _,
+        } => {
+            let account = deps.api.addr_validate(&account)?;
+
+            money_market.fetch_data(account.clone(), &deps.querier, &ans_host)?;
+            to_json_binary(&simulate_health(
+                deps,
+                module,
+                money_market.as_ref(),
+                account,
+                after,
+                borrowed_asset,
+                collateral_asset,
+            )?)?
+        }
         MoneyMarketQueryMsg::RawPrice {
             quote,
             base,
@@ -0,0 +1,47 @@
+use abstract_adapter::sdk::{base::ReplyPayload, Execution};
+use abstract_adapter::std::registry::Account;
+use abstract_money_market_standard::MoneyMarketError;
+use cosmwasm_std::{DepsMut, Env, Reply, Response};
+
+use crate::{
+    contract::{MoneyMarketAdapter, MoneyMarketResult},
+    handlers::execute::resolve_local_money_market,
+    leverage::{self, LoopLeftovers},
+};
+
+/// Payload of the money market actions executed on the account, handled after they succeed.
+#[cosmwasm_schema::cw_serde]
+pub enum MoneyMarketReplyPayload {
+    /// A leverage or deleverage loop is executed, sweep what its swaps returned above their minimum
+    LoopExecuted(LoopLeftovers),
+}
+
+impl ReplyPayload<MoneyMarketAdapter, MoneyMarketError> for MoneyMarketReplyPayload {
+    fn handle(
+        self,
+        deps: DepsMut,
+        env: Env,
+        mut module: MoneyMarketAdapter,
+        _reply: Reply,
+    ) -> MoneyMarketResult {
+        match self {
+            MoneyMarketReplyPayload::LoopExecuted(leftovers) => {
+                // Continue executing on the account of the loop
+                module.target_account = Some(Account::new(leftovers.account.clone()));
+                let deps = deps.as_ref();
+                let (money_market, _) = resolve_local_money_market(
+                    deps,
+                    &env,
+                    &module,
+                    leftovers.money_market.clone(),
+                )?;
+                let msgs = leverage::sweep_leftovers(deps, money_market.as_ref(), leftovers)?;
+                if msgs.is_empty() {
+                    return Ok(Response::new());
+                }
+                let account_msg = module.executor(deps).execute(msgs)?;
+                Ok(Response::new().add_message(account_msg))
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+//! Health simulation of a money-market position.
+
+use abstract_adapter::sdk::features::AbstractNameService;
+use abstract_adapter::std::objects::AssetEntry;
+use abstract_money_market_standard::{
+    ans_action::MoneyMarketAnsAction, msg::HealthResponse, MoneyMarketCommand, MoneyMarketError,
+};
+use cosmwasm_std::{Addr, Decimal, Decimal256, Deps, StdError};
+
+use crate::contract::{MoneyMarketAdapter, MoneyMarketResult};
+
+/// Collateral and debt of a position, valued in the borrowed asset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Position {
+    pub collateral_value: Decimal256,
+    pub debt_value: Decimal256,
+}
+
+/// Simulate the health of the `account` position in the market of the asset pair after `actions`.
+/// Collateral changes are weighted with the current max LTV and liquidation threshold.
+pub(crate) fn simulate_health(
+    deps: Deps,
+    module: &MoneyMarketAdapter,
+    money_market: &dyn MoneyMarketCommand,
+    account: Addr,
+    actions: Vec<MoneyMarketAnsAction>,
+    borrowed_asset: AssetEntry,
+    collateral_asset: AssetEntry,
+) -> MoneyMarketResult<HealthResponse> {
+    let ans_host = module.ans_host(deps)?;
+    let ans = module.name_service(deps);
+    let borrowed_info = ans.query(&borrowed_asset)?;
+    let collateral_info = ans.query(&collateral_asset)?;
+
+    let position_addr = money_market.current_ltv_address(
+        &deps.querier,
+        &ans_host,
+        borrowed_asset.clone(),
+        collateral_asset.clone(),
+    )?;
+    let (collateral_value, debt_value) = money_market.position_value(
+        deps,
+        position_addr,
+        account.clone(),
+        borrowed_info.clone(),
+        collateral_info.clone(),
+    )?;
+    let max_ltv_addr = money_market.max_ltv_address(
+        &deps.querier,
+        &ans_host,
+        borrowed_asset.clone(),
+        collateral_asset.clone(),
+    )?;
+    let max_ltv = money_market.max_ltv(
+        deps,
+        max_ltv_addr,
+        account.clone(),
+        borrowed_info.clone(),
+        collateral_info.clone(),
+    )?;
+    let liquidation_threshold_addr = money_market.liquidation_threshold_address(
+        &deps.querier,
+        &ans_host,
+        borrowed_asset.clone(),
+        collateral_asset.clone(),
+    )?;
+    let liquidation_threshold = money_market.liquidation_threshold(
+        deps,
+        liquidation_threshold_addr,
+        account,
+        borrowed_info.clone(),
+        collateral_info.clone(),
+    )?;
+    let collateral_price = money_market.price(deps, collateral_info, borrowed_info)?;
+
+    let mut position = Position {
+        collateral_value,
+        debt_value,
+    };
+    for action in actions {
+        position = apply_action(
+            position,
+            action,
+            &borrowed_asset,
+            &collateral_asset,
+            collateral_price,
+        )?;
+    }
+    Ok(health(position, max_ltv, liquidation_threshold))
+}
+
+/// Apply the change of a single action to the position.
+/// Lending and liquidations don't change the position.
+/// Collateral and debt actions must target the market of the position.
+pub(crate) fn apply_action(
+    position: Position,
+    action: MoneyMarketAnsAction,
+    borrowed_asset: &AssetEntry,
+    collateral_asset: &AssetEntry,
+    collateral_price: Decimal,
+) -> MoneyMarketResult<Position> {
+    let ensure_market = |borrowed: &AssetEntry, collateral: &AssetEntry| {
+        if borrowed != borrowed_asset || collateral != collateral_asset {
+            return Err(MoneyMarketError::ArgumentMismatch(
+                format!("{borrowed}/{collateral}"),
+                vec![borrowed_asset.to_string(), collateral_asset.to_string()],
+            ));
+        }
+        Ok(())
+    };
+    let Position {
+        collateral_value,
+        debt_value,
+    } = position;
+    Ok(match action {
+        MoneyMarketAnsAction::ProvideCollateral {
+            borrowable_asset,
+            collateral_asset,
+        } => {
+            ensure_market(&borrowable_asset, &collateral_asset.name)?;
+            let value = Decimal256::from_ratio(collateral_asset.amount, 1u128)
+                .checked_mul(collateral_price.into())
+                .map_err(StdError::from)?;
+            Position {
+                collateral_value: collateral_value
+                    .checked_add(value)
+                    .map_err(StdError::from)?,
+                debt_value,
+            }
+        }
+        MoneyMarketAnsAction::WithdrawCollateral {
+            borrowable_asset,
+            collateral_asset,
+        } => {
+            ensure_market(&borrowable_asset, &collateral_asset.name)?;
+            let value = Decimal256::from_ratio(collateral_asset.amount, 1u128)
+                .checked_mul(collateral_price.into())
+                .map_err(StdError::from)?;
+            Position {
+                collateral_value: collateral_value
+                    .checked_sub(value)
+                    .map_err(StdError::from)?,
+                debt_value,
+            }
+        }
+        MoneyMarketAnsAction::Borrow {
+            borrow_asset,
+            collateral_asset,
+        } => {
+            ensure_market(&borrow_asset.name, &collateral_asset)?;
+            let amount = Decimal256::from_ratio(borrow_asset.amount, 1u128);
+            Position {
+                collateral_value,
+                debt_value: debt_value.checked_add(amount).map_err(StdError::from)?,
+            }
+        }
+        MoneyMarketAnsAction::Repay {
+            borrowed_asset,
+            collateral_asset,
+        } => {
+            ensure_market(&borrowed_asset.name, &collateral_asset)?;
+            // Repaying more than the debt is refunded
+            let amount = Decimal256::from_ratio(borrowed_asset.amount, 1u128);
+            Position {
+                collateral_value,
+                debt_value: debt_value.saturating_sub(amount),
+            }
+        }
+        MoneyMarketAnsAction::Deposit { .. }
+        | MoneyMarketAnsAction::Withdraw { .. }
+        | MoneyMarketAnsAction::Liquidate { .. } => position,
+    })
+}
+
+/// Health of the position given its risk parameters.
+pub(crate) fn health(
+    position: Position,
+    max_ltv: Decimal,
+    liquidation_threshold: Decimal,
+) -> HealthResponse {
+    let Position {
+        collateral_value,
+        debt_value,
+    } = position;
+    let (ltv, health_factor) = if debt_value.is_zero() {
+        (Decimal::zero(), None)
+    } else if collateral_value.is_zero() {
+        // Debt without collateral is always liquidatable
+        (Decimal::MAX, Some(Decimal::zero()))
+    } else {
+        let ltv = debt_value
+            .checked_div(collateral_value)
+            .ok()
+            .and_then(|ltv| Decimal::try_from(ltv).ok())
+            .unwrap_or(Decimal::MAX);
+        let health_factor = liquidation_threshold
+            .checked_div(ltv)
+            .unwrap_or(Decimal::MAX);
+        (ltv, Some(health_factor))
+    };
+    HealthResponse {
+        collateral_value,
+        debt_value,
+        ltv,
+        max_ltv,
+        liquidation_threshold,
+        health_factor,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use abstract_adapter::std::objects::AnsAsset;
+
+    fn position(collateral_value: u128, debt_value: u128) -> Position {
+        Position {
+            collateral_value: Decimal256::from_ratio(collateral_value, 1u128),
+            debt_value: Decimal256::from_ratio(debt_value, 1u128),
+        }
+    }
+
+    #[test]
+    fn borrow_lowers_health() {
+        let usdc = AssetEntry::new("usdc");
+        let juno = AssetEntry::new("juno");
+        let after = apply_action(
+            position(1_000, 400),
+            MoneyMarketAnsAction::Borrow {
+                borrow_asset: AnsAsset::new("usdc", 100u128),
+                collateral_asset: juno.clone(),
+            },
+            &usdc,
+            &juno,
+            Decimal::percent(50),
+        )
+        .unwrap();
+        assert_eq!(after, position(1_000, 500));
+
+        let health = health(after, Decimal::percent(60), Decimal::percent(80));
+        assert_eq!(health.ltv, Decimal::percent(50));
+        assert_eq!(health.health_factor, Some(Decimal::percent(160)));
+    }
+
+    #[test]
+    fn collateral_is_valued_in_borrowed_asset() {
+        let usdc = AssetEntry::new("usdc");
+        let juno = AssetEntry::new("juno");
+        let after = apply_action(
+            position(1_000, 500),
+            MoneyMarketAnsAction::WithdrawCollateral {
+                borrowable_asset: usdc.clone(),
+                collateral_asset: AnsAsset::new("juno", 800u128),
+            },
+            &usdc,
+            &juno,
+            Decimal::percent(50),
+        )
+        .unwrap();
+        assert_eq!(after, position(600, 500));
+
+        let health = health(after, Decimal::percent(60), Decimal::percent(80));
+        assert!(health.health_factor.unwrap() < Decimal::one());
+    }
+
+    #[test]
+    fn rejects_other_markets() {
+        let usdc = AssetEntry::new("usdc");
+        let juno = AssetEntry::new("juno");
+        let err = apply_action(
+            position(1_000, 500),
+            MoneyMarketAnsAction::Borrow {
+                borrow_asset: AnsAsset::new("usdc", 100u128),
+                collateral_asset: AssetEntry::new("osmo"),
+            },
+            &usdc,
+            &juno,
+            Decimal::one(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, MoneyMarketError::ArgumentMismatch(..)));
+    }
+
+    #[test]
+    fn no_debt_has_no_health_factor() {
+        let health = health(position(1_000, 0), Decimal::one(), Decimal::one());
+        assert_eq!(health.ltv, Decimal::zero());
+        assert_eq!(health.health_factor, None);
+    }
+
+    #[test]
+    fn values_base_units_of_18_decimal_tokens() {
+        let usdc = AssetEntry::new("usdc");
+        let eth = AssetEntry::new("eth");
+        let whole = 10u128.pow(18);
+        let after = apply_action(
+            position(10_000 * whole, 4_000 * whole),
+            MoneyMarketAnsAction::ProvideCollateral {
+                borrowable_asset: usdc.clone(),
+                collateral_asset: AnsAsset::new("eth", 1_000 * whole),
+            },
+            &usdc,
+            &eth,
+            Decimal::percent(200),
+        )
+        .unwrap();
+        assert_eq!(after, position(12_000 * whole, 4_000 * whole));
+
+        let health = health(after, Decimal::percent(60), Decimal::percent(80));
+        assert_eq!(health.ltv, Decimal::from_ratio(1u128, 3u128));
+    }
+}
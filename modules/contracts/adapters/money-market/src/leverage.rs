@@ -0,0 +1,642 @@
+//! Leverage and deleverage loops over a money market, swapping through the dex adapter.
+
+use abstract_adapter::sdk::{features::AbstractNameService, AbstractSdkError, ModuleInterface};
+use abstract_adapter::std::{
+    adapter::AdapterRequestMsg,
+    objects::{AnsAsset, AssetEntry},
+};
+use abstract_dex_adapter::{
+    msg::{AggregatedSwapResponse, DexExecuteMsg, DexQueryMsg, ExecuteMsg, QueryMsg},
+    DEX_ADAPTER_ID,
+};
+use abstract_money_market_standard::{MoneyMarketCommand, MoneyMarketError};
+use cosmwasm_std::{ensure, wasm_execute, Addr, CosmosMsg, Decimal, Decimal256, Deps, Uint128};
+use cw_asset::{Asset, AssetInfo};
+
+use crate::contract::{MoneyMarketAdapter, MoneyMarketResult};
+
+/// Maximum number of swap steps of a single leverage or deleverage action.
+pub(crate) const MAX_LEVERAGE_STEPS: usize = 5;
+
+/// Step of a leverage or deleverage loop.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LoopStep {
+    /// Amount taken out of the money market, borrowed or withdrawn from the collateral
+    pub offer_amount: Uint128,
+    /// Minimum amount returned by the swap, provided as collateral or repaid
+    pub min_receive: Uint128,
+}
+
+/// Balances the account is left with if every swap of a loop returns its minimum.
+/// The swaps can return more, what they return above it is swept once the loop is executed.
+#[cosmwasm_schema::cw_serde]
+pub struct LoopLeftovers {
+    pub account: Addr,
+    pub money_market: String,
+    pub collateral: AssetInfo,
+    pub collateral_addr: Addr,
+    pub collateral_balance: Uint128,
+    pub borrowed: AssetInfo,
+    pub borrow_addr: Addr,
+    pub borrowed_balance: Uint128,
+}
+
+/// Borrow, swap and provide collateral until the position of the `account` reaches `target_ltv`.
+/// Returns the messages of the loop and the balances it leaves the account with.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn leverage(
+    deps: Deps,
+    module: &MoneyMarketAdapter,
+    money_market: &dyn MoneyMarketCommand,
+    account: Addr,
+    target_ltv: Decimal,
+    collateral: AnsAsset,
+    borrow_asset: AssetEntry,
+    dex: String,
+    max_spread: Decimal,
+) -> MoneyMarketResult<(Vec<CosmosMsg>, LoopLeftovers)> {
+    let dex_adapter = dex_adapter(deps, module)?;
+    let ans_host = module.ans_host(deps)?;
+    let ans = module.name_service(deps);
+    let collateral_info = ans.query(&collateral.name)?;
+    let borrow_info = ans.query(&borrow_asset)?;
+
+    let max_ltv_addr = money_market.max_ltv_address(
+        &deps.querier,
+        &ans_host,
+        borrow_asset.clone(),
+        collateral.name.clone(),
+    )?;
+    let max_ltv = money_market.max_ltv(
+        deps,
+        max_ltv_addr,
+        account.clone(),
+        borrow_info.clone(),
+        collateral_info.clone(),
+    )?;
+    if target_ltv >= max_ltv {
+        return Err(MoneyMarketError::TargetLtvTooHigh {
+            target_ltv,
+            max_ltv,
+        });
+    }
+
+    let position_addr = money_market.current_ltv_address(
+        &deps.querier,
+        &ans_host,
+        borrow_asset.clone(),
+        collateral.name.clone(),
+    )?;
+    let (mut collateral_value, debt_value) = money_market.position_value(
+        deps,
+        position_addr,
+        account.clone(),
+        borrow_info.clone(),
+        collateral_info.clone(),
+    )?;
+    let price = money_market.price(deps, collateral_info.clone(), borrow_info.clone())?;
+
+    let collateral_addr = money_market.collateral_address(
+        &deps.querier,
+        &ans_host,
+        borrow_asset.clone(),
+        collateral.name.clone(),
+    )?;
+    let borrow_addr = money_market.borrow_address(
+        &deps.querier,
+        &ans_host,
+        borrow_asset.clone(),
+        collateral.name.clone(),
+    )?;
+
+    let leftovers = LoopLeftovers {
+        money_market: money_market.name().to_owned(),
+        collateral_balance: collateral_info
+            .query_balance(&deps.querier, &account)?
+            .saturating_sub(collateral.amount),
+        borrowed_balance: borrow_info.query_balance(&deps.querier, &account)?,
+        account,
+        collateral: collateral_info.clone(),
+        collateral_addr: collateral_addr.clone(),
+        borrowed: borrow_info.clone(),
+        borrow_addr: borrow_addr.clone(),
+    };
+
+    let mut msgs = vec![];
+    if !collateral.amount.is_zero() {
+        collateral_value +=
+            Decimal256::from_ratio(collateral.amount, 1u128) * Decimal256::from(price);
+        msgs.extend(money_market.provide_collateral(
+            deps,
+            collateral_addr.clone(),
+            Asset::new(collateral_info.clone(), collateral.amount),
+        )?);
+    }
+
+    let steps = plan_leverage(
+        collateral_value,
+        debt_value,
+        target_ltv,
+        price,
+        max_spread,
+        |amount| {
+            simulate_swap(
+                deps,
+                &dex_adapter,
+                &dex,
+                AnsAsset::new(borrow_asset.clone(), amount),
+                &collateral.name,
+            )
+        },
+    )?;
+    for step in steps {
+        msgs.extend(money_market.borrow(
+            deps,
+            borrow_addr.clone(),
+            Asset::new(borrow_info.clone(), step.offer_amount),
+        )?);
+        msgs.push(swap_msg(
+            &dex_adapter,
+            &dex,
+            AnsAsset::new(borrow_asset.clone(), step.offer_amount),
+            &collateral.name,
+            step.min_receive,
+            max_spread,
+        )?);
+        msgs.extend(money_market.provide_collateral(
+            deps,
+            collateral_addr.clone(),
+            Asset::new(collateral_info.clone(), step.min_receive),
+        )?);
+    }
+    Ok((msgs, leftovers))
+}
+
+/// Withdraw collateral, swap and repay until the position of the `account` is down to `target_ltv`.
+/// Returns the messages of the loop and the balances it leaves the account with.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn deleverage(
+    deps: Deps,
+    module: &MoneyMarketAdapter,
+    money_market: &dyn MoneyMarketCommand,
+    account: Addr,
+    target_ltv: Decimal,
+    collateral_asset: AssetEntry,
+    borrowed_asset: AssetEntry,
+    dex: String,
+    max_spread: Decimal,
+) -> MoneyMarketResult<(Vec<CosmosMsg>, LoopLeftovers)> {
+    let dex_adapter = dex_adapter(deps, module)?;
+    let ans_host = module.ans_host(deps)?;
+    let ans = module.name_service(deps);
+    let collateral_info = ans.query(&collateral_asset)?;
+    let borrowed_info = ans.query(&borrowed_asset)?;
+
+    let max_ltv_addr = money_market.max_ltv_address(
+        &deps.querier,
+        &ans_host,
+        borrowed_asset.clone(),
+        collateral_asset.clone(),
+    )?;
+    let max_ltv = money_market.max_ltv(
+        deps,
+        max_ltv_addr,
+        account.clone(),
+        borrowed_info.clone(),
+        collateral_info.clone(),
+    )?;
+    if target_ltv >= max_ltv {
+        return Err(MoneyMarketError::TargetLtvTooHigh {
+            target_ltv,
+            max_ltv,
+        });
+    }
+
+    let position_addr = money_market.current_ltv_address(
+        &deps.querier,
+        &ans_host,
+        borrowed_asset.clone(),
+        collateral_asset.clone(),
+    )?;
+    let (collateral_value, debt_value) = money_market.position_value(
+        deps,
+        position_addr,
+        account.clone(),
+        borrowed_info.clone(),
+        collateral_info.clone(),
+    )?;
+    let price = money_market.price(deps, collateral_info.clone(), borrowed_info.clone())?;
+
+    let collateral_addr = money_market.collateral_address(
+        &deps.querier,
+        &ans_host,
+        borrowed_asset.clone(),
+        collateral_asset.clone(),
+    )?;
+    let borrow_addr = money_market.borrow_address(
+        &deps.querier,
+        &ans_host,
+        borrowed_asset.clone(),
+        collateral_asset.clone(),
+    )?;
+
+    let steps = plan_deleverage(
+        collateral_value,
+        debt_value,
+        target_ltv,
+        max_ltv,
+        price,
+        max_spread,
+        |amount| {
+            simulate_swap(
+                deps,
+                &dex_adapter,
+                &dex,
+                AnsAsset::new(collateral_asset.clone(), amount),
+                &borrowed_asset,
+            )
+        },
+    )?;
+    let leftovers = LoopLeftovers {
+        money_market: money_market.name().to_owned(),
+        collateral_balance: collateral_info.query_balance(&deps.querier, &account)?,
+        borrowed_balance: borrowed_info.query_balance(&deps.querier, &account)?,
+        account,
+        collateral: collateral_info.clone(),
+        collateral_addr: collateral_addr.clone(),
+        borrowed: borrowed_info.clone(),
+        borrow_addr: borrow_addr.clone(),
+    };
+    let mut msgs = vec![];
+    for step in steps {
+        msgs.extend(money_market.withdraw_collateral(
+            deps,
+            collateral_addr.clone(),
+            Asset::new(collateral_info.clone(), step.offer_amount),
+        )?);
+        msgs.push(swap_msg(
+            &dex_adapter,
+            &dex,
+            AnsAsset::new(collateral_asset.clone(), step.offer_amount),
+            &borrowed_asset,
+            step.min_receive,
+            max_spread,
+        )?);
+        msgs.extend(money_market.repay(
+            deps,
+            borrow_addr.clone(),
+            Asset::new(borrowed_info.clone(), step.min_receive),
+        )?);
+    }
+    Ok((msgs, leftovers))
+}
+
+/// Plan the borrow and swap steps that bring the position up to `target_ltv`.
+///
+/// Values are in the borrowed asset and `price` is the value of one unit of collateral.
+/// `simulate` returns the collateral received for swapping an amount of the borrowed asset.
+pub(crate) fn plan_leverage(
+    mut collateral_value: Decimal256,
+    mut debt_value: Decimal256,
+    target_ltv: Decimal,
+    price: Decimal,
+    max_spread: Decimal,
+    mut simulate: impl FnMut(Uint128) -> MoneyMarketResult<Uint128>,
+) -> MoneyMarketResult<Vec<LoopStep>> {
+    let min_return = min_return_ratio(max_spread)?;
+    let (target, price) = (Decimal256::from(target_ltv), Decimal256::from(price));
+    let mut steps = vec![];
+    // The swaps are executed one after the other, each one is simulated after the previous ones
+    let (mut swapped, mut returned) = (Uint128::zero(), Uint128::zero());
+    for _ in 0..MAX_LEVERAGE_STEPS {
+        let borrow: Uint128 = (collateral_value * target)
+            .saturating_sub(debt_value)
+            .to_uint_floor()
+            .try_into()?;
+        if borrow.is_zero() {
+            break;
+        }
+        let total_return = simulate(swapped + borrow)?;
+        let min_receive = total_return.saturating_sub(returned).mul_floor(min_return);
+        if min_receive.is_zero() {
+            break;
+        }
+        (swapped, returned) = (swapped + borrow, total_return);
+        collateral_value += Decimal256::from_ratio(min_receive, 1u128) * price;
+        debt_value += Decimal256::from_ratio(borrow, 1u128);
+        steps.push(LoopStep {
+            offer_amount: borrow,
+            min_receive,
+        });
+    }
+    Ok(steps)
+}
+
+/// Share of a simulated swap return that a step must receive with `max_spread`
+fn min_return_ratio(max_spread: Decimal) -> MoneyMarketResult<Decimal> {
+    ensure!(
+        max_spread < Decimal::one(),
+        MoneyMarketError::MaxSpreadTooHigh(max_spread)
+    );
+    Ok(Decimal::one() - max_spread)
+}
+
+/// Plan the withdraw and swap steps that bring the position down to `target_ltv`,
+/// without exceeding `max_ltv` in between.
+///
+/// Values are in the borrowed asset and `price` is the value of one unit of collateral.
+/// `simulate` returns the borrowed asset received for swapping an amount of collateral.
+pub(crate) fn plan_deleverage(
+    mut collateral_value: Decimal256,
+    mut debt_value: Decimal256,
+    target_ltv: Decimal,
+    max_ltv: Decimal,
+    price: Decimal,
+    max_spread: Decimal,
+    mut simulate: impl FnMut(Uint128) -> MoneyMarketResult<Uint128>,
+) -> MoneyMarketResult<Vec<LoopStep>> {
+    let min_return = min_return_ratio(max_spread)?;
+    let (target, max_ltv, price) = (
+        Decimal256::from(target_ltv),
+        Decimal256::from(max_ltv),
+        Decimal256::from(price),
+    );
+    let mut steps = vec![];
+    let (mut swapped, mut returned) = (Uint128::zero(), Uint128::zero());
+    for _ in 0..MAX_LEVERAGE_STEPS {
+        if debt_value <= collateral_value * target {
+            break;
+        }
+        // Collateral value to sell if it repaid the same debt value
+        let needed =
+            (debt_value - collateral_value * target).checked_div(Decimal256::one() - target)?;
+        // Collateral value that can be withdrawn without exceeding the max LTV
+        let headroom = collateral_value.saturating_sub(debt_value.checked_div(max_ltv)?);
+        let withdraw: Uint128 = needed
+            .min(headroom)
+            .checked_div(price)?
+            .to_uint_floor()
+            .try_into()?;
+        if withdraw.is_zero() {
+            break;
+        }
+        let total_return = simulate(swapped + withdraw)?;
+        let repay = total_return.saturating_sub(returned).mul_floor(min_return);
+        if repay.is_zero() {
+            break;
+        }
+        (swapped, returned) = (swapped + withdraw, total_return);
+        collateral_value =
+            collateral_value.saturating_sub(Decimal256::from_ratio(withdraw, 1u128) * price);
+        debt_value = debt_value.saturating_sub(Decimal256::from_ratio(repay, 1u128));
+        steps.push(LoopStep {
+            offer_amount: withdraw,
+            min_receive: repay,
+        });
+    }
+    if steps.is_empty() && debt_value > collateral_value * target {
+        return Err(MoneyMarketError::DeleverageNotPossible(target_ltv));
+    }
+    Ok(steps)
+}
+
+/// Provide the collateral and repay the borrowed asset the swaps of a loop returned above their
+/// minimum, that are left in the account.
+pub(crate) fn sweep_leftovers(
+    deps: Deps,
+    money_market: &dyn MoneyMarketCommand,
+    leftovers: LoopLeftovers,
+) -> MoneyMarketResult<Vec<CosmosMsg>> {
+    let LoopLeftovers {
+        account,
+        collateral,
+        collateral_addr,
+        collateral_balance,
+        borrowed,
+        borrow_addr,
+        borrowed_balance,
+        ..
+    } = leftovers;
+    let mut msgs = vec![];
+    let collateral_left = collateral
+        .query_balance(&deps.querier, &account)?
+        .saturating_sub(collateral_balance);
+    if !collateral_left.is_zero() {
+        msgs.extend(money_market.provide_collateral(
+            deps,
+            collateral_addr,
+            Asset::new(collateral.clone(), collateral_left),
+        )?);
+    }
+    // Never repay more than the outstanding debt
+    let debt = money_market.user_borrow(
+        deps,
+        borrow_addr.clone(),
+        account.clone(),
+        borrowed.clone(),
+        collateral,
+    )?;
+    let borrowed_left = borrowed
+        .query_balance(&deps.querier, &account)?
+        .saturating_sub(borrowed_balance)
+        .min(debt);
+    if !borrowed_left.is_zero() {
+        msgs.extend(money_market.repay(deps, borrow_addr, Asset::new(borrowed, borrowed_left))?);
+    }
+    Ok(msgs)
+}
+
+/// Address of the dex adapter installed on the account.
+/// It's not a dependency of this adapter, so only accounts that leverage have to install it.
+fn dex_adapter(deps: Deps, module: &MoneyMarketAdapter) -> MoneyMarketResult<Addr> {
+    module
+        .modules(deps)
+        .module_address(DEX_ADAPTER_ID)
+        .map_err(|err| match err {
+            AbstractSdkError::MissingModule { .. } => MoneyMarketError::DexAdapterNotInstalled {},
+            err => err.into(),
+        })
+}
+
+/// Return of swapping `offer_asset` on the `dex`, simulated by the dex adapter.
+fn simulate_swap(
+    deps: Deps,
+    dex_adapter: &Addr,
+    dex: &str,
+    offer_asset: AnsAsset,
+    ask_asset: &AssetEntry,
+) -> MoneyMarketResult<Uint128> {
+    let response: AggregatedSwapResponse = deps.querier.query_wasm_smart(
+        dex_adapter,
+        &QueryMsg::Module(DexQueryMsg::SimulateAggregatedSwap {
+            offer_asset,
+            ask_asset: ask_asset.clone(),
+            dexes: Some(vec![dex.to_owned()]),
+            max_splits: None,
+        }),
+    )?;
+    Ok(response.return_amount)
+}
+
+/// Swap through the dex adapter, executed by the account.
+fn swap_msg(
+    dex_adapter: &Addr,
+    dex: &str,
+    offer_asset: AnsAsset,
+    ask_asset: &AssetEntry,
+    min_receive: Uint128,
+    max_spread: Decimal,
+) -> MoneyMarketResult<CosmosMsg> {
+    // The account is the sender, no need to specify it
+    let msg: ExecuteMsg = AdapterRequestMsg::new(
+        None,
+        DexExecuteMsg::AggregatedSwap {
+            dexes: Some(vec![dex.to_owned()]),
+            offer_asset,
+            ask_asset: ask_asset.clone(),
+            min_receive,
+            max_splits: None,
+            max_spread: Some(max_spread),
+        },
+    )
+    .into();
+    Ok(wasm_execute(dex_adapter, &msg, vec![])?.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn value(amount: u128) -> Decimal256 {
+        Decimal256::from_ratio(amount, 1u128)
+    }
+
+    /// Constant product pool with equal reserves, the price of the collateral is one
+    fn simulate(amount: Uint128) -> MoneyMarketResult<Uint128> {
+        let reserves = Uint128::new(1_000_000);
+        Ok(reserves.multiply_ratio(amount, reserves + amount))
+    }
+
+    #[test]
+    fn leverages_up_to_target() {
+        let target_ltv = Decimal::percent(50);
+        let steps = plan_leverage(
+            value(1_000),
+            value(0),
+            target_ltv,
+            Decimal::one(),
+            Decimal::percent(1),
+            simulate,
+        )
+        .unwrap();
+
+        assert!(steps.len() > 1);
+        // First borrow takes the position to the target LTV before swapping
+        assert_eq!(steps[0].offer_amount, Uint128::new(500));
+
+        let collateral: Uint128 = steps.iter().map(|step| step.min_receive).sum();
+        let debt: Uint128 = steps.iter().map(|step| step.offer_amount).sum();
+        let ltv = Decimal::from_ratio(debt, collateral + Uint128::new(1_000));
+        assert!(ltv <= target_ltv);
+        assert!(ltv > Decimal::percent(45));
+    }
+
+    #[test]
+    fn leverages_18_decimal_amounts() {
+        let whole = 10u128.pow(18);
+        let steps = plan_leverage(
+            value(1_000 * whole),
+            value(0),
+            Decimal::percent(50),
+            Decimal::one(),
+            Decimal::zero(),
+            |amount| Ok(amount),
+        )
+        .unwrap();
+        assert_eq!(steps[0].offer_amount, Uint128::new(500 * whole));
+    }
+
+    #[test]
+    fn already_leveraged_position() {
+        let steps = plan_leverage(
+            value(1_000),
+            value(600),
+            Decimal::percent(50),
+            Decimal::one(),
+            Decimal::percent(1),
+            simulate,
+        )
+        .unwrap();
+        assert!(steps.is_empty());
+    }
+
+    #[test]
+    fn deleverages_within_max_ltv() {
+        let max_ltv = Decimal::percent(80);
+        let steps = plan_deleverage(
+            value(1_000),
+            value(750),
+            Decimal::percent(50),
+            max_ltv,
+            Decimal::one(),
+            Decimal::percent(1),
+            simulate,
+        )
+        .unwrap();
+
+        assert!(!steps.is_empty());
+        // The first withdrawal is limited by the max LTV
+        assert_eq!(steps[0].offer_amount, Uint128::new(62));
+
+        let withdrawn: Uint128 = steps.iter().map(|step| step.offer_amount).sum();
+        let repaid: Uint128 = steps.iter().map(|step| step.min_receive).sum();
+        let ltv = Decimal::from_ratio(Uint128::new(750) - repaid, Uint128::new(1_000) - withdrawn);
+        assert!(ltv < Decimal::percent(75));
+    }
+
+    #[test]
+    fn deleverage_above_max_ltv() {
+        let err = plan_deleverage(
+            value(1_000),
+            value(900),
+            Decimal::percent(50),
+            Decimal::percent(80),
+            Decimal::one(),
+            Decimal::percent(1),
+            simulate,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            MoneyMarketError::DeleverageNotPossible(Decimal::percent(50))
+        );
+    }
+
+    #[test]
+    fn max_spread_out_of_range() {
+        for max_spread in [Decimal::one(), Decimal::percent(150)] {
+            let err = plan_leverage(
+                value(1_000),
+                value(0),
+                Decimal::percent(50),
+                Decimal::one(),
+                max_spread,
+                simulate,
+            )
+            .unwrap_err();
+            assert_eq!(err, MoneyMarketError::MaxSpreadTooHigh(max_spread));
+
+            let err = plan_deleverage(
+                value(1_000),
+                value(750),
+                Decimal::percent(50),
+                Decimal::percent(80),
+                Decimal::one(),
+                max_spread,
+                simulate,
+            )
+            .unwrap_err();
+            assert_eq!(err, MoneyMarketError::MaxSpreadTooHigh(max_spread));
+        }
+    }
+}
@@ -2,6 +2,8 @@ pub mod adapter;
 pub mod api;
 pub mod contract;
 pub(crate) mod handlers;
+mod health;
+mod leverage;
 mod platform_resolver;
 pub mod state;
 pub mod msg {
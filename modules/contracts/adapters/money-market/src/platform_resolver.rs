@@ -15,6 +15,10 @@ pub(crate) fn identify_money_market(value: &str) -> Result<Box<dyn Identify>, Mo
         // abstract_mars_adapter::MARS => {
         //     Ok(Box::<abstract_mars_adapter::money_market::Mars>::default())
         // }
+        #[cfg(feature = "mock")]
+        abstract_mock_adapter::MOCK => {
+            Ok(Box::<abstract_mock_adapter::money_market::MockMoneyMarket>::default())
+        }
         _ => Err(MoneyMarketError::UnknownMoneyMarket(value.to_owned())),
     }
 }
@@ -31,6 +35,10 @@ pub(crate) fn resolve_money_market(
         abstract_mars_adapter::MARS => {
             Ok(Box::<abstract_mars_adapter::money_market::Mars>::default())
         }
+        #[cfg(feature = "mock")]
+        abstract_mock_adapter::MOCK => {
+            Ok(Box::<abstract_mock_adapter::money_market::MockMoneyMarket>::default())
+        }
         _ => Err(MoneyMarketError::ForeignMoneyMarket(value.to_owned())),
     }
 }
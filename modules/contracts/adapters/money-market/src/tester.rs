@@ -14,6 +14,7 @@ use abstract_adapter::std::{
     },
 };
 use abstract_client::{AbstractClient, Account, Environment};
+use abstract_dex_adapter::{interface::DexAdapter, msg::DexInstantiateMsg, DEX_ADAPTER_ID};
 use abstract_money_market_standard::msg::MoneyMarketQueryMsgFns;
 use abstract_money_market_standard::{
     ans_action::MoneyMarketAnsAction, msg::MoneyMarketExecuteMsg,
@@ -59,6 +60,16 @@ impl<Chain: MutCwEnv, Moneymarket: MockMoneyMarket> MoneyMarketTester<Chain, Mon
                 MONEY_MARKET_ADAPTER_ID,
                 ModuleVersion::Version(crate::contract::CONTRACT_VERSION.to_owned()),
             )?);
+        // Leverage actions swap through the dex adapter installed on the account
+        let dex_adapter = DexAdapter::new(DEX_ADAPTER_ID, abstr_deployment.environment());
+        dex_adapter.deploy(
+            abstract_dex_adapter::contract::CONTRACT_VERSION.parse()?,
+            DexInstantiateMsg {
+                recipient_account: 0,
+                swap_fee: FEE,
+            },
+            DeployStrategy::Try,
+        )?;
         let moneymarket_adapter =
             MoneyMarketAdapter::new(MONEY_MARKET_ADAPTER_ID, abstr_deployment.environment());
         moneymarket_adapter.deploy(
@@ -98,6 +109,7 @@ impl<Chain: MutCwEnv, Moneymarket: MockMoneyMarket> MoneyMarketTester<Chain, Mon
         let new_account = self
             .abstr_deployment
             .account_builder()
+            .install_adapter::<DexAdapter<Chain>>()
             .install_adapter::<MoneyMarketAdapter<Chain>>()
             .build()?;
         let account_addr = new_account.address()?;
@@ -185,6 +197,7 @@ impl<Chain: MutCwEnv, Moneymarket: MockMoneyMarket> MoneyMarketTester<Chain, Mon
         let new_account = self
             .abstr_deployment
             .account_builder()
+            .install_adapter::<DexAdapter<Chain>>()
             .install_adapter::<MoneyMarketAdapter<Chain>>()
             .build()?;
         let account_addr = new_account.address()?;
@@ -363,6 +376,7 @@ impl<Chain: MutCwEnv, Moneymarket: MockMoneyMarket> MoneyMarketTester<Chain, Mon
         let account = self
             .abstr_deployment
             .account_builder()
+            .install_adapter::<DexAdapter<Chain>>()
             .install_adapter::<MoneyMarketAdapter<Chain>>()
             .build()?;
 
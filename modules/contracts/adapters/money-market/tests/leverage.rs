@@ -0,0 +1,213 @@
+#![cfg(feature = "mock")]
+
+use abstract_adapter::std::{
+    adapter::AdapterRequestMsg,
+    objects::{gov_type::GovernanceDetails, AnsAsset},
+};
+use abstract_dex_adapter::{interface::DexAdapter, msg::DexInstantiateMsg, DEX_ADAPTER_ID};
+use abstract_interface::{Abstract, AccountI, AdapterDeployer, DeployStrategy};
+use abstract_mock_adapter::{
+    interface::MockProtocol,
+    msg::{InstantiateMsg, MarketConfig, PositionResponse, QueryMsg},
+    MOCK,
+};
+use abstract_money_market_adapter::{
+    contract::CONTRACT_VERSION,
+    interface::MoneyMarketAdapter,
+    msg::{ExecuteMsg, MoneyMarketExecuteMsg, MoneyMarketInstantiateMsg},
+    MONEY_MARKET_ADAPTER_ID,
+};
+use abstract_money_market_standard::ans_action::MoneyMarketAnsAction;
+use cosmwasm_std::{coin, coins, Decimal};
+use cw_orch::prelude::*;
+
+const JUNO: &str = "ujuno";
+const USD: &str = "uusd";
+
+/// Account with 1_000 juno and the money-market adapter, juno and usd trade at the same price.
+/// The dex adapter is deployed, but not installed on the account.
+#[allow(clippy::type_complexity)]
+fn setup() -> anyhow::Result<(
+    MockBech32,
+    AccountI<MockBech32>,
+    MockProtocol<MockBech32>,
+    DexAdapter<MockBech32>,
+)> {
+    let chain = MockBech32::new("mock");
+    let abstr = Abstract::deploy_on(chain.clone(), ())?;
+    let governance = GovernanceDetails::Monarchy {
+        monarch: chain.sender_addr().to_string(),
+    };
+    let _root_account = AccountI::create_default_account(&abstr, governance.clone())?;
+
+    let protocol = MockProtocol::deploy(
+        chain.clone(),
+        &InstantiateMsg {
+            swap_fee: Decimal::zero(),
+            prices: vec![
+                (JUNO.to_owned(), Decimal::one()),
+                (USD.to_owned(), Decimal::one()),
+            ],
+            markets: vec![(
+                JUNO.to_owned(),
+                MarketConfig {
+                    max_ltv: Decimal::percent(80),
+                    liquidation_threshold: Decimal::percent(90),
+                },
+            )],
+            reward_rates: vec![],
+        },
+    )?;
+    chain.set_balance(
+        &protocol.address()?,
+        vec![coin(1_000_000, JUNO), coin(1_000_000, USD)],
+    )?;
+    protocol.register_on_ans(&abstr, &[("juno", JUNO), ("usd", USD)])?;
+
+    let dex_adapter = DexAdapter::new(DEX_ADAPTER_ID, chain.clone());
+    dex_adapter.deploy(
+        abstract_dex_adapter::contract::CONTRACT_VERSION.parse()?,
+        DexInstantiateMsg {
+            swap_fee: Decimal::zero(),
+            recipient_account: 0,
+        },
+        DeployStrategy::Try,
+    )?;
+    let money_market_adapter = MoneyMarketAdapter::new(MONEY_MARKET_ADAPTER_ID, chain.clone());
+    money_market_adapter.deploy(
+        CONTRACT_VERSION.parse()?,
+        MoneyMarketInstantiateMsg {
+            fee: Decimal::zero(),
+            recipient_account: 0,
+        },
+        DeployStrategy::Try,
+    )?;
+
+    let account = AccountI::create_default_account(&abstr, governance)?;
+    account.install_adapter(&money_market_adapter, &[])?;
+    chain.set_balance(&account.address()?, coins(1_000, JUNO))?;
+    Ok((chain, account, protocol, dex_adapter))
+}
+
+fn leverage(account: &AccountI<MockBech32>, target_ltv: Decimal) -> anyhow::Result<()> {
+    let msg: ExecuteMsg = AdapterRequestMsg::new(
+        None,
+        MoneyMarketExecuteMsg::Leverage {
+            money_market: MOCK.to_owned(),
+            target_ltv,
+            collateral: AnsAsset::new("juno", 1_000u128),
+            borrow_asset: "usd".into(),
+            dex: MOCK.to_owned(),
+            max_spread: None,
+        },
+    )
+    .into();
+    account.execute_on_module(MONEY_MARKET_ADAPTER_ID, msg, vec![])?;
+    Ok(())
+}
+
+fn position(
+    protocol: &MockProtocol<MockBech32>,
+    account: &AccountI<MockBech32>,
+) -> anyhow::Result<PositionResponse> {
+    let position = protocol.query(&QueryMsg::Position {
+        user: account.addr_str()?,
+    })?;
+    Ok(position)
+}
+
+fn ans_action(account: &AccountI<MockBech32>, action: MoneyMarketAnsAction) -> anyhow::Result<()> {
+    let msg: ExecuteMsg = AdapterRequestMsg::new(
+        None,
+        MoneyMarketExecuteMsg::AnsAction {
+            money_market: MOCK.to_owned(),
+            action,
+        },
+    )
+    .into();
+    account.execute_on_module(MONEY_MARKET_ADAPTER_ID, msg, vec![])?;
+    Ok(())
+}
+
+#[test]
+fn borrow_against_collateral() -> anyhow::Result<()> {
+    let (chain, account, protocol, _) = setup()?;
+
+    ans_action(
+        &account,
+        MoneyMarketAnsAction::ProvideCollateral {
+            borrowable_asset: "usd".into(),
+            collateral_asset: AnsAsset::new("juno", 1_000u128),
+        },
+    )?;
+    ans_action(
+        &account,
+        MoneyMarketAnsAction::Borrow {
+            borrow_asset: AnsAsset::new("usd", 500u128),
+            collateral_asset: "juno".into(),
+        },
+    )?;
+
+    let position = position(&protocol, &account)?;
+    assert_eq!(position.collateral, coins(1_000, JUNO));
+    assert_eq!(position.debts, coins(500, USD));
+    assert_eq!(position.ltv, Decimal::percent(50));
+    assert_eq!(chain.query_balance(&account.address()?, USD)?.u128(), 500);
+    assert_eq!(chain.query_balance(&account.address()?, JUNO)?.u128(), 0);
+    Ok(())
+}
+
+#[test]
+fn leverage_requires_dex_adapter() -> anyhow::Result<()> {
+    let (chain, account, protocol, _) = setup()?;
+
+    let err: anyhow::Error = leverage(&account, Decimal::percent(50)).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("install it on the account first"));
+    // Nothing is provided or borrowed
+    assert!(position(&protocol, &account)?.collateral.is_empty());
+    assert_eq!(
+        chain.query_balance(&account.address()?, JUNO)?.u128(),
+        1_000
+    );
+    Ok(())
+}
+
+#[test]
+fn leverage_and_deleverage() -> anyhow::Result<()> {
+    let (chain, account, protocol, dex_adapter) = setup()?;
+    account.install_adapter(&dex_adapter, &[])?;
+
+    leverage(&account, Decimal::percent(50))?;
+    let leveraged = position(&protocol, &account)?;
+    assert!(leveraged.ltv <= Decimal::percent(50));
+    assert!(leveraged.ltv > Decimal::percent(40));
+    // Borrowed usd was swapped to juno and all of it provided as collateral,
+    // including what the swaps returned above their minimum
+    assert!(leveraged.collateral[0].amount.u128() > 1_000);
+    assert_eq!(chain.query_balance(&account.address()?, USD)?.u128(), 0);
+    assert_eq!(chain.query_balance(&account.address()?, JUNO)?.u128(), 0);
+
+    let msg: ExecuteMsg = AdapterRequestMsg::new(
+        None,
+        MoneyMarketExecuteMsg::Deleverage {
+            money_market: MOCK.to_owned(),
+            target_ltv: Decimal::percent(20),
+            collateral_asset: "juno".into(),
+            borrowed_asset: "usd".into(),
+            dex: MOCK.to_owned(),
+            max_spread: None,
+        },
+    )
+    .into();
+    account.execute_on_module(MONEY_MARKET_ADAPTER_ID, msg, vec![])?;
+    let deleveraged = position(&protocol, &account)?;
+    assert!(deleveraged.ltv < Decimal::percent(21));
+    assert!(deleveraged.debt_value < leveraged.debt_value);
+    // Everything the swaps returned is repaid
+    assert_eq!(chain.query_balance(&account.address()?, USD)?.u128(), 0);
+    assert_eq!(chain.query_balance(&account.address()?, JUNO)?.u128(), 0);
+    Ok(())
+}
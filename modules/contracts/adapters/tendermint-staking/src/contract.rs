@@ -1,13 +1,19 @@
-use std::iter;
-
-use abstract_adapter::sdk::Execution;
+use abstract_adapter::sdk::{AbstractResponse, Execution};
 use abstract_adapter::AdapterContract;
-use cosmwasm_std::{DepsMut, Empty, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, Storage, Uint128,
+};
 
 use crate::{
     error::TendermintStakeError,
-    msg::{TendermintStakingExecuteMsg, TendermintStakingQueryMsg},
+    msg::{
+        TargetValidatorsResponse, TendermintStakingExecuteMsg, TendermintStakingQueryMsg,
+        ValidatorWeight, DEFAULT_REDELEGATION_COOLDOWN,
+    },
+    policy::{drift, plan_rebalance, target_amounts},
     staking::*,
+    state::{PendingRedelegation, ValidatorPolicy, PENDING_REDELEGATIONS, VALIDATOR_POLICIES},
     TENDERMINT_STAKING,
 };
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -21,7 +27,8 @@ pub type TendermintStakeAdapter = AdapterContract<
 
 pub const STAKING_ADAPTER: TendermintStakeAdapter =
     TendermintStakeAdapter::new(TENDERMINT_STAKING, CONTRACT_VERSION, None)
-        .with_execute(handle_request);
+        .with_execute(handle_request)
+        .with_query(handle_query);
 
 pub type TendermintStakeResult = Result<Response, TendermintStakeError>;
 
@@ -38,25 +45,25 @@ abstract_adapter::cw_orch_interface!(
 
 pub fn handle_request(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _info: MessageInfo,
     module: TendermintStakeAdapter,
     msg: TendermintStakingExecuteMsg,
 ) -> TendermintStakeResult {
-    let executor = module.executor(deps.as_ref());
-    let msg = match msg {
-        TendermintStakingExecuteMsg::Delegate { validator, amount } => executor.execute(
-            iter::once(delegate_to(&deps.querier, &validator, amount.u128())?),
-        ),
+    let action = action_name(&msg);
+    let msgs: Vec<CosmosMsg> = match msg {
+        TendermintStakingExecuteMsg::Delegate { validator, amount } => {
+            vec![delegate_to(&deps.querier, &validator, amount.u128())?]
+        }
         TendermintStakingExecuteMsg::UndelegateFrom { validator, amount } => {
             let undelegate_msg = match amount {
                 Some(amount) => undelegate_from(&deps.querier, &validator, amount.u128())?,
                 None => undelegate_all_from(&deps.querier, module.target()?, &validator)?,
             };
-            executor.execute(iter::once(undelegate_msg))
+            vec![undelegate_msg]
         }
         TendermintStakingExecuteMsg::UndelegateAll {} => {
-            executor.execute(undelegate_all(&deps.querier, module.target()?)?)
+            undelegate_all(&deps.querier, module.target()?)?
         }
 
         TendermintStakingExecuteMsg::Redelegate {
@@ -64,6 +71,19 @@ pub fn handle_request(
             destination_validator,
             amount,
         } => {
+            let account = module.target()?;
+            let cooldown = VALIDATOR_POLICIES
+                .may_load(deps.storage, account)?
+                .map_or(DEFAULT_REDELEGATION_COOLDOWN, |policy| {
+                    policy.redelegation_cooldown
+                });
+            record_redelegations(
+                deps.storage,
+                &env,
+                account,
+                cooldown,
+                vec![destination_validator.clone()],
+            )?;
             let redelegate_msg = match amount {
                 Some(amount) => redelegate(
                     &deps.querier,
@@ -78,20 +98,489 @@ pub fn handle_request(
                     module.target()?,
                 )?,
             };
-            executor.execute(iter::once(redelegate_msg))
+            vec![redelegate_msg]
         }
         TendermintStakingExecuteMsg::SetWithdrawAddress {
             new_withdraw_address,
-        } => executor.execute(iter::once(update_withdraw_address(
-            deps.api,
-            &new_withdraw_address,
-        )?)),
+        } => vec![update_withdraw_address(deps.api, &new_withdraw_address)?],
         TendermintStakingExecuteMsg::WithdrawDelegatorReward { validator } => {
-            executor.execute(iter::once(withdraw_rewards(&validator)))
+            vec![withdraw_rewards(&validator)]
         }
         TendermintStakingExecuteMsg::WithdrawAllRewards {} => {
-            executor.execute(withdraw_all_rewards(&deps.querier, module.target()?)?)
+            withdraw_all_rewards(&deps.querier, module.target()?)?
+        }
+        TendermintStakingExecuteMsg::SetTargetValidators {
+            validators,
+            redelegation_cooldown,
+        } => {
+            validate_targets(deps.as_ref(), module.target()?, &validators)?;
+            let policy = ValidatorPolicy {
+                validators,
+                redelegation_cooldown: redelegation_cooldown
+                    .unwrap_or(DEFAULT_REDELEGATION_COOLDOWN),
+            };
+            VALIDATOR_POLICIES.save(deps.storage, module.target()?, &policy)?;
+            vec![]
+        }
+        TendermintStakingExecuteMsg::Rebalance {} => {
+            let account = module.target()?;
+            let policy = load_policy(deps.as_ref(), account)?;
+            let delegations = delegated_amounts(deps.as_ref(), account)?;
+            let total = delegations.iter().map(|(_, amount)| *amount).sum();
+            let targets = target_amounts(&policy.validators, total);
+            let locked = locked_validators(deps.storage, &env, account)?;
+
+            let redelegations = plan_rebalance(&delegations, &targets, &locked);
+            record_redelegations(
+                deps.storage,
+                &env,
+                account,
+                policy.redelegation_cooldown,
+                redelegations
+                    .iter()
+                    .map(|redelegation| redelegation.destination.clone())
+                    .collect(),
+            )?;
+            redelegations
+                .into_iter()
+                .map(|redelegation| {
+                    redelegate(
+                        &deps.querier,
+                        &redelegation.source,
+                        &redelegation.destination,
+                        redelegation.amount.u128(),
+                    )
+                })
+                .collect::<StdResult<Vec<_>>>()?
+        }
+        TendermintStakingExecuteMsg::RestakeRewards {} => {
+            let account = module.target()?;
+            let policy = load_policy(deps.as_ref(), account)?;
+            // The rewards are only delegated if they're withdrawn to the Account
+            let withdraw_address = deps.querier.query_delegator_withdraw_address(account)?;
+            if &withdraw_address != account {
+                return Err(TendermintStakeError::RewardsNotWithdrawnToAccount {
+                    account: account.to_string(),
+                    withdraw_address: withdraw_address.to_string(),
+                });
+            }
+            let denom = deps.querier.query_bonded_denom()?;
+            let mut rewards = Uint128::zero();
+            for delegation in deps.querier.query_all_delegations(account)? {
+                if let Some(delegation) = deps
+                    .querier
+                    .query_delegation(account, delegation.validator)?
+                {
+                    rewards += delegation
+                        .accumulated_rewards
+                        .iter()
+                        .filter(|coin| coin.denom == denom)
+                        .map(|coin| coin.amount)
+                        .sum::<Uint128>();
+                }
+            }
+            let mut msgs: Vec<CosmosMsg> = withdraw_all_rewards(&deps.querier, account)?;
+            for (validator, amount) in target_amounts(&policy.validators, rewards) {
+                if !amount.is_zero() {
+                    msgs.push(delegate_to(&deps.querier, &validator, amount.u128())?);
+                }
+            }
+            msgs
+        }
+    };
+
+    let mut response = module.custom_response(action, Vec::<(&str, String)>::new());
+    if !msgs.is_empty() {
+        response = response.add_message(module.executor(deps.as_ref()).execute(msgs)?);
+    }
+    Ok(response)
+}
+
+fn action_name(msg: &TendermintStakingExecuteMsg) -> &'static str {
+    match msg {
+        TendermintStakingExecuteMsg::Delegate { .. } => "delegate",
+        TendermintStakingExecuteMsg::UndelegateFrom { .. } => "undelegate_from",
+        TendermintStakingExecuteMsg::UndelegateAll {} => "undelegate_all",
+        TendermintStakingExecuteMsg::Redelegate { .. } => "redelegate",
+        TendermintStakingExecuteMsg::SetWithdrawAddress { .. } => "set_withdraw_address",
+        TendermintStakingExecuteMsg::WithdrawDelegatorReward { .. } => "withdraw_delegator_reward",
+        TendermintStakingExecuteMsg::WithdrawAllRewards {} => "withdraw_all_rewards",
+        TendermintStakingExecuteMsg::SetTargetValidators { .. } => "set_target_validators",
+        TendermintStakingExecuteMsg::Rebalance {} => "rebalance",
+        TendermintStakingExecuteMsg::RestakeRewards {} => "restake_rewards",
+    }
+}
+
+pub fn handle_query(
+    deps: Deps,
+    _env: Env,
+    _module: &TendermintStakeAdapter,
+    msg: TendermintStakingQueryMsg,
+) -> Result<Binary, TendermintStakeError> {
+    match msg {
+        TendermintStakingQueryMsg::TargetValidators { account } => {
+            let account = deps.api.addr_validate(&account)?;
+            let policy = load_policy(deps, &account)?;
+            to_json_binary(&TargetValidatorsResponse {
+                validators: policy.validators,
+                redelegation_cooldown: policy.redelegation_cooldown,
+            })
+        }
+        TendermintStakingQueryMsg::Drift { account } => {
+            let account = deps.api.addr_validate(&account)?;
+            let policy = load_policy(deps, &account)?;
+            let delegations = delegated_amounts(deps, &account)?;
+            to_json_binary(&drift(&policy.validators, &delegations))
+        }
+    }
+    .map_err(Into::into)
+}
+
+fn load_policy(deps: Deps, account: &Addr) -> Result<ValidatorPolicy, TendermintStakeError> {
+    VALIDATOR_POLICIES
+        .may_load(deps.storage, account)?
+        .ok_or_else(|| TendermintStakeError::NoTargetValidators(account.to_string()))
+}
+
+fn validate_targets(
+    deps: Deps,
+    account: &Addr,
+    validators: &[ValidatorWeight],
+) -> Result<(), TendermintStakeError> {
+    if validators.is_empty() {
+        return Err(TendermintStakeError::NoTargetValidators(
+            account.to_string(),
+        ));
+    }
+    for (i, target) in validators.iter().enumerate() {
+        if target.weight.is_zero() {
+            return Err(TendermintStakeError::ZeroWeight(target.validator.clone()));
+        }
+        if validators[..i]
+            .iter()
+            .any(|other| other.validator == target.validator)
+        {
+            return Err(TendermintStakeError::DuplicateValidator(
+                target.validator.clone(),
+            ));
+        }
+        if deps.querier.query_validator(&target.validator)?.is_none() {
+            return Err(TendermintStakeError::UnknownValidator(
+                target.validator.clone(),
+            ));
         }
-    }?;
-    Ok(Response::new().add_message(msg))
+    }
+    Ok(())
+}
+
+/// Delegated amount of the Account per validator
+fn delegated_amounts(
+    deps: Deps,
+    account: &Addr,
+) -> Result<Vec<(String, Uint128)>, TendermintStakeError> {
+    Ok(deps
+        .querier
+        .query_all_delegations(account)?
+        .into_iter()
+        .map(|delegation| (delegation.validator, delegation.amount.amount))
+        .collect())
+}
+
+/// Validators that received a redelegation that isn't completed yet.
+/// Redelegations from these validators are rejected by the chain.
+fn locked_validators(
+    storage: &dyn Storage,
+    env: &Env,
+    account: &Addr,
+) -> Result<Vec<String>, TendermintStakeError> {
+    Ok(PENDING_REDELEGATIONS
+        .may_load(storage, account)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|pending| pending.completion_time > env.block.time)
+        .map(|pending| pending.validator)
+        .collect())
+}
+
+/// Track the redelegations to the `validators` until their cooldown passed
+fn record_redelegations(
+    storage: &mut dyn Storage,
+    env: &Env,
+    account: &Addr,
+    cooldown: u64,
+    validators: Vec<String>,
+) -> Result<(), TendermintStakeError> {
+    let completion_time = env.block.time.plus_seconds(cooldown);
+    let mut pending: Vec<PendingRedelegation> = PENDING_REDELEGATIONS
+        .may_load(storage, account)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|pending| pending.completion_time > env.block.time)
+        .collect();
+    pending.extend(validators.into_iter().map(|validator| PendingRedelegation {
+        validator,
+        completion_time,
+    }));
+    PENDING_REDELEGATIONS.save(storage, account, &pending)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use abstract_adapter::std::{account, registry::Account};
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{
+        coin, coins, from_json, Decimal, DistributionMsg, FullDelegation, OwnedDeps, StakingMsg,
+        Validator, WasmMsg,
+    };
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    const DENOM: &str = "ustake";
+
+    /// Account with the `delegations` to validators "a", "b" and "c", each earning 10 of rewards
+    fn setup(delegations: &[(&str, u128)]) -> MockDeps {
+        let mut deps = mock_dependencies();
+        let account = deps.api.addr_make("account");
+        let validators: Vec<Validator> = ["a", "b", "c"]
+            .into_iter()
+            .map(|validator| {
+                Validator::create(
+                    validator.to_string(),
+                    Decimal::percent(5),
+                    Decimal::percent(10),
+                    Decimal::percent(1),
+                )
+            })
+            .collect();
+        let delegations: Vec<FullDelegation> = delegations
+            .iter()
+            .map(|(validator, amount)| {
+                FullDelegation::create(
+                    account.clone(),
+                    validator.to_string(),
+                    coin(*amount, DENOM),
+                    coin(*amount, DENOM),
+                    coins(10, DENOM),
+                )
+            })
+            .collect();
+        deps.querier
+            .update_staking(DENOM, &validators, &delegations);
+        deps
+    }
+
+    fn account_addr(deps: &MockDeps) -> Addr {
+        deps.api.addr_make("account")
+    }
+
+    fn execute(
+        deps: &mut MockDeps,
+        env: &Env,
+        msg: TendermintStakingExecuteMsg,
+    ) -> TendermintStakeResult {
+        let mut module = STAKING_ADAPTER;
+        module.target_account = Some(Account::new(account_addr(deps)));
+        let info = message_info(&deps.api.addr_make("owner"), &[]);
+        handle_request(deps.as_mut(), env.clone(), info, module, msg)
+    }
+
+    fn set_targets(
+        deps: &mut MockDeps,
+        targets: &[(&str, u64)],
+        redelegation_cooldown: Option<u64>,
+    ) -> TendermintStakeResult {
+        let validators = targets
+            .iter()
+            .map(|(validator, weight)| ValidatorWeight {
+                validator: validator.to_string(),
+                weight: Decimal::percent(*weight),
+            })
+            .collect();
+        execute(
+            deps,
+            &mock_env(),
+            TendermintStakingExecuteMsg::SetTargetValidators {
+                validators,
+                redelegation_cooldown,
+            },
+        )
+    }
+
+    /// Messages the response executes on the Account
+    fn account_msgs(response: &Response) -> Vec<CosmosMsg> {
+        response
+            .messages
+            .iter()
+            .flat_map(|sub_msg| {
+                let CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) = &sub_msg.msg else {
+                    panic!("expected an execution on the Account");
+                };
+                match from_json(msg).unwrap() {
+                    account::ExecuteMsg::Execute { msgs } => msgs,
+                    other => panic!("unexpected Account message {other:?}"),
+                }
+            })
+            .collect()
+    }
+
+    fn redelegation(source: &str, destination: &str, amount: u128) -> CosmosMsg {
+        CosmosMsg::Staking(StakingMsg::Redelegate {
+            src_validator: source.to_string(),
+            dst_validator: destination.to_string(),
+            amount: coin(amount, DENOM),
+        })
+    }
+
+    fn locked(deps: &MockDeps, env: &Env) -> Vec<String> {
+        locked_validators(&deps.storage, env, &account_addr(deps)).unwrap()
+    }
+
+    #[test]
+    fn target_validators_are_validated() {
+        let mut deps = setup(&[]);
+        let account = account_addr(&deps).to_string();
+
+        assert_eq!(
+            set_targets(&mut deps, &[], None).unwrap_err(),
+            TendermintStakeError::NoTargetValidators(account.clone())
+        );
+        assert_eq!(
+            set_targets(&mut deps, &[("a", 50), ("b", 0)], None).unwrap_err(),
+            TendermintStakeError::ZeroWeight("b".to_string())
+        );
+        assert_eq!(
+            set_targets(&mut deps, &[("a", 50), ("a", 50)], None).unwrap_err(),
+            TendermintStakeError::DuplicateValidator("a".to_string())
+        );
+        assert_eq!(
+            set_targets(&mut deps, &[("a", 50), ("x", 50)], None).unwrap_err(),
+            TendermintStakeError::UnknownValidator("x".to_string())
+        );
+
+        let response = set_targets(&mut deps, &[("a", 50), ("b", 50)], None).unwrap();
+        assert!(response.messages.is_empty());
+        let policy = VALIDATOR_POLICIES
+            .load(&deps.storage, &account_addr(&deps))
+            .unwrap();
+        assert_eq!(policy.validators.len(), 2);
+        assert_eq!(policy.redelegation_cooldown, DEFAULT_REDELEGATION_COOLDOWN);
+    }
+
+    #[test]
+    fn redelegations_lock_their_destination_for_the_cooldown() {
+        let mut deps = setup(&[("a", 100), ("c", 50)]);
+        set_targets(&mut deps, &[("a", 50), ("b", 50)], Some(100)).unwrap();
+        let env = mock_env();
+
+        execute(
+            &mut deps,
+            &env,
+            TendermintStakingExecuteMsg::Redelegate {
+                source_validator: "c".to_string(),
+                destination_validator: "a".to_string(),
+                amount: Some(10u128.into()),
+            },
+        )
+        .unwrap();
+        assert_eq!(locked(&deps, &env), vec!["a".to_string()]);
+
+        // "a" is above its target but can't be redelegated from
+        let response = execute(&mut deps, &env, TendermintStakingExecuteMsg::Rebalance {}).unwrap();
+        assert_eq!(account_msgs(&response), vec![redelegation("c", "b", 50)]);
+        assert_eq!(locked(&deps, &env), vec!["a".to_string(), "b".to_string()]);
+
+        let mut later = env.clone();
+        later.block.time = env.block.time.plus_seconds(100);
+        assert!(locked(&deps, &later).is_empty());
+        let response =
+            execute(&mut deps, &later, TendermintStakingExecuteMsg::Rebalance {}).unwrap();
+        assert_eq!(
+            account_msgs(&response),
+            vec![redelegation("a", "b", 25), redelegation("c", "b", 50)]
+        );
+    }
+
+    #[test]
+    fn balanced_delegations_are_not_rebalanced() {
+        let mut deps = setup(&[("a", 50), ("b", 50)]);
+        set_targets(&mut deps, &[("a", 50), ("b", 50)], None).unwrap();
+        let env = mock_env();
+
+        let response = execute(&mut deps, &env, TendermintStakingExecuteMsg::Rebalance {}).unwrap();
+        assert!(response.messages.is_empty());
+        assert!(locked(&deps, &env).is_empty());
+    }
+
+    #[test]
+    fn rewards_are_restaked_by_weight() {
+        let mut deps = setup(&[("a", 100), ("c", 100)]);
+        set_targets(&mut deps, &[("a", 25), ("b", 75)], None).unwrap();
+
+        let response = execute(
+            &mut deps,
+            &mock_env(),
+            TendermintStakingExecuteMsg::RestakeRewards {},
+        )
+        .unwrap();
+        let delegation = |validator: &str, amount: u128| {
+            CosmosMsg::Staking(StakingMsg::Delegate {
+                validator: validator.to_string(),
+                amount: coin(amount, DENOM),
+            })
+        };
+        let withdrawal = |validator: &str| {
+            CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+                validator: validator.to_string(),
+            })
+        };
+        assert_eq!(
+            account_msgs(&response),
+            vec![
+                withdrawal("a"),
+                withdrawal("c"),
+                delegation("a", 5),
+                delegation("b", 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn rewards_withdrawn_elsewhere_are_not_restaked() {
+        let mut deps = setup(&[("a", 100)]);
+        set_targets(&mut deps, &[("a", 100)], None).unwrap();
+        let account = account_addr(&deps).to_string();
+        let treasury = deps.api.addr_make("treasury").to_string();
+        let env = mock_env();
+
+        // Withdraw address set on the chain, without the adapter
+        deps.querier
+            .distribution
+            .set_withdraw_address(&account, &treasury);
+        assert_eq!(
+            execute(
+                &mut deps,
+                &env,
+                TendermintStakingExecuteMsg::RestakeRewards {}
+            )
+            .unwrap_err(),
+            TendermintStakeError::RewardsNotWithdrawnToAccount {
+                account: account.clone(),
+                withdraw_address: treasury,
+            }
+        );
+
+        deps.querier
+            .distribution
+            .set_withdraw_address(&account, &account);
+        execute(
+            &mut deps,
+            &env,
+            TendermintStakingExecuteMsg::RestakeRewards {},
+        )
+        .unwrap();
+    }
 }
@@ -17,4 +17,22 @@ pub enum TendermintStakeError {
 
     #[error(transparent)]
     AdapterError(#[from] AdapterError),
+
+    #[error("No target validators set for Account {0}")]
+    NoTargetValidators(String),
+
+    #[error("Validator {0} is not a known validator")]
+    UnknownValidator(String),
+
+    #[error("Validator {0} is targeted more than once")]
+    DuplicateValidator(String),
+
+    #[error("Weight of validator {0} must be positive")]
+    ZeroWeight(String),
+
+    #[error("Rewards of Account {account} are withdrawn to {withdraw_address}, not to the Account")]
+    RewardsNotWithdrawnToAccount {
+        account: String,
+        withdraw_address: String,
+    },
 }
@@ -1,7 +1,9 @@
 pub mod contract;
 pub mod error;
 pub mod msg;
+mod policy;
 mod staking;
+pub mod state;
 
 pub const TENDERMINT_STAKING: &str = "abstract:tendermint-staking";
 
@@ -4,7 +4,7 @@
 
 use abstract_adapter::std::adapter;
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Empty, Uint128};
+use cosmwasm_std::{Decimal, Empty, Uint128};
 
 pub type InstantiateMsg = adapter::InstantiateMsg<Empty>;
 pub type ExecuteMsg = adapter::ExecuteMsg<TendermintStakingExecuteMsg>;
@@ -13,6 +13,10 @@ pub type QueryMsg = adapter::QueryMsg<TendermintStakingQueryMsg>;
 impl adapter::AdapterExecuteMsg for TendermintStakingExecuteMsg {}
 impl adapter::AdapterQueryMsg for TendermintStakingQueryMsg {}
 
+/// Default cooldown before a validator that received a redelegation can be redelegated from,
+/// the unbonding time of most chains.
+pub const DEFAULT_REDELEGATION_COOLDOWN: u64 = 21 * 24 * 60 * 60;
+
 #[cosmwasm_schema::cw_serde]
 #[derive(cw_orch::ExecuteFns)]
 pub enum TendermintStakingExecuteMsg {
@@ -44,9 +48,65 @@ pub enum TendermintStakingExecuteMsg {
     },
     /// Withdraw all the rewards
     WithdrawAllRewards {},
+    /// Set the validators the Account delegates to, with their weights.
+    SetTargetValidators {
+        validators: Vec<ValidatorWeight>,
+        /// Seconds before a validator that received a redelegation can be redelegated from.
+        /// Defaults to [`DEFAULT_REDELEGATION_COOLDOWN`].
+        redelegation_cooldown: Option<u64>,
+    },
+    /// Redelegate to move the delegations towards the weights of the target validators.
+    /// Validators within their redelegation cooldown are not redelegated from.
+    Rebalance {},
+    /// Withdraw all the rewards and delegate them by the weights of the target validators.
+    RestakeRewards {},
 }
 
-/// Staking queries are available on [`cosmwasm_std::QuerierWrapper`] through [`cosmwasm_std::Deps`].
+/// Queries of the validator targets of Accounts.
+/// Delegations are available on [`cosmwasm_std::QuerierWrapper`] through [`cosmwasm_std::Deps`].
 #[cosmwasm_schema::cw_serde]
 #[derive(QueryResponses, cw_orch::QueryFns)]
-pub enum TendermintStakingQueryMsg {}
+pub enum TendermintStakingQueryMsg {
+    /// Target validators of the Account
+    #[returns(TargetValidatorsResponse)]
+    TargetValidators { account: String },
+    /// Drift of the delegations of the Account from the weights of its target validators
+    #[returns(DriftResponse)]
+    Drift { account: String },
+}
+
+/// Target validator of an Account.
+#[cosmwasm_schema::cw_serde]
+pub struct ValidatorWeight {
+    /// Validator address
+    pub validator: String,
+    /// Weight relative to the other target validators
+    pub weight: Decimal,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct TargetValidatorsResponse {
+    pub validators: Vec<ValidatorWeight>,
+    pub redelegation_cooldown: u64,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct DriftResponse {
+    pub total_delegated: Uint128,
+    /// Delegations of the target validators and of the other validators delegated to
+    pub validators: Vec<ValidatorDrift>,
+    /// Largest difference between the current and the target share of a validator
+    pub max_drift: Decimal,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ValidatorDrift {
+    /// Validator address
+    pub validator: String,
+    pub delegated: Uint128,
+    pub target: Uint128,
+    /// Share of the total delegated
+    pub current_share: Decimal,
+    /// Share of the total weight
+    pub target_share: Decimal,
+}
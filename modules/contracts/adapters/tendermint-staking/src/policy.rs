@@ -0,0 +1,224 @@
+//! # Validator policy
+//! Delegation targets of an Account and the redelegations that move its delegations towards them.
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::msg::{DriftResponse, ValidatorDrift, ValidatorWeight};
+
+/// Redelegation of `amount` from `source` to `destination`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Redelegation {
+    pub source: String,
+    pub destination: String,
+    pub amount: Uint128,
+}
+
+/// Amount to delegate to each validator to distribute `total` by weight.
+/// The last validator gets the rounding remainder.
+pub fn target_amounts(validators: &[ValidatorWeight], total: Uint128) -> Vec<(String, Uint128)> {
+    let total_weight = total_weight(validators);
+    let mut assigned = Uint128::zero();
+    validators
+        .iter()
+        .enumerate()
+        .map(|(i, target)| {
+            let amount = if i == validators.len() - 1 {
+                total - assigned
+            } else {
+                total.multiply_ratio(target.weight.atomics(), total_weight.atomics())
+            };
+            assigned += amount;
+            (target.validator.clone(), amount)
+        })
+        .collect()
+}
+
+/// Redelegations that move the `delegations` towards the `targets`.
+/// Validators that aren't targeted have a target of zero.
+/// `locked` validators received a redelegation that isn't completed and can't be redelegated from.
+pub fn plan_rebalance(
+    delegations: &[(String, Uint128)],
+    targets: &[(String, Uint128)],
+    locked: &[String],
+) -> Vec<Redelegation> {
+    let amount_of = |amounts: &[(String, Uint128)], validator: &str| {
+        amounts
+            .iter()
+            .find(|(v, _)| v == validator)
+            .map(|(_, amount)| *amount)
+            .unwrap_or_default()
+    };
+    let mut surpluses: Vec<(String, Uint128)> = delegations
+        .iter()
+        .filter(|(validator, _)| !locked.contains(validator))
+        .filter_map(|(validator, delegated)| {
+            let target = amount_of(targets, validator);
+            (*delegated > target).then(|| (validator.clone(), *delegated - target))
+        })
+        .collect();
+    let mut deficits: Vec<(String, Uint128)> = targets
+        .iter()
+        .filter_map(|(validator, target)| {
+            let delegated = amount_of(delegations, validator);
+            (*target > delegated).then(|| (validator.clone(), *target - delegated))
+        })
+        .collect();
+
+    let mut redelegations = vec![];
+    let (mut s, mut d) = (0, 0);
+    while s < surpluses.len() && d < deficits.len() {
+        let amount = surpluses[s].1.min(deficits[d].1);
+        redelegations.push(Redelegation {
+            source: surpluses[s].0.clone(),
+            destination: deficits[d].0.clone(),
+            amount,
+        });
+        surpluses[s].1 -= amount;
+        deficits[d].1 -= amount;
+        if surpluses[s].1.is_zero() {
+            s += 1;
+        }
+        if deficits[d].1.is_zero() {
+            d += 1;
+        }
+    }
+    redelegations
+}
+
+/// Drift of the `delegations` from the weights of the target `validators`.
+pub fn drift(validators: &[ValidatorWeight], delegations: &[(String, Uint128)]) -> DriftResponse {
+    let total_delegated: Uint128 = delegations.iter().map(|(_, amount)| *amount).sum();
+    let total_weight = total_weight(validators);
+    let targets = target_amounts(validators, total_delegated);
+    let share = |amount: Uint128| {
+        if total_delegated.is_zero() {
+            Decimal::zero()
+        } else {
+            Decimal::from_ratio(amount, total_delegated)
+        }
+    };
+
+    let mut drifts: Vec<ValidatorDrift> = validators
+        .iter()
+        .zip(targets)
+        .map(|(target, (validator, target_amount))| {
+            let delegated = delegations
+                .iter()
+                .find(|(v, _)| *v == validator)
+                .map(|(_, amount)| *amount)
+                .unwrap_or_default();
+            ValidatorDrift {
+                validator,
+                delegated,
+                target: target_amount,
+                current_share: share(delegated),
+                target_share: Decimal::from_ratio(target.weight.atomics(), total_weight.atomics()),
+            }
+        })
+        .collect();
+    // Delegations to validators that aren't targeted
+    drifts.extend(
+        delegations
+            .iter()
+            .filter(|(validator, _)| !validators.iter().any(|t| &t.validator == validator))
+            .map(|(validator, delegated)| ValidatorDrift {
+                validator: validator.clone(),
+                delegated: *delegated,
+                target: Uint128::zero(),
+                current_share: share(*delegated),
+                target_share: Decimal::zero(),
+            }),
+    );
+    let max_drift = drifts
+        .iter()
+        .map(|drift| drift.current_share.abs_diff(drift.target_share))
+        .max()
+        .unwrap_or_default();
+
+    DriftResponse {
+        total_delegated,
+        validators: drifts,
+        max_drift,
+    }
+}
+
+fn total_weight(validators: &[ValidatorWeight]) -> Decimal {
+    validators.iter().map(|target| target.weight).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn weight(validator: &str, weight: u64) -> ValidatorWeight {
+        ValidatorWeight {
+            validator: validator.to_string(),
+            weight: Decimal::percent(weight),
+        }
+    }
+
+    fn amounts(amounts: &[(&str, u128)]) -> Vec<(String, Uint128)> {
+        amounts
+            .iter()
+            .map(|(validator, amount)| (validator.to_string(), Uint128::new(*amount)))
+            .collect()
+    }
+
+    #[test]
+    fn targets_distribute_total_by_weight() {
+        let targets = target_amounts(
+            &[weight("a", 50), weight("b", 25), weight("c", 25)],
+            101u128.into(),
+        );
+        assert_eq!(targets, amounts(&[("a", 50), ("b", 25), ("c", 26)]));
+    }
+
+    #[test]
+    fn rebalance_moves_surplus_to_deficits() {
+        let delegations = amounts(&[("a", 100), ("x", 50)]);
+        let targets = amounts(&[("a", 50), ("b", 100)]);
+        let redelegations = plan_rebalance(&delegations, &targets, &[]);
+        assert_eq!(
+            redelegations,
+            vec![
+                Redelegation {
+                    source: "a".to_string(),
+                    destination: "b".to_string(),
+                    amount: 50u128.into(),
+                },
+                Redelegation {
+                    source: "x".to_string(),
+                    destination: "b".to_string(),
+                    amount: 50u128.into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rebalance_skips_locked_validators() {
+        let delegations = amounts(&[("a", 100), ("x", 50)]);
+        let targets = amounts(&[("a", 50), ("b", 100)]);
+        let redelegations = plan_rebalance(&delegations, &targets, &["a".to_string()]);
+        assert_eq!(
+            redelegations,
+            vec![Redelegation {
+                source: "x".to_string(),
+                destination: "b".to_string(),
+                amount: 50u128.into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn drift_includes_untargeted_validators() {
+        let drift = drift(
+            &[weight("a", 1), weight("b", 3)],
+            &amounts(&[("a", 50), ("x", 50)]),
+        );
+        assert_eq!(drift.total_delegated, Uint128::new(100));
+        assert_eq!(drift.validators.len(), 3);
+        assert_eq!(drift.validators[1].target, Uint128::new(75));
+        assert_eq!(drift.validators[2].target_share, Decimal::zero());
+        assert_eq!(drift.max_drift, Decimal::percent(75));
+    }
+}
@@ -0,0 +1,23 @@
+use cosmwasm_std::{Addr, Timestamp};
+use cw_storage_plus::Map;
+
+use crate::msg::ValidatorWeight;
+
+/// Validators an Account delegates to, with their weights.
+#[cosmwasm_schema::cw_serde]
+pub struct ValidatorPolicy {
+    pub validators: Vec<ValidatorWeight>,
+    /// Seconds before a validator that received a redelegation can be redelegated from
+    pub redelegation_cooldown: u64,
+}
+
+/// Redelegation to a validator that isn't completed yet.
+#[cosmwasm_schema::cw_serde]
+pub struct PendingRedelegation {
+    pub validator: String,
+    pub completion_time: Timestamp,
+}
+
+pub const VALIDATOR_POLICIES: Map<&Addr, ValidatorPolicy> = Map::new("validator_policies");
+pub const PENDING_REDELEGATIONS: Map<&Addr, Vec<PendingRedelegation>> =
+    Map::new("pending_redelegations");
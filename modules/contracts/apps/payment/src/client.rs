@@ -0,0 +1,19 @@
+//! Typed client of the payment app, for modules that depend on it.
+
+use abstract_app::sdk::{AppKind, ClientModule};
+
+pub use crate::msg::{AppExecuteMsgClient, AppQueryMsgClient};
+use crate::{
+    contract::APP_ID,
+    msg::{AppExecuteMsg, AppQueryMsg},
+};
+
+/// The payment app, called with `module.app::<PaymentApp>(deps)`.
+pub struct PaymentApp;
+
+impl ClientModule for PaymentApp {
+    const MODULE_ID: &'static str = APP_ID;
+    type Kind = AppKind;
+    type ExecuteMsg = AppExecuteMsg;
+    type QueryMsg = AppQueryMsg;
+}
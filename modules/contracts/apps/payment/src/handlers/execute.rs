@@ -3,14 +3,17 @@ use std::collections::HashSet;
 use abstract_app::sdk::{
     cw_helpers::{AbstractAttributes, Clearable},
     features::AbstractNameService,
-    AbstractResponse, TransferInterface,
+    AbstractResponse, ModuleClientInterface, TransferInterface,
 };
 use abstract_app::std::{
     ans_host::AssetPairingFilter,
     ans_host::AssetPairingMapEntry,
     objects::{AnsAsset, AssetEntry, DexName},
 };
-use abstract_dex_adapter::DexInterface;
+use abstract_dex_adapter::{
+    client::{DexAdapter, DexQueryMsgClient},
+    DexInterface,
+};
 use cosmwasm_std::{
     Addr, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, Storage, Uint128,
 };
@@ -130,8 +133,13 @@ pub fn tip(
             swap_msgs.push(trigger_swap_msg);
             attrs.push(("swap", format!("{} for {}", pay_asset.name, desired_asset)));
 
-            desired_asset_amount += dex
-                .simulate_swap(pay_asset.clone(), desired_asset.clone())?
+            desired_asset_amount += module
+                .adapter::<DexAdapter>(deps.as_ref())
+                .simulate_swap(
+                    pay_asset.clone(),
+                    desired_asset.clone(),
+                    pair.dex().to_owned(),
+                )?
                 .return_amount;
         } else {
             // If swap not found just accept payment
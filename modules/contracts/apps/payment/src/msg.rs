@@ -27,7 +27,7 @@ pub struct AppInstantiateMsg {
 
 /// PaymentApp execute messages
 #[cosmwasm_schema::cw_serde]
-#[derive(cw_orch::ExecuteFns)]
+#[derive(cw_orch::ExecuteFns, abstract_app::sdk::ModuleExecuteClient)]
 pub enum AppExecuteMsg {
     #[cw_orch(payable)]
     Tip {},
@@ -59,7 +59,7 @@ impl From<AppExecuteMsg> for CustomExecuteMsg {
 }
 
 #[cosmwasm_schema::cw_serde]
-#[derive(QueryResponses, cw_orch::QueryFns)]
+#[derive(QueryResponses, cw_orch::QueryFns, abstract_app::sdk::ModuleQueryClient)]
 pub enum AppQueryMsg {
     /// Returns [`ConfigResponse`]
     #[returns(ConfigResponse)]